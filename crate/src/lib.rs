@@ -1,19 +1,66 @@
 pub use squads_mpl::errors;
 pub use squads_mpl::ID;
 pub use squads_mpl::program::SquadsMpl;
+pub use squads_mpl::pda;
 
 
 pub mod state {
     pub use squads_mpl::state::{
-       IncomingInstruction, MsTransaction, MsAccountMeta, Ms, MsTransactionStatus 
+       IncomingInstruction, MsTransaction, MsAccountMeta, Ms, MsTransactionStatus, MsInstruction,
+       MemberWeight, MemberExpiration, MemberPermissions, Permissions, ApprovalRequest, SignedVote,
+       SpendingLimit, RecurringTransaction, Delegate, VoteRecord, Allowance, MsMetadata, VoteCounts,
+       ProgramAllowlist, DeniedInstruction, PendingRemoval, Recovery,
+       MemberRecovery, MsConfigAction, MsConfigTransaction, AuthorityRecord, StakeAction, VoteAction,
+       PolicyConfig, PolicyStage, MsActivityLog, ActivityEntry, ActivityAction,
+       TransactionTag, VoteMemo, OpenTransactions, GovernanceAction, MembershipGate, TokenVoteRecord,
+       StakeWeightSource, StakeSnapshot, SessionKey, ValueTier,
+       ExecutionCondition, OracleKind, PriceComparison, ExecutionWindow, BlackoutPeriod,
+       ManagedProgram, ProgramUpgrade, ProgramRollback, IdlAction, Ratification, TypedAction
     };
 }
 
+/// Account and argument structs for building squads-mpl instructions directly,
+/// ie. off-chain or from programs that don't go through a CpiContext.
+pub mod instructions {
+    use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, InstructionData, ToAccountMetas};
+
+    pub use squads_mpl::accounts;
+    pub use squads_mpl::instruction as args;
+
+    /// Builds a squads-mpl instruction from its typed accounts and arguments, ie.
+    /// `build_instruction(accounts::VoteTransaction { .. }, args::ApproveTransaction {})`.
+    /// Remaining accounts can be appended to the returned instruction's accounts.
+    pub fn build_instruction(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+        build_instruction_for(squads_mpl::ID, accounts, args)
+    }
+
+    /// Same as build_instruction, for a deployment of squads-mpl at another program id.
+    pub fn build_instruction_for(program_id: Pubkey, accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+        Instruction {
+            program_id,
+            accounts: accounts.to_account_metas(None),
+            data: args.data(),
+        }
+    }
+}
+
 pub mod cpi {
     use anchor_lang::prelude::{CpiContext, Result, Pubkey};
 
     pub use squads_mpl::cpi::accounts::{
-        ActivateTransaction, VoteTransaction, AddInstruction,CancelTransaction, Create, CreateTransaction, ExecuteInstruction, MsAuth, MsAuthRealloc, ExecuteTransaction
+        ActivateTransaction, VoteTransaction, AddInstruction, AddInstructions, CancelTransaction, Create, CreateTransaction, CreateLinkedTransaction, ExecuteInstruction, ExpireTransaction, FundExecutionBounty, CloseExecutedInstructions, SetLookupTables, MsAuth, MsAuthRealloc, MigrateBaselineMultisig, CloseMultisig, ExecuteTransaction, AddSpendingLimit, RemoveSpendingLimit, SpendFromVault, PruneExpiredMember, Freeze, CreateMetadata, UpdateMetadata, SetTransactionDescription, SubmitSignedVote, AddDelegate, RemoveDelegate, SubmitDelegatedVote, CreateRecurringTransaction, RemoveRecurringTransaction, ExecuteRecurringTransaction, UpgradeProgram, AddAllowance, RemoveAllowance, SpendAllowance, CreateVoteRecord, ChangeVoteRecord, CloseVoteRecord, MigrateTransaction, FinalizeExecution, AppendInstructionData, RemoveInstruction, ReplaceInstruction, CloneTransaction, ViewMultisig, ViewTransaction, FinalizeMemberRemoval, AbortMemberRemoval, AddRecovery, RemoveRecovery, Recover,
+        ProposeMemberRecovery, ApproveMemberRecovery, ExecuteMemberRecovery, CancelMemberRecovery,
+        CreateConfigTransaction, VoteConfigTransaction, ExecuteConfigTransaction, RetireAuthority,
+        AddNamedAuthority, UpdateAuthorityRecord, CreateVaultAta, AddTypedInstruction,
+        AddPolicy, RemovePolicy, CreateActivityLog, CloseActivityLog,
+        AddVoteMemo, CloseDraftTransaction, CloseStaleTransaction,
+        InvalidateTransaction, SetCoCreators, ApproveAndExecute,
+        CreateTokenVoteRecord, CloseTokenVoteRecord, ActivateWithStakeSnapshot, CloseStakeSnapshot,
+        AddSessionKey, RemoveSessionKey, SubmitSessionVote, AdminConfig, RenounceAdmin,
+        CreateManagedProgram, RemoveManagedProgram, CreateProgramUpgrade, CloseProgramUpgrade,
+        SetRollbackThreshold, ProposeRollback, ApproveRollback, ExecuteRollback, CancelRollback,
+        CreateMemberPage, MsMembersAuth, CreatePagedVoteRecord, CreateProofVoteRecord,
+        CouncilVetoTransaction, CouncilVetoRecurringTransaction
     };
     
     pub fn create_multisig<'info>(
@@ -26,6 +73,18 @@ pub mod cpi {
         squads_mpl::cpi::create(ctx, threshold, creator, members, name)
     }
 
+    pub fn create_multisig_with_admin<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, Create<'info>>,
+        creator: Pubkey,
+        threshold: u16,
+        members: Vec<Pubkey>,
+        name: String,
+        admin: Pubkey,
+        admin_expires_at_slot: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_with_admin(ctx, threshold, creator, members, name, admin, admin_expires_at_slot)
+    }
+
     pub fn create_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, CreateTransaction<'info>>,
         authority_index: u32,
@@ -33,12 +92,284 @@ pub mod cpi {
         squads_mpl::cpi::create_transaction(ctx, authority_index)
     }
 
+    pub fn create_transaction_v2<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateTransaction<'info>>,
+        authority_index: u32,
+        incoming_instructions: Vec<crate::state::IncomingInstruction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_transaction_v2(ctx, authority_index, incoming_instructions)
+    }
+
+    pub fn create_linked_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateLinkedTransaction<'info>>,
+        authority_index: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_linked_transaction(ctx, authority_index)
+    }
+
+    pub fn add_instructions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddInstructions<'info>>,
+        incoming_instructions: Vec<crate::state::IncomingInstruction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_instructions(ctx, incoming_instructions)
+    }
+
+    pub fn get_transaction_status<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ViewTransaction<'info>>,
+    ) -> Result<crate::state::MsTransactionStatus> {
+        Ok(squads_mpl::cpi::get_transaction_status(ctx)?.get())
+    }
+
+    pub fn get_vote_counts<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ViewTransaction<'info>>,
+    ) -> Result<crate::state::VoteCounts> {
+        Ok(squads_mpl::cpi::get_vote_counts(ctx)?.get())
+    }
+
+    pub fn is_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ViewMultisig<'info>>,
+        member: Pubkey,
+    ) -> Result<bool> {
+        Ok(squads_mpl::cpi::is_member(ctx, member)?.get())
+    }
+
+    pub fn create_config_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateConfigTransaction<'info>>,
+        actions: Vec<crate::state::MsConfigAction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_config_transaction(ctx, actions)
+    }
+
+    pub fn approve_config_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VoteConfigTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_config_transaction(ctx)
+    }
+
+    pub fn reject_config_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VoteConfigTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::reject_config_transaction(ctx)
+    }
+
+    pub fn execute_config_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteConfigTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_config_transaction(ctx)
+    }
+
+    pub fn clone_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloneTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::clone_transaction(ctx)
+    }
+
+    pub fn remove_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveInstruction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_instruction(ctx)
+    }
+
+    pub fn replace_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ReplaceInstruction<'info>>,
+        incoming_instruction: crate::state::IncomingInstruction,
+    ) -> Result<()> {
+        squads_mpl::cpi::replace_instruction(ctx, incoming_instruction)
+    }
+
+    pub fn append_instruction_data<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AppendInstructionData<'info>>,
+        data: Vec<u8>,
+        final_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        squads_mpl::cpi::append_instruction_data(ctx, data, final_hash)
+    }
+
     pub fn activate_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
     ) -> Result<()> {
         squads_mpl::cpi::activate_transaction(ctx)
     }
 
+    pub fn activate_with_stake_snapshot<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ActivateWithStakeSnapshot<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::activate_with_stake_snapshot(ctx)
+    }
+
+    pub fn close_stake_snapshot<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseStakeSnapshot<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_stake_snapshot(ctx)
+    }
+
+    pub fn set_transaction_expiration<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
+        expires_at: i64,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_transaction_expiration(ctx, expires_at)
+    }
+
+    pub fn set_transaction_tag<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
+        tag: crate::state::TransactionTag,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_transaction_tag(ctx, tag)
+    }
+
+    pub fn set_execution_condition<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
+        execution_condition: Option<crate::state::ExecutionCondition>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_execution_condition(ctx, execution_condition)
+    }
+
+    pub fn set_ratifying_proposal<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
+        ratifying_proposal: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_ratifying_proposal(ctx, ratifying_proposal)
+    }
+
+    pub fn expire_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExpireTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::expire_transaction(ctx)
+    }
+
+    pub fn fund_execution_bounty<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FundExecutionBounty<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::fund_execution_bounty(ctx, amount)
+    }
+
+    pub fn set_lookup_tables<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SetLookupTables<'info>>,
+        lookup_tables: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_lookup_tables(ctx, lookup_tables)
+    }
+
+    pub fn close_executed_instructions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseExecutedInstructions<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_executed_instructions(ctx)
+    }
+
+    pub fn close_draft_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseDraftTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_draft_transaction(ctx)
+    }
+
+    pub fn close_stale_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseStaleTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_stale_transaction(ctx)
+    }
+
+    pub fn invalidate_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, InvalidateTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::invalidate_transaction(ctx)
+    }
+
+    pub fn set_co_creators<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SetCoCreators<'info>>,
+        co_creators: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_co_creators(ctx, co_creators)
+    }
+
+    pub fn approve_and_execute<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ApproveAndExecute<'info>>,
+        account_list: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_and_execute(ctx, account_list)
+    }
+
+    pub fn abstain_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::abstain_transaction(ctx)
+    }
+
+    pub fn create_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_vote_record(ctx, approve)
+    }
+
+    pub fn change_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ChangeVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_vote_record(ctx, approve)
+    }
+
+    pub fn close_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseVoteRecord<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_vote_record(ctx)
+    }
+
+    pub fn create_token_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateTokenVoteRecord<'info>>,
+        voting_key: Pubkey,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_token_vote_record(ctx, voting_key, approve)
+    }
+
+    pub fn create_paged_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreatePagedVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_paged_vote_record(ctx, approve)
+    }
+
+    pub fn create_proof_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateProofVoteRecord<'info>>,
+        approve: bool,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_proof_vote_record(ctx, approve, proof)
+    }
+
+    pub fn close_token_vote_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseTokenVoteRecord<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_token_vote_record(ctx)
+    }
+
+    pub fn add_vote_memo<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddVoteMemo<'info>>,
+        vote: squads_mpl::events::Vote,
+        memo: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_vote_memo(ctx, vote, memo)
+    }
+
+    pub fn veto_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CancelTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::veto_transaction(ctx)
+    }
+
+    pub fn council_veto_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CouncilVetoTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::council_veto_transaction(ctx)
+    }
+
+    pub fn council_veto_recurring_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CouncilVetoRecurringTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::council_veto_recurring_transaction(ctx)
+    }
+
     pub fn cancel_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, CancelTransaction<'info>>,
     ) -> Result<()> {
@@ -51,6 +382,12 @@ pub mod cpi {
         squads_mpl::cpi::execute_instruction(ctx)
     }
 
+    pub fn finalize_execution<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FinalizeExecution<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::finalize_execution(ctx)
+    }
+
     pub fn add_instruction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, AddInstruction<'info>>,
         incoming_instruction: crate::state::IncomingInstruction,
@@ -58,6 +395,13 @@ pub mod cpi {
         squads_mpl::cpi::add_instruction(ctx, incoming_instruction)
     }
 
+    pub fn add_typed_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddTypedInstruction<'info>>,
+        action: crate::state::TypedAction,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_typed_instruction(ctx, action)
+    }
+
 
     pub fn approve_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, VoteTransaction<'info>>,
@@ -77,6 +421,74 @@ pub mod cpi {
         squads_mpl::cpi::add_authority(ctx)
     }
 
+    pub fn add_named_authority<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddNamedAuthority<'info>>,
+        label: String,
+        purpose: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_named_authority(ctx, label, purpose)
+    }
+
+    pub fn update_authority_record<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateAuthorityRecord<'info>>,
+        label: String,
+        purpose: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_authority_record(ctx, label, purpose)
+    }
+
+    pub fn change_default_vault<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        vault_index: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_default_vault(ctx, vault_index)
+    }
+
+    pub fn change_time_lock<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        time_lock: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_time_lock(ctx, time_lock)
+    }
+
+    pub fn change_config_time_lock<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        config_time_lock: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_config_time_lock(ctx, config_time_lock)
+    }
+
+    pub fn change_veto_period<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        veto_period: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_veto_period(ctx, veto_period)
+    }
+
+    pub fn add_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddSpendingLimit<'info>>,
+        authority_index: u32,
+        mint: Pubkey,
+        member: Pubkey,
+        amount: u64,
+        period: i64,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_spending_limit(ctx, authority_index, mint, member, amount, period)
+    }
+
+    pub fn remove_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveSpendingLimit<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_spending_limit(ctx)
+    }
+
+    pub fn spend_from_vault<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SpendFromVault<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::spend_from_vault(ctx, amount)
+    }
+
     pub fn add_member<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
         new_member: Pubkey,
@@ -92,6 +504,614 @@ pub mod cpi {
         squads_mpl::cpi::add_member_and_change_threshold(ctx, new_member, new_threshold)
     }
 
+    pub fn set_member_weight<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_member_weight(ctx, member, weight)
+    }
+
+    pub fn set_member_permissions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_member_permissions(ctx, member, permissions)
+    }
+
+    pub fn change_config_threshold<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        config_threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_config_threshold(ctx, config_threshold)
+    }
+
+    pub fn change_threshold_percent<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        threshold_percent: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_threshold_percent(ctx, threshold_percent)
+    }
+
+    pub fn change_max_open_transactions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        max_open_transactions: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_max_open_transactions(ctx, max_open_transactions)
+    }
+
+    pub fn change_max_members<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        max_members: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_max_members(ctx, max_members)
+    }
+
+    pub fn change_min_quorum<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        min_quorum: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_min_quorum(ctx, min_quorum)
+    }
+
+    pub fn apply_config_actions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        actions: Vec<crate::state::MsConfigAction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::apply_config_actions(ctx, actions)
+    }
+
+    pub fn admin_config_actions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AdminConfig<'info>>,
+        actions: Vec<crate::state::MsConfigAction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::admin_config_actions(ctx, actions)
+    }
+
+    pub fn renounce_admin<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RenounceAdmin<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::renounce_admin(ctx)
+    }
+
+    pub fn set_member_program<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member_program: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_member_program(ctx, member_program, allowed)
+    }
+
+    pub fn set_program_allowlist<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        authority_index: u32,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_program_allowlist(ctx, authority_index, programs)
+    }
+
+    pub fn set_executors<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        any_executor: bool,
+        executors: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_executors(ctx, any_executor, executors)
+    }
+
+    pub fn set_execution_hook<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        execution_hook: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_execution_hook(ctx, execution_hook)
+    }
+
+    pub fn set_parent<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        parent: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_parent(ctx, parent)
+    }
+
+    pub fn set_membership_gate<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        membership_gate: Option<crate::state::MembershipGate>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_membership_gate(ctx, membership_gate)
+    }
+
+    pub fn set_execution_window<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        execution_window: Option<crate::state::ExecutionWindow>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_execution_window(ctx, execution_window)
+    }
+
+    pub fn set_blackout_periods<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        blackout_periods: Vec<crate::state::BlackoutPeriod>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_blackout_periods(ctx, blackout_periods)
+    }
+
+    pub fn set_value_tiers<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        value_tiers: Vec<crate::state::ValueTier>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_value_tiers(ctx, value_tiers)
+    }
+
+    pub fn set_stake_weight_source<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        stake_weight_source: Option<crate::state::StakeWeightSource>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_stake_weight_source(ctx, stake_weight_source)
+    }
+
+    pub fn set_denied_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        target_program: Pubkey,
+        prefix: Vec<u8>,
+        denied: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_denied_instruction(ctx, target_program, prefix, denied)
+    }
+
+    pub fn retire_authority<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RetireAuthority<'info>>,
+        authority_index: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::retire_authority(ctx, authority_index)
+    }
+
+    pub fn add_program_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member_program: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_program_member(ctx, member_program, seeds)
+    }
+
+    pub fn set_member_expiration<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_member_expiration(ctx, member, expires_at)
+    }
+
+    pub fn replace_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        old_member: Pubkey,
+        new_member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::replace_member(ctx, old_member, new_member)
+    }
+
+    pub fn finalize_member_removal<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FinalizeMemberRemoval<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::finalize_member_removal(ctx)
+    }
+
+    pub fn abort_member_removal<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AbortMemberRemoval<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::abort_member_removal(ctx)
+    }
+
+    pub fn change_removal_delay<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        removal_delay: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_removal_delay(ctx, removal_delay)
+    }
+
+    pub fn add_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddRecovery<'info>>,
+        recovery_key: Pubkey,
+        inactivity_epochs: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_recovery(ctx, recovery_key, inactivity_epochs)
+    }
+
+    pub fn remove_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_recovery(ctx)
+    }
+
+    pub fn recover<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, Recover<'info>>,
+        members: Vec<Pubkey>,
+        threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::recover(ctx, members, threshold)
+    }
+
+    pub fn set_recovery_set<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        recovery_keys: Vec<Pubkey>,
+        recovery_threshold: u16,
+        recovery_delay: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_recovery_set(ctx, recovery_keys, recovery_threshold, recovery_delay)
+    }
+
+    pub fn set_veto_council<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        veto_council: Vec<Pubkey>,
+        veto_council_threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_veto_council(ctx, veto_council, veto_council_threshold)
+    }
+
+    pub fn propose_member_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ProposeMemberRecovery<'info>>,
+        old_member: Pubkey,
+        new_member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::propose_member_recovery(ctx, old_member, new_member)
+    }
+
+    pub fn approve_member_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ApproveMemberRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_member_recovery(ctx)
+    }
+
+    pub fn execute_member_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteMemberRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_member_recovery(ctx)
+    }
+
+    pub fn cancel_member_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CancelMemberRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::cancel_member_recovery(ctx)
+    }
+
+    pub fn prune_expired_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, PruneExpiredMember<'info>>,
+        member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::prune_expired_member(ctx, member)
+    }
+
+    pub fn set_guardian<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_guardian(ctx, guardian)
+    }
+
+    pub fn freeze<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, Freeze<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::freeze(ctx)
+    }
+
+    pub fn unfreeze<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::unfreeze(ctx)
+    }
+
+    pub fn pause<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::pause(ctx)
+    }
+
+    pub fn unpause<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::unpause(ctx)
+    }
+
+    pub fn set_vote_records<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_vote_records(ctx, enabled)
+    }
+
+    pub fn close_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseMultisig<'info>>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_multisig(ctx, destination)
+    }
+
+    pub fn migrate_baseline_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateBaselineMultisig<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_baseline_multisig(ctx)
+    }
+
+    pub fn migrate_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_transaction(ctx)
+    }
+
+    pub fn migrate_baseline_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_baseline_transaction(ctx)
+    }
+
+    pub fn create_metadata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateMetadata<'info>>,
+        name: String,
+        description: String,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        squads_mpl::cpi::create_metadata(ctx, name, description, uri_hash)
+    }
+
+    pub fn update_metadata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>>,
+        name: String,
+        description: String,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        squads_mpl::cpi::update_metadata(ctx, name, description, uri_hash)
+    }
+
+    pub fn set_transaction_description<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SetTransactionDescription<'info>>,
+        title: String,
+        description: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_transaction_description(ctx, title, description)
+    }
+
+    pub fn submit_signed_vote<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        member: Pubkey,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::submit_signed_vote(ctx, member, approve)
+    }
+
+    pub fn submit_signed_votes<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        members: Vec<Pubkey>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::submit_signed_votes(ctx, members, approve)
+    }
+
+    pub fn submit_approval_request<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::submit_approval_request(ctx, member)
+    }
+
+    pub fn add_delegate<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddDelegate<'info>>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_delegate(ctx, delegate)
+    }
+
+    pub fn remove_delegate<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveDelegate<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_delegate(ctx)
+    }
+
+    pub fn submit_delegated_vote<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SubmitDelegatedVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::submit_delegated_vote(ctx, approve)
+    }
+
+    pub fn add_session_key<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddSessionKey<'info>>,
+        session_key: Pubkey,
+        actions: u8,
+        expires_at_slot: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_session_key(ctx, session_key, actions, expires_at_slot)
+    }
+
+    pub fn remove_session_key<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveSessionKey<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_session_key(ctx)
+    }
+
+    pub fn submit_session_vote<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SubmitSessionVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::submit_session_vote(ctx, approve)
+    }
+
+    pub fn create_recurring_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateRecurringTransaction<'info>>,
+        interval: i64,
+        start_at: i64,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_recurring_transaction(ctx, interval, start_at)
+    }
+
+    pub fn remove_recurring_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveRecurringTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_recurring_transaction(ctx)
+    }
+
+    pub fn execute_recurring_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteRecurringTransaction<'info>>,
+        account_list: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_recurring_transaction(ctx, account_list)
+    }
+
+    pub fn upgrade_program<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpgradeProgram<'info>>,
+        expected_hash: [u8; 32],
+    ) -> Result<()> {
+        squads_mpl::cpi::upgrade_program(ctx, expected_hash)
+    }
+
+    pub fn create_managed_program<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateManagedProgram<'info>>,
+        authority_index: u32,
+        name: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_managed_program(ctx, authority_index, name)
+    }
+
+    pub fn remove_managed_program<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveManagedProgram<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_managed_program(ctx)
+    }
+
+    pub fn create_program_upgrade<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateProgramUpgrade<'info>>,
+        buffer: Pubkey,
+        expected_hash: [u8; 32],
+    ) -> Result<()> {
+        squads_mpl::cpi::create_program_upgrade(ctx, buffer, expected_hash)
+    }
+
+    pub fn close_program_upgrade<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseProgramUpgrade<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_program_upgrade(ctx)
+    }
+
+    pub fn set_rollback_threshold<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SetRollbackThreshold<'info>>,
+        rollback_threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_rollback_threshold(ctx, rollback_threshold)
+    }
+
+    pub fn propose_rollback<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ProposeRollback<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::propose_rollback(ctx)
+    }
+
+    pub fn approve_rollback<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ApproveRollback<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_rollback(ctx)
+    }
+
+    pub fn execute_rollback<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteRollback<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_rollback(ctx)
+    }
+
+    pub fn cancel_rollback<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CancelRollback<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::cancel_rollback(ctx)
+    }
+
+    pub fn create_vault_ata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateVaultAta<'info>>,
+        authority_index: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_vault_ata(ctx, authority_index)
+    }
+
+    pub fn add_allowance<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddAllowance<'info>>,
+        authority_index: u32,
+        mint: Pubkey,
+        spender: Pubkey,
+        cap: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_allowance(ctx, authority_index, mint, spender, cap, expires_at)
+    }
+
+    pub fn remove_allowance<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveAllowance<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_allowance(ctx)
+    }
+
+    pub fn add_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddPolicy<'info>>,
+        policy_program: Pubkey,
+        stages: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_policy(ctx, policy_program, stages)
+    }
+
+    pub fn remove_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemovePolicy<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_policy(ctx)
+    }
+
+    pub fn create_activity_log<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateActivityLog<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_activity_log(ctx)
+    }
+
+    pub fn close_activity_log<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseActivityLog<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_activity_log(ctx)
+    }
+
+    pub fn create_member_page<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateMemberPage<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_member_page(ctx)
+    }
+
+    pub fn set_member_root<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        member_root: Option<[u8; 32]>,
+        member_count: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_member_root(ctx, member_root, member_count)
+    }
+
+    pub fn set_ratification<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        ratification: Option<crate::state::Ratification>,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_ratification(ctx, ratification)
+    }
+
+    pub fn add_paged_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsMembersAuth<'info>>,
+        new_member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_paged_member(ctx, new_member)
+    }
+
+    pub fn remove_paged_member<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsMembersAuth<'info>>,
+        old_member: Pubkey,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_paged_member(ctx, old_member)
+    }
+
+    pub fn spend_allowance<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, SpendAllowance<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::spend_allowance(ctx, amount)
+    }
+
     pub fn change_threshold<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
         new_threshold: u16,
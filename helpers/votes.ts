@@ -0,0 +1,9 @@
+// vote bitmap helper for test suite
+// transactions record votes as bitmaps over the (sorted) multisig member keys
+
+import * as anchor from "@coral-xyz/anchor";
+
+// returns the member keys whose bit is set in the vote bitmap
+export const bitmapMembers = (bitmap: Buffer | number[], keys: anchor.web3.PublicKey[]) => {
+    return keys.filter((_, i) => (bitmap[Math.floor(i / 8)] & (1 << (i % 8))) !== 0);
+};
@@ -46,23 +46,22 @@ export type SquadsMpl = {
       ]
     },
     {
-      "name": "addMember",
+      "name": "createWithAdmin",
       "docs": [
-        "The instruction to add a new member to the multisig.",
-        "Adds member/key to the multisig and reallocates space if neccessary",
-        "If the multisig needs to be reallocated, it must be prefunded with",
-        "enough lamports to cover the new size."
+        "Creates a new multisig account with an admin, a bootstrap key that can apply config",
+        "changes on its own until the expiry slot (at most about 30 days away) or until it",
+        "renounces, so new teams can set up the multisig before it is fully trust-minimized."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "systemProgram",
@@ -72,57 +71,74 @@ export type SquadsMpl = {
       ],
       "args": [
         {
-          "name": "newMember",
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "createKey",
           "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "removeMember",
-      "docs": [
-        "The instruction to remove a member from the multisig"
-      ],
-      "accounts": [
+        },
         {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
         {
-          "name": "oldMember",
+          "name": "meta",
+          "type": "string"
+        },
+        {
+          "name": "admin",
           "type": "publicKey"
+        },
+        {
+          "name": "adminExpiresAtSlot",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "removeMemberAndChangeThreshold",
+      "name": "addMember",
       "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously remove a member"
+        "The instruction to add a new member to the multisig.",
+        "Adds member/key to the multisig and reallocates space if neccessary",
+        "If the multisig needs to be reallocated, the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account, or the multisig",
+        "must be prefunded with enough lamports to cover the new size."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "oldMember",
+          "name": "newMember",
           "type": "publicKey"
-        },
-        {
-          "name": "newThreshold",
-          "type": "u16"
         }
       ]
     },
     {
-      "name": "addMemberAndChangeThreshold",
+      "name": "applyConfigActions",
       "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously add a member"
+        "The instruction to apply several config changes as one batch, ie. add a member, remove",
+        "another and raise the threshold. The changes are applied in order and the multisig is",
+        "validated once all of them are applied, then active transactions are deprecated once.",
+        "Reallocates space if neccessary, in which case the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account."
       ],
       "accounts": [
         {
@@ -143,77 +159,86 @@ export type SquadsMpl = {
       ],
       "args": [
         {
-          "name": "newMember",
-          "type": "publicKey"
-        },
-        {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
         }
       ]
     },
     {
-      "name": "changeThreshold",
+      "name": "adminConfigActions",
       "docs": [
-        "The instruction to change the threshold of the multisig"
+        "The instruction for the admin to apply config changes on its own, like apply_config_actions",
+        "without a vote, until the admin expires. Reallocates space if neccessary, paid by the admin."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
         }
       ]
     },
     {
-      "name": "addAuthority",
+      "name": "renounceAdmin",
       "docs": [
-        "instruction to increase the authority value tracked in the multisig",
-        "This is optional, as authorities are simply PDAs, however it may be helpful",
-        "to keep track of commonly used authorities in a UI.",
-        "This has no functional impact on the multisig or its functionality, but",
-        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+        "The instruction for the admin to give up its powers before it expires. It can't be set again."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "createTransaction",
+      "name": "setMemberProgram",
       "docs": [
-        "Instruction to create a multisig transaction.",
-        "Each transaction is tied to a single authority, and must be specified when",
-        "creating the instruction below. authority 0 is reserved for internal",
-        "instructions, whereas authorities 1 or greater refer to a vault,",
-        "upgrade authority, or other."
+        "The instruction to allow (or disallow) PDAs of a program to be added as members.",
+        "Reallocates space if neccessary, in which case the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -222,65 +247,70 @@ export type SquadsMpl = {
       ],
       "args": [
         {
-          "name": "authorityIndex",
-          "type": "u32"
+          "name": "memberProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "allowed",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "activateTransaction",
+      "name": "setProgramAllowlist",
       "docs": [
-        "Instruction to set the state of a transaction \"active\".",
-        "\"active\" transactions can then be signed off by multisig members"
+        "The instruction to restrict the programs that instructions of transactions of an",
+        "authority (vault) may target, ie. only the token and system programs. An empty list",
+        "removes the restriction. Instructions are checked when attached and when executed."
       ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "addInstruction",
+      "name": "setExecutors",
       "docs": [
-        "Instruction to attach an instruction to a transaction.",
-        "Transactions must be in the \"draft\" status, and any",
-        "signer (aside from execution payer) specified in an instruction",
-        "must match the authority PDA specified during the transaction creation."
+        "The instruction to set the keys that can execute approved transactions without being",
+        "members, ie. a keeper service, or to let any key execute them. Replaces the deprecated",
+        "allow_external_execute flag."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "instruction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -289,491 +319,518 @@ export type SquadsMpl = {
       ],
       "args": [
         {
-          "name": "incomingInstruction",
+          "name": "anyExecutor",
+          "type": "bool"
+        },
+        {
+          "name": "executors",
           "type": {
-            "defined": "IncomingInstruction"
+            "vec": "publicKey"
           }
         }
       ]
     },
     {
-      "name": "approveTransaction",
+      "name": "setExecutionHook",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "The instruction to set (or remove) the execution hook, a program invoked with",
+        "pre_execute and post_execute instructions around the execution of vault transactions,",
+        "which can abort the execution by failing. Internal transactions don't invoke the hook."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "executionHook",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "rejectTransaction",
+      "name": "setParent",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
+        "The instruction to set (or remove) the parent of the multisig, the vault authority of",
+        "another multisig. The parent formally proposes transactions into this multisig through",
+        "CPI, like a member with only the initiate permission, but doesn't vote."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "parent",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "cancelTransaction",
+      "name": "setMembershipGate",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
+        "The instruction to set (or remove) the membership gate, the non-transferable mint or verified",
+        "NFT collection whose holders vote with token vote records, resolved at vote time, and the number",
+        "of token votes that vault transactions need besides the members. The token threshold must be at least 1. Vote records",
+        "must be enabled for token votes. Any active transactions are deprecated, as the voters change."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "membershipGate",
+          "type": {
+            "option": {
+              "defined": "MembershipGate"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "executeTransaction",
+      "name": "setExecutionWindow",
       "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
+        "The instruction to set (or remove) the execution window, the UTC days and hours vault",
+        "transactions can be executed in, ie. weekdays from 09:00 to 18:00. Spending limits,",
+        "allowances and recurring transactions are held to it as well. Internal transactions",
+        "aren't, so the multisig can always change its schedule."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "executionWindow",
+          "type": {
+            "option": {
+              "defined": "ExecutionWindow"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBlackoutPeriods",
+      "docs": [
+        "The instruction to replace the blackout periods, the time ranges vault transactions can't",
+        "be executed in, whatever the execution window. An empty list removes the periods."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "blackoutPeriods",
+          "type": {
+            "vec": {
+              "defined": "BlackoutPeriod"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setValueTiers",
+      "docs": [
+        "The instruction to replace the value tiers, the thresholds of vault transactions by the total",
+        "amount of SOL (the default pubkey as the mint) or of a mint they transfer, ie. 1 below 1 SOL",
+        "and 4 from 100 SOL. The tiers are applied when transactions are activated, so their",
+        "instruction accounts must be passed to activate them. An empty list removes the tiers."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "accountList",
-          "type": "bytes"
+          "name": "valueTiers",
+          "type": {
+            "vec": {
+              "defined": "ValueTier"
+            }
+          }
         }
       ]
     },
     {
-      "name": "executeInstruction",
+      "name": "setStakeWeightSource",
       "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
+        "The instruction to set (or remove) the stake weight source, the staking program whose lockers",
+        "the vote weights of the members are read from, snapshotted when transactions are activated.",
+        "The member weights cap the stake weights, so they must be raised to give stake its influence,",
+        "and internal transactions keep the member weights.",
+        "Vote records must be enabled, as stake weights are tallied with vote records.",
+        "Any active transactions are deprecated, as the vote weights change."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stakeWeightSource",
+          "type": {
+            "option": {
+              "defined": "StakeWeightSource"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDeniedInstruction",
+      "docs": [
+        "The instruction to deny (or allow again) instructions of a program whose data starts",
+        "with the prefix, ie. SetAuthority on the token program. Instructions are checked when",
+        "attached (including appended data) and when executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instruction",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetProgram",
+          "type": "publicKey"
         },
         {
-          "name": "member",
+          "name": "prefix",
+          "type": "bytes"
+        },
+        {
+          "name": "denied",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "invalidateTransaction",
+      "docs": [
+        "The instruction to invalidate a single pending (draft, active or approved) transaction,",
+        "marking it as cancelled, without deprecating the other active transactions the way",
+        "a change of the multisig does."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "ms",
+      "name": "retireAuthority",
       "docs": [
-        "Ms is the basic state account for a multisig."
+        "The instruction to decommission an authority (vault): all of its lamports are swept to",
+        "the destination and the authority is marked retired, so no transactions of it can be",
+        "created or executed anymore. The default vault can't be retired."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "threshold",
-            "type": "u16"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u16"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "msChangeIndex",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "createKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "allowExternalExecute",
-            "type": "bool"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": "publicKey"
-            }
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addProgramMember",
+      "docs": [
+        "The instruction to add a PDA of an allowed member program as a member.",
+        "The seeds (including the bump) must derive the PDA from the program, which then",
+        "approves, rejects and cancels transactions by signing for the PDA through CPI."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "memberProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "seeds",
+          "type": {
+            "vec": "bytes"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "msTransaction",
+      "name": "removeMember",
       "docs": [
-        "The MsTransaction is the state account for a multisig transaction"
+        "The instruction to remove a member from the multisig"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "ms",
-            "type": "publicKey"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityBump",
-            "type": "u8"
-          },
-          {
-            "name": "status",
-            "type": {
-              "defined": "MsTransactionStatus"
-            }
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "approved",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "rejected",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "cancelled",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "executedIndex",
-            "type": "u8"
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "name": "msInstruction",
+      "name": "replaceMember",
       "docs": [
-        "The state account for an instruction that is attached to a transaction.",
-        "Almost analagous to the native Instruction struct for solana, but with an extra",
-        "field for the bump."
+        "The instruction to swap the key of a member in one step, ie. to rotate a key. The weight,",
+        "permissions and expiration of the member carry over, so the threshold is unchanged, and",
+        "active transactions are deprecated like for any change of the members."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "executed",
-            "type": "bool"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "MsAccountMeta",
-      "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
-          {
-            "name": "isSigner",
-            "type": "bool"
-          },
-          {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "name": "IncomingInstruction",
+      "name": "finalizeMemberRemoval",
       "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
+        "The instruction to finalize a pending member removal once the removal delay has passed,",
+        "which anyone can call."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "MsTransactionStatus",
+      "name": "abortMemberRemoval",
       "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+        "The instruction to abort a pending member removal during the removal delay.",
+        "A single member with the veto permission (other than the member being removed) is enough."
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Draft"
-          },
-          {
-            "name": "Active"
-          },
-          {
-            "name": "ExecuteReady"
-          },
-          {
-            "name": "Executed"
-          },
-          {
-            "name": "Rejected"
-          },
-          {
-            "name": "Cancelled"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "KeyNotInMultisig"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidTransactionState"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidNumberOfAccounts"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidInstructionAccount"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidAuthorityIndex"
-    },
-    {
-      "code": 6005,
-      "name": "TransactionAlreadyExecuted"
-    },
-    {
-      "code": 6006,
-      "name": "CannotRemoveSoloMember"
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6007,
-      "name": "InvalidThreshold"
+      "name": "changeRemovalDelay",
+      "docs": [
+        "The instruction to change the removal delay of the multisig, the number of seconds",
+        "between a member removal being executed and taking effect. 0 removes members right away."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "removalDelay",
+          "type": "u32"
+        }
+      ]
     },
     {
-      "code": 6008,
-      "name": "DeprecatedTransaction"
-    },
-    {
-      "code": 6009,
-      "name": "InstructionFailed"
-    },
-    {
-      "code": 6010,
-      "name": "MaxMembersReached"
-    },
-    {
-      "code": 6011,
-      "name": "EmptyMembers"
-    },
-    {
-      "code": 6012,
-      "name": "PartialExecution"
-    },
-    {
-      "code": 6013,
-      "name": "NotEnoughLamports"
-    }
-  ]
-};
-
-export const IDL: SquadsMpl = {
-  "version": "1.3.0",
-  "name": "squads_mpl",
-  "instructions": [
-    {
-      "name": "create",
+      "name": "setMemberExpiration",
       "docs": [
-        "Creates a new multisig account"
+        "The instruction to set when the membership of a member expires, None removes the expiration.",
+        "Expired members can no longer vote, their earlier votes no longer count and anyone",
+        "can prune them from the multisig. Reallocates space if neccessary, in which case the rent",
+        "is topped up by a signing payer or the default vault passed as a remaining account."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "systemProgram",
@@ -783,32 +840,64 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "threshold",
-          "type": "u16"
-        },
-        {
-          "name": "createKey",
+          "name": "member",
           "type": "publicKey"
         },
         {
-          "name": "members",
+          "name": "expiresAt",
           "type": {
-            "vec": "publicKey"
+            "option": "i64"
           }
+        }
+      ]
+    },
+    {
+      "name": "pruneExpiredMember",
+      "docs": [
+        "The instruction to remove a member whose membership has expired, which anyone can call."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeMemberAndChangeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig and simultaneously remove a member.",
+        "The threshold is changed first, so it can be lowered to what the remaining members can reach."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
         },
         {
-          "name": "meta",
-          "type": "string"
+          "name": "newThreshold",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "addMember",
+      "name": "addMemberAndChangeThreshold",
       "docs": [
-        "The instruction to add a new member to the multisig.",
-        "Adds member/key to the multisig and reallocates space if neccessary",
-        "If the multisig needs to be reallocated, it must be prefunded with",
-        "enough lamports to cover the new size."
+        "The instruction to change the threshold of the multisig and simultaneously add a member"
       ],
       "accounts": [
         {
@@ -831,13 +920,17 @@ export const IDL: SquadsMpl = {
         {
           "name": "newMember",
           "type": "publicKey"
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "removeMember",
+      "name": "changeThreshold",
       "docs": [
-        "The instruction to remove a member from the multisig"
+        "The instruction to change the threshold of the multisig"
       ],
       "accounts": [
         {
@@ -848,15 +941,17 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "oldMember",
-          "type": "publicKey"
+          "name": "newThreshold",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "removeMemberAndChangeThreshold",
+      "name": "changeThresholdPercent",
       "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously remove a member"
+        "The instruction to set the threshold as a percentage of the total vote weight (ie. 60),",
+        "which is then recomputed whenever members are added or removed or their weights change.",
+        "A threshold percent of 0 switches back to an absolute threshold, keeping the current one."
       ],
       "accounts": [
         {
@@ -867,52 +962,60 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "oldMember",
-          "type": "publicKey"
-        },
-        {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "thresholdPercent",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "addMemberAndChangeThreshold",
+      "name": "changeConfigThreshold",
       "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously add a member"
+        "The instruction to change the threshold for internal transactions, ie. member",
+        "and threshold changes, separately from the threshold for vault transactions.",
+        "A config threshold of 0 falls back to the regular threshold."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
           "isSigner": true
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "configThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMinQuorum",
+      "docs": [
+        "The instruction to change the min quorum, the number of members that must have voted",
+        "(approved, rejected or abstained) before a transaction is decided, independently of the",
+        "threshold. A min quorum of 0 disables the quorum."
+      ],
+      "accounts": [
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "newMember",
-          "type": "publicKey"
-        },
-        {
-          "name": "newThreshold",
+          "name": "minQuorum",
           "type": "u16"
         }
       ]
     },
     {
-      "name": "changeThreshold",
+      "name": "changeMaxOpenTransactions",
       "docs": [
-        "The instruction to change the threshold of the multisig"
+        "The instruction to cap the transactions a single creator can have open (neither executed,",
+        "rejected, cancelled, expired nor deprecated), so one member can't flood the queue of the",
+        "others. A max open transactions of 0 removes the cap. Transactions are only counted while",
+        "the cap is set, so active transactions are deprecated."
       ],
       "accounts": [
         {
@@ -923,19 +1026,16 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "newThreshold",
+          "name": "maxOpenTransactions",
           "type": "u16"
         }
       ]
     },
     {
-      "name": "addAuthority",
+      "name": "changeMaxMembers",
       "docs": [
-        "instruction to increase the authority value tracked in the multisig",
-        "This is optional, as authorities are simply PDAs, however it may be helpful",
-        "to keep track of commonly used authorities in a UI.",
-        "This has no functional impact on the multisig or its functionality, but",
-        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+        "The instruction to cap the number of members of the multisig, enforced when members are",
+        "added. A max members of 0 removes the cap, leaving the global ceiling of Ms::MAX_MEMBERS."
       ],
       "accounts": [
         {
@@ -944,33 +1044,32 @@ export const IDL: SquadsMpl = {
           "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "maxMembers",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "createTransaction",
+      "name": "setMemberWeight",
       "docs": [
-        "Instruction to create a multisig transaction.",
-        "Each transaction is tied to a single authority, and must be specified when",
-        "creating the instruction below. authority 0 is reserved for internal",
-        "instructions, whereas authorities 1 or greater refer to a vault,",
-        "upgrade authority, or other."
+        "The instruction to set the vote weight of a member.",
+        "Approvals, rejections and cancellations are tallied by weight against the threshold,",
+        "members default to a weight of 1. Reallocates space if neccessary,",
+        "in which case the multisig must be prefunded with enough lamports to cover the new size."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -979,30 +1078,63 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "authorityIndex",
-          "type": "u32"
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "activateTransaction",
+      "name": "setMemberPermissions",
       "docs": [
-        "Instruction to set the state of a transaction \"active\".",
-        "\"active\" transactions can then be signed off by multisig members"
+        "The instruction to set the permissions of a member.",
+        "Permissions are a bitmask of Initiate, Vote, Execute and ChangeConfig,",
+        "members default to having all permissions. Reallocates space if neccessary,",
+        "in which case the multisig must be prefunded with enough lamports to cover the new size."
       ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
         },
         {
-          "name": "creator",
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addAuthority",
+      "docs": [
+        "instruction to increase the authority value tracked in the multisig",
+        "This is optional, as authorities are simply PDAs, however it may be helpful",
+        "to keep track of commonly used authorities in a UI.",
+        "This has no functional impact on the multisig or its functionality, but",
+        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
         }
@@ -1010,31 +1142,25 @@ export const IDL: SquadsMpl = {
       "args": []
     },
     {
-      "name": "addInstruction",
+      "name": "addNamedAuthority",
       "docs": [
-        "Instruction to attach an instruction to a transaction.",
-        "Transactions must be in the \"draft\" status, and any",
-        "signer (aside from execution payer) specified in an instruction",
-        "must match the authority PDA specified during the transaction creation."
+        "instruction to increase the authority value tracked in the multisig, the same as",
+        "add_authority, while also creating a record labeling the new authority with what",
+        "it is used for, ie. \"ops vault\" or \"upgrade authority for program X\"."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "instruction",
+          "name": "authorityRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true
         },
@@ -1046,412 +1172,16926 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "incomingInstruction",
-          "type": {
-            "defined": "IncomingInstruction"
-          }
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "purpose",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "approveTransaction",
+      "name": "updateAuthorityRecord",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "instruction to update the label and purpose in the record of an authority"
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
+          "name": "authorityRecord",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "label",
+          "type": "string"
         },
         {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
+          "name": "purpose",
+          "type": "string"
         }
+      ]
+    },
+    {
+      "name": "changeDefaultVault",
+      "docs": [
+        "instruction to change the authority index designated as the default vault.",
+        "Defaults to authority 1, and must be within the authorities tracked by the multisig,",
+        "so add_authority may need to be called first."
       ],
-      "args": []
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeTimeLock",
+      "docs": [
+        "The instruction to change the time lock of the multisig, the number of seconds",
+        "a transaction must wait after being approved before it can be executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeConfigTimeLock",
+      "docs": [
+        "The instruction to change the config time lock of the multisig, the number of seconds an",
+        "internal (config) transaction must wait after being approved before it can be executed,",
+        "independently of the time lock, so that changing the members or threshold is never instant."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "configTimeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeVetoPeriod",
+      "docs": [
+        "The instruction to change the veto period of the multisig, the number of seconds after",
+        "a transaction is approved during which a single member with the veto permission can",
+        "cancel it. Execution is held back until the veto period has passed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vetoPeriod",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "docs": [
+        "The instruction to set (or remove) the guardian of the multisig. The guardian can freeze",
+        "the multisig in an emergency, but cannot vote or move funds."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "freeze",
+      "docs": [
+        "Instruction for the guardian to freeze the multisig, which blocks activating and",
+        "executing transactions (and spending limits) until the multisig unfreezes itself.",
+        "Internal transactions are not frozen, so the members can still unfreeze or change the config."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreeze",
+      "docs": [
+        "The instruction to unfreeze the multisig, which requires the normal threshold"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause",
+      "docs": [
+        "The instruction to pause executions of the multisig, ie. when a member key is suspected",
+        "to be compromised. Executing transactions (and spending limits) is blocked while voting",
+        "continues, internal transactions can still be executed to rotate keys and unpause."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "docs": [
+        "The instruction to unpause executions of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateBaselineMultisig",
+      "docs": [
+        "The instruction to migrate a multisig of the original layout, whose authority index was",
+        "two bytes wide (while transactions sign with four byte authority indexes) and which lacks",
+        "every field introduced since. Such accounts can't be loaded with the current layout, so",
+        "this migrates them in place permissionlessly. The layout is confirmed by the create key",
+        "and bump it is parsed with deriving the address of the multisig."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTransactionStatus",
+      "docs": [
+        "View of the status of a transaction, returned through the return data so that",
+        "clients and other programs can read it without deserializing the account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "MsTransactionStatus"
+      }
+    },
+    {
+      "name": "getVoteCounts",
+      "docs": [
+        "View of the vote counts of a transaction, returned through the return data."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "VoteCounts"
+      }
+    },
+    {
+      "name": "isMember",
+      "docs": [
+        "View of whether the key is a member of the multisig, returned through the return data."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ],
+      "returns": "bool"
+    },
+    {
+      "name": "migrateTransaction",
+      "docs": [
+        "Instruction to migrate a transaction account to the current layout version in place.",
+        "The account is parsed with the layout of its version and written back with the current",
+        "one, growing it if neccessary. Transactions of the original layout have no version, and",
+        "are migrated with migrate_baseline_transaction instead."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateBaselineTransaction",
+      "docs": [
+        "Instruction to migrate a transaction of the original layout, whose votes were lists of",
+        "member keys and which lacks every field introduced since. The votes are converted to",
+        "bitmaps over the keys of the multisig, which must have been migrated first."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setVoteRecords",
+      "docs": [
+        "The instruction to switch the multisig between recording votes on the transaction",
+        "(the default), and recording them in per-member VoteRecord accounts, so that",
+        "multisigs with hundreds of members can vote without growing the transaction.",
+        "Votes of active transactions would be split between the two, so they're deprecated."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction.",
+        "Each transaction is tied to a single authority, and must be specified when",
+        "creating the instruction below. authority 0 is reserved for internal",
+        "instructions, whereas authorities 1 or greater refer to a vault,",
+        "upgrade authority, or other."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createTransactionV2",
+      "docs": [
+        "Instruction to create a multisig transaction, attach its instructions and activate it",
+        "at once, so that a client failing midway doesn't leave a half-built draft behind.",
+        "The instruction accounts are passed as remaining accounts, in the same order as the",
+        "incoming instructions, followed by the activity log and policy accounts (if any).",
+        "The same rules as create_transaction, add_instructions and activate_transaction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createConfigTransaction",
+      "docs": [
+        "Instruction to create a config transaction, which carries typed config actions instead",
+        "of instructions. It is active right away, and is tallied against the config threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveConfigTransaction",
+      "docs": [
+        "Instruction to approve a config transaction on behalf of a member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectConfigTransaction",
+      "docs": [
+        "Instruction to reject a config transaction on behalf of a member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeConfigTransaction",
+      "docs": [
+        "Instruction to execute an approved config transaction, applying its actions as one",
+        "batch, like apply_config_actions. Reallocates the multisig if neccessary, paid by the member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMultisig",
+      "docs": [
+        "Instruction to close a dissolved multisig, returning its rent to the destination. The",
+        "closure must be approved as an internal transaction whose only instruction is this one,",
+        "which is executed here rather than through execute_transaction, as the multisig (which",
+        "signs internal instructions) can't be closed under it. All of the vaults must be empty,",
+        "and the (config) transactions after the change index closed or done."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "destination",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "cloneTransaction",
+      "docs": [
+        "Instruction to clone a transaction into a new draft with copies of all of its attached",
+        "instructions, ie. to re-propose a transaction deprecated by a change of the multisig.",
+        "The source transaction must be executed, rejected or deprecated. The source and new",
+        "instruction accounts are passed as remaining accounts, in pairs."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTransaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createLinkedTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction linked to a previously executed one.",
+        "Executions can't be reversed, but a compensating transaction can reference the",
+        "original so that corrective actions can be traced back to their cause.",
+        "The linked transaction must belong to the same multisig and be \"executed\"."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "linkedTransaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "activateTransaction",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\".",
+        "\"active\" transactions can then be signed off by multisig members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "activateWithStakeSnapshot",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\" for multisigs with a stake weight",
+        "source, snapshotting the vote weights of the members from their staking lockers, so that",
+        "locking (or unlocking) tokens afterwards doesn't change the vote on the transaction.",
+        "The locker of every member must be passed, members without one have no weight.",
+        "Internal transactions keep the member weights, so a misconfigured source can be fixed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakeSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStakeSnapshot",
+      "docs": [
+        "Instruction to close the stake snapshot of a transaction once voting on it has ended,",
+        "returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTransactionExpiration",
+      "docs": [
+        "Instruction to set an expiration on a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the expiration.",
+        "Once the expiration has passed the transaction can no longer be activated, approved",
+        "or executed, and anyone can expire it to return the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setTransactionTag",
+      "docs": [
+        "Instruction to tag a transaction with a category and a reference, ie. for reporting on",
+        "payroll, grants or config transactions. Transactions must be in the \"draft\" status, and",
+        "only the creator can set the tag, so it can be set along with the creation and is fixed",
+        "by the time members vote. The tag is at a fixed offset to filter transactions with memcmp."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tag",
+          "type": {
+            "defined": "TransactionTag"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionCondition",
+      "docs": [
+        "Instruction to set (or remove) an oracle price condition on a transaction, ie. execute only",
+        "while SOL/USD is above a floor, so an approved proposal doesn't execute into a crashed market.",
+        "The condition is checked on execution, with the price account in the remaining accounts.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the condition,",
+        "so it is fixed by the time members vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "executionCondition",
+          "type": {
+            "option": {
+              "defined": "ExecutionCondition"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRatifyingProposal",
+      "docs": [
+        "Instruction to set (or remove) the governance proposal ratifying a transaction, for multisigs",
+        "requiring the ratification of their token holders. The proposal must have succeeded when the",
+        "transaction is executed, and is passed in the remaining accounts.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the proposal,",
+        "so the members approve the transaction along with it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ratifyingProposal",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLookupTables",
+      "docs": [
+        "Instruction to set the address lookup tables of a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the tables.",
+        "Transactions with many unique accounts can then be executed with a versioned",
+        "transaction loading the accounts through these tables."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lookupTables",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCoCreators",
+      "docs": [
+        "Instruction to set the co-creators of a transaction, members that can attach",
+        "instructions to it along with the creator while it is a draft, so that complex",
+        "proposals can be assembled together. Only the creator can set them, or activate it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "coCreators",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTransactionDescription",
+      "docs": [
+        "Instruction to set the title and description of a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set them,",
+        "so the context members approve is bound to the proposal account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "fundExecutionBounty",
+      "docs": [
+        "Instruction to fund a bounty for executing a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can fund the bounty.",
+        "The lamports are held by the transaction account and paid out to the executor,",
+        "and any key can execute a transaction with a bounty."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to expire a transaction whose expiration has passed.",
+        "Marks the transaction as expired and closes the account, returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addInstruction",
+      "docs": [
+        "Instruction to attach an instruction to a transaction.",
+        "Transactions must be in the \"draft\" status, and any",
+        "signer (aside from execution payer) specified in an instruction",
+        "must match the authority PDA specified during the transaction creation."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addTypedInstruction",
+      "docs": [
+        "Instruction to attach an instruction built from a typed action to a vault transaction,",
+        "with the vault of the transaction as the signing authority, so the instruction doesn't",
+        "have to be encoded by hand. The actions are:",
+        "- stake: delegate, deactivate, withdraw, split or merge a stake account whose stake and",
+        "withdraw authority is the vault",
+        "- vote: authorize a new withdrawer or withdraw from a vote account, withdrawals are",
+        "checked again when executed to leave the vote account rent exempt",
+        "- idl: set the IDL authority, set the IDL buffer or close the IDL account of a program",
+        "whose IDL authority is the vault",
+        "- governance: an SPL Governance instruction with the vault as the realm authority,",
+        "governing token owner or governing token mint authority, ie. to vote in a realm where",
+        "the vault holds the council tokens. The governance program is passed, as realms run",
+        "their own deployments",
+        "",
+        "The same rules as for add_instruction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "TypedAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeInstruction",
+      "docs": [
+        "Instruction to remove the last instruction attached to a transaction, returning",
+        "its rent to the creator. Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceInstruction",
+      "docs": [
+        "Instruction to replace an instruction attached to a transaction, with the same",
+        "rules as add_instruction. Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "appendInstructionData",
+      "docs": [
+        "Instruction to append a chunk of data to an attached instruction, so that instruction",
+        "data bigger than a single Solana transaction can be uploaded in chunks before activation.",
+        "The last chunk should pass the hash of the full data, to check the upload is complete."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "finalHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addInstructions",
+      "docs": [
+        "Instruction to attach multiple instructions to a transaction at once.",
+        "The instruction accounts are passed as remaining accounts, in the same order",
+        "as the incoming instructions, and the same rules as add_instruction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveAndExecute",
+      "docs": [
+        "Instruction to cast the final approval of a transaction and execute it in the same call,",
+        "so there's no window between the approval and the execution. The approval is cast as by",
+        "approve_transaction, and if the transaction isn't ready to execute yet, that's all it does.",
+        "Otherwise it is executed as by execute_transaction, with the same account list, which fails",
+        "for multisigs with a time lock or veto period, as their transactions can't be executed right away."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitSignedVote",
+      "docs": [
+        "Instruction to relay the approval or rejection of a member who signed the vote offline.",
+        "The preceding instruction must verify the member's ed25519 signature over the signed",
+        "vote message of the transaction, any key can then submit the vote. The message holds the",
+        "vote sequence of the transaction, so the vote has to be signed again after any other vote.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "submitApprovalRequest",
+      "docs": [
+        "Instruction to relay the approval of a member who signed the approval request of the",
+        "transaction offline, ie. on a hardware wallet that displays the attached instructions.",
+        "The attached instruction accounts must come first in the remaining accounts, in order, and",
+        "the preceding instruction must verify the member's ed25519 signature over the approval",
+        "request built from them, so a tampered instruction set fails. Like a signed vote, the",
+        "request holds the vote sequence of the transaction.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "submitSignedVotes",
+      "docs": [
+        "Instruction to relay the approvals or rejections of several members who signed the same",
+        "vote offline, ie. to land a whole quorum at once. Each member's ed25519 signature over the",
+        "signed vote message must be verified by its own instruction, directly preceding this one",
+        "and in the order of the members. The members sign the same message, over the vote sequence",
+        "before the batch, so a stale signature fails the whole batch. Votes after the one deciding",
+        "the transaction are ignored.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addDelegate",
+      "docs": [
+        "Instruction for a member to delegate their approve/reject votes to another key,",
+        "ie. a hot key while travelling. Doesn't change the membership."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeDelegate",
+      "docs": [
+        "Instruction for a member to revoke the delegation of their votes"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitDelegatedVote",
+      "docs": [
+        "Instruction for a delegate to approve or reject a transaction on behalf of a member.",
+        "The vote counts as the member's own vote, and replaces any previous vote of the member.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addSessionKey",
+      "docs": [
+        "Instruction for a member to register a session key, a short-lived key (ie. a browser hot key)",
+        "that can vote on their behalf, restricted to the given actions and until the expiry slot.",
+        "Doesn't change the membership."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "actions",
+          "type": "u8"
+        },
+        {
+          "name": "expiresAtSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeSessionKey",
+      "docs": [
+        "Instruction for a member to revoke a session key, or to close it once expired"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitSessionVote",
+      "docs": [
+        "Instruction for a session key to approve or reject a transaction on behalf of a member.",
+        "The vote counts as the member's own vote, and replaces any previous vote of the member.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createVoteRecord",
+      "docs": [
+        "Instruction to vote on a transaction with a vote record, for multisigs with vote",
+        "records enabled. The vote is tallied on the transaction with the weight of the member,",
+        "or the snapshotted stake weight for vault transactions with a stake weight source.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createPagedVoteRecord",
+      "docs": [
+        "Instruction for a member of a page of additional members to vote on a transaction with a",
+        "vote record. Paged members vote with a weight of 1.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "membersPage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createProofVoteRecord",
+      "docs": [
+        "Instruction for a member of the set of the member root to vote on a transaction with a vote",
+        "record, along with the inclusion proof of its key. Members of the root vote with a weight of 1.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTokenVoteRecord",
+      "docs": [
+        "Instruction for a holder of a membership token to vote on a transaction with a token vote",
+        "record, for multisigs with vote records enabled and a membership gate. Token votes are",
+        "tallied apart from the member weights, against the token threshold of the gate, and can't",
+        "be changed. Each holder of a token of the mint, or each NFT of the collection, votes once.",
+        "Token votes only decide vault transactions, along with the members.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "votingKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "closeTokenVoteRecord",
+      "docs": [
+        "Instruction to close a token vote record once voting on the transaction has ended,",
+        "returning the rent to the voter."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voter",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeVoteRecord",
+      "docs": [
+        "Instruction to change the vote of a vote record, replacing the previous vote in the tallies.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "closeVoteRecord",
+      "docs": [
+        "Instruction to close a vote record once the transaction is no longer active,",
+        "returning the rent to the member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addVoteMemo",
+      "docs": [
+        "Instruction for a member to record a short memo (or the hash of a memo) with their vote,",
+        "ie. the reason for rejecting a transaction, so it is part of the audit trail. The member",
+        "must have cast the vote (approve, reject, cancel or abstain), and can record one memo per vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteMemo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          }
+        },
+        {
+          "name": "memo",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "abstainTransaction",
+      "docs": [
+        "Instruction to formally abstain from a transaction.",
+        "The transaction must have an \"active\" status. Abstaining replaces a previous",
+        "approval or rejection, and doesn't count towards either, but counts towards the quorum."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed.",
+        "A cancellation replaces the approval of the member, like any change of vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "vetoTransaction",
+      "docs": [
+        "Instruction to veto a transaction.",
+        "Transactions must be in the \"executeReady\" status and within the veto period",
+        "of the multisig. A single member with the veto permission is enough to cancel it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "councilVetoTransaction",
+      "docs": [
+        "Instruction for a key of the veto council to veto a transaction. The council can't approve,",
+        "but once its vetoes reach the veto council threshold the transaction is rejected, even after",
+        "it was approved. Transactions must be in the \"active\" or \"executeReady\" status, recurring",
+        "transactions are vetoed with council_veto_recurring_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "councilMember",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "councilVetoRecurringTransaction",
+      "docs": [
+        "Instruction for a key of the veto council to veto a recurring transaction. Once the vetoes",
+        "reach the veto council threshold the transaction is rejected and its schedule ends, the rent",
+        "of the recurring transaction being returned to the creator of the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "councilMember",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "",
+        "The account list can also be left empty, in which case the remaining accounts must start",
+        "with the instruction accounts in order, followed by the accounts of the instructions",
+        "deduplicated (in any order), and each instruction key is resolved from them.",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "executeInstruction",
+      "docs": [
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater. Once the first instruction is executed, other non-internal",
+        "transactions of the multisig can't be executed until this one is finalized with",
+        "finalize_execution (or cancelled/expired).",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeExecution",
+      "docs": [
+        "Instruction to finalize a transaction executed instruction by instruction, once",
+        "all of its instructions have been executed. Marks the transaction as executed, and",
+        "lets other transactions execute again. The execution hook (if any) must be passed",
+        "in the remaining accounts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExecutedInstructions",
+      "docs": [
+        "Instruction to close the instruction accounts of an executed transaction.",
+        "The instruction accounts are passed as remaining accounts, and their rent",
+        "is returned to the creator of the transaction. Anyone can close them."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeDraftTransaction",
+      "docs": [
+        "Instruction for the creator to close their transaction while it is still a draft (so no",
+        "one has voted on it), ie. an abandoned proposal. All of its instruction accounts must be",
+        "passed as remaining accounts, they're closed along with the transaction and the rent is",
+        "returned to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStaleTransaction",
+      "docs": [
+        "Instruction to close a stale transaction, one deprecated by a change of the multisig",
+        "that wasn't executed, reclaiming the rent for its creator. Anyone can close them.",
+        "All of its instruction accounts must be passed as remaining accounts, they're closed",
+        "along with the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSpendingLimit",
+      "docs": [
+        "The instruction to add a spending limit to an authority (vault).",
+        "The designated member can then spend up to the amount of the mint",
+        "per period from the vault without a proposal. The native SOL limit uses",
+        "the default pubkey as the mint. A period of 0 makes the amount a one-time budget."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "The instruction to remove a spending limit, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addRecovery",
+      "docs": [
+        "The instruction to add the inactivity recovery of the multisig. If no transaction is",
+        "executed for the number of epochs, the recovery key can replace all of the members."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recoveryKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "inactivityEpochs",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeRecovery",
+      "docs": [
+        "The instruction to remove the inactivity recovery, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recover",
+      "docs": [
+        "Instruction for the recovery key to replace the members and threshold of a multisig",
+        "that hasn't executed a transaction for the inactivity epochs of its recovery.",
+        "Reallocates space if neccessary, paid by the recovery key."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRecoverySet",
+      "docs": [
+        "The instruction to set the recovery set of the multisig, keys that can together replace",
+        "a single lost member key after the recovery delay, but can't change anything else.",
+        "An empty set with a threshold of 0 disables member recovery."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recoveryKeys",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "recoveryThreshold",
+          "type": "u16"
+        },
+        {
+          "name": "recoveryDelay",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setVetoCouncil",
+      "docs": [
+        "The instruction to set the veto council of the multisig, keys apart from the members that",
+        "can together reject any transaction before it is executed, but can't approve (nor spend).",
+        "An empty council with a threshold of 0 removes it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "vetoCouncil",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "vetoCouncilThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeMemberRecovery",
+      "docs": [
+        "Instruction for a key of the recovery set to propose replacing a lost member key,",
+        "which counts as its approval."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "approveMemberRecovery",
+      "docs": [
+        "Instruction for a key of the recovery set to approve a member recovery"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeMemberRecovery",
+      "docs": [
+        "Instruction to execute an approved member recovery once the recovery delay has passed,",
+        "which anyone can call. The rent of the recovery is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelMemberRecovery",
+      "docs": [
+        "Instruction for the old member to cancel a recovery of their key, proving it isn't lost.",
+        "The rent of the recovery is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldMember",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "spendFromVault",
+      "docs": [
+        "Instruction to move funds out of a vault under a spending limit.",
+        "Only the member designated in the spending limit can spend, and the amount",
+        "counts towards the limit of the current period."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createVaultAta",
+      "docs": [
+        "Instruction to create the associated token account of a vault for a mint, so the vault",
+        "can receive a new token without a proposal. Anyone can call it, paying the rent."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addAllowance",
+      "docs": [
+        "The instruction to add an allowance from an authority (vault). The key, which doesn't",
+        "have to be a member, can then pull up to the cap of the mint from the vault until the",
+        "optional expiration, ie. an expense account. The native SOL allowance uses the default pubkey as the mint."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "spender",
+          "type": "publicKey"
+        },
+        {
+          "name": "cap",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeAllowance",
+      "docs": [
+        "The instruction to remove an allowance, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPolicy",
+      "docs": [
+        "The instruction to add a policy config, registering an external policy program that",
+        "is consulted when vault transactions are activated, approved and/or executed (per the",
+        "stages mask), and can reject them by failing. A multisig has at most one policy config."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policyConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "policyProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "stages",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removePolicy",
+      "docs": [
+        "The instruction to remove the policy config, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policyConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createActivityLog",
+      "docs": [
+        "The instruction to create the activity log, a ring buffer of the most recent transaction",
+        "lifecycle actions (create, activate, approve, reject, cancel and execute) of the multisig.",
+        "Once created, the log must be passed in the remaining accounts of these instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "activityLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeActivityLog",
+      "docs": [
+        "The instruction to close the activity log, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "activityLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMemberPage",
+      "docs": [
+        "The instruction to create the next page of additional members, for rosters that don't fit",
+        "in the multisig account. Paged members vote with vote records, which must be enabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMemberRoot",
+      "docs": [
+        "The instruction to set (or remove) the merkle root of an additional member set, for signer",
+        "sets too large for member pages. Its members vote with vote records and an inclusion proof.",
+        "The member count of the set can't be checked on-chain, the members approving the root vouch for it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "memberRoot",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "memberCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setRatification",
+      "docs": [
+        "The instruction to set (or remove) the governance whose token holders must ratify every",
+        "transaction, so that the members can only execute what a proposal of the governance passed.",
+        "Internal transactions are held to it as well, so the requirement can only be lifted with",
+        "the token holders. Active transactions are deprecated, as they may lack a proposal."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "ratification",
+          "type": {
+            "option": {
+              "defined": "Ratification"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addPagedMember",
+      "docs": [
+        "The instruction to add a member to a page of additional members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removePagedMember",
+      "docs": [
+        "The instruction to remove a member from a page of additional members. Like remove_member,",
+        "the remaining members must still be able to reach the thresholds."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "spendAllowance",
+      "docs": [
+        "Instruction for the key of an allowance to pull funds out of the vault.",
+        "The amount counts towards the cap of the allowance. The accounts are passed",
+        "the same way as for spend_from_vault."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createMetadata",
+      "docs": [
+        "The instruction to create the metadata account of the multisig, labeling it with a",
+        "name, a short description and an image/URI hash for wallets and explorers."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "uriHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMetadata",
+      "docs": [
+        "The instruction to update the metadata account of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "uriHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createRecurringTransaction",
+      "docs": [
+        "The instruction to make an approved vault transaction recurring, ie. a monthly payroll.",
+        "Instead of being executed once, the instructions of the transaction are executed every",
+        "interval (in seconds) from start_at, by anyone cranking the recurring transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "interval",
+          "type": "i64"
+        },
+        {
+          "name": "startAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "removeRecurringTransaction",
+      "docs": [
+        "The instruction to end the schedule of a recurring transaction, returning the rent to the",
+        "rent collector. The transaction is marked as executed if it was executed at least once,",
+        "otherwise as cancelled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeRecurringTransaction",
+      "docs": [
+        "Instruction to execute a recurring transaction once it's due, which anyone can crank.",
+        "The execution condition and ratification (if any) are checked on every run.",
+        "The accounts are passed the same way as for execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "upgradeProgram",
+      "docs": [
+        "Instruction to upgrade a program from a buffer, checking that the sha256 hash of the",
+        "program in the buffer matches the expected hash. Attached to a vault transaction (the vault",
+        "being the upgrade authority), it binds the approval to the buffer contents, so the buffer",
+        "can't be swapped between approval and execution. A buffer of the vault holding the current",
+        "program data can be passed (with the managed program) as a remaining account, and is retained",
+        "so the upgrade can be rolled back with propose_rollback."
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "expectedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createManagedProgram",
+      "docs": [
+        "The instruction to add a program whose upgrade authority is an authority (vault) of the",
+        "multisig to its managed programs, replacing the separate program manager program."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "removeManagedProgram",
+      "docs": [
+        "The instruction to remove a program from the managed programs, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createProgramUpgrade",
+      "docs": [
+        "Instruction to propose an upgrade of a managed program, tracking the vault transaction that",
+        "carries the upgrade_program instruction and the buffer it upgrades from. The upgrade is marked",
+        "as upgraded when upgrade_program executes with the managed program and the upgrade passed as",
+        "remaining accounts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programUpgrade",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buffer",
+          "type": "publicKey"
+        },
+        {
+          "name": "expectedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeProgramUpgrade",
+      "docs": [
+        "The instruction to close a proposed upgrade of a managed program, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programUpgrade",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRollbackThreshold",
+      "docs": [
+        "The instruction to set the number of member approvals needed to roll back the last upgrade",
+        "of a managed program, meant to be lower than the threshold. 0 disables rollbacks."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rollbackThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeRollback",
+      "docs": [
+        "Instruction for a member to propose rolling back the last upgrade of a managed program",
+        "from the buffer it retained, which counts as their approval."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveRollback",
+      "docs": [
+        "Instruction for a member to approve the rollback of a managed program"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeRollback",
+      "docs": [
+        "Instruction to execute an approved rollback, which anyone can call. It is the expedited path",
+        "to undo a bad deploy, so it only needs the rollback threshold of approvals and isn't subject",
+        "to the time lock, but the vault can't sign while it's frozen or paused. The rent of the",
+        "rollback is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRollback",
+      "docs": [
+        "Instruction for the proposer to cancel a rollback, ie. one made stale by a later upgrade.",
+        "The rent of the rollback is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ms",
+      "docs": [
+        "Ms is the basic state account for a multisig.",
+        "Fields are appended after the keys in the order their layout versions introduced them,",
+        "so that an account of an older version reads the fields it lacks as zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowExternalExecute",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "defaultVaultIndex",
+            "type": "u32"
+          },
+          {
+            "name": "memberWeights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "memberPermissions",
+            "type": {
+              "vec": {
+                "defined": "MemberPermissions"
+              }
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "configThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "vetoPeriod",
+            "type": "u32"
+          },
+          {
+            "name": "memberPrograms",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "memberExpirations",
+            "type": {
+              "vec": {
+                "defined": "MemberExpiration"
+              }
+            }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "frozen",
+            "type": "bool"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "voteRecords",
+            "type": "bool"
+          },
+          {
+            "name": "executingTransaction",
+            "type": "u32"
+          },
+          {
+            "name": "programAllowlists",
+            "type": {
+              "vec": {
+                "defined": "ProgramAllowlist"
+              }
+            }
+          },
+          {
+            "name": "deniedInstructions",
+            "type": {
+              "vec": {
+                "defined": "DeniedInstruction"
+              }
+            }
+          },
+          {
+            "name": "removalDelay",
+            "type": "u32"
+          },
+          {
+            "name": "pendingRemoval",
+            "type": {
+              "option": {
+                "defined": "PendingRemoval"
+              }
+            }
+          },
+          {
+            "name": "lastExecutionEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "recoveryThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "recoveryDelay",
+            "type": "u32"
+          },
+          {
+            "name": "recoveryKeys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "retiredAuthorities",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "anyExecutor",
+            "type": "bool"
+          },
+          {
+            "name": "executors",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "executionHook",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "policyEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "activityLogEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          },
+          {
+            "name": "minQuorum",
+            "type": "u16"
+          },
+          {
+            "name": "thresholdPercent",
+            "type": "u8"
+          },
+          {
+            "name": "maxOpenTransactions",
+            "type": "u16"
+          },
+          {
+            "name": "openTransactions",
+            "type": {
+              "vec": {
+                "defined": "OpenTransactions"
+              }
+            }
+          },
+          {
+            "name": "parent",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "membershipGate",
+            "type": {
+              "option": {
+                "defined": "MembershipGate"
+              }
+            }
+          },
+          {
+            "name": "stakeWeightSource",
+            "type": {
+              "option": {
+                "defined": "StakeWeightSource"
+              }
+            }
+          },
+          {
+            "name": "valueTiers",
+            "type": {
+              "vec": {
+                "defined": "ValueTier"
+              }
+            }
+          },
+          {
+            "name": "executionWindow",
+            "type": {
+              "option": {
+                "defined": "ExecutionWindow"
+              }
+            }
+          },
+          {
+            "name": "blackoutPeriods",
+            "type": {
+              "vec": {
+                "defined": "BlackoutPeriod"
+              }
+            }
+          },
+          {
+            "name": "configTimeLock",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "adminExpiresAtSlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxMembers",
+            "type": "u16"
+          },
+          {
+            "name": "memberPages",
+            "type": "u8"
+          },
+          {
+            "name": "pagedMemberCount",
+            "type": "u32"
+          },
+          {
+            "name": "memberRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "rootMemberCount",
+            "type": "u32"
+          },
+          {
+            "name": "ratification",
+            "type": {
+              "option": {
+                "defined": "Ratification"
+              }
+            }
+          },
+          {
+            "name": "vetoCouncilThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "vetoCouncil",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msTransaction",
+      "docs": [
+        "The MsTransaction is the state account for a multisig transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "tag",
+            "type": {
+              "defined": "TransactionTag"
+            }
+          },
+          {
+            "name": "approved",
+            "type": "bytes"
+          },
+          {
+            "name": "rejected",
+            "type": "bytes"
+          },
+          {
+            "name": "cancelled",
+            "type": "bytes"
+          },
+          {
+            "name": "executedIndex",
+            "type": "u8"
+          },
+          {
+            "name": "linkedTx",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "executionBounty",
+            "type": "u64"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "abstained",
+            "type": "bytes"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "approvedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "rejectedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "voteRecordCount",
+            "type": "u16"
+          },
+          {
+            "name": "coCreators",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "executionCondition",
+            "type": {
+              "option": {
+                "defined": "ExecutionCondition"
+              }
+            }
+          },
+          {
+            "name": "ratifyingProposal",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "councilVetoes",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "tokenApproved",
+            "type": "u32"
+          },
+          {
+            "name": "tokenRejected",
+            "type": "u32"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msConfigTransaction",
+      "docs": [
+        "A config transaction holds typed config actions, rather than instructions for the multisig",
+        "to invoke on itself, so that signer UIs can render exactly what is changed. The actions are",
+        "checked against the multisig when proposed, and applied as one batch when executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "actions",
+            "type": {
+              "vec": {
+                "defined": "MsConfigAction"
+              }
+            }
+          },
+          {
+            "name": "approved",
+            "type": "bytes"
+          },
+          {
+            "name": "rejected",
+            "type": "bytes"
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstruction",
+      "docs": [
+        "Almost analagous to the native Instruction struct for solana, but with an extra",
+        "field for the bump."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "A SpendingLimit allows a designated key to move funds out of a vault (authority)",
+        "without a full proposal, up to `amount` per `period`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recurringTransaction",
+      "docs": [
+        "A RecurringTransaction executes the instructions of an approved transaction repeatedly,",
+        "every `interval` seconds. Anyone can crank the execution once it's due."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "interval",
+            "type": "i64"
+          },
+          {
+            "name": "nextExecutionAt",
+            "type": "i64"
+          },
+          {
+            "name": "executions",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegate",
+      "docs": [
+        "A Delegate lets the delegate key of a member approve and reject transactions on their behalf,",
+        "without changing the membership. The member can revoke it at any time by closing it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionKey",
+      "docs": [
+        "A SessionKey lets a short-lived key of a member (ie. a browser hot key) vote on its behalf,",
+        "restricted to the actions of the session and until the expiry slot, so the cold member",
+        "key doesn't have to sign. The member can revoke it at any time by closing it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "actions",
+            "type": "u8"
+          },
+          {
+            "name": "expiresAtSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recovery",
+      "docs": [
+        "A Recovery lets the recovery key rotate the full membership of a multisig that hasn't",
+        "executed a transaction for a number of epochs, ie. when its members lost their keys.",
+        "The recovery key can be a vault of another multisig, to recover with a backup member set."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "recoveryKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "inactivityEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberRecovery",
+      "docs": [
+        "A MemberRecovery is a proposal of the recovery set to replace a single lost member key.",
+        "Once approved by the recovery threshold, anyone can execute the replacement after the",
+        "recovery delay, unless the old member (who still holds their key) cancels it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "oldMember",
+            "type": "publicKey"
+          },
+          {
+            "name": "newMember",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeSnapshot",
+      "docs": [
+        "A StakeSnapshot holds the vote weights of the members read from their staking lockers when",
+        "the transaction was activated, so that locking more tokens afterwards doesn't change the vote."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "weights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenVoteRecord",
+      "docs": [
+        "A TokenVoteRecord holds the vote of a token holder on a transaction of a multisig with a",
+        "membership gate. It is recorded under the voting key, so each holder of a token of the mint",
+        "(or each NFT of the collection) votes once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "votingKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "voter",
+            "type": "publicKey"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteRecord",
+      "docs": [
+        "A VoteRecord holds the vote of a single member on a transaction, for multisigs with",
+        "vote records enabled. The transaction only keeps the tallies, so it doesn't grow with",
+        "the number of voting members."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteMemo",
+      "docs": [
+        "A VoteMemo holds the memo a member recorded along with their vote on a transaction,",
+        "ie. the reason of a rejection, or the hash of a longer memo kept elsewhere."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "vote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "memo",
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "allowance",
+      "docs": [
+        "An Allowance lets a key that doesn't have to be a member pull funds out of a vault",
+        "(authority), up to a total `cap` and until the optional expiration."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "spender",
+            "type": "publicKey"
+          },
+          {
+            "name": "cap",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMetadata",
+      "docs": [
+        "MsMetadata is an optional account labeling a multisig for wallets and explorers.",
+        "It can only be created and updated through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "uriHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityRecord",
+      "docs": [
+        "AuthorityRecord is an optional account labeling an authority (vault) of the multisig,",
+        "so clients know what each authority index is used for. It can only be created and",
+        "updated through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "label",
+            "type": "string"
+          },
+          {
+            "name": "purpose",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "policyConfig",
+      "docs": [
+        "A PolicyConfig registers an external policy program for a multisig, ie. for spending rules,",
+        "KYC gating or risk scoring. The policy program is invoked with a `check_policy(stage, actor)`",
+        "instruction (Anchor style discriminator) and the policy config, multisig and transaction",
+        "accounts (read only) at the stages in its mask, and rejects the action by failing.",
+        "Internal (authority 0) transactions aren't checked, so the multisig can always remove it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "policyProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "stages",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msActivityLog",
+      "docs": [
+        "The MsActivityLog is an optional ring buffer of the most recent transaction lifecycle actions",
+        "of a multisig, so explorers and auditors can show the recent history without replaying it.",
+        "Once full, the entry at `head` is the oldest and is overwritten next."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "ActivityEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMembers",
+      "docs": [
+        "An MsMembers page holds additional members of a multisig, for rosters that don't fit in the",
+        "keys of the multisig account. Paged members can only vote, with vote records and a weight of 1,",
+        "they can't initiate or execute transactions. A key shared with the multisig keys still votes",
+        "once per transaction, as the vote records are seeded by the key."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "pageIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "managedProgram",
+      "docs": [
+        "A ManagedProgram lists a program whose upgrade authority is an authority (vault) of the",
+        "multisig, so clients can show the programs of a squad along with their upgrade history.",
+        "It can only be created and removed through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "programAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "upgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "lastUpgrade",
+            "type": "i64"
+          },
+          {
+            "name": "lastUpgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rollbackThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "rollbackBuffer",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "rollbackHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programRollback",
+      "docs": [
+        "A ProgramRollback is a proposal of the members to restore a managed program from the buffer",
+        "retained by its last upgrade. Once approved by the rollback threshold of the managed program,",
+        "anyone can execute it, without the time lock of vault transactions."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managedProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "buffer",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programUpgrade",
+      "docs": [
+        "A ProgramUpgrade tracks a proposed upgrade of a managed program, ie. the vault transaction",
+        "carrying the upgrade_program instruction and the buffer it upgrades from. It is marked as",
+        "upgraded when upgrade_program executes with the managed program and the upgrade passed as",
+        "remaining accounts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managedProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "upgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "buffer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expectedHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "upgradedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MemberWeight",
+      "docs": [
+        "Vote weight override for a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ValueTier",
+      "docs": [
+        "The threshold for vault transactions transferring at least the min amount of the mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionWindow",
+      "docs": [
+        "The UTC days and hours vault transactions can be executed in, ie. weekdays from 09:00 to 18:00.",
+        "A window ending before it starts spans midnight, ie. from 22:00 to 06:00."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "days",
+            "type": "u8"
+          },
+          {
+            "name": "startMinute",
+            "type": "u16"
+          },
+          {
+            "name": "endMinute",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BlackoutPeriod",
+      "docs": [
+        "A time range (unix timestamps, end excluded) vault transactions can't be executed in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "start",
+            "type": "i64"
+          },
+          {
+            "name": "end",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingRemoval",
+      "docs": [
+        "A member removal waiting for the removal delay of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "removeAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberExpiration",
+      "docs": [
+        "Membership expiration of a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OpenTransactions",
+      "docs": [
+        "The number of open transactions of a creator, for the max open transactions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "count",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramAllowlist",
+      "docs": [
+        "Programs that the instructions of transactions of an authority may target"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "programs",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DeniedInstruction",
+      "docs": [
+        "A program and instruction data prefix (ie. a discriminator) pair that is denied"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "prefix",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberPermissions",
+      "docs": [
+        "Permission override for a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionTag",
+      "docs": [
+        "The tag of a transaction, ie. the \"PAYR\" category with the payroll run as the reference.",
+        "Untagged transactions have a zero category."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "category",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "reference",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionCondition",
+      "docs": [
+        "A condition on an oracle price that must hold when a transaction is executed, ie. only",
+        "rebalance the treasury while SOL/USD is above a floor. The price is price * 10^expo,",
+        "and the oracle price must have been updated within the max age."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracle",
+            "type": {
+              "defined": "OracleKind"
+            }
+          },
+          {
+            "name": "priceAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "comparison",
+            "type": {
+              "defined": "PriceComparison"
+            }
+          },
+          {
+            "name": "price",
+            "type": "i64"
+          },
+          {
+            "name": "expo",
+            "type": "i32"
+          },
+          {
+            "name": "maxAge",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalRequest",
+      "docs": [
+        "Self-describing summary of a transaction that is serialized (after a fixed prefix)",
+        "to form the approval request blob signed by offline signers."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          },
+          {
+            "name": "instructionHashes",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SignedVote",
+      "docs": [
+        "Vote of a member that is serialized (after a fixed prefix) to form the message",
+        "signed offline and verified through the ed25519 program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteCounts",
+      "docs": [
+        "The vote counts of a transaction, returned by the get_vote_counts view.",
+        "With vote records enabled, votes are only tallied by weight, so the member counts are zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "approved",
+            "type": "u16"
+          },
+          {
+            "name": "rejected",
+            "type": "u16"
+          },
+          {
+            "name": "cancelled",
+            "type": "u16"
+          },
+          {
+            "name": "abstained",
+            "type": "u16"
+          },
+          {
+            "name": "approvedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "rejectedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Ratification",
+      "docs": [
+        "The SPL governance whose token holders must ratify the transactions of a multisig, on top of",
+        "the approvals of the members. A transaction records a proposal of the governance, voted on with",
+        "the governing token, which must have succeeded by the time the transaction is executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "governanceProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "governance",
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StakeWeightSource",
+      "docs": [
+        "The staking (ie. vote escrow) program whose locker accounts the vote weights of the members",
+        "are read from. The locker of a member is the PDA of the program with the seeds",
+        "[seed prefix, registrar, member], and holds the locked amount as a u64 at the amount offset."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program",
+            "type": "publicKey"
+          },
+          {
+            "name": "registrar",
+            "type": "publicKey"
+          },
+          {
+            "name": "seedPrefix",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "amountOffset",
+            "type": "u16"
+          },
+          {
+            "name": "amountPerVote",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ActivityEntry",
+      "docs": [
+        "An entry of the activity log, the actor is the member (or executor/cranker) taking the action"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "actor",
+            "type": "publicKey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "ActivityAction"
+            }
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionHookStage",
+      "docs": [
+        "The stage of the execution an execution hook is invoked for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PreExecute"
+          },
+          {
+            "name": "PostExecute"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigAction",
+      "docs": [
+        "The config change that was applied to the multisig"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AddMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberWeight",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "weight",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberPermissions",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "permissions",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "AddAuthority",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeDefaultVault",
+            "fields": [
+              {
+                "name": "vault_index",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeTimeLock",
+            "fields": [
+              {
+                "name": "time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "AddSpendingLimit",
+            "fields": [
+              {
+                "name": "spending_limit",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveSpendingLimit",
+            "fields": [
+              {
+                "name": "spending_limit",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigThreshold",
+            "fields": [
+              {
+                "name": "config_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeVetoPeriod",
+            "fields": [
+              {
+                "name": "veto_period",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberProgram",
+            "fields": [
+              {
+                "name": "member_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "allowed",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberExpiration",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "expires_at",
+                "type": {
+                  "option": "i64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "PruneExpiredMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Unfreeze"
+          },
+          {
+            "name": "Pause"
+          },
+          {
+            "name": "Unpause"
+          },
+          {
+            "name": "SetMetadata",
+            "fields": [
+              {
+                "name": "metadata",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddRecurringTransaction",
+            "fields": [
+              {
+                "name": "recurring_transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveRecurringTransaction",
+            "fields": [
+              {
+                "name": "recurring_transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddAllowance",
+            "fields": [
+              {
+                "name": "allowance",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveAllowance",
+            "fields": [
+              {
+                "name": "allowance",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetVoteRecords",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "Migrate",
+            "fields": [
+              {
+                "name": "version",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetProgramAllowlist",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              },
+              {
+                "name": "programs",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetDeniedInstruction",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "prefix",
+                "type": "bytes"
+              },
+              {
+                "name": "denied",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "ChangeRemovalDelay",
+            "fields": [
+              {
+                "name": "removal_delay",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ScheduleMemberRemoval",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "remove_at",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "AbortMemberRemoval",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddRecovery",
+            "fields": [
+              {
+                "name": "recovery",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveRecovery",
+            "fields": [
+              {
+                "name": "recovery",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetRecoverySet",
+            "fields": [
+              {
+                "name": "recovery_keys",
+                "type": {
+                  "vec": "publicKey"
+                }
+              },
+              {
+                "name": "recovery_threshold",
+                "type": "u16"
+              },
+              {
+                "name": "recovery_delay",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceMember",
+            "fields": [
+              {
+                "name": "old_member",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RetireAuthority",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetAuthorityRecord",
+            "fields": [
+              {
+                "name": "authority_record",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetExecutors",
+            "fields": [
+              {
+                "name": "any_executor",
+                "type": "bool"
+              },
+              {
+                "name": "executors",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetExecutionHook",
+            "fields": [
+              {
+                "name": "execution_hook",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "AddPolicy",
+            "fields": [
+              {
+                "name": "policy_config",
+                "type": "publicKey"
+              },
+              {
+                "name": "policy_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "stages",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "RemovePolicy",
+            "fields": [
+              {
+                "name": "policy_config",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CreateActivityLog",
+            "fields": [
+              {
+                "name": "activity_log",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CloseActivityLog",
+            "fields": [
+              {
+                "name": "activity_log",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMinQuorum",
+            "fields": [
+              {
+                "name": "min_quorum",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThresholdPercent",
+            "fields": [
+              {
+                "name": "threshold_percent",
+                "type": "u8"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMaxOpenTransactions",
+            "fields": [
+              {
+                "name": "max_open_transactions",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMaxMembers",
+            "fields": [
+              {
+                "name": "max_members",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "CreateMemberPage",
+            "fields": [
+              {
+                "name": "members_page",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "AddPagedMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "RemovePagedMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberRoot",
+            "fields": [
+              {
+                "name": "member_root",
+                "type": {
+                  "option": {
+                    "array": [
+                      "u8",
+                      32
+                    ]
+                  }
+                }
+              },
+              {
+                "name": "member_count",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetRatification",
+            "fields": [
+              {
+                "name": "ratification",
+                "type": {
+                  "option": {
+                    "defined": "Ratification"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetVetoCouncil",
+            "fields": [
+              {
+                "name": "veto_council",
+                "type": {
+                  "vec": "publicKey"
+                }
+              },
+              {
+                "name": "veto_council_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "InvalidateTransaction",
+            "fields": [
+              {
+                "name": "transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetParent",
+            "fields": [
+              {
+                "name": "parent",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMembershipGate",
+            "fields": [
+              {
+                "name": "membership_gate",
+                "type": {
+                  "option": {
+                    "defined": "MembershipGate"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetStakeWeightSource",
+            "fields": [
+              {
+                "name": "stake_weight_source",
+                "type": {
+                  "option": {
+                    "defined": "StakeWeightSource"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetValueTiers",
+            "fields": [
+              {
+                "name": "value_tiers",
+                "type": {
+                  "vec": {
+                    "defined": "ValueTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetExecutionWindow",
+            "fields": [
+              {
+                "name": "execution_window",
+                "type": {
+                  "option": {
+                    "defined": "ExecutionWindow"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetBlackoutPeriods",
+            "fields": [
+              {
+                "name": "blackout_periods",
+                "type": {
+                  "vec": {
+                    "defined": "BlackoutPeriod"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigTimeLock",
+            "fields": [
+              {
+                "name": "config_time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetAdmin",
+            "fields": [
+              {
+                "name": "admin",
+                "type": "publicKey"
+              },
+              {
+                "name": "admin_expires_at_slot",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "RenounceAdmin",
+            "fields": [
+              {
+                "name": "admin",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CreateManagedProgram",
+            "fields": [
+              {
+                "name": "managed_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "program_address",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveManagedProgram",
+            "fields": [
+              {
+                "name": "managed_program",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vote",
+      "docs": [
+        "The type of vote cast on a transaction"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Cancel"
+          },
+          {
+            "name": "Abstain"
+          },
+          {
+            "name": "Veto"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsConfigAction",
+      "docs": [
+        "A typed change of the multisig config, for applying several changes as one batch"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AddMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceMember",
+            "fields": [
+              {
+                "name": "old_member",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigThreshold",
+            "fields": [
+              {
+                "name": "config_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberWeight",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "weight",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberPermissions",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "permissions",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberExpiration",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "expires_at",
+                "type": {
+                  "option": "i64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "ChangeTimeLock",
+            "fields": [
+              {
+                "name": "time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeVetoPeriod",
+            "fields": [
+              {
+                "name": "veto_period",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigTimeLock",
+            "fields": [
+              {
+                "name": "config_time_lock",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsTransactionStatus",
+      "docs": [
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Active"
+          },
+          {
+            "name": "ExecuteReady"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Expired"
+          },
+          {
+            "name": "Recurring"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleKind",
+      "docs": [
+        "The state account for an instruction that is attached to a transaction.",
+        "The oracle that publishes the price account of an execution condition"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Switchboard"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceComparison",
+      "docs": [
+        "How the oracle price is compared to the price of an execution condition"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Above"
+          },
+          {
+            "name": "Below"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StakeAction",
+      "docs": [
+        "Typed stake program actions for a stake account whose stake and withdraw authority",
+        "is a vault, so the instruction doesn't have to be encoded by hand."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Delegate",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "vote",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Deactivate",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Withdraw",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Split",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "split_stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Merge",
+            "fields": [
+              {
+                "name": "destination_stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "source_stake",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteAction",
+      "docs": [
+        "Typed vote program actions for a vote account whose withdraw authority is a vault,",
+        "so validator teams don't have to encode the instruction by hand."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AuthorizeWithdrawer",
+            "fields": [
+              {
+                "name": "vote_account",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_withdrawer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Withdraw",
+            "fields": [
+              {
+                "name": "vote_account",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "GovernanceAction",
+      "docs": [
+        "Typed SPL Governance actions for a vault that is the authority of a realm, or that holds",
+        "governing (ie. council) tokens in it, so proposals translate into realm instructions",
+        "without encoding them by hand. Realms with voter weight addins aren't supported."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetRealmAuthority",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_realm_authority",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "DepositGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_source",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "WithdrawGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_destination",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetGovernanceDelegate",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_governance_delegate",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "CastVote",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governance",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal_owner_record",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "approve",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RelinquishVote",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governance",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RevokeGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_owner",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdlAction",
+      "docs": [
+        "Typed Anchor IDL actions for a program whose IDL authority is a vault, so the rarely used",
+        "IDL instructions don't have to be encoded by hand. Closing the IDL account needs the program",
+        "to be built with an Anchor version that supports it (0.29 or later)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetAuthority",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_authority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetBufferAuthority",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_authority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetBuffer",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Close",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "TypedAction",
+      "docs": [
+        "The typed actions that can be attached to a vault transaction with add_typed_instruction,",
+        "each building its instruction with the vault of the transaction as the signing authority."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Stake",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "StakeAction"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Vote",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "VoteAction"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Idl",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "IdlAction"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Governance",
+            "fields": [
+              {
+                "name": "governance_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "action",
+                "type": {
+                  "defined": "GovernanceAction"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "MembershipGate",
+      "docs": [
+        "The mint or NFT collection whose token holders can vote on the vault transactions of a multisig.",
+        "Tokens of a mint must be non-transferable (Token-2022), as a balance that can be split or moved",
+        "between wallets would vote again."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Mint",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "Collection",
+            "fields": [
+              {
+                "name": "collection",
+                "type": "publicKey"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PolicyStage",
+      "docs": [
+        "The stage of a vault transaction a policy program is consulted for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Activate"
+          },
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Execute"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ActivityAction",
+      "docs": [
+        "The transaction lifecycle actions recorded in the activity log"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Create"
+          },
+          {
+            "name": "Activate"
+          },
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Cancel"
+          },
+          {
+            "name": "Execute"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "ConfigAction"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SeqAdvanced",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "seq",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigFrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigRecovered",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recoveryKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "linkedTransaction",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "VoteCast",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "VoteMemoAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": "bytes",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionStatusChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllowanceUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "allowance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "KeyNotInMultisig"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidTransactionState"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidNumberOfAccounts"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidInstructionAccount"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidAuthorityIndex"
+    },
+    {
+      "code": 6005,
+      "name": "TransactionAlreadyExecuted"
+    },
+    {
+      "code": 6006,
+      "name": "CannotRemoveSoloMember"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidThreshold"
+    },
+    {
+      "code": 6008,
+      "name": "DeprecatedTransaction"
+    },
+    {
+      "code": 6009,
+      "name": "InstructionFailed"
+    },
+    {
+      "code": 6010,
+      "name": "MaxMembersReached"
+    },
+    {
+      "code": 6011,
+      "name": "EmptyMembers"
+    },
+    {
+      "code": 6012,
+      "name": "PartialExecution"
+    },
+    {
+      "code": 6013,
+      "name": "NotEnoughLamports"
+    },
+    {
+      "code": 6014,
+      "name": "VoteCapacityExceeded"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidApprovalRequest"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidMemberWeight"
+    },
+    {
+      "code": 6017,
+      "name": "MissingPermission"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6019,
+      "name": "TimeLockNotElapsed"
+    },
+    {
+      "code": 6020,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidExpiration"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidSpendingLimit"
+    },
+    {
+      "code": 6023,
+      "name": "SpendingLimitExceeded"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidLookupTable"
+    },
+    {
+      "code": 6025,
+      "name": "VetoPeriodElapsed"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMemberProgram"
+    },
+    {
+      "code": 6027,
+      "name": "MemberExpired"
+    },
+    {
+      "code": 6028,
+      "name": "MemberNotExpired"
+    },
+    {
+      "code": 6029,
+      "name": "MultisigFrozen"
+    },
+    {
+      "code": 6030,
+      "name": "NotGuardian"
+    },
+    {
+      "code": 6031,
+      "name": "MultisigPaused"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMetadata"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidTransactionDescription"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidSignedVote"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidDelegate"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidRecurringTransaction"
+    },
+    {
+      "code": 6037,
+      "name": "RecurringTransactionNotDue"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidBuffer"
+    },
+    {
+      "code": 6039,
+      "name": "BufferHashMismatch"
+    },
+    {
+      "code": 6040,
+      "name": "AllowanceExceeded"
+    },
+    {
+      "code": 6041,
+      "name": "AllowanceExpired"
+    },
+    {
+      "code": 6042,
+      "name": "VoteRecordsEnabled"
+    },
+    {
+      "code": 6043,
+      "name": "VoteRecordsDisabled"
+    },
+    {
+      "code": 6044,
+      "name": "AlreadyMigrated"
+    },
+    {
+      "code": 6045,
+      "name": "ExecutionInProgress"
+    },
+    {
+      "code": 6046,
+      "name": "ExecutionIncomplete"
+    },
+    {
+      "code": 6047,
+      "name": "InstructionDataHashMismatch"
+    },
+    {
+      "code": 6048,
+      "name": "ProgramNotAllowed"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidProgramAllowlist"
+    },
+    {
+      "code": 6050,
+      "name": "InstructionDenied"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidDeniedInstruction"
+    },
+    {
+      "code": 6052,
+      "name": "RemovalPending"
+    },
+    {
+      "code": 6053,
+      "name": "NoPendingRemoval"
+    },
+    {
+      "code": 6054,
+      "name": "RemovalDelayNotElapsed"
+    },
+    {
+      "code": 6055,
+      "name": "RemovalDelayed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRecovery"
+    },
+    {
+      "code": 6057,
+      "name": "RecoveryNotDue"
+    },
+    {
+      "code": 6058,
+      "name": "DuplicateMember"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidRecoverySet"
+    },
+    {
+      "code": 6060,
+      "name": "NotRecoveryKey"
+    },
+    {
+      "code": 6061,
+      "name": "RecoveryAlreadyApproved"
+    },
+    {
+      "code": 6062,
+      "name": "RecoveryNotApproved"
+    },
+    {
+      "code": 6063,
+      "name": "RecoveryDelayNotElapsed"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidConfigActions"
+    },
+    {
+      "code": 6065,
+      "name": "AuthorityRetired"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidAuthorityRecord"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidVoteAction"
+    },
+    {
+      "code": 6068,
+      "name": "VoteWithdrawTooLarge"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidExecutors"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidExecutionHook"
+    },
+    {
+      "code": 6071,
+      "name": "MissingExecutionHook"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidPolicy"
+    },
+    {
+      "code": 6073,
+      "name": "MissingPolicy"
+    },
+    {
+      "code": 6074,
+      "name": "MissingActivityLog"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidVoteMemo"
+    },
+    {
+      "code": 6076,
+      "name": "VoteNotCast"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidQuorum"
+    },
+    {
+      "code": 6078,
+      "name": "MaxOpenTransactionsReached"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidCoCreators"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidGovernanceAction"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidMembershipToken"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidStakeLocker"
+    },
+    {
+      "code": 6083,
+      "name": "StakeSnapshotRequired"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidSessionKey"
+    },
+    {
+      "code": 6085,
+      "name": "SessionKeyExpired"
+    },
+    {
+      "code": 6086,
+      "name": "InvalidValueTier"
+    },
+    {
+      "code": 6087,
+      "name": "InvalidPriceAccount"
+    },
+    {
+      "code": 6088,
+      "name": "ExecutionConditionNotMet"
+    },
+    {
+      "code": 6089,
+      "name": "InvalidExecutionWindow"
+    },
+    {
+      "code": 6090,
+      "name": "OutsideExecutionWindow"
+    },
+    {
+      "code": 6091,
+      "name": "InvalidAdmin"
+    },
+    {
+      "code": 6092,
+      "name": "NotAdmin"
+    },
+    {
+      "code": 6093,
+      "name": "AdminExpired"
+    },
+    {
+      "code": 6094,
+      "name": "InvalidManagedProgram"
+    },
+    {
+      "code": 6095,
+      "name": "InvalidRollbackBuffer"
+    },
+    {
+      "code": 6096,
+      "name": "RollbackNotApproved"
+    },
+    {
+      "code": 6097,
+      "name": "RollbackAlreadyApproved"
+    },
+    {
+      "code": 6098,
+      "name": "InvalidIdlAction"
+    },
+    {
+      "code": 6099,
+      "name": "AuthorityIndexOverflow"
+    },
+    {
+      "code": 6100,
+      "name": "MultisigNotEmpty"
+    },
+    {
+      "code": 6101,
+      "name": "InvalidStatusTransition"
+    },
+    {
+      "code": 6102,
+      "name": "ThresholdUnderflow"
+    },
+    {
+      "code": 6103,
+      "name": "SizeOverflow"
+    },
+    {
+      "code": 6104,
+      "name": "ThresholdTooLow"
+    },
+    {
+      "code": 6105,
+      "name": "ThresholdTooHigh"
+    },
+    {
+      "code": 6106,
+      "name": "RemovalBelowThreshold"
+    },
+    {
+      "code": 6107,
+      "name": "InvalidMaxMembers"
+    },
+    {
+      "code": 6108,
+      "name": "UnsortedMembers"
+    },
+    {
+      "code": 6109,
+      "name": "VoteRecordsRequired"
+    },
+    {
+      "code": 6110,
+      "name": "MemberPageFull"
+    },
+    {
+      "code": 6111,
+      "name": "InvalidMemberRoot"
+    },
+    {
+      "code": 6112,
+      "name": "InvalidMemberProof"
+    },
+    {
+      "code": 6113,
+      "name": "RatificationRequired"
+    },
+    {
+      "code": 6114,
+      "name": "InvalidRatifyingProposal"
+    },
+    {
+      "code": 6115,
+      "name": "NotRatified"
+    },
+    {
+      "code": 6116,
+      "name": "InvalidVetoCouncil"
+    },
+    {
+      "code": 6117,
+      "name": "NotVetoCouncilMember"
+    },
+    {
+      "code": 6118,
+      "name": "AlreadyVetoed"
+    },
+    {
+      "code": 6119,
+      "name": "InvalidMembershipGate"
+    },
+    {
+      "code": 6120,
+      "name": "TransactionIndexOverflow"
+    },
+    {
+      "code": 6121,
+      "name": "TallyOverflow"
+    },
+    {
+      "code": 6122,
+      "name": "TimestampOverflow"
+    },
+    {
+      "code": 6123,
+      "name": "BountyOverflow"
+    },
+    {
+      "code": 6124,
+      "name": "InstructionIndexOverflow"
+    },
+    {
+      "code": 6125,
+      "name": "NotMigrated"
+    }
+  ]
+};
+
+export const IDL: SquadsMpl = {
+  "version": "1.3.0",
+  "name": "squads_mpl",
+  "instructions": [
+    {
+      "name": "create",
+      "docs": [
+        "Creates a new multisig account"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "meta",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createWithAdmin",
+      "docs": [
+        "Creates a new multisig account with an admin, a bootstrap key that can apply config",
+        "changes on its own until the expiry slot (at most about 30 days away) or until it",
+        "renounces, so new teams can set up the multisig before it is fully trust-minimized."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "meta",
+          "type": "string"
+        },
+        {
+          "name": "admin",
+          "type": "publicKey"
+        },
+        {
+          "name": "adminExpiresAtSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addMember",
+      "docs": [
+        "The instruction to add a new member to the multisig.",
+        "Adds member/key to the multisig and reallocates space if neccessary",
+        "If the multisig needs to be reallocated, the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account, or the multisig",
+        "must be prefunded with enough lamports to cover the new size."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "applyConfigActions",
+      "docs": [
+        "The instruction to apply several config changes as one batch, ie. add a member, remove",
+        "another and raise the threshold. The changes are applied in order and the multisig is",
+        "validated once all of them are applied, then active transactions are deprecated once.",
+        "Reallocates space if neccessary, in which case the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "adminConfigActions",
+      "docs": [
+        "The instruction for the admin to apply config changes on its own, like apply_config_actions",
+        "without a vote, until the admin expires. Reallocates space if neccessary, paid by the admin."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "renounceAdmin",
+      "docs": [
+        "The instruction for the admin to give up its powers before it expires. It can't be set again."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMemberProgram",
+      "docs": [
+        "The instruction to allow (or disallow) PDAs of a program to be added as members.",
+        "Reallocates space if neccessary, in which case the rent is topped up by a signing",
+        "payer or the default vault passed as a remaining account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "memberProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "allowed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setProgramAllowlist",
+      "docs": [
+        "The instruction to restrict the programs that instructions of transactions of an",
+        "authority (vault) may target, ie. only the token and system programs. An empty list",
+        "removes the restriction. Instructions are checked when attached and when executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutors",
+      "docs": [
+        "The instruction to set the keys that can execute approved transactions without being",
+        "members, ie. a keeper service, or to let any key execute them. Replaces the deprecated",
+        "allow_external_execute flag."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "anyExecutor",
+          "type": "bool"
+        },
+        {
+          "name": "executors",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionHook",
+      "docs": [
+        "The instruction to set (or remove) the execution hook, a program invoked with",
+        "pre_execute and post_execute instructions around the execution of vault transactions,",
+        "which can abort the execution by failing. Internal transactions don't invoke the hook."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "executionHook",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setParent",
+      "docs": [
+        "The instruction to set (or remove) the parent of the multisig, the vault authority of",
+        "another multisig. The parent formally proposes transactions into this multisig through",
+        "CPI, like a member with only the initiate permission, but doesn't vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "parent",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMembershipGate",
+      "docs": [
+        "The instruction to set (or remove) the membership gate, the non-transferable mint or verified",
+        "NFT collection whose holders vote with token vote records, resolved at vote time, and the number",
+        "of token votes that vault transactions need besides the members. The token threshold must be at least 1. Vote records",
+        "must be enabled for token votes. Any active transactions are deprecated, as the voters change."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "membershipGate",
+          "type": {
+            "option": {
+              "defined": "MembershipGate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionWindow",
+      "docs": [
+        "The instruction to set (or remove) the execution window, the UTC days and hours vault",
+        "transactions can be executed in, ie. weekdays from 09:00 to 18:00. Spending limits,",
+        "allowances and recurring transactions are held to it as well. Internal transactions",
+        "aren't, so the multisig can always change its schedule."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "executionWindow",
+          "type": {
+            "option": {
+              "defined": "ExecutionWindow"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBlackoutPeriods",
+      "docs": [
+        "The instruction to replace the blackout periods, the time ranges vault transactions can't",
+        "be executed in, whatever the execution window. An empty list removes the periods."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "blackoutPeriods",
+          "type": {
+            "vec": {
+              "defined": "BlackoutPeriod"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setValueTiers",
+      "docs": [
+        "The instruction to replace the value tiers, the thresholds of vault transactions by the total",
+        "amount of SOL (the default pubkey as the mint) or of a mint they transfer, ie. 1 below 1 SOL",
+        "and 4 from 100 SOL. The tiers are applied when transactions are activated, so their",
+        "instruction accounts must be passed to activate them. An empty list removes the tiers."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "valueTiers",
+          "type": {
+            "vec": {
+              "defined": "ValueTier"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setStakeWeightSource",
+      "docs": [
+        "The instruction to set (or remove) the stake weight source, the staking program whose lockers",
+        "the vote weights of the members are read from, snapshotted when transactions are activated.",
+        "The member weights cap the stake weights, so they must be raised to give stake its influence,",
+        "and internal transactions keep the member weights.",
+        "Vote records must be enabled, as stake weights are tallied with vote records.",
+        "Any active transactions are deprecated, as the vote weights change."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stakeWeightSource",
+          "type": {
+            "option": {
+              "defined": "StakeWeightSource"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDeniedInstruction",
+      "docs": [
+        "The instruction to deny (or allow again) instructions of a program whose data starts",
+        "with the prefix, ie. SetAuthority on the token program. Instructions are checked when",
+        "attached (including appended data) and when executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "prefix",
+          "type": "bytes"
+        },
+        {
+          "name": "denied",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "invalidateTransaction",
+      "docs": [
+        "The instruction to invalidate a single pending (draft, active or approved) transaction,",
+        "marking it as cancelled, without deprecating the other active transactions the way",
+        "a change of the multisig does."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "retireAuthority",
+      "docs": [
+        "The instruction to decommission an authority (vault): all of its lamports are swept to",
+        "the destination and the authority is marked retired, so no transactions of it can be",
+        "created or executed anymore. The default vault can't be retired."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addProgramMember",
+      "docs": [
+        "The instruction to add a PDA of an allowed member program as a member.",
+        "The seeds (including the bump) must derive the PDA from the program, which then",
+        "approves, rejects and cancels transactions by signing for the PDA through CPI."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "memberProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "seeds",
+          "type": {
+            "vec": "bytes"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeMember",
+      "docs": [
+        "The instruction to remove a member from the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "replaceMember",
+      "docs": [
+        "The instruction to swap the key of a member in one step, ie. to rotate a key. The weight,",
+        "permissions and expiration of the member carry over, so the threshold is unchanged, and",
+        "active transactions are deprecated like for any change of the members."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "finalizeMemberRemoval",
+      "docs": [
+        "The instruction to finalize a pending member removal once the removal delay has passed,",
+        "which anyone can call."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "abortMemberRemoval",
+      "docs": [
+        "The instruction to abort a pending member removal during the removal delay.",
+        "A single member with the veto permission (other than the member being removed) is enough."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeRemovalDelay",
+      "docs": [
+        "The instruction to change the removal delay of the multisig, the number of seconds",
+        "between a member removal being executed and taking effect. 0 removes members right away."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "removalDelay",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setMemberExpiration",
+      "docs": [
+        "The instruction to set when the membership of a member expires, None removes the expiration.",
+        "Expired members can no longer vote, their earlier votes no longer count and anyone",
+        "can prune them from the multisig. Reallocates space if neccessary, in which case the rent",
+        "is topped up by a signing payer or the default vault passed as a remaining account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pruneExpiredMember",
+      "docs": [
+        "The instruction to remove a member whose membership has expired, which anyone can call."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeMemberAndChangeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig and simultaneously remove a member.",
+        "The threshold is changed first, so it can be lowered to what the remaining members can reach."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "addMemberAndChangeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig and simultaneously add a member"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeThresholdPercent",
+      "docs": [
+        "The instruction to set the threshold as a percentage of the total vote weight (ie. 60),",
+        "which is then recomputed whenever members are added or removed or their weights change.",
+        "A threshold percent of 0 switches back to an absolute threshold, keeping the current one."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "thresholdPercent",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changeConfigThreshold",
+      "docs": [
+        "The instruction to change the threshold for internal transactions, ie. member",
+        "and threshold changes, separately from the threshold for vault transactions.",
+        "A config threshold of 0 falls back to the regular threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "configThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMinQuorum",
+      "docs": [
+        "The instruction to change the min quorum, the number of members that must have voted",
+        "(approved, rejected or abstained) before a transaction is decided, independently of the",
+        "threshold. A min quorum of 0 disables the quorum."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "minQuorum",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMaxOpenTransactions",
+      "docs": [
+        "The instruction to cap the transactions a single creator can have open (neither executed,",
+        "rejected, cancelled, expired nor deprecated), so one member can't flood the queue of the",
+        "others. A max open transactions of 0 removes the cap. Transactions are only counted while",
+        "the cap is set, so active transactions are deprecated."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxOpenTransactions",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMaxMembers",
+      "docs": [
+        "The instruction to cap the number of members of the multisig, enforced when members are",
+        "added. A max members of 0 removes the cap, leaving the global ceiling of Ms::MAX_MEMBERS."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxMembers",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setMemberWeight",
+      "docs": [
+        "The instruction to set the vote weight of a member.",
+        "Approvals, rejections and cancellations are tallied by weight against the threshold,",
+        "members default to a weight of 1. Reallocates space if neccessary,",
+        "in which case the multisig must be prefunded with enough lamports to cover the new size."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setMemberPermissions",
+      "docs": [
+        "The instruction to set the permissions of a member.",
+        "Permissions are a bitmask of Initiate, Vote, Execute and ChangeConfig,",
+        "members default to having all permissions. Reallocates space if neccessary,",
+        "in which case the multisig must be prefunded with enough lamports to cover the new size."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addAuthority",
+      "docs": [
+        "instruction to increase the authority value tracked in the multisig",
+        "This is optional, as authorities are simply PDAs, however it may be helpful",
+        "to keep track of commonly used authorities in a UI.",
+        "This has no functional impact on the multisig or its functionality, but",
+        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addNamedAuthority",
+      "docs": [
+        "instruction to increase the authority value tracked in the multisig, the same as",
+        "add_authority, while also creating a record labeling the new authority with what",
+        "it is used for, ie. \"ops vault\" or \"upgrade authority for program X\"."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authorityRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "purpose",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateAuthorityRecord",
+      "docs": [
+        "instruction to update the label and purpose in the record of an authority"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authorityRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "purpose",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "changeDefaultVault",
+      "docs": [
+        "instruction to change the authority index designated as the default vault.",
+        "Defaults to authority 1, and must be within the authorities tracked by the multisig,",
+        "so add_authority may need to be called first."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeTimeLock",
+      "docs": [
+        "The instruction to change the time lock of the multisig, the number of seconds",
+        "a transaction must wait after being approved before it can be executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeConfigTimeLock",
+      "docs": [
+        "The instruction to change the config time lock of the multisig, the number of seconds an",
+        "internal (config) transaction must wait after being approved before it can be executed,",
+        "independently of the time lock, so that changing the members or threshold is never instant."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "configTimeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeVetoPeriod",
+      "docs": [
+        "The instruction to change the veto period of the multisig, the number of seconds after",
+        "a transaction is approved during which a single member with the veto permission can",
+        "cancel it. Execution is held back until the veto period has passed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vetoPeriod",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "docs": [
+        "The instruction to set (or remove) the guardian of the multisig. The guardian can freeze",
+        "the multisig in an emergency, but cannot vote or move funds."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "freeze",
+      "docs": [
+        "Instruction for the guardian to freeze the multisig, which blocks activating and",
+        "executing transactions (and spending limits) until the multisig unfreezes itself.",
+        "Internal transactions are not frozen, so the members can still unfreeze or change the config."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreeze",
+      "docs": [
+        "The instruction to unfreeze the multisig, which requires the normal threshold"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause",
+      "docs": [
+        "The instruction to pause executions of the multisig, ie. when a member key is suspected",
+        "to be compromised. Executing transactions (and spending limits) is blocked while voting",
+        "continues, internal transactions can still be executed to rotate keys and unpause."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "docs": [
+        "The instruction to unpause executions of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateBaselineMultisig",
+      "docs": [
+        "The instruction to migrate a multisig of the original layout, whose authority index was",
+        "two bytes wide (while transactions sign with four byte authority indexes) and which lacks",
+        "every field introduced since. Such accounts can't be loaded with the current layout, so",
+        "this migrates them in place permissionlessly. The layout is confirmed by the create key",
+        "and bump it is parsed with deriving the address of the multisig."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTransactionStatus",
+      "docs": [
+        "View of the status of a transaction, returned through the return data so that",
+        "clients and other programs can read it without deserializing the account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "MsTransactionStatus"
+      }
+    },
+    {
+      "name": "getVoteCounts",
+      "docs": [
+        "View of the vote counts of a transaction, returned through the return data."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "VoteCounts"
+      }
+    },
+    {
+      "name": "isMember",
+      "docs": [
+        "View of whether the key is a member of the multisig, returned through the return data."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ],
+      "returns": "bool"
+    },
+    {
+      "name": "migrateTransaction",
+      "docs": [
+        "Instruction to migrate a transaction account to the current layout version in place.",
+        "The account is parsed with the layout of its version and written back with the current",
+        "one, growing it if neccessary. Transactions of the original layout have no version, and",
+        "are migrated with migrate_baseline_transaction instead."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateBaselineTransaction",
+      "docs": [
+        "Instruction to migrate a transaction of the original layout, whose votes were lists of",
+        "member keys and which lacks every field introduced since. The votes are converted to",
+        "bitmaps over the keys of the multisig, which must have been migrated first."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setVoteRecords",
+      "docs": [
+        "The instruction to switch the multisig between recording votes on the transaction",
+        "(the default), and recording them in per-member VoteRecord accounts, so that",
+        "multisigs with hundreds of members can vote without growing the transaction.",
+        "Votes of active transactions would be split between the two, so they're deprecated."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction.",
+        "Each transaction is tied to a single authority, and must be specified when",
+        "creating the instruction below. authority 0 is reserved for internal",
+        "instructions, whereas authorities 1 or greater refer to a vault,",
+        "upgrade authority, or other."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createTransactionV2",
+      "docs": [
+        "Instruction to create a multisig transaction, attach its instructions and activate it",
+        "at once, so that a client failing midway doesn't leave a half-built draft behind.",
+        "The instruction accounts are passed as remaining accounts, in the same order as the",
+        "incoming instructions, followed by the activity log and policy accounts (if any).",
+        "The same rules as create_transaction, add_instructions and activate_transaction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createConfigTransaction",
+      "docs": [
+        "Instruction to create a config transaction, which carries typed config actions instead",
+        "of instructions. It is active right away, and is tallied against the config threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "actions",
+          "type": {
+            "vec": {
+              "defined": "MsConfigAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveConfigTransaction",
+      "docs": [
+        "Instruction to approve a config transaction on behalf of a member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectConfigTransaction",
+      "docs": [
+        "Instruction to reject a config transaction on behalf of a member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeConfigTransaction",
+      "docs": [
+        "Instruction to execute an approved config transaction, applying its actions as one",
+        "batch, like apply_config_actions. Reallocates the multisig if neccessary, paid by the member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMultisig",
+      "docs": [
+        "Instruction to close a dissolved multisig, returning its rent to the destination. The",
+        "closure must be approved as an internal transaction whose only instruction is this one,",
+        "which is executed here rather than through execute_transaction, as the multisig (which",
+        "signs internal instructions) can't be closed under it. All of the vaults must be empty,",
+        "and the (config) transactions after the change index closed or done."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "destination",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "cloneTransaction",
+      "docs": [
+        "Instruction to clone a transaction into a new draft with copies of all of its attached",
+        "instructions, ie. to re-propose a transaction deprecated by a change of the multisig.",
+        "The source transaction must be executed, rejected or deprecated. The source and new",
+        "instruction accounts are passed as remaining accounts, in pairs."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTransaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createLinkedTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction linked to a previously executed one.",
+        "Executions can't be reversed, but a compensating transaction can reference the",
+        "original so that corrective actions can be traced back to their cause.",
+        "The linked transaction must belong to the same multisig and be \"executed\"."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "linkedTransaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "activateTransaction",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\".",
+        "\"active\" transactions can then be signed off by multisig members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "activateWithStakeSnapshot",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\" for multisigs with a stake weight",
+        "source, snapshotting the vote weights of the members from their staking lockers, so that",
+        "locking (or unlocking) tokens afterwards doesn't change the vote on the transaction.",
+        "The locker of every member must be passed, members without one have no weight.",
+        "Internal transactions keep the member weights, so a misconfigured source can be fixed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakeSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStakeSnapshot",
+      "docs": [
+        "Instruction to close the stake snapshot of a transaction once voting on it has ended,",
+        "returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTransactionExpiration",
+      "docs": [
+        "Instruction to set an expiration on a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the expiration.",
+        "Once the expiration has passed the transaction can no longer be activated, approved",
+        "or executed, and anyone can expire it to return the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setTransactionTag",
+      "docs": [
+        "Instruction to tag a transaction with a category and a reference, ie. for reporting on",
+        "payroll, grants or config transactions. Transactions must be in the \"draft\" status, and",
+        "only the creator can set the tag, so it can be set along with the creation and is fixed",
+        "by the time members vote. The tag is at a fixed offset to filter transactions with memcmp."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tag",
+          "type": {
+            "defined": "TransactionTag"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExecutionCondition",
+      "docs": [
+        "Instruction to set (or remove) an oracle price condition on a transaction, ie. execute only",
+        "while SOL/USD is above a floor, so an approved proposal doesn't execute into a crashed market.",
+        "The condition is checked on execution, with the price account in the remaining accounts.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the condition,",
+        "so it is fixed by the time members vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "executionCondition",
+          "type": {
+            "option": {
+              "defined": "ExecutionCondition"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRatifyingProposal",
+      "docs": [
+        "Instruction to set (or remove) the governance proposal ratifying a transaction, for multisigs",
+        "requiring the ratification of their token holders. The proposal must have succeeded when the",
+        "transaction is executed, and is passed in the remaining accounts.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the proposal,",
+        "so the members approve the transaction along with it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ratifyingProposal",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLookupTables",
+      "docs": [
+        "Instruction to set the address lookup tables of a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set the tables.",
+        "Transactions with many unique accounts can then be executed with a versioned",
+        "transaction loading the accounts through these tables."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lookupTables",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCoCreators",
+      "docs": [
+        "Instruction to set the co-creators of a transaction, members that can attach",
+        "instructions to it along with the creator while it is a draft, so that complex",
+        "proposals can be assembled together. Only the creator can set them, or activate it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "coCreators",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTransactionDescription",
+      "docs": [
+        "Instruction to set the title and description of a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can set them,",
+        "so the context members approve is bound to the proposal account."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "fundExecutionBounty",
+      "docs": [
+        "Instruction to fund a bounty for executing a transaction.",
+        "Transactions must be in the \"draft\" status, and only the creator can fund the bounty.",
+        "The lamports are held by the transaction account and paid out to the executor,",
+        "and any key can execute a transaction with a bounty."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to expire a transaction whose expiration has passed.",
+        "Marks the transaction as expired and closes the account, returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addInstruction",
+      "docs": [
+        "Instruction to attach an instruction to a transaction.",
+        "Transactions must be in the \"draft\" status, and any",
+        "signer (aside from execution payer) specified in an instruction",
+        "must match the authority PDA specified during the transaction creation."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addTypedInstruction",
+      "docs": [
+        "Instruction to attach an instruction built from a typed action to a vault transaction,",
+        "with the vault of the transaction as the signing authority, so the instruction doesn't",
+        "have to be encoded by hand. The actions are:",
+        "- stake: delegate, deactivate, withdraw, split or merge a stake account whose stake and",
+        "withdraw authority is the vault",
+        "- vote: authorize a new withdrawer or withdraw from a vote account, withdrawals are",
+        "checked again when executed to leave the vote account rent exempt",
+        "- idl: set the IDL authority, set the IDL buffer or close the IDL account of a program",
+        "whose IDL authority is the vault",
+        "- governance: an SPL Governance instruction with the vault as the realm authority,",
+        "governing token owner or governing token mint authority, ie. to vote in a realm where",
+        "the vault holds the council tokens. The governance program is passed, as realms run",
+        "their own deployments",
+        "",
+        "The same rules as for add_instruction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "TypedAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeInstruction",
+      "docs": [
+        "Instruction to remove the last instruction attached to a transaction, returning",
+        "its rent to the creator. Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceInstruction",
+      "docs": [
+        "Instruction to replace an instruction attached to a transaction, with the same",
+        "rules as add_instruction. Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "appendInstructionData",
+      "docs": [
+        "Instruction to append a chunk of data to an attached instruction, so that instruction",
+        "data bigger than a single Solana transaction can be uploaded in chunks before activation.",
+        "The last chunk should pass the hash of the full data, to check the upload is complete."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "finalHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addInstructions",
+      "docs": [
+        "Instruction to attach multiple instructions to a transaction at once.",
+        "The instruction accounts are passed as remaining accounts, in the same order",
+        "as the incoming instructions, and the same rules as add_instruction apply."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveAndExecute",
+      "docs": [
+        "Instruction to cast the final approval of a transaction and execute it in the same call,",
+        "so there's no window between the approval and the execution. The approval is cast as by",
+        "approve_transaction, and if the transaction isn't ready to execute yet, that's all it does.",
+        "Otherwise it is executed as by execute_transaction, with the same account list, which fails",
+        "for multisigs with a time lock or veto period, as their transactions can't be executed right away."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitSignedVote",
+      "docs": [
+        "Instruction to relay the approval or rejection of a member who signed the vote offline.",
+        "The preceding instruction must verify the member's ed25519 signature over the signed",
+        "vote message of the transaction, any key can then submit the vote. The message holds the",
+        "vote sequence of the transaction, so the vote has to be signed again after any other vote.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "submitApprovalRequest",
+      "docs": [
+        "Instruction to relay the approval of a member who signed the approval request of the",
+        "transaction offline, ie. on a hardware wallet that displays the attached instructions.",
+        "The attached instruction accounts must come first in the remaining accounts, in order, and",
+        "the preceding instruction must verify the member's ed25519 signature over the approval",
+        "request built from them, so a tampered instruction set fails. Like a signed vote, the",
+        "request holds the vote sequence of the transaction.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "submitSignedVotes",
+      "docs": [
+        "Instruction to relay the approvals or rejections of several members who signed the same",
+        "vote offline, ie. to land a whole quorum at once. Each member's ed25519 signature over the",
+        "signed vote message must be verified by its own instruction, directly preceding this one",
+        "and in the order of the members. The members sign the same message, over the vote sequence",
+        "before the batch, so a stale signature fails the whole batch. Votes after the one deciding",
+        "the transaction are ignored.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addDelegate",
+      "docs": [
+        "Instruction for a member to delegate their approve/reject votes to another key,",
+        "ie. a hot key while travelling. Doesn't change the membership."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeDelegate",
+      "docs": [
+        "Instruction for a member to revoke the delegation of their votes"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitDelegatedVote",
+      "docs": [
+        "Instruction for a delegate to approve or reject a transaction on behalf of a member.",
+        "The vote counts as the member's own vote, and replaces any previous vote of the member.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addSessionKey",
+      "docs": [
+        "Instruction for a member to register a session key, a short-lived key (ie. a browser hot key)",
+        "that can vote on their behalf, restricted to the given actions and until the expiry slot.",
+        "Doesn't change the membership."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "actions",
+          "type": "u8"
+        },
+        {
+          "name": "expiresAtSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeSessionKey",
+      "docs": [
+        "Instruction for a member to revoke a session key, or to close it once expired"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitSessionVote",
+      "docs": [
+        "Instruction for a session key to approve or reject a transaction on behalf of a member.",
+        "The vote counts as the member's own vote, and replaces any previous vote of the member.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createVoteRecord",
+      "docs": [
+        "Instruction to vote on a transaction with a vote record, for multisigs with vote",
+        "records enabled. The vote is tallied on the transaction with the weight of the member,",
+        "or the snapshotted stake weight for vault transactions with a stake weight source.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createPagedVoteRecord",
+      "docs": [
+        "Instruction for a member of a page of additional members to vote on a transaction with a",
+        "vote record. Paged members vote with a weight of 1.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "membersPage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createProofVoteRecord",
+      "docs": [
+        "Instruction for a member of the set of the member root to vote on a transaction with a vote",
+        "record, along with the inclusion proof of its key. Members of the root vote with a weight of 1.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTokenVoteRecord",
+      "docs": [
+        "Instruction for a holder of a membership token to vote on a transaction with a token vote",
+        "record, for multisigs with vote records enabled and a membership gate. Token votes are",
+        "tallied apart from the member weights, against the token threshold of the gate, and can't",
+        "be changed. Each holder of a token of the mint, or each NFT of the collection, votes once.",
+        "Token votes only decide vault transactions, along with the members.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "votingKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "closeTokenVoteRecord",
+      "docs": [
+        "Instruction to close a token vote record once voting on the transaction has ended,",
+        "returning the rent to the voter."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voter",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeVoteRecord",
+      "docs": [
+        "Instruction to change the vote of a vote record, replacing the previous vote in the tallies.",
+        "The transaction must have an \"active\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "closeVoteRecord",
+      "docs": [
+        "Instruction to close a vote record once the transaction is no longer active,",
+        "returning the rent to the member."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addVoteMemo",
+      "docs": [
+        "Instruction for a member to record a short memo (or the hash of a memo) with their vote,",
+        "ie. the reason for rejecting a transaction, so it is part of the audit trail. The member",
+        "must have cast the vote (approve, reject, cancel or abstain), and can record one memo per vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voteMemo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          }
+        },
+        {
+          "name": "memo",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "abstainTransaction",
+      "docs": [
+        "Instruction to formally abstain from a transaction.",
+        "The transaction must have an \"active\" status. Abstaining replaces a previous",
+        "approval or rejection, and doesn't count towards either, but counts towards the quorum."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed.",
+        "A cancellation replaces the approval of the member, like any change of vote."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "vetoTransaction",
+      "docs": [
+        "Instruction to veto a transaction.",
+        "Transactions must be in the \"executeReady\" status and within the veto period",
+        "of the multisig. A single member with the veto permission is enough to cancel it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "councilVetoTransaction",
+      "docs": [
+        "Instruction for a key of the veto council to veto a transaction. The council can't approve,",
+        "but once its vetoes reach the veto council threshold the transaction is rejected, even after",
+        "it was approved. Transactions must be in the \"active\" or \"executeReady\" status, recurring",
+        "transactions are vetoed with council_veto_recurring_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "councilMember",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "councilVetoRecurringTransaction",
+      "docs": [
+        "Instruction for a key of the veto council to veto a recurring transaction. Once the vetoes",
+        "reach the veto council threshold the transaction is rejected and its schedule ends, the rent",
+        "of the recurring transaction being returned to the creator of the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "councilMember",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "",
+        "The account list can also be left empty, in which case the remaining accounts must start",
+        "with the instruction accounts in order, followed by the accounts of the instructions",
+        "deduplicated (in any order), and each instruction key is resolved from them.",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "executeInstruction",
+      "docs": [
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater. Once the first instruction is executed, other non-internal",
+        "transactions of the multisig can't be executed until this one is finalized with",
+        "finalize_execution (or cancelled/expired).",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeExecution",
+      "docs": [
+        "Instruction to finalize a transaction executed instruction by instruction, once",
+        "all of its instructions have been executed. Marks the transaction as executed, and",
+        "lets other transactions execute again. The execution hook (if any) must be passed",
+        "in the remaining accounts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExecutedInstructions",
+      "docs": [
+        "Instruction to close the instruction accounts of an executed transaction.",
+        "The instruction accounts are passed as remaining accounts, and their rent",
+        "is returned to the creator of the transaction. Anyone can close them."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeDraftTransaction",
+      "docs": [
+        "Instruction for the creator to close their transaction while it is still a draft (so no",
+        "one has voted on it), ie. an abandoned proposal. All of its instruction accounts must be",
+        "passed as remaining accounts, they're closed along with the transaction and the rent is",
+        "returned to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStaleTransaction",
+      "docs": [
+        "Instruction to close a stale transaction, one deprecated by a change of the multisig",
+        "that wasn't executed, reclaiming the rent for its creator. Anyone can close them.",
+        "All of its instruction accounts must be passed as remaining accounts, they're closed",
+        "along with the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSpendingLimit",
+      "docs": [
+        "The instruction to add a spending limit to an authority (vault).",
+        "The designated member can then spend up to the amount of the mint",
+        "per period from the vault without a proposal. The native SOL limit uses",
+        "the default pubkey as the mint. A period of 0 makes the amount a one-time budget."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "The instruction to remove a spending limit, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addRecovery",
+      "docs": [
+        "The instruction to add the inactivity recovery of the multisig. If no transaction is",
+        "executed for the number of epochs, the recovery key can replace all of the members."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recoveryKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "inactivityEpochs",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeRecovery",
+      "docs": [
+        "The instruction to remove the inactivity recovery, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recover",
+      "docs": [
+        "Instruction for the recovery key to replace the members and threshold of a multisig",
+        "that hasn't executed a transaction for the inactivity epochs of its recovery.",
+        "Reallocates space if neccessary, paid by the recovery key."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recovery",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRecoverySet",
+      "docs": [
+        "The instruction to set the recovery set of the multisig, keys that can together replace",
+        "a single lost member key after the recovery delay, but can't change anything else.",
+        "An empty set with a threshold of 0 disables member recovery."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recoveryKeys",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "recoveryThreshold",
+          "type": "u16"
+        },
+        {
+          "name": "recoveryDelay",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setVetoCouncil",
+      "docs": [
+        "The instruction to set the veto council of the multisig, keys apart from the members that",
+        "can together reject any transaction before it is executed, but can't approve (nor spend).",
+        "An empty council with a threshold of 0 removes it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "vetoCouncil",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "vetoCouncilThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeMemberRecovery",
+      "docs": [
+        "Instruction for a key of the recovery set to propose replacing a lost member key,",
+        "which counts as its approval."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "approveMemberRecovery",
+      "docs": [
+        "Instruction for a key of the recovery set to approve a member recovery"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recoveryKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeMemberRecovery",
+      "docs": [
+        "Instruction to execute an approved member recovery once the recovery delay has passed,",
+        "which anyone can call. The rent of the recovery is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelMemberRecovery",
+      "docs": [
+        "Instruction for the old member to cancel a recovery of their key, proving it isn't lost.",
+        "The rent of the recovery is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memberRecovery",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldMember",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "spendFromVault",
+      "docs": [
+        "Instruction to move funds out of a vault under a spending limit.",
+        "Only the member designated in the spending limit can spend, and the amount",
+        "counts towards the limit of the current period."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createVaultAta",
+      "docs": [
+        "Instruction to create the associated token account of a vault for a mint, so the vault",
+        "can receive a new token without a proposal. Anyone can call it, paying the rent."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addAllowance",
+      "docs": [
+        "The instruction to add an allowance from an authority (vault). The key, which doesn't",
+        "have to be a member, can then pull up to the cap of the mint from the vault until the",
+        "optional expiration, ie. an expense account. The native SOL allowance uses the default pubkey as the mint."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "spender",
+          "type": "publicKey"
+        },
+        {
+          "name": "cap",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeAllowance",
+      "docs": [
+        "The instruction to remove an allowance, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPolicy",
+      "docs": [
+        "The instruction to add a policy config, registering an external policy program that",
+        "is consulted when vault transactions are activated, approved and/or executed (per the",
+        "stages mask), and can reject them by failing. A multisig has at most one policy config."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policyConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "policyProgram",
+          "type": "publicKey"
+        },
+        {
+          "name": "stages",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removePolicy",
+      "docs": [
+        "The instruction to remove the policy config, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policyConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createActivityLog",
+      "docs": [
+        "The instruction to create the activity log, a ring buffer of the most recent transaction",
+        "lifecycle actions (create, activate, approve, reject, cancel and execute) of the multisig.",
+        "Once created, the log must be passed in the remaining accounts of these instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "activityLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeActivityLog",
+      "docs": [
+        "The instruction to close the activity log, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "activityLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMemberPage",
+      "docs": [
+        "The instruction to create the next page of additional members, for rosters that don't fit",
+        "in the multisig account. Paged members vote with vote records, which must be enabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMemberRoot",
+      "docs": [
+        "The instruction to set (or remove) the merkle root of an additional member set, for signer",
+        "sets too large for member pages. Its members vote with vote records and an inclusion proof.",
+        "The member count of the set can't be checked on-chain, the members approving the root vouch for it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "memberRoot",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "memberCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setRatification",
+      "docs": [
+        "The instruction to set (or remove) the governance whose token holders must ratify every",
+        "transaction, so that the members can only execute what a proposal of the governance passed.",
+        "Internal transactions are held to it as well, so the requirement can only be lifted with",
+        "the token holders. Active transactions are deprecated, as they may lack a proposal."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "ratification",
+          "type": {
+            "option": {
+              "defined": "Ratification"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addPagedMember",
+      "docs": [
+        "The instruction to add a member to a page of additional members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removePagedMember",
+      "docs": [
+        "The instruction to remove a member from a page of additional members. Like remove_member,",
+        "the remaining members must still be able to reach the thresholds."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "membersPage",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "spendAllowance",
+      "docs": [
+        "Instruction for the key of an allowance to pull funds out of the vault.",
+        "The amount counts towards the cap of the allowance. The accounts are passed",
+        "the same way as for spend_from_vault."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowance",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spender",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createMetadata",
+      "docs": [
+        "The instruction to create the metadata account of the multisig, labeling it with a",
+        "name, a short description and an image/URI hash for wallets and explorers."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "uriHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMetadata",
+      "docs": [
+        "The instruction to update the metadata account of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "uriHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createRecurringTransaction",
+      "docs": [
+        "The instruction to make an approved vault transaction recurring, ie. a monthly payroll.",
+        "Instead of being executed once, the instructions of the transaction are executed every",
+        "interval (in seconds) from start_at, by anyone cranking the recurring transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "interval",
+          "type": "i64"
+        },
+        {
+          "name": "startAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "removeRecurringTransaction",
+      "docs": [
+        "The instruction to end the schedule of a recurring transaction, returning the rent to the",
+        "rent collector. The transaction is marked as executed if it was executed at least once,",
+        "otherwise as cancelled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeRecurringTransaction",
+      "docs": [
+        "Instruction to execute a recurring transaction once it's due, which anyone can crank.",
+        "The execution condition and ratification (if any) are checked on every run.",
+        "The accounts are passed the same way as for execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recurringTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "upgradeProgram",
+      "docs": [
+        "Instruction to upgrade a program from a buffer, checking that the sha256 hash of the",
+        "program in the buffer matches the expected hash. Attached to a vault transaction (the vault",
+        "being the upgrade authority), it binds the approval to the buffer contents, so the buffer",
+        "can't be swapped between approval and execution. A buffer of the vault holding the current",
+        "program data can be passed (with the managed program) as a remaining account, and is retained",
+        "so the upgrade can be rolled back with propose_rollback."
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "expectedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createManagedProgram",
+      "docs": [
+        "The instruction to add a program whose upgrade authority is an authority (vault) of the",
+        "multisig to its managed programs, replacing the separate program manager program."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "removeManagedProgram",
+      "docs": [
+        "The instruction to remove a program from the managed programs, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createProgramUpgrade",
+      "docs": [
+        "Instruction to propose an upgrade of a managed program, tracking the vault transaction that",
+        "carries the upgrade_program instruction and the buffer it upgrades from. The upgrade is marked",
+        "as upgraded when upgrade_program executes with the managed program and the upgrade passed as",
+        "remaining accounts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programUpgrade",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buffer",
+          "type": "publicKey"
+        },
+        {
+          "name": "expectedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeProgramUpgrade",
+      "docs": [
+        "The instruction to close a proposed upgrade of a managed program, returning the rent to the rent collector"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programUpgrade",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRollbackThreshold",
+      "docs": [
+        "The instruction to set the number of member approvals needed to roll back the last upgrade",
+        "of a managed program, meant to be lower than the threshold. 0 disables rollbacks."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rollbackThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeRollback",
+      "docs": [
+        "Instruction for a member to propose rolling back the last upgrade of a managed program",
+        "from the buffer it retained, which counts as their approval."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveRollback",
+      "docs": [
+        "Instruction for a member to approve the rollback of a managed program"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeRollback",
+      "docs": [
+        "Instruction to execute an approved rollback, which anyone can call. It is the expedited path",
+        "to undo a bad deploy, so it only needs the rollback threshold of approvals and isn't subject",
+        "to the time lock, but the vault can't sign while it's frozen or paused. The rent of the",
+        "rollback is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "managedProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRollback",
+      "docs": [
+        "Instruction for the proposer to cancel a rollback, ie. one made stale by a later upgrade.",
+        "The rent of the rollback is returned to the proposer."
+      ],
+      "accounts": [
+        {
+          "name": "managedProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programRollback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ms",
+      "docs": [
+        "Ms is the basic state account for a multisig.",
+        "Fields are appended after the keys in the order their layout versions introduced them,",
+        "so that an account of an older version reads the fields it lacks as zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowExternalExecute",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "defaultVaultIndex",
+            "type": "u32"
+          },
+          {
+            "name": "memberWeights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "memberPermissions",
+            "type": {
+              "vec": {
+                "defined": "MemberPermissions"
+              }
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "configThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "vetoPeriod",
+            "type": "u32"
+          },
+          {
+            "name": "memberPrograms",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "memberExpirations",
+            "type": {
+              "vec": {
+                "defined": "MemberExpiration"
+              }
+            }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "frozen",
+            "type": "bool"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "voteRecords",
+            "type": "bool"
+          },
+          {
+            "name": "executingTransaction",
+            "type": "u32"
+          },
+          {
+            "name": "programAllowlists",
+            "type": {
+              "vec": {
+                "defined": "ProgramAllowlist"
+              }
+            }
+          },
+          {
+            "name": "deniedInstructions",
+            "type": {
+              "vec": {
+                "defined": "DeniedInstruction"
+              }
+            }
+          },
+          {
+            "name": "removalDelay",
+            "type": "u32"
+          },
+          {
+            "name": "pendingRemoval",
+            "type": {
+              "option": {
+                "defined": "PendingRemoval"
+              }
+            }
+          },
+          {
+            "name": "lastExecutionEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "recoveryThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "recoveryDelay",
+            "type": "u32"
+          },
+          {
+            "name": "recoveryKeys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "retiredAuthorities",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "anyExecutor",
+            "type": "bool"
+          },
+          {
+            "name": "executors",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "executionHook",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "policyEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "activityLogEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          },
+          {
+            "name": "minQuorum",
+            "type": "u16"
+          },
+          {
+            "name": "thresholdPercent",
+            "type": "u8"
+          },
+          {
+            "name": "maxOpenTransactions",
+            "type": "u16"
+          },
+          {
+            "name": "openTransactions",
+            "type": {
+              "vec": {
+                "defined": "OpenTransactions"
+              }
+            }
+          },
+          {
+            "name": "parent",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "membershipGate",
+            "type": {
+              "option": {
+                "defined": "MembershipGate"
+              }
+            }
+          },
+          {
+            "name": "stakeWeightSource",
+            "type": {
+              "option": {
+                "defined": "StakeWeightSource"
+              }
+            }
+          },
+          {
+            "name": "valueTiers",
+            "type": {
+              "vec": {
+                "defined": "ValueTier"
+              }
+            }
+          },
+          {
+            "name": "executionWindow",
+            "type": {
+              "option": {
+                "defined": "ExecutionWindow"
+              }
+            }
+          },
+          {
+            "name": "blackoutPeriods",
+            "type": {
+              "vec": {
+                "defined": "BlackoutPeriod"
+              }
+            }
+          },
+          {
+            "name": "configTimeLock",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "adminExpiresAtSlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxMembers",
+            "type": "u16"
+          },
+          {
+            "name": "memberPages",
+            "type": "u8"
+          },
+          {
+            "name": "pagedMemberCount",
+            "type": "u32"
+          },
+          {
+            "name": "memberRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "rootMemberCount",
+            "type": "u32"
+          },
+          {
+            "name": "ratification",
+            "type": {
+              "option": {
+                "defined": "Ratification"
+              }
+            }
+          },
+          {
+            "name": "vetoCouncilThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "vetoCouncil",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msTransaction",
+      "docs": [
+        "The MsTransaction is the state account for a multisig transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "tag",
+            "type": {
+              "defined": "TransactionTag"
+            }
+          },
+          {
+            "name": "approved",
+            "type": "bytes"
+          },
+          {
+            "name": "rejected",
+            "type": "bytes"
+          },
+          {
+            "name": "cancelled",
+            "type": "bytes"
+          },
+          {
+            "name": "executedIndex",
+            "type": "u8"
+          },
+          {
+            "name": "linkedTx",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "executionBounty",
+            "type": "u64"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "abstained",
+            "type": "bytes"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "approvedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "rejectedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "voteRecordCount",
+            "type": "u16"
+          },
+          {
+            "name": "coCreators",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "executionCondition",
+            "type": {
+              "option": {
+                "defined": "ExecutionCondition"
+              }
+            }
+          },
+          {
+            "name": "ratifyingProposal",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "councilVetoes",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "tokenApproved",
+            "type": "u32"
+          },
+          {
+            "name": "tokenRejected",
+            "type": "u32"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msConfigTransaction",
+      "docs": [
+        "A config transaction holds typed config actions, rather than instructions for the multisig",
+        "to invoke on itself, so that signer UIs can render exactly what is changed. The actions are",
+        "checked against the multisig when proposed, and applied as one batch when executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "actions",
+            "type": {
+              "vec": {
+                "defined": "MsConfigAction"
+              }
+            }
+          },
+          {
+            "name": "approved",
+            "type": "bytes"
+          },
+          {
+            "name": "rejected",
+            "type": "bytes"
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstruction",
+      "docs": [
+        "Almost analagous to the native Instruction struct for solana, but with an extra",
+        "field for the bump."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "A SpendingLimit allows a designated key to move funds out of a vault (authority)",
+        "without a full proposal, up to `amount` per `period`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recurringTransaction",
+      "docs": [
+        "A RecurringTransaction executes the instructions of an approved transaction repeatedly,",
+        "every `interval` seconds. Anyone can crank the execution once it's due."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "interval",
+            "type": "i64"
+          },
+          {
+            "name": "nextExecutionAt",
+            "type": "i64"
+          },
+          {
+            "name": "executions",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegate",
+      "docs": [
+        "A Delegate lets the delegate key of a member approve and reject transactions on their behalf,",
+        "without changing the membership. The member can revoke it at any time by closing it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionKey",
+      "docs": [
+        "A SessionKey lets a short-lived key of a member (ie. a browser hot key) vote on its behalf,",
+        "restricted to the actions of the session and until the expiry slot, so the cold member",
+        "key doesn't have to sign. The member can revoke it at any time by closing it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "actions",
+            "type": "u8"
+          },
+          {
+            "name": "expiresAtSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recovery",
+      "docs": [
+        "A Recovery lets the recovery key rotate the full membership of a multisig that hasn't",
+        "executed a transaction for a number of epochs, ie. when its members lost their keys.",
+        "The recovery key can be a vault of another multisig, to recover with a backup member set."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "recoveryKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "inactivityEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "memberRecovery",
+      "docs": [
+        "A MemberRecovery is a proposal of the recovery set to replace a single lost member key.",
+        "Once approved by the recovery threshold, anyone can execute the replacement after the",
+        "recovery delay, unless the old member (who still holds their key) cancels it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "oldMember",
+            "type": "publicKey"
+          },
+          {
+            "name": "newMember",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeSnapshot",
+      "docs": [
+        "A StakeSnapshot holds the vote weights of the members read from their staking lockers when",
+        "the transaction was activated, so that locking more tokens afterwards doesn't change the vote."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "weights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenVoteRecord",
+      "docs": [
+        "A TokenVoteRecord holds the vote of a token holder on a transaction of a multisig with a",
+        "membership gate. It is recorded under the voting key, so each holder of a token of the mint",
+        "(or each NFT of the collection) votes once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "votingKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "voter",
+            "type": "publicKey"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteRecord",
+      "docs": [
+        "A VoteRecord holds the vote of a single member on a transaction, for multisigs with",
+        "vote records enabled. The transaction only keeps the tallies, so it doesn't grow with",
+        "the number of voting members."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteMemo",
+      "docs": [
+        "A VoteMemo holds the memo a member recorded along with their vote on a transaction,",
+        "ie. the reason of a rejection, or the hash of a longer memo kept elsewhere."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "vote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "memo",
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "allowance",
+      "docs": [
+        "An Allowance lets a key that doesn't have to be a member pull funds out of a vault",
+        "(authority), up to a total `cap` and until the optional expiration."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "spender",
+            "type": "publicKey"
+          },
+          {
+            "name": "cap",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMetadata",
+      "docs": [
+        "MsMetadata is an optional account labeling a multisig for wallets and explorers.",
+        "It can only be created and updated through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "uriHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityRecord",
+      "docs": [
+        "AuthorityRecord is an optional account labeling an authority (vault) of the multisig,",
+        "so clients know what each authority index is used for. It can only be created and",
+        "updated through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "label",
+            "type": "string"
+          },
+          {
+            "name": "purpose",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "policyConfig",
+      "docs": [
+        "A PolicyConfig registers an external policy program for a multisig, ie. for spending rules,",
+        "KYC gating or risk scoring. The policy program is invoked with a `check_policy(stage, actor)`",
+        "instruction (Anchor style discriminator) and the policy config, multisig and transaction",
+        "accounts (read only) at the stages in its mask, and rejects the action by failing.",
+        "Internal (authority 0) transactions aren't checked, so the multisig can always remove it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "policyProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "stages",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msActivityLog",
+      "docs": [
+        "The MsActivityLog is an optional ring buffer of the most recent transaction lifecycle actions",
+        "of a multisig, so explorers and auditors can show the recent history without replaying it.",
+        "Once full, the entry at `head` is the oldest and is overwritten next."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "ActivityEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMembers",
+      "docs": [
+        "An MsMembers page holds additional members of a multisig, for rosters that don't fit in the",
+        "keys of the multisig account. Paged members can only vote, with vote records and a weight of 1,",
+        "they can't initiate or execute transactions. A key shared with the multisig keys still votes",
+        "once per transaction, as the vote records are seeded by the key."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "pageIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "managedProgram",
+      "docs": [
+        "A ManagedProgram lists a program whose upgrade authority is an authority (vault) of the",
+        "multisig, so clients can show the programs of a squad along with their upgrade history.",
+        "It can only be created and removed through the multisig itself."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "programAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "upgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "lastUpgrade",
+            "type": "i64"
+          },
+          {
+            "name": "lastUpgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rollbackThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "rollbackBuffer",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "rollbackHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programRollback",
+      "docs": [
+        "A ProgramRollback is a proposal of the members to restore a managed program from the buffer",
+        "retained by its last upgrade. Once approved by the rollback threshold of the managed program,",
+        "anyone can execute it, without the time lock of vault transactions."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managedProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "buffer",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programUpgrade",
+      "docs": [
+        "A ProgramUpgrade tracks a proposed upgrade of a managed program, ie. the vault transaction",
+        "carrying the upgrade_program instruction and the buffer it upgrades from. It is marked as",
+        "upgraded when upgrade_program executes with the managed program and the upgrade passed as",
+        "remaining accounts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managedProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "upgradeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "buffer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expectedHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "upgradedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MemberWeight",
+      "docs": [
+        "Vote weight override for a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ValueTier",
+      "docs": [
+        "The threshold for vault transactions transferring at least the min amount of the mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionWindow",
+      "docs": [
+        "The UTC days and hours vault transactions can be executed in, ie. weekdays from 09:00 to 18:00.",
+        "A window ending before it starts spans midnight, ie. from 22:00 to 06:00."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "days",
+            "type": "u8"
+          },
+          {
+            "name": "startMinute",
+            "type": "u16"
+          },
+          {
+            "name": "endMinute",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BlackoutPeriod",
+      "docs": [
+        "A time range (unix timestamps, end excluded) vault transactions can't be executed in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "start",
+            "type": "i64"
+          },
+          {
+            "name": "end",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingRemoval",
+      "docs": [
+        "A member removal waiting for the removal delay of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "removeAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberExpiration",
+      "docs": [
+        "Membership expiration of a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OpenTransactions",
+      "docs": [
+        "The number of open transactions of a creator, for the max open transactions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "count",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramAllowlist",
+      "docs": [
+        "Programs that the instructions of transactions of an authority may target"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "programs",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DeniedInstruction",
+      "docs": [
+        "A program and instruction data prefix (ie. a discriminator) pair that is denied"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "prefix",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberPermissions",
+      "docs": [
+        "Permission override for a member of the multisig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransactionTag",
+      "docs": [
+        "The tag of a transaction, ie. the \"PAYR\" category with the payroll run as the reference.",
+        "Untagged transactions have a zero category."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "category",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "reference",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionCondition",
+      "docs": [
+        "A condition on an oracle price that must hold when a transaction is executed, ie. only",
+        "rebalance the treasury while SOL/USD is above a floor. The price is price * 10^expo,",
+        "and the oracle price must have been updated within the max age."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracle",
+            "type": {
+              "defined": "OracleKind"
+            }
+          },
+          {
+            "name": "priceAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "comparison",
+            "type": {
+              "defined": "PriceComparison"
+            }
+          },
+          {
+            "name": "price",
+            "type": "i64"
+          },
+          {
+            "name": "expo",
+            "type": "i32"
+          },
+          {
+            "name": "maxAge",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApprovalRequest",
+      "docs": [
+        "Self-describing summary of a transaction that is serialized (after a fixed prefix)",
+        "to form the approval request blob signed by offline signers."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          },
+          {
+            "name": "instructionHashes",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SignedVote",
+      "docs": [
+        "Vote of a member that is serialized (after a fixed prefix) to form the message",
+        "signed offline and verified through the ed25519 program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisig",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "voteSeq",
+            "type": "u32"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteCounts",
+      "docs": [
+        "The vote counts of a transaction, returned by the get_vote_counts view.",
+        "With vote records enabled, votes are only tallied by weight, so the member counts are zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "approved",
+            "type": "u16"
+          },
+          {
+            "name": "rejected",
+            "type": "u16"
+          },
+          {
+            "name": "cancelled",
+            "type": "u16"
+          },
+          {
+            "name": "abstained",
+            "type": "u16"
+          },
+          {
+            "name": "approvedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "rejectedWeight",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Ratification",
+      "docs": [
+        "The SPL governance whose token holders must ratify the transactions of a multisig, on top of",
+        "the approvals of the members. A transaction records a proposal of the governance, voted on with",
+        "the governing token, which must have succeeded by the time the transaction is executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "governanceProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "governance",
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StakeWeightSource",
+      "docs": [
+        "The staking (ie. vote escrow) program whose locker accounts the vote weights of the members",
+        "are read from. The locker of a member is the PDA of the program with the seeds",
+        "[seed prefix, registrar, member], and holds the locked amount as a u64 at the amount offset."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program",
+            "type": "publicKey"
+          },
+          {
+            "name": "registrar",
+            "type": "publicKey"
+          },
+          {
+            "name": "seedPrefix",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "amountOffset",
+            "type": "u16"
+          },
+          {
+            "name": "amountPerVote",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ActivityEntry",
+      "docs": [
+        "An entry of the activity log, the actor is the member (or executor/cranker) taking the action"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "actor",
+            "type": "publicKey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "ActivityAction"
+            }
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionHookStage",
+      "docs": [
+        "The stage of the execution an execution hook is invoked for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PreExecute"
+          },
+          {
+            "name": "PostExecute"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigAction",
+      "docs": [
+        "The config change that was applied to the multisig"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AddMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberWeight",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "weight",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberPermissions",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "permissions",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "AddAuthority",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeDefaultVault",
+            "fields": [
+              {
+                "name": "vault_index",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeTimeLock",
+            "fields": [
+              {
+                "name": "time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "AddSpendingLimit",
+            "fields": [
+              {
+                "name": "spending_limit",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveSpendingLimit",
+            "fields": [
+              {
+                "name": "spending_limit",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigThreshold",
+            "fields": [
+              {
+                "name": "config_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeVetoPeriod",
+            "fields": [
+              {
+                "name": "veto_period",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberProgram",
+            "fields": [
+              {
+                "name": "member_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "allowed",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberExpiration",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "expires_at",
+                "type": {
+                  "option": "i64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "PruneExpiredMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Unfreeze"
+          },
+          {
+            "name": "Pause"
+          },
+          {
+            "name": "Unpause"
+          },
+          {
+            "name": "SetMetadata",
+            "fields": [
+              {
+                "name": "metadata",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddRecurringTransaction",
+            "fields": [
+              {
+                "name": "recurring_transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveRecurringTransaction",
+            "fields": [
+              {
+                "name": "recurring_transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddAllowance",
+            "fields": [
+              {
+                "name": "allowance",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveAllowance",
+            "fields": [
+              {
+                "name": "allowance",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetVoteRecords",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "Migrate",
+            "fields": [
+              {
+                "name": "version",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetProgramAllowlist",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              },
+              {
+                "name": "programs",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetDeniedInstruction",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "prefix",
+                "type": "bytes"
+              },
+              {
+                "name": "denied",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "ChangeRemovalDelay",
+            "fields": [
+              {
+                "name": "removal_delay",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ScheduleMemberRemoval",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "remove_at",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "AbortMemberRemoval",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddRecovery",
+            "fields": [
+              {
+                "name": "recovery",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveRecovery",
+            "fields": [
+              {
+                "name": "recovery",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetRecoverySet",
+            "fields": [
+              {
+                "name": "recovery_keys",
+                "type": {
+                  "vec": "publicKey"
+                }
+              },
+              {
+                "name": "recovery_threshold",
+                "type": "u16"
+              },
+              {
+                "name": "recovery_delay",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceMember",
+            "fields": [
+              {
+                "name": "old_member",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RetireAuthority",
+            "fields": [
+              {
+                "name": "authority_index",
+                "type": "u32"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetAuthorityRecord",
+            "fields": [
+              {
+                "name": "authority_record",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetExecutors",
+            "fields": [
+              {
+                "name": "any_executor",
+                "type": "bool"
+              },
+              {
+                "name": "executors",
+                "type": {
+                  "vec": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetExecutionHook",
+            "fields": [
+              {
+                "name": "execution_hook",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "AddPolicy",
+            "fields": [
+              {
+                "name": "policy_config",
+                "type": "publicKey"
+              },
+              {
+                "name": "policy_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "stages",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "RemovePolicy",
+            "fields": [
+              {
+                "name": "policy_config",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CreateActivityLog",
+            "fields": [
+              {
+                "name": "activity_log",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CloseActivityLog",
+            "fields": [
+              {
+                "name": "activity_log",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMinQuorum",
+            "fields": [
+              {
+                "name": "min_quorum",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThresholdPercent",
+            "fields": [
+              {
+                "name": "threshold_percent",
+                "type": "u8"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMaxOpenTransactions",
+            "fields": [
+              {
+                "name": "max_open_transactions",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeMaxMembers",
+            "fields": [
+              {
+                "name": "max_members",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "CreateMemberPage",
+            "fields": [
+              {
+                "name": "members_page",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "AddPagedMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "RemovePagedMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "page_index",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberRoot",
+            "fields": [
+              {
+                "name": "member_root",
+                "type": {
+                  "option": {
+                    "array": [
+                      "u8",
+                      32
+                    ]
+                  }
+                }
+              },
+              {
+                "name": "member_count",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetRatification",
+            "fields": [
+              {
+                "name": "ratification",
+                "type": {
+                  "option": {
+                    "defined": "Ratification"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetVetoCouncil",
+            "fields": [
+              {
+                "name": "veto_council",
+                "type": {
+                  "vec": "publicKey"
+                }
+              },
+              {
+                "name": "veto_council_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "InvalidateTransaction",
+            "fields": [
+              {
+                "name": "transaction",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetParent",
+            "fields": [
+              {
+                "name": "parent",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMembershipGate",
+            "fields": [
+              {
+                "name": "membership_gate",
+                "type": {
+                  "option": {
+                    "defined": "MembershipGate"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetStakeWeightSource",
+            "fields": [
+              {
+                "name": "stake_weight_source",
+                "type": {
+                  "option": {
+                    "defined": "StakeWeightSource"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetValueTiers",
+            "fields": [
+              {
+                "name": "value_tiers",
+                "type": {
+                  "vec": {
+                    "defined": "ValueTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetExecutionWindow",
+            "fields": [
+              {
+                "name": "execution_window",
+                "type": {
+                  "option": {
+                    "defined": "ExecutionWindow"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetBlackoutPeriods",
+            "fields": [
+              {
+                "name": "blackout_periods",
+                "type": {
+                  "vec": {
+                    "defined": "BlackoutPeriod"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigTimeLock",
+            "fields": [
+              {
+                "name": "config_time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "SetAdmin",
+            "fields": [
+              {
+                "name": "admin",
+                "type": "publicKey"
+              },
+              {
+                "name": "admin_expires_at_slot",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "RenounceAdmin",
+            "fields": [
+              {
+                "name": "admin",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CreateManagedProgram",
+            "fields": [
+              {
+                "name": "managed_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "program_address",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveManagedProgram",
+            "fields": [
+              {
+                "name": "managed_program",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vote",
+      "docs": [
+        "The type of vote cast on a transaction"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Cancel"
+          },
+          {
+            "name": "Abstain"
+          },
+          {
+            "name": "Veto"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsConfigAction",
+      "docs": [
+        "A typed change of the multisig config, for applying several changes as one batch"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AddMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveMember",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceMember",
+            "fields": [
+              {
+                "name": "old_member",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_member",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ChangeThreshold",
+            "fields": [
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigThreshold",
+            "fields": [
+              {
+                "name": "config_threshold",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberWeight",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "weight",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberPermissions",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "permissions",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "SetMemberExpiration",
+            "fields": [
+              {
+                "name": "member",
+                "type": "publicKey"
+              },
+              {
+                "name": "expires_at",
+                "type": {
+                  "option": "i64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "ChangeTimeLock",
+            "fields": [
+              {
+                "name": "time_lock",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeVetoPeriod",
+            "fields": [
+              {
+                "name": "veto_period",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "ChangeConfigTimeLock",
+            "fields": [
+              {
+                "name": "config_time_lock",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "name": "rejectTransaction",
+      "name": "MsTransactionStatus",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        }
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
       ],
-      "args": []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Active"
+          },
+          {
+            "name": "ExecuteReady"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Expired"
+          },
+          {
+            "name": "Recurring"
+          }
+        ]
+      }
     },
     {
-      "name": "cancelTransaction",
+      "name": "OracleKind",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+        "The state account for an instruction that is attached to a transaction.",
+        "The oracle that publishes the price account of an execution condition"
       ],
-      "args": []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Switchboard"
+          }
+        ]
+      }
     },
     {
-      "name": "executeTransaction",
+      "name": "PriceComparison",
       "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        }
+        "How the oracle price is compared to the price of an execution condition"
       ],
-      "args": [
-        {
-          "name": "accountList",
-          "type": "bytes"
-        }
-      ]
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Above"
+          },
+          {
+            "name": "Below"
+          }
+        ]
+      }
     },
     {
-      "name": "executeInstruction",
-      "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
-      ],
-      "accounts": [
-        {
-          "name": "multisig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "instruction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "ms",
+      "name": "StakeAction",
       "docs": [
-        "Ms is the basic state account for a multisig."
+        "Typed stake program actions for a stake account whose stake and withdraw authority",
+        "is a vault, so the instruction doesn't have to be encoded by hand."
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "threshold",
-            "type": "u16"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "authorityIndex",
-            "type": "u16"
+            "name": "Delegate",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "vote",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "transactionIndex",
-            "type": "u32"
+            "name": "Deactivate",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "msChangeIndex",
-            "type": "u32"
+            "name": "Withdraw",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "Split",
+            "fields": [
+              {
+                "name": "stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "split_stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "createKey",
-            "type": "publicKey"
-          },
+            "name": "Merge",
+            "fields": [
+              {
+                "name": "destination_stake",
+                "type": "publicKey"
+              },
+              {
+                "name": "source_stake",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteAction",
+      "docs": [
+        "Typed vote program actions for a vote account whose withdraw authority is a vault,",
+        "so validator teams don't have to encode the instruction by hand."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "allowExternalExecute",
-            "type": "bool"
+            "name": "AuthorizeWithdrawer",
+            "fields": [
+              {
+                "name": "vote_account",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_withdrawer",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "keys",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "Withdraw",
+            "fields": [
+              {
+                "name": "vote_account",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "msTransaction",
+      "name": "GovernanceAction",
       "docs": [
-        "The MsTransaction is the state account for a multisig transaction"
+        "Typed SPL Governance actions for a vault that is the authority of a realm, or that holds",
+        "governing (ie. council) tokens in it, so proposals translate into realm instructions",
+        "without encoding them by hand. Realms with voter weight addins aren't supported."
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "ms",
-            "type": "publicKey"
+            "name": "SetRealmAuthority",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_realm_authority",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
           },
           {
-            "name": "transactionIndex",
-            "type": "u32"
+            "name": "DepositGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_source",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "authorityIndex",
-            "type": "u32"
+            "name": "WithdrawGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_destination",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "authorityBump",
-            "type": "u8"
+            "name": "SetGovernanceDelegate",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_governance_delegate",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
           },
           {
-            "name": "status",
-            "type": {
-              "defined": "MsTransactionStatus"
-            }
+            "name": "CastVote",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governance",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal_owner_record",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "approve",
+                "type": "bool"
+              }
+            ]
           },
           {
-            "name": "instructionIndex",
-            "type": "u8"
+            "name": "RelinquishVote",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governance",
+                "type": "publicKey"
+              },
+              {
+                "name": "proposal",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "bump",
-            "type": "u8"
-          },
+            "name": "RevokeGoverningTokens",
+            "fields": [
+              {
+                "name": "realm",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "governing_token_owner",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdlAction",
+      "docs": [
+        "Typed Anchor IDL actions for a program whose IDL authority is a vault, so the rarely used",
+        "IDL instructions don't have to be encoded by hand. Closing the IDL account needs the program",
+        "to be built with an Anchor version that supports it (0.29 or later)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "approved",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "SetAuthority",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_authority",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "rejected",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "SetBufferAuthority",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_authority",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "cancelled",
-            "type": {
-              "vec": "publicKey"
-            }
+            "name": "SetBuffer",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "executedIndex",
-            "type": "u8"
+            "name": "Close",
+            "fields": [
+              {
+                "name": "program_id",
+                "type": "publicKey"
+              },
+              {
+                "name": "destination",
+                "type": "publicKey"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "msInstruction",
+      "name": "TypedAction",
       "docs": [
-        "The state account for an instruction that is attached to a transaction.",
-        "Almost analagous to the native Instruction struct for solana, but with an extra",
-        "field for the bump."
+        "The typed actions that can be attached to a vault transaction with add_typed_instruction,",
+        "each building its instruction with the vault of the transaction as the signing authority."
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
+            "name": "Stake",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "StakeAction"
+                }
               }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
+            ]
           },
           {
-            "name": "instructionIndex",
-            "type": "u8"
+            "name": "Vote",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "VoteAction"
+                }
+              }
+            ]
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "Idl",
+            "fields": [
+              {
+                "name": "action",
+                "type": {
+                  "defined": "IdlAction"
+                }
+              }
+            ]
           },
           {
-            "name": "executed",
-            "type": "bool"
+            "name": "Governance",
+            "fields": [
+              {
+                "name": "governance_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "action",
+                "type": {
+                  "defined": "GovernanceAction"
+                }
+              }
+            ]
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "MsAccountMeta",
+      "name": "MembershipGate",
       "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
+        "The mint or NFT collection whose token holders can vote on the vault transactions of a multisig.",
+        "Tokens of a mint must be non-transferable (Token-2022), as a balance that can be split or moved",
+        "between wallets would vote again."
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "isSigner",
-            "type": "bool"
+            "name": "Mint",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
           },
           {
-            "name": "isWritable",
-            "type": "bool"
+            "name": "Collection",
+            "fields": [
+              {
+                "name": "collection",
+                "type": "publicKey"
+              },
+              {
+                "name": "threshold",
+                "type": "u16"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "IncomingInstruction",
+      "name": "PolicyStage",
       "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
+        "The stage of a vault transaction a policy program is consulted for"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "programId",
-            "type": "publicKey"
+            "name": "Activate"
           },
           {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
+            "name": "Approve"
           },
           {
-            "name": "data",
-            "type": "bytes"
+            "name": "Execute"
           }
         ]
       }
     },
     {
-      "name": "MsTransactionStatus",
+      "name": "ActivityAction",
       "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+        "The transaction lifecycle actions recorded in the activity log"
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Draft"
+            "name": "Create"
           },
           {
-            "name": "Active"
+            "name": "Activate"
           },
           {
-            "name": "ExecuteReady"
+            "name": "Approve"
           },
           {
-            "name": "Executed"
+            "name": "Reject"
           },
           {
-            "name": "Rejected"
+            "name": "Cancel"
           },
           {
-            "name": "Cancelled"
+            "name": "Execute"
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "ConfigAction"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SeqAdvanced",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "seq",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigClosed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigFrozen",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MultisigRecovered",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recoveryKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "linkedTransaction",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "VoteCast",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "VoteMemoAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vote",
+          "type": {
+            "defined": "Vote"
+          },
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": "bytes",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionStatusChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllowanceUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "allowance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -1508,6 +18148,454 @@ export const IDL: SquadsMpl = {
     {
       "code": 6013,
       "name": "NotEnoughLamports"
+    },
+    {
+      "code": 6014,
+      "name": "VoteCapacityExceeded"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidApprovalRequest"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidMemberWeight"
+    },
+    {
+      "code": 6017,
+      "name": "MissingPermission"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6019,
+      "name": "TimeLockNotElapsed"
+    },
+    {
+      "code": 6020,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidExpiration"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidSpendingLimit"
+    },
+    {
+      "code": 6023,
+      "name": "SpendingLimitExceeded"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidLookupTable"
+    },
+    {
+      "code": 6025,
+      "name": "VetoPeriodElapsed"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMemberProgram"
+    },
+    {
+      "code": 6027,
+      "name": "MemberExpired"
+    },
+    {
+      "code": 6028,
+      "name": "MemberNotExpired"
+    },
+    {
+      "code": 6029,
+      "name": "MultisigFrozen"
+    },
+    {
+      "code": 6030,
+      "name": "NotGuardian"
+    },
+    {
+      "code": 6031,
+      "name": "MultisigPaused"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMetadata"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidTransactionDescription"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidSignedVote"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidDelegate"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidRecurringTransaction"
+    },
+    {
+      "code": 6037,
+      "name": "RecurringTransactionNotDue"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidBuffer"
+    },
+    {
+      "code": 6039,
+      "name": "BufferHashMismatch"
+    },
+    {
+      "code": 6040,
+      "name": "AllowanceExceeded"
+    },
+    {
+      "code": 6041,
+      "name": "AllowanceExpired"
+    },
+    {
+      "code": 6042,
+      "name": "VoteRecordsEnabled"
+    },
+    {
+      "code": 6043,
+      "name": "VoteRecordsDisabled"
+    },
+    {
+      "code": 6044,
+      "name": "AlreadyMigrated"
+    },
+    {
+      "code": 6045,
+      "name": "ExecutionInProgress"
+    },
+    {
+      "code": 6046,
+      "name": "ExecutionIncomplete"
+    },
+    {
+      "code": 6047,
+      "name": "InstructionDataHashMismatch"
+    },
+    {
+      "code": 6048,
+      "name": "ProgramNotAllowed"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidProgramAllowlist"
+    },
+    {
+      "code": 6050,
+      "name": "InstructionDenied"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidDeniedInstruction"
+    },
+    {
+      "code": 6052,
+      "name": "RemovalPending"
+    },
+    {
+      "code": 6053,
+      "name": "NoPendingRemoval"
+    },
+    {
+      "code": 6054,
+      "name": "RemovalDelayNotElapsed"
+    },
+    {
+      "code": 6055,
+      "name": "RemovalDelayed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRecovery"
+    },
+    {
+      "code": 6057,
+      "name": "RecoveryNotDue"
+    },
+    {
+      "code": 6058,
+      "name": "DuplicateMember"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidRecoverySet"
+    },
+    {
+      "code": 6060,
+      "name": "NotRecoveryKey"
+    },
+    {
+      "code": 6061,
+      "name": "RecoveryAlreadyApproved"
+    },
+    {
+      "code": 6062,
+      "name": "RecoveryNotApproved"
+    },
+    {
+      "code": 6063,
+      "name": "RecoveryDelayNotElapsed"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidConfigActions"
+    },
+    {
+      "code": 6065,
+      "name": "AuthorityRetired"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidAuthorityRecord"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidVoteAction"
+    },
+    {
+      "code": 6068,
+      "name": "VoteWithdrawTooLarge"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidExecutors"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidExecutionHook"
+    },
+    {
+      "code": 6071,
+      "name": "MissingExecutionHook"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidPolicy"
+    },
+    {
+      "code": 6073,
+      "name": "MissingPolicy"
+    },
+    {
+      "code": 6074,
+      "name": "MissingActivityLog"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidVoteMemo"
+    },
+    {
+      "code": 6076,
+      "name": "VoteNotCast"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidQuorum"
+    },
+    {
+      "code": 6078,
+      "name": "MaxOpenTransactionsReached"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidCoCreators"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidGovernanceAction"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidMembershipToken"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidStakeLocker"
+    },
+    {
+      "code": 6083,
+      "name": "StakeSnapshotRequired"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidSessionKey"
+    },
+    {
+      "code": 6085,
+      "name": "SessionKeyExpired"
+    },
+    {
+      "code": 6086,
+      "name": "InvalidValueTier"
+    },
+    {
+      "code": 6087,
+      "name": "InvalidPriceAccount"
+    },
+    {
+      "code": 6088,
+      "name": "ExecutionConditionNotMet"
+    },
+    {
+      "code": 6089,
+      "name": "InvalidExecutionWindow"
+    },
+    {
+      "code": 6090,
+      "name": "OutsideExecutionWindow"
+    },
+    {
+      "code": 6091,
+      "name": "InvalidAdmin"
+    },
+    {
+      "code": 6092,
+      "name": "NotAdmin"
+    },
+    {
+      "code": 6093,
+      "name": "AdminExpired"
+    },
+    {
+      "code": 6094,
+      "name": "InvalidManagedProgram"
+    },
+    {
+      "code": 6095,
+      "name": "InvalidRollbackBuffer"
+    },
+    {
+      "code": 6096,
+      "name": "RollbackNotApproved"
+    },
+    {
+      "code": 6097,
+      "name": "RollbackAlreadyApproved"
+    },
+    {
+      "code": 6098,
+      "name": "InvalidIdlAction"
+    },
+    {
+      "code": 6099,
+      "name": "AuthorityIndexOverflow"
+    },
+    {
+      "code": 6100,
+      "name": "MultisigNotEmpty"
+    },
+    {
+      "code": 6101,
+      "name": "InvalidStatusTransition"
+    },
+    {
+      "code": 6102,
+      "name": "ThresholdUnderflow"
+    },
+    {
+      "code": 6103,
+      "name": "SizeOverflow"
+    },
+    {
+      "code": 6104,
+      "name": "ThresholdTooLow"
+    },
+    {
+      "code": 6105,
+      "name": "ThresholdTooHigh"
+    },
+    {
+      "code": 6106,
+      "name": "RemovalBelowThreshold"
+    },
+    {
+      "code": 6107,
+      "name": "InvalidMaxMembers"
+    },
+    {
+      "code": 6108,
+      "name": "UnsortedMembers"
+    },
+    {
+      "code": 6109,
+      "name": "VoteRecordsRequired"
+    },
+    {
+      "code": 6110,
+      "name": "MemberPageFull"
+    },
+    {
+      "code": 6111,
+      "name": "InvalidMemberRoot"
+    },
+    {
+      "code": 6112,
+      "name": "InvalidMemberProof"
+    },
+    {
+      "code": 6113,
+      "name": "RatificationRequired"
+    },
+    {
+      "code": 6114,
+      "name": "InvalidRatifyingProposal"
+    },
+    {
+      "code": 6115,
+      "name": "NotRatified"
+    },
+    {
+      "code": 6116,
+      "name": "InvalidVetoCouncil"
+    },
+    {
+      "code": 6117,
+      "name": "NotVetoCouncilMember"
+    },
+    {
+      "code": 6118,
+      "name": "AlreadyVetoed"
+    },
+    {
+      "code": 6119,
+      "name": "InvalidMembershipGate"
+    },
+    {
+      "code": 6120,
+      "name": "TransactionIndexOverflow"
+    },
+    {
+      "code": 6121,
+      "name": "TallyOverflow"
+    },
+    {
+      "code": 6122,
+      "name": "TimestampOverflow"
+    },
+    {
+      "code": 6123,
+      "name": "BountyOverflow"
+    },
+    {
+      "code": 6124,
+      "name": "InstructionIndexOverflow"
+    },
+    {
+      "code": 6125,
+      "name": "NotMigrated"
     }
   ]
 };
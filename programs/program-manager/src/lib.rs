@@ -1,6 +1,8 @@
+// DEPRECATED - managed programs and their upgrades are now tracked by squads-mpl itself,
+// see create_managed_program and create_program_upgrade.
 use anchor_lang::prelude::*;
 use state::pm::*;
-use squads_mpl::state::*;
+use squads_mpl::state::{Ms, MsTransaction, MsInstruction};
 use squads_mpl::errors::*;
 pub mod state;
 
@@ -106,7 +106,7 @@ impl UpgradeInstruction {
 
     pub fn get_max_size(&self) -> usize {
         // add three the size to correlate with the saved instruction account
-        return get_instance_packed_len(&self).unwrap_or_default();
+        get_instance_packed_len(&self).unwrap_or_default()
     }
 }
 
@@ -233,7 +233,7 @@ pub mod roles {
         if delegate_pda != ctx.accounts.delegate.key() {
             return err!(MsError::InvalidInstructionAccount);
         }
-        let remaining_accounts = ctx.remaining_accounts.clone().to_vec();
+        let remaining_accounts = ctx.remaining_accounts.to_vec();
         squads_mpl::cpi::execute_transaction(ctx.accounts.execute_transaction_ctx()
             .with_remaining_accounts(remaining_accounts)
             .with_signer(&[
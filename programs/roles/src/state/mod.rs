@@ -1,2 +1,2 @@
-pub use roles::*;
+pub use self::roles::*;
 pub mod roles;
\ No newline at end of file
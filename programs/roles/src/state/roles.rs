@@ -49,7 +49,7 @@ impl User {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum Role {
     Initiate,
     Vote,
@@ -83,7 +83,7 @@ impl IncomingInstruction {
         // there are 3 extra bytes in a saved instruction account: index, bump, executed
         // this is used to determine how much space the incoming instruction
         // will used when saved
-        return get_instance_packed_len(&self).unwrap_or_default().checked_add(3).unwrap_or_default();
+        get_instance_packed_len(&self).unwrap_or_default().checked_add(3).unwrap_or_default()
     }
 }
 
@@ -4,8 +4,16 @@
 */
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    ed25519_program,
+    program::invoke_signed,
+    sysvar::instructions as sysvar_instructions,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::{SeqAdvanced, TransactionStatusChanged, Vote, VoteCast};
 
 /// The create multisig account context
 /// Expects the following accounts:
@@ -36,6 +44,8 @@ pub struct Create<'info> {
 
 /// The account context for creating a new multisig transaction
 /// Upon fresh creation the transaction will be in a Draft state
+/// The multisig is reallocated if it needs to count the open transactions of the creator, paid for by the creator.
+/// The creator is a member, or the vault authority of the parent multisig signing through CPI.
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
@@ -52,6 +62,9 @@ pub struct CreateTransaction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_open_transaction(creator.key())?),
+        realloc::payer = creator,
+        realloc::zero = false,
     )]
     pub multisig: Account<'info, Ms>,
 
@@ -62,7 +75,7 @@ pub struct CreateTransaction<'info> {
         seeds = [
             b"squad",
             multisig.key().as_ref(),
-            &multisig.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+            &multisig.transaction_index.wrapping_add(1).to_le_bytes(),
             b"transaction"
         ], bump
     )]
@@ -70,84 +83,74 @@ pub struct CreateTransaction<'info> {
 
     #[account(
         mut,
-        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
     )]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for adding an instruction to a transaction
-/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The account context for creating a config transaction
+/// Upon creation the config transaction will be in an Active state
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
-/// 2. transaction account
-/// 3. instruction account
-/// 4. creator account [signer]
-/// 5. system program
+/// 2. config transaction account
+/// 3. creator account [signer]
+/// 4. system program
 /// 
 /// Expects the following arguments:
-/// 1. instruction_data: IncomingInstruction
+/// 1. actions: Vec<MsConfigAction>
 #[derive(Accounts)]
-#[instruction(instruction_data: IncomingInstruction)]
-pub struct AddInstruction<'info> {
+#[instruction(actions: Vec<MsConfigAction>)]
+pub struct CreateConfigTransaction<'info> {
     #[account(
+        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
         ],
         bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_open_transaction(creator.key())?),
+        realloc::payer = creator,
+        realloc::zero = false,
     )]
-    pub multisig: Account<'info, Ms>,
-
-    #[account(
-        mut,
-        seeds = [
-            b"squad",
-            multisig.key().as_ref(),
-            &transaction.transaction_index.to_le_bytes(),
-            b"transaction"
-        ], bump = transaction.bump,
-        constraint = transaction.creator == creator.key(),
-        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
-        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
-    )]
-    pub transaction: Account<'info, MsTransaction>,
+    pub multisig: Box<Account<'info, Ms>>,
 
     #[account(
         init,
         payer = creator,
-        space = 8 + instruction_data.get_max_size(),
+        space = MsConfigTransaction::size(&actions, multisig.keys.len()),
         seeds = [
             b"squad",
-            transaction.key().as_ref(),
-            &transaction.instruction_index.checked_add(1).unwrap().to_le_bytes(),
-            b"instruction"
-        ],
-        bump
+            multisig.key().as_ref(),
+            &multisig.transaction_index.wrapping_add(1).to_le_bytes(),
+            b"configtransaction"
+        ], bump
     )]
-    pub instruction: Account<'info, MsInstruction>,
+    pub transaction: Box<Account<'info, MsConfigTransaction>>,
 
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), Permissions::INITIATE | Permissions::CHANGE_CONFIG) @MsError::MissingPermission,
     )]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for activating a transaction
-/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The account context for voting on a config transaction
+/// The config transaction must be in an Active state, and the voter must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
-/// 2. transaction account
-/// 3. creator account [signer]
-/// 
+/// 2. config transaction account
+/// 3. voter account [signer]
 #[derive(Accounts)]
-pub struct ActivateTransaction<'info> {
+pub struct VoteConfigTransaction<'info> {
     #[account(
+        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
@@ -155,7 +158,7 @@ pub struct ActivateTransaction<'info> {
         ],
         bump = multisig.bump,
     )]
-    pub multisig: Account<'info, Ms>,
+    pub multisig: Box<Account<'info, Ms>>,
 
     #[account(
         mut,
@@ -163,42 +166,46 @@ pub struct ActivateTransaction<'info> {
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
-            b"transaction"
+            b"configtransaction"
         ], bump = transaction.bump,
-        constraint = transaction.creator == creator.key(),
-        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
         constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
-    pub transaction: Account<'info, MsTransaction>,
+    pub transaction: Box<Account<'info, MsConfigTransaction>>,
 
     #[account(
-        mut,
-        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
     )]
-    pub creator: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+    pub member: Signer<'info>,
 }
 
-/// The account context for voting on a transaction
-/// The transaction must be in an Active state, and the voter must be a member of the multisig
+/// The account context for executing a config transaction
+/// The config transaction must be in an ExecuteReady state, and the executor must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
-/// 2. transaction account
-/// 3. voter account [signer]
-/// 
+/// 2. config transaction account
+/// 3. member account [signer]
+/// 4. payer account [signer], which can be the member or any other key paying for the realloc
+/// 5. system program
 #[derive(Accounts)]
-pub struct VoteTransaction<'info> {
+pub struct ExecuteConfigTransaction<'info> {
     #[account(
+        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
         ],
         bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_additional(transaction.additional_len())?),
+        realloc::payer = payer,
+        realloc::zero = false,
     )]
-    pub multisig: Account<'info, Ms>,
+    pub multisig: Box<Account<'info, Ms>>,
 
     #[account(
         mut,
@@ -206,32 +213,39 @@ pub struct VoteTransaction<'info> {
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
-            b"transaction"
+            b"configtransaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
         constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
-    pub transaction: Account<'info, MsTransaction>,
+    pub transaction: Box<Account<'info, MsConfigTransaction>>,
 
     #[account(
-        mut,
         constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
     )]
     pub member: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for submitting a vote to cancel a transaction
-/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig
+/// The account context for cloning a transaction into a new Draft transaction
+/// The source transaction must be executed, rejected or deprecated, and the creator must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
-/// 2. transaction account
-/// 3. member account [signer]
+/// 2. source transaction account
+/// 3. transaction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the source and new instruction accounts as remaining accounts, in pairs:
+/// [source_ix_1, new_ix_1, source_ix_2, new_ix_2, ...]
 #[derive(Accounts)]
-pub struct CancelTransaction<'info> {
+pub struct CloneTransaction<'info> {
     #[account(
         mut,
         seeds = [
@@ -240,40 +254,123 @@ pub struct CancelTransaction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_open_transaction(creator.key())?),
+        realloc::payer = creator,
+        realloc::zero = false,
     )]
     pub multisig: Account<'info, Ms>,
 
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &source_transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = source_transaction.bump,
+        constraint = source_transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = source_transaction.is_clonable(multisig.ms_change_index) @MsError::InvalidTransactionState,
+        constraint = source_transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub source_transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MsTransaction::initial_size_with_members(multisig.keys.len()),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &multisig.transaction_index.wrapping_add(1).to_le_bytes(),
+            b"transaction"
+        ], bump
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for creating a new multisig transaction that is linked
+/// to a previously executed transaction (ie. a compensating action)
+/// Upon fresh creation the transaction will be in a Draft state
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. linked transaction account
+/// 4. creator account [signer]
+/// 5. system program
+#[derive(Accounts)]
+pub struct CreateLinkedTransaction<'info> {
     #[account(
         mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_open_transaction(creator.key())?),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MsTransaction::initial_size_with_members(multisig.keys.len()),
         seeds = [
             b"squad",
             multisig.key().as_ref(),
-            &transaction.transaction_index.to_le_bytes(),
+            &multisig.transaction_index.wrapping_add(1).to_le_bytes(),
             b"transaction"
-        ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
-        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        ], bump
     )]
     pub transaction: Account<'info, MsTransaction>,
 
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &linked_transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = linked_transaction.bump,
+        constraint = linked_transaction.status == MsTransactionStatus::Executed @MsError::InvalidTransactionState,
+        constraint = linked_transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = linked_transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub linked_transaction: Account<'info, MsTransaction>,
+
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing a transaction
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig
+/// The account context for adding an instruction to a transaction
+/// The transaction must be in a Draft state, and the creator (or a co-creator) must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. instruction_data: IncomingInstruction
 #[derive(Accounts)]
-pub struct ExecuteTransaction<'info> {
+#[instruction(instruction_data: IncomingInstruction)]
+pub struct AddInstruction<'info> {
     #[account(
         mut,
         seeds = [
@@ -283,7 +380,7 @@ pub struct ExecuteTransaction<'info> {
         ],
         bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
         mut,
@@ -293,30 +390,53 @@ pub struct ExecuteTransaction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.is_author(creator.key()),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
-        // if they've already started sequential execution, they must continue
-        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + instruction_data.get_max_size(),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.instruction_index.wrapping_add(1).to_le_bytes(),
+            b"instruction"
+        ],
+        bump
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing a transaction instruction individually
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig, and the instruction must correlate to the next executed index
+/// The account context for attaching an instruction built from a typed action (stake, vote,
+/// IDL or SPL Governance)
+/// The transaction must be in a Draft state and sign with a vault (authority 1 or greater),
+/// and the creator (or a co-creator) must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. action: TypedAction
 #[derive(Accounts)]
-pub struct ExecuteInstruction<'info> {
+#[instruction(action: TypedAction)]
+pub struct AddTypedInstruction<'info> {
     #[account(
         mut,
         seeds = [
@@ -326,7 +446,7 @@ pub struct ExecuteInstruction<'info> {
         ],
         bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
         mut,
@@ -336,59 +456,4055 @@ pub struct ExecuteInstruction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.is_author(creator.key()),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.authority_index > 0 @MsError::InvalidAuthorityIndex,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
-        mut,
+        init,
+        payer = creator,
+        space = 8 + action.to_instruction(&Pubkey::default()).get_max_size(),
         seeds = [
             b"squad",
             transaction.key().as_ref(),
-            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            &transaction.instruction_index.wrapping_add(1).to_le_bytes(),
             b"instruction"
-        ], bump = instruction.bump,
-        // it should be the next expected instruction account to be executed
-        constraint = instruction.instruction_index == transaction.executed_index.checked_add(1).unwrap() @MsError::InvalidInstructionAccount,
+        ],
+        bump
     )]
     pub instruction: Account<'info, MsInstruction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing an internal multisig transaction (which changes the multisig account)
+/// The account context for removing the last instruction attached to a transaction
+/// The transaction must be in a Draft state, and only the creator can remove it.
+/// The rent of the instruction account is returned to the creator.
 /// 
 /// Expects the following accounts:
-/// 1. multisig account [signer]
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account
+/// 4. creator account [signer]
 #[derive(Accounts)]
-pub struct MsAuth<'info> {
+pub struct RemoveInstruction<'info> {
     #[account(
         mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
-        ], bump = multisig.bump,
-        signer
+        ],
+        bump = multisig.bump,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // instructions are executed by index, so only the last one can be removed
+        constraint = instruction.instruction_index == transaction.instruction_index @MsError::InvalidInstructionAccount,
+        close = creator
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
 }
 
-/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// The account context for replacing an instruction attached to a transaction
+/// The transaction must be in a Draft state, and only the creator can replace it.
+/// The instruction account is reallocated to fit the new instruction, paid for by
+/// (or refunded to) the creator.
 /// 
 /// Expects the following accounts:
-/// 1. multisig account [signer]
-/// 2. rent sysvar
-/// 3. system program
-/// 
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. incoming_instruction: IncomingInstruction
 #[derive(Accounts)]
-pub struct MsAuthRealloc<'info> {
+#[instruction(incoming_instruction: IncomingInstruction)]
+pub struct ReplaceInstruction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        realloc = 8 + incoming_instruction.get_max_size(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for appending a chunk of data to an instruction of a transaction
+/// The transaction must be in a Draft state, and only the creator (or a co-creator) can append to it.
+/// The instruction account is reallocated to fit the chunk, paid for by the appending key.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
+/// 
+/// Expects the following arguments:
+/// 1. data: Vec<u8>
+/// 2. final_hash: Option<[u8; 32]>
+#[derive(Accounts)]
+#[instruction(data: Vec<u8>)]
+pub struct AppendInstructionData<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.is_author(creator.key()),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        realloc = instruction.to_account_info().data_len() + data.len(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding multiple instructions to a transaction at once
+/// The transaction must be in a Draft state, and the creator (or a co-creator) must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 4. system program
+/// 
+/// Expects the following remaining accounts:
+/// 1. the instruction account for each incoming instruction, in order
+#[derive(Accounts)]
+pub struct AddInstructions<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.is_author(creator.key()),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for setting the address lookup tables of a transaction
+/// The transaction must be in a Draft state, and only the creator can set the lookup tables.
+/// The transaction account is reallocated if needed, paid for by the creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. lookup_tables: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(lookup_tables: Vec<Pubkey>)]
+pub struct SetLookupTables<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        realloc = transaction.to_account_info().data_len() + 32 * lookup_tables.len().saturating_sub(transaction.lookup_tables.len()),
+        realloc::payer = creator,
+        realloc::zero = false,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for setting the co-creators of a transaction
+/// The transaction must be in a Draft state, and only the creator can set them.
+/// The transaction account is reallocated if needed, paid for by the creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. co_creators: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(co_creators: Vec<Pubkey>)]
+pub struct SetCoCreators<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        realloc = transaction.to_account_info().data_len() + 32 * co_creators.len().saturating_sub(transaction.co_creators.len()),
+        realloc::payer = creator,
+        realloc::zero = false,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for setting the title and description of a transaction
+/// The transaction must be in a Draft state, and only the creator can set them.
+/// The transaction account is reallocated if needed, paid for by the creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. title: String
+/// 2. description: String
+#[derive(Accounts)]
+#[instruction(title: String, description: String)]
+pub struct SetTransactionDescription<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        realloc = transaction.to_account_info().data_len() + (title.len() + description.len()).saturating_sub(transaction.title.len() + transaction.description.len()),
+        realloc::payer = creator,
+        realloc::zero = false,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for activating a transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ActivateTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
+    )]
+    pub creator: Signer<'info>,
+    // pub system_program: Program<'info, System>,
+}
+
+/// The account context for activating a transaction of a multisig with a stake weight source,
+/// snapshotting the vote weights of the members from their staking lockers.
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. stake snapshot account
+/// 4. creator account [signer], paying the rent
+/// 5. system program
+/// 
+/// Expects the following remaining accounts:
+/// 1. the staking locker of every member, whether it exists or not
+#[derive(Accounts)]
+pub struct ActivateWithStakeSnapshot<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = StakeSnapshot::size(multisig.keys.len()),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"stakesnapshot"
+        ], bump
+    )]
+    pub stake_snapshot: Account<'info, StakeSnapshot>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() || multisig.parent == Some(creator.key()) @MsError::KeyNotInMultisig,
+        constraint = multisig.can_initiate(creator.key()) @MsError::MissingPermission,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for closing the stake snapshot of a transaction once voting on it has
+/// ended, or it was deprecated. The rent of the snapshot is returned to the creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. stake snapshot account
+/// 4. creator account [signer]
+#[derive(Accounts)]
+pub struct CloseStakeSnapshot<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status != MsTransactionStatus::Active
+            || transaction.transaction_index <= multisig.ms_change_index @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"stakesnapshot"
+        ], bump = stake_snapshot.bump,
+        close = creator
+    )]
+    pub stake_snapshot: Account<'info, StakeSnapshot>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// The account context for voting on a transaction
+/// The transaction must be in an Active state, and the voter must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. voter account [signer]
+/// 
+#[derive(Accounts)]
+pub struct VoteTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+    // pub system_program: Program<'info, System>,
+}
+
+/// The account context for relaying votes that members signed offline
+/// The transaction must be in an Active state, and each vote must be verified
+/// by an ed25519 program instruction directly preceding this one (one per vote, in order).
+/// Only the relative position is checked, so the transaction can start with the advance nonce
+/// instruction of a durable nonce.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. relayer account [signer]
+/// 4. instructions sysvar
+/// 
+#[derive(Accounts)]
+pub struct SubmitSignedVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    pub relayer: Signer<'info>,
+
+    /// CHECK: the address is checked to be the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+impl<'info> SubmitSignedVote<'info> {
+    /// checks that the instruction before this one is an ed25519 program instruction
+    /// verifying a single signature of the member over the message
+    pub fn verify_signature(&self, member: Pubkey, message: &[u8]) -> Result<()> {
+        self.verify_signatures(&[member], message)
+    }
+
+    /// checks that the instruction before this one is an ed25519 program instruction verifying
+    /// a single signature of the member over the approval request of the transaction, built
+    /// from the instruction accounts, which must be all of the attached instructions in order
+    pub fn verify_approval_request(&self, member: Pubkey, instruction_infos: &[AccountInfo<'info>]) -> Result<()> {
+        if instruction_infos.len() != usize::from(self.transaction.instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let instructions = (1..=self.transaction.instruction_index)
+            .zip(instruction_infos)
+            .map(|(instruction_index, instruction_info)| {
+                // instructions are indexed from 1, and must be the ones attached to this transaction
+                let (instruction_pda, _) = crate::pda::find_ix_address(&self.transaction.key(), instruction_index);
+                if instruction_info.key != &instruction_pda {
+                    return err!(MsError::InvalidInstructionAccount);
+                }
+                let instruction: Account<MsInstruction> = Account::try_from(instruction_info)?;
+                Ok(instruction.into_inner())
+            })
+            .collect::<Result<Vec<MsInstruction>>>()?;
+        let threshold = self.transaction.vote_threshold(&self.multisig);
+        let request = self.transaction.to_approval_request(threshold, &instructions)?;
+        self.verify_signature(member, &request)
+            .map_err(|_| error!(MsError::InvalidApprovalRequest))
+    }
+
+    /// checks that the instructions before this one are ed25519 program instructions, each
+    /// verifying a single signature over the message, of the members in the same order
+    pub fn verify_signatures(&self, members: &[Pubkey], message: &[u8]) -> Result<()> {
+        let current_index = usize::from(sysvar_instructions::load_current_index_checked(&self.instructions)?);
+        let first_index = current_index.checked_sub(members.len()).ok_or(MsError::InvalidSignedVote)?;
+        for (ed25519_index, member) in (first_index..current_index).zip(members) {
+            self.verify_signature_at(ed25519_index, *member, message)?;
+        }
+        Ok(())
+    }
+
+    fn verify_signature_at(&self, ed25519_index: usize, member: Pubkey, message: &[u8]) -> Result<()> {
+        let ed25519_ix = sysvar_instructions::load_instruction_at_checked(ed25519_index, &self.instructions)?;
+        if ed25519_ix.program_id != ed25519_program::ID {
+            return err!(MsError::InvalidSignedVote);
+        }
+
+        // the ed25519 instruction data starts with the number of signatures and a padding byte,
+        // followed by the offsets of the signature, public key and message (7 little endian u16s)
+        let data = &ed25519_ix.data;
+        if data.len() < 16 || data[0] != 1 {
+            return err!(MsError::InvalidSignedVote);
+        }
+        let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+        let (signature_ix_index, public_key_offset, public_key_ix_index) = (read_u16(4), read_u16(6), read_u16(8));
+        let (message_offset, message_size, message_ix_index) = (read_u16(10), read_u16(12), read_u16(14));
+        // the signature, public key and message must all be in the ed25519 instruction itself
+        let own_ix_index = usize::from(u16::MAX);
+        if signature_ix_index != own_ix_index || public_key_ix_index != own_ix_index || message_ix_index != own_ix_index {
+            return err!(MsError::InvalidSignedVote);
+        }
+        let public_key = data.get(public_key_offset..public_key_offset + 32).ok_or(MsError::InvalidSignedVote)?;
+        let signed_message = data.get(message_offset..message_offset + message_size).ok_or(MsError::InvalidSignedVote)?;
+        if public_key != member.as_ref() || signed_message != message {
+            return err!(MsError::InvalidSignedVote);
+        }
+        Ok(())
+    }
+}
+
+/// The account context for a member to delegate their vote to another key
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. delegation account
+/// 3. member account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct AddDelegate<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        init,
+        payer = member,
+        space = Delegate::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            member.key().as_ref(),
+            b"delegate"
+        ], bump
+    )]
+    pub delegation: Account<'info, Delegate>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member to revoke the delegation of their vote
+/// The rent of the delegation account is returned to the member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. delegation account
+/// 3. member account [signer]
+#[derive(Accounts)]
+pub struct RemoveDelegate<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            member.key().as_ref(),
+            b"delegate"
+        ], bump = delegation.bump,
+        close = member
+    )]
+    pub delegation: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+}
+
+/// The account context for a delegate to vote on behalf of a member
+/// The transaction must be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. delegation account
+/// 4. delegate account [signer]
+#[derive(Accounts)]
+pub struct SubmitDelegatedVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            delegation.member.as_ref(),
+            b"delegate"
+        ], bump = delegation.bump,
+        constraint = delegation.delegate == delegate.key() @MsError::InvalidDelegate,
+    )]
+    pub delegation: Account<'info, Delegate>,
+
+    pub delegate: Signer<'info>,
+}
+
+/// The account context for a member to register a session key
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. session account
+/// 3. member account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. session_key: Pubkey, the short-lived key that signs for the member
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct AddSessionKey<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        init,
+        payer = member,
+        space = SessionKey::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            session_key.as_ref(),
+            b"sessionkey"
+        ], bump
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member to revoke a session key, or to close it once expired.
+/// The rent of the session account is returned to the member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. session account
+/// 3. member account [signer]
+#[derive(Accounts)]
+pub struct RemoveSessionKey<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            session.session_key.as_ref(),
+            b"sessionkey"
+        ], bump = session.bump,
+        constraint = session.member == member.key() @MsError::InvalidSessionKey,
+        close = member
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+}
+
+/// The account context for a session key to vote on behalf of a member
+/// The transaction must be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. session account
+/// 4. session key account [signer]
+#[derive(Accounts)]
+pub struct SubmitSessionVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            session_key.key().as_ref(),
+            b"sessionkey"
+        ], bump = session.bump,
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    pub session_key: Signer<'info>,
+}
+
+/// The account context for a member to vote on a transaction with a vote record,
+/// for multisigs with vote records enabled. The transaction must be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. vote record account
+/// 4. member account [signer]
+/// 5. payer account [signer], which can be the member or any other key paying the rent
+/// 6. system program
+#[derive(Accounts)]
+pub struct CreateVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VoteRecord::SIZE,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            b"vote"
+        ], bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member of a page of additional members to vote on a transaction
+/// with a vote record. The multisig must have vote records enabled, and the transaction must
+/// be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. members page account holding the member
+/// 4. vote record account
+/// 5. member account [signer]
+/// 6. payer account [signer], which can be the member or any other key paying the rent
+/// 7. system program
+#[derive(Accounts)]
+pub struct CreatePagedVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.vote_records @MsError::VoteRecordsDisabled,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &[members_page.page_index],
+            b"members"
+        ], bump = members_page.bump,
+        constraint = members_page.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = members_page.is_member(member.key()) @MsError::KeyNotInMultisig,
+    )]
+    pub members_page: Account<'info, MsMembers>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VoteRecord::SIZE,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            b"vote"
+        ], bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member of the set of the member root to vote on a transaction with
+/// a vote record, proving its membership with an inclusion proof. The multisig must have vote
+/// records enabled, and the transaction must be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. vote record account
+/// 4. member account [signer]
+/// 5. payer account [signer], which can be the member or any other key paying the rent
+/// 6. system program
+#[derive(Accounts)]
+pub struct CreateProofVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.vote_records @MsError::VoteRecordsDisabled,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VoteRecord::SIZE,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            b"vote"
+        ], bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member to record the memo of their vote on a transaction.
+/// The member must have cast the vote, one memo can be recorded per member and vote.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. vote memo account
+/// 4. member account [signer, writable], paying the rent
+/// 5. system program
+/// 
+/// Expects the following arguments:
+/// 1. vote: Vote
+#[derive(Accounts)]
+#[instruction(vote: Vote)]
+pub struct AddVoteMemo<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = member,
+        space = VoteMemo::SIZE,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            &[vote as u8],
+            b"votememo"
+        ], bump
+    )]
+    pub vote_memo: Account<'info, VoteMemo>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a member to change the vote of their vote record
+/// The transaction must be in an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. vote record account
+/// 4. member account [signer]
+#[derive(Accounts)]
+pub struct ChangeVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            b"vote"
+        ], bump = vote_record.bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for closing a vote record once voting on the transaction has ended,
+/// or the transaction was deprecated. The rent of the vote record is returned to the member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. vote record account
+/// 4. member account [signer]
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status != MsTransactionStatus::Active
+            || transaction.transaction_index <= multisig.ms_change_index @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            member.key().as_ref(),
+            b"vote"
+        ], bump = vote_record.bump,
+        close = member
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+}
+
+/// The account context for a token holder to vote on a transaction with a token vote record,
+/// for multisigs with vote records enabled and a membership gate. The transaction must be in
+/// an Active state.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. token vote record account
+/// 4. voter account [signer], paying the rent
+/// 5. token account of the voter, holding the token of the mint or the NFT
+/// 6. system program
+/// 
+/// Expects the following remaining accounts:
+/// 1. the metadata account of the NFT, for a collection gate
+/// 
+/// Expects the following arguments:
+/// 1. voting_key: Pubkey, the voter for a mint gate, the mint of the NFT for a collection gate
+#[derive(Accounts)]
+#[instruction(voting_key: Pubkey)]
+pub struct CreateTokenVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.vote_records @MsError::VoteRecordsDisabled,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = TokenVoteRecord::SIZE,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            voting_key.as_ref(),
+            b"tokenvote"
+        ], bump
+    )]
+    pub vote_record: Account<'info, TokenVoteRecord>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: the token account is checked against the membership gate
+    pub token_account: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for closing a token vote record once voting on the transaction has ended,
+/// or the transaction was deprecated. The rent of the vote record is returned to the voter.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. token vote record account
+/// 4. voter account [signer]
+#[derive(Accounts)]
+pub struct CloseTokenVoteRecord<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status != MsTransactionStatus::Active
+            || transaction.transaction_index <= multisig.ms_change_index @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            vote_record.voting_key.as_ref(),
+            b"tokenvote"
+        ], bump = vote_record.bump,
+        constraint = vote_record.voter == voter.key(),
+        close = voter
+    )]
+    pub vote_record: Account<'info, TokenVoteRecord>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+/// The account context for submitting a vote to cancel a transaction
+/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a key of the veto council to veto a transaction
+/// The transaction must be in an Active or ExecuteReady state, and the council member must be in
+/// the veto council. The transaction is reallocated to record the veto, paid for by the council member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. council member account [signer]
+/// 4. system program
+/// 
+#[derive(Accounts)]
+pub struct CouncilVetoTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(transaction.status, MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        realloc = transaction.to_account_info().data_len() + 32,
+        realloc::payer = council_member,
+        realloc::zero = false,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_veto_council_member(council_member.key()) @MsError::NotVetoCouncilMember,
+    )]
+    pub council_member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a key of the veto council to veto a recurring transaction
+/// The transaction must be in a Recurring state, and the council member must be in the veto council.
+/// The transaction is reallocated to record the veto, paid for by the council member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. recurring transaction account
+/// 4. creator account, receiving the rent of the recurring transaction once it's rejected
+/// 5. council member account [signer]
+/// 6. system program
+/// 
+#[derive(Accounts)]
+pub struct CouncilVetoRecurringTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Recurring @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        realloc = transaction.to_account_info().data_len() + 32,
+        realloc::payer = council_member,
+        realloc::zero = false,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            transaction.key().as_ref(),
+            b"recurring"
+        ], bump = recurring_transaction.bump,
+    )]
+    pub recurring_transaction: Account<'info, RecurringTransaction>,
+
+    /// CHECK: only receives the rent of the recurring transaction, must be the creator of the transaction
+    #[account(
+        mut,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_veto_council_member(council_member.key()) @MsError::NotVetoCouncilMember,
+    )]
+    pub council_member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for expiring a transaction
+/// The transaction must not be executed (or already closed out), and its expiration must have passed.
+/// Anyone can expire the transaction, the rent is returned to the creator of the transaction.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account
+/// 4. payer account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExpireTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(
+            transaction.status,
+            MsTransactionStatus::Draft | MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady
+        ) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+        close = creator
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: only receives the rent of the transaction, must be its creator
+    #[account(
+        mut,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    pub payer: Signer<'info>,
+}
+
+/// The account context for funding the execution bounty of a transaction
+/// The transaction must be in a Draft state, and only the creator can fund the bounty
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 4. system program
+/// 
+#[derive(Accounts)]
+pub struct FundExecutionBounty<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for closing the instruction accounts of an executed transaction
+/// Anyone can close the instruction accounts, the rent is returned to the creator of the transaction.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account
+/// 
+/// Expects the following remaining accounts:
+/// 1. the instruction accounts to close
+#[derive(Accounts)]
+pub struct CloseExecutedInstructions<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Executed @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: only receives the rent of the instruction accounts, must be the transaction creator
+    #[account(
+        mut,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: AccountInfo<'info>,
+}
+
+/// The account context for the creator closing their transaction while it is still a draft
+/// The transaction and its instruction accounts are closed, with the rent returned to the creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 
+/// Expects the following remaining accounts:
+/// 1. all of the instruction accounts of the transaction
+#[derive(Accounts)]
+pub struct CloseDraftTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// The account context for closing a stale transaction, one deprecated by a change of the multisig
+/// that wasn't executed. Anyone can close it, the rent is returned to the creator of the transaction.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account
+/// 
+/// Expects the following remaining accounts:
+/// 1. all of the instruction accounts of the transaction
+#[derive(Accounts)]
+pub struct CloseStaleTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.is_stale(multisig.ms_change_index) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: only receives the rent of the transaction and instruction accounts, must be the transaction creator
+    #[account(
+        mut,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: AccountInfo<'info>,
+}
+
+/// The account context for executing a transaction
+/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig,
+/// unless the transaction has an execution bounty, in which case any key can execute it.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(transaction.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(transaction.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(transaction.authority_index) @MsError::AuthorityRetired,
+        constraint = !multisig.is_executing_other(transaction.transaction_index, transaction.authority_index) @MsError::ExecutionInProgress,
+        // if they've already started sequential execution, they must continue
+        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    // with a bounty set, execution is permissionless, otherwise allowed executors can execute as well
+    #[account(
+        mut,
+        constraint = transaction.execution_bounty > 0 || multisig.is_executor(member.key())
+            || multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = transaction.execution_bounty > 0 || multisig.is_executor(member.key())
+            || multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for casting the final approval of a transaction and executing it right away
+/// The transaction must be in an Active state, and the member must be able to both vote and execute
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ApproveAndExecute<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(transaction.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(transaction.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(transaction.authority_index) @MsError::AuthorityRetired,
+        constraint = !multisig.is_executing_other(transaction.transaction_index, transaction.authority_index) @MsError::ExecutionInProgress,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_expired_member(member.key()) @MsError::MemberExpired,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+        constraint = multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+impl<'info> ExecuteTransaction<'info> {
+    /// Pays the execution bounty (if any) out of the transaction account to the executor.
+    pub fn pay_execution_bounty(&self) -> Result<()> {
+        let bounty = self.transaction.execution_bounty;
+        if bounty == 0 {
+            return Ok(());
+        }
+        let transaction_account_info = self.transaction.to_account_info();
+        let member_account_info = self.member.to_account_info();
+        **transaction_account_info.try_borrow_mut_lamports()? = transaction_account_info
+            .lamports()
+            .checked_sub(bounty)
+            .ok_or(MsError::NotEnoughLamports)?;
+        **member_account_info.try_borrow_mut_lamports()? = member_account_info
+            .lamports()
+            .checked_add(bounty)
+            .unwrap();
+        Ok(())
+    }
+}
+
+/// The account context for executing a transaction instruction individually
+/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig, and the instruction must correlate to the next executed index
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteInstruction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(transaction.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(transaction.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(transaction.authority_index) @MsError::AuthorityRetired,
+        constraint = !multisig.is_executing_other(transaction.transaction_index, transaction.authority_index) @MsError::ExecutionInProgress,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: read in place by execute_instruction, which checks its discriminator and instruction index
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ], bump,
+        owner = crate::ID @MsError::InvalidInstructionAccount,
+    )]
+    pub instruction: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_executor(member.key()) || multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_executor(member.key()) || multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for finalizing a transaction executed instruction by instruction
+/// All of the instructions must have been executed, and the executor must be a member of the multisig.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct FinalizeExecution<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.executed_index > 0 @MsError::ExecutionIncomplete,
+        constraint = transaction.executed_index == transaction.instruction_index @MsError::ExecutionIncomplete,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        constraint = multisig.is_executor(member.key()) || multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_executor(member.key()) || multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for migrating a multisig of the original layout, which can't be loaded
+/// with the current layout. Anyone can migrate it, as its members and thresholds don't change,
+/// and the payer covers the rent of the fields introduced since if the account has no spare space.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. payer account [signer]
+/// 3. system program
+#[derive(Accounts)]
+pub struct MigrateBaselineMultisig<'info> {
+    /// CHECK: parsed with the original layout by migrate_baseline_multisig, which checks its address
+    #[account(mut, owner = crate::ID @MsError::InvalidInstructionAccount)]
+    pub multisig: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBaselineMultisig<'info> {
+    /// Writes the migrated multisig back to its account, growing it if needed.
+    pub fn write_multisig(&self, multisig: &Ms) -> Result<()> {
+        write_migrated_account(
+            &self.multisig,
+            multisig,
+            multisig.size_with_additional(0)?,
+            &self.payer,
+            &self.system_program,
+        )
+    }
+}
+
+/// Writes a migrated account back to its account info, growing it to the needed length if it
+/// is shorter, in which case the payer covers the rent of the new size. The rest of the account
+/// is zeroed, so that the stale bytes of the older layout don't linger in it.
+pub fn write_migrated_account<'info, T: AccountSerialize>(
+    account_info: &AccountInfo<'info>,
+    account: &T,
+    needed_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if needed_len > account_info.data_len() {
+        account_info.realloc(needed_len, false)?;
+        let top_up_lamports = Rent::get()?
+            .minimum_balance(needed_len)
+            .saturating_sub(account_info.lamports());
+        if top_up_lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                top_up_lamports,
+            )?;
+        }
+    }
+    let mut account_data = account_info.try_borrow_mut_data()?;
+    account_data[needed_len..].fill(0);
+    account.try_serialize(&mut &mut account_data[..needed_len])
+}
+
+/// The account context for migrating a transaction account to the current layout version
+/// Any member of the multisig can migrate its transactions, and covers the rent of the fields
+/// introduced since if the account has no spare space.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct MigrateTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    /// CHECK: parsed with the layout of its version by the migration, which checks its address
+    #[account(mut, owner = crate::ID @MsError::InvalidInstructionAccount)]
+    pub transaction: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateTransaction<'info> {
+    /// Checks that the parsed transaction belongs to the multisig and is at the address of the
+    /// transaction account, then writes it back migrated, growing the account if needed.
+    pub fn write_transaction(&self, transaction: &MsTransaction) -> Result<()> {
+        let transaction_pda = Pubkey::create_program_address(
+            &[
+                b"squad",
+                self.multisig.key().as_ref(),
+                &transaction.transaction_index.to_le_bytes(),
+                b"transaction",
+                &[transaction.bump],
+            ],
+            &crate::ID,
+        ).map_err(|_| MsError::InvalidInstructionAccount)?;
+        if transaction.ms != self.multisig.key() || &transaction_pda != self.transaction.key {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        write_migrated_account(
+            &self.transaction,
+            transaction,
+            8 + transaction.try_to_vec()?.len(),
+            &self.member,
+            &self.system_program,
+        )
+    }
+}
+
+/// The account context for executing an internal multisig transaction (which changes the multisig account)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+#[derive(Accounts)]
+pub struct MsAuth<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+}
+
+/// The account context for invalidating a single pending transaction through an internal transaction
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. transaction account
+#[derive(Accounts)]
+pub struct InvalidateTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(
+            transaction.status,
+            MsTransactionStatus::Draft | MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady
+        ) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+}
+
+/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. rent sysvar
+/// 3. system program
+/// 
+/// 
+#[derive(Accounts)]
+pub struct MsAuthRealloc<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for sweeping and retiring one of the multisig authorities (vaults)
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. vault account
+/// 3. destination account
+/// 4. rent sysvar
+/// 5. system program
+/// 
+/// Expects the following arguments:
+/// 1. authority_index: u32
+#[derive(Accounts)]
+#[instruction(authority_index: u32)]
+pub struct RetireAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer,
+        constraint = multisig.default_vault_index != authority_index @MsError::InvalidAuthorityIndex,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    /// CHECK: the vault is derived from the authority index
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority"
+        ], bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: the recipient of the swept lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding a spending limit to one of the multisig authorities (vaults)
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. spending limit account
+/// 3. rent payer account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. authority_index: u32
+/// 2. mint: Pubkey
+#[derive(Accounts)]
+#[instruction(authority_index: u32, mint: Pubkey)]
+pub struct AddSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = SpendingLimit::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            mint.as_ref(),
+            b"spendinglimit"
+        ], bump
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing a spending limit
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. spending limit account
+/// 3. rent collector account
+#[derive(Accounts)]
+pub struct RemoveSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            spending_limit.mint.as_ref(),
+            b"spendinglimit"
+        ], bump = spending_limit.bump,
+        constraint = spending_limit.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// CHECK: only receives the rent of the spending limit
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for adding the inactivity recovery of the multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. recovery account
+/// 3. rent payer account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct AddRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Recovery::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing the inactivity recovery
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. recovery account
+/// 3. rent collector account
+#[derive(Accounts)]
+pub struct RemoveRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump = recovery.bump,
+        constraint = recovery.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    /// CHECK: only receives the rent of the recovery
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for the recovery key to recover an inactive multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. recovery account
+/// 3. recovery key account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. members: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(members: Vec<Pubkey>)]
+pub struct Recover<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_keys(members.len())?),
+        realloc::payer = recovery_key,
+        realloc::zero = false,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump = recovery.bump,
+        constraint = recovery.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    #[account(
+        mut,
+        constraint = recovery_key.key() == recovery.recovery_key @MsError::InvalidRecovery,
+    )]
+    pub recovery_key: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for proposing the recovery of a lost member key
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. recovery key account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. old_member: Pubkey
+#[derive(Accounts)]
+#[instruction(old_member: Pubkey)]
+pub struct ProposeMemberRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = recovery_key,
+        space = MemberRecovery::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            old_member.as_ref(),
+            b"memberrecovery"
+        ], bump
+    )]
+    pub member_recovery: Account<'info, MemberRecovery>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_recovery_key(recovery_key.key()) @MsError::NotRecoveryKey,
+    )]
+    pub recovery_key: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for approving the recovery of a lost member key
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. recovery key account [signer]
+#[derive(Accounts)]
+pub struct ApproveMemberRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            member_recovery.old_member.as_ref(),
+            b"memberrecovery"
+        ], bump = member_recovery.bump,
+        constraint = member_recovery.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub member_recovery: Account<'info, MemberRecovery>,
+
+    pub recovery_key: Signer<'info>,
+}
+
+/// The account context for executing the recovery of a lost member key
+/// Anyone can execute an approved recovery once the recovery delay has passed.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. proposer account
+#[derive(Accounts)]
+pub struct ExecuteMemberRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            member_recovery.old_member.as_ref(),
+            b"memberrecovery"
+        ], bump = member_recovery.bump,
+        constraint = member_recovery.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        has_one = proposer @MsError::InvalidInstructionAccount,
+        close = proposer
+    )]
+    pub member_recovery: Account<'info, MemberRecovery>,
+
+    /// CHECK: only receives the rent of the member recovery
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+/// The account context for the old member to cancel the recovery of their key
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member recovery account
+/// 3. proposer account
+/// 4. old member account [signer]
+#[derive(Accounts)]
+pub struct CancelMemberRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            member_recovery.old_member.as_ref(),
+            b"memberrecovery"
+        ], bump = member_recovery.bump,
+        constraint = member_recovery.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        has_one = proposer @MsError::InvalidInstructionAccount,
+        has_one = old_member @MsError::InvalidInstructionAccount,
+        close = proposer
+    )]
+    pub member_recovery: Account<'info, MemberRecovery>,
+
+    /// CHECK: only receives the rent of the member recovery
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    pub old_member: Signer<'info>,
+}
+
+/// The account context for spending from a vault under a spending limit
+/// The member must be the key designated in the spending limit.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. spending limit account
+/// 3. vault (authority) account
+/// 4. destination account (wallet for SOL, token account for SPL tokens)
+/// 5. member account [signer]
+/// 6. system program
+/// 
+/// For SPL token spending limits, the following remaining accounts are expected:
+/// 1. the vault token account
+/// 2. the mint
+/// 3. the token program (SPL token or Token-2022)
+/// 4. any extra accounts required by a Token-2022 mint, ie. for its transfer hook
+#[derive(Accounts)]
+pub struct SpendFromVault<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            spending_limit.mint.as_ref(),
+            b"spendinglimit"
+        ], bump = spending_limit.bump,
+        constraint = spending_limit.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = spending_limit.member == member.key() @MsError::KeyNotInMultisig,
+        constraint = !multisig.is_frozen_for(spending_limit.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(spending_limit.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(spending_limit.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(spending_limit.authority_index) @MsError::AuthorityRetired,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// CHECK: the vault is derived from the spending limit authority index
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            b"authority"
+        ], bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: the recipient of the funds, validated by the system or token program
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for creating the associated token account of a vault
+/// Anyone can call this, the payer pays the rent of the token account.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. vault account
+/// 3. vault token account
+/// 4. mint account
+/// 5. payer account [signer]
+/// 6. token program (SPL token or Token-2022)
+/// 7. associated token program
+/// 8. system program
+/// 
+/// Expects the following arguments:
+/// 1. authority_index: u32
+#[derive(Accounts)]
+#[instruction(authority_index: u32)]
+pub struct CreateVaultAta<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = authority_index > 0 @MsError::InvalidAuthorityIndex,
+        constraint = !multisig.is_authority_retired(authority_index) @MsError::AuthorityRetired,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    /// CHECK: the vault is derived from the authority index
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority"
+        ], bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: the address is validated by the associated token program
+    #[account(mut)]
+    pub vault_token_account: AccountInfo<'info>,
+
+    /// CHECK: the mint must belong to the token program
+    #[account(
+        constraint = mint.owner == token_program.key @MsError::InvalidInstructionAccount,
+    )]
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: the SPL token or Token-2022 program
+    #[account(
+        constraint = SpendingLimit::is_token_program(token_program.key) @MsError::InvalidInstructionAccount,
+    )]
+    pub token_program: AccountInfo<'info>,
+
+    /// CHECK: the associated token program
+    #[account(address = CreateVaultAta::ASSOCIATED_TOKEN_PROGRAM_ID @MsError::InvalidInstructionAccount)]
+    pub associated_token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding an allowance to one of the multisig authorities (vaults)
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. allowance account
+/// 3. rent payer account [signer]
+/// 4. system program
+/// 
+/// Expects the following arguments:
+/// 1. authority_index: u32
+/// 2. mint: Pubkey
+/// 3. spender: Pubkey
+#[derive(Accounts)]
+#[instruction(authority_index: u32, mint: Pubkey, spender: Pubkey)]
+pub struct AddAllowance<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Allowance::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            mint.as_ref(),
+            spender.as_ref(),
+            b"allowance"
+        ], bump
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding the policy config of a multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. policy config account [writable]
+/// 3. rent payer account [signer, writable]
+/// 4. system program
+#[derive(Accounts)]
+pub struct AddPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = PolicyConfig::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"policyconfig"
+        ], bump
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing the policy config of a multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. policy config account [writable]
+/// 3. rent collector account [writable]
+#[derive(Accounts)]
+pub struct RemovePolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"policyconfig"
+        ], bump = policy_config.bump,
+        constraint = policy_config.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: only receives the rent of the policy config
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for creating the activity log of a multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. activity log account [writable]
+/// 3. rent payer account [signer, writable]
+/// 4. system program
+#[derive(Accounts)]
+pub struct CreateActivityLog<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = MsActivityLog::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"activitylog"
+        ], bump
+    )]
+    pub activity_log: Account<'info, MsActivityLog>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for closing the activity log of a multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. activity log account [writable]
+/// 3. rent collector account [writable]
+#[derive(Accounts)]
+pub struct CloseActivityLog<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"activitylog"
+        ], bump = activity_log.bump,
+        constraint = activity_log.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub activity_log: Account<'info, MsActivityLog>,
+
+    /// CHECK: only receives the rent of the activity log
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for creating the next page of additional members of a multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. members page account [writable], at the next page index
+/// 3. rent payer account [signer, writable]
+/// 4. system program
+#[derive(Accounts)]
+pub struct CreateMemberPage<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = MsMembers::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &[multisig.member_pages],
+            b"members"
+        ], bump
+    )]
+    pub members_page: Account<'info, MsMembers>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding or removing a member of a page of additional members
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer, writable]
+/// 2. members page account [writable]
+#[derive(Accounts)]
+pub struct MsMembersAuth<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &[members_page.page_index],
+            b"members"
+        ], bump = members_page.bump,
+        constraint = members_page.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub members_page: Account<'info, MsMembers>,
+}
+
+/// The account context for removing an allowance
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. allowance account
+/// 3. rent collector account
+#[derive(Accounts)]
+pub struct RemoveAllowance<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &allowance.authority_index.to_le_bytes(),
+            allowance.mint.as_ref(),
+            allowance.spender.as_ref(),
+            b"allowance"
+        ], bump = allowance.bump,
+        constraint = allowance.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    /// CHECK: only receives the rent of the allowance
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for pulling funds out of a vault under an allowance
+/// The spender must be the key designated in the allowance, and doesn't have to be a member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. allowance account
+/// 3. vault (authority) account
+/// 4. destination account (wallet for SOL, token account for SPL tokens)
+/// 5. spender account [signer]
+/// 6. system program
+/// 
+/// For SPL token allowances, the remaining accounts are the same as for spending limits.
+#[derive(Accounts)]
+pub struct SpendAllowance<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &allowance.authority_index.to_le_bytes(),
+            allowance.mint.as_ref(),
+            spender.key().as_ref(),
+            b"allowance"
+        ], bump = allowance.bump,
+        constraint = allowance.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(allowance.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(allowance.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(allowance.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(allowance.authority_index) @MsError::AuthorityRetired,
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    /// CHECK: the vault is derived from the allowance authority index
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &allowance.authority_index.to_le_bytes(),
+            b"authority"
+        ], bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: the recipient of the funds, validated by the system or token program
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    pub spender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for creating the metadata account of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. metadata account
+/// 3. rent payer account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct CreateMetadata<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = MsMetadata::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"metadata"
+        ], bump
+    )]
+    pub metadata: Account<'info, MsMetadata>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating the metadata account of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. metadata account
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"metadata"
+        ], bump = metadata.bump,
+    )]
+    pub metadata: Account<'info, MsMetadata>,
+}
+
+/// The account context for adding an authority along with its record
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority record account
+/// 3. rent payer account [signer]
+/// 4. system program
+#[derive(Accounts)]
+pub struct AddNamedAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = AuthorityRecord::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &multisig.authority_index.saturating_add(1).to_le_bytes(),
+            b"authorityrecord"
+        ], bump
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating the record of an authority
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority record account
+#[derive(Accounts)]
+pub struct UpdateAuthorityRecord<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_record.authority_index.to_le_bytes(),
+            b"authorityrecord"
+        ], bump = authority_record.bump,
+    )]
+    pub authority_record: Account<'info, AuthorityRecord>,
+}
+
+/// The account context for making an approved transaction recurring
+/// This is an internal instruction, the transaction must be ExecuteReady and sign
+/// with a vault (authority 1 or greater).
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. transaction account
+/// 3. recurring transaction account
+/// 4. rent payer account [signer]
+/// 5. system program
+#[derive(Accounts)]
+pub struct CreateRecurringTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.authority_index > 0 @MsError::InvalidAuthorityIndex,
+        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = RecurringTransaction::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            transaction.key().as_ref(),
+            b"recurring"
+        ], bump
+    )]
+    pub recurring_transaction: Account<'info, RecurringTransaction>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing a recurring transaction, which ends its schedule
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. transaction account
+/// 3. recurring transaction account
+/// 4. rent collector account
+#[derive(Accounts)]
+pub struct RemoveRecurringTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Recurring @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            transaction.key().as_ref(),
+            b"recurring"
+        ], bump = recurring_transaction.bump,
+        close = rent_collector
+    )]
+    pub recurring_transaction: Account<'info, RecurringTransaction>,
+
+    /// CHECK: only receives the rent of the recurring transaction
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for cranking a recurring transaction once it's due
+/// Anyone can execute a recurring transaction.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. recurring transaction account
+/// 4. cranker account [signer]
+#[derive(Accounts)]
+pub struct ExecuteRecurringTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Recurring @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = !multisig.is_frozen_for(transaction.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(transaction.authority_index) @MsError::MultisigPaused,
+        constraint = !multisig.is_outside_execution_window(transaction.authority_index) @MsError::OutsideExecutionWindow,
+        constraint = !multisig.is_authority_retired(transaction.authority_index) @MsError::AuthorityRetired,
+        constraint = !multisig.is_executing_other(transaction.transaction_index, transaction.authority_index) @MsError::ExecutionInProgress,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            transaction.key().as_ref(),
+            b"recurring"
+        ], bump = recurring_transaction.bump,
+    )]
+    pub recurring_transaction: Account<'info, RecurringTransaction>,
+
+    pub cranker: Signer<'info>,
+}
+
+/// The account context for upgrading a program from a buffer with a verified hash
+/// Attached to a vault transaction, the vault being the upgrade authority of the program.
+/// 
+/// Expects the following accounts:
+/// 1. authority (vault) account [signer]
+/// 2. program account
+/// 3. program data account
+/// 4. buffer account
+/// 5. spill account
+/// 6. rent sysvar
+/// 7. clock sysvar
+/// 8. bpf upgradeable loader program
+#[derive(Accounts)]
+pub struct UpgradeProgram<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: validated by the upgradeable loader
+    #[account(mut)]
+    pub program: AccountInfo<'info>,
+
+    /// CHECK: validated by the upgradeable loader
+    #[account(mut)]
+    pub program_data: AccountInfo<'info>,
+
+    /// CHECK: the contents are checked against the expected hash
+    #[account(
+        mut,
+        owner = bpf_loader_upgradeable::ID @MsError::InvalidBuffer,
+    )]
+    pub buffer: AccountInfo<'info>,
+
+    /// CHECK: only receives the lamports of the buffer
+    #[account(mut)]
+    pub spill: AccountInfo<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: the address is checked to be the upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: AccountInfo<'info>,
+}
+
+impl<'info> UpgradeProgram<'info> {
+    /// Finds the buffer retaining the current program for a rollback, if it is passed in the
+    /// remaining accounts: a buffer of the upgrade authority holding exactly the current program
+    /// data, ie. written from `solana program dump`. Returns its key and the hash of the program
+    /// data, which must be snapshot before the upgrade replaces it.
+    pub fn snapshot_rollback(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<Option<(Pubkey, [u8; 32])>> {
+        let rollback_buffer = remaining_accounts.iter().find(|a| {
+            a.key != self.buffer.key && matches!(
+                Account::<UpgradeableLoaderState>::try_from(a).map(|state| state.into_inner()),
+                Ok(UpgradeableLoaderState::Buffer { authority_address: Some(authority) }) if authority == self.authority.key()
+            )
+        });
+        let rollback_buffer = match rollback_buffer {
+            Some(rollback_buffer) => rollback_buffer,
+            None => return Ok(None),
+        };
+        let rollback_data = rollback_buffer.try_borrow_data()?;
+        let program_data = self.program_data.try_borrow_data()?;
+        let program_bytes = program_data
+            .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+            .ok_or(MsError::InvalidRollbackBuffer)?;
+        if rollback_data.get(UpgradeableLoaderState::size_of_buffer_metadata()..) != Some(program_bytes) {
+            return err!(MsError::InvalidRollbackBuffer);
+        }
+        Ok(Some((rollback_buffer.key(), anchor_lang::solana_program::hash::hash(program_bytes).to_bytes())))
+    }
+
+    /// Records the upgrade on the managed program of the upgraded program, if it is passed
+    /// (writable) in the remaining accounts, along with the rollback buffer (if any), and marks
+    /// the proposed upgrade from the buffer as upgraded, if it is passed as well.
+    pub fn record_upgrade(&self, expected_hash: [u8; 32], rollback: Option<(Pubkey, [u8; 32])>, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let mut managed_program = match remaining_accounts
+            .iter()
+            .filter(|a| a.is_writable)
+            .find_map(|a| Account::<ManagedProgram>::try_from(a).ok())
+        {
+            Some(managed_program) => managed_program,
+            None => return Ok(()),
+        };
+        let (vault_pda, _) = crate::pda::find_authority_address(&managed_program.multisig, managed_program.authority_index);
+        if managed_program.program_address != self.program.key() || vault_pda != self.authority.key() {
+            return err!(MsError::InvalidManagedProgram);
+        }
+        let managed_program_key = managed_program.key();
+        let program_upgrade = remaining_accounts
+            .iter()
+            .filter(|a| a.is_writable)
+            .filter_map(|a| Account::<ProgramUpgrade>::try_from(a).ok())
+            .find(|u| u.managed_program == managed_program_key && u.buffer == self.buffer.key() && u.expected_hash == expected_hash && u.upgraded_at == 0);
+        let now = Clock::get()?.unix_timestamp;
+        managed_program.last_upgrade = now;
+        managed_program.rollback_buffer = rollback.map(|(rollback_buffer, _)| rollback_buffer);
+        managed_program.rollback_hash = rollback.map(|(_, rollback_hash)| rollback_hash).unwrap_or_default();
+        if let Some(mut program_upgrade) = program_upgrade {
+            program_upgrade.upgraded_at = now;
+            managed_program.last_upgrade_index = program_upgrade.upgrade_index;
+            program_upgrade.exit(&crate::ID)?;
+        }
+        managed_program.exit(&crate::ID)
+    }
+}
+
+/// The account context for adding a program to the managed programs of the multisig
+/// This is an internal instruction, the multisig must be the signer. The upgrade authority
+/// of the program must be the authority (vault) at the given index.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. program account
+/// 3. program data account
+/// 4. managed program account
+/// 5. rent payer account [signer]
+/// 6. system program
+/// 
+/// Expects the following arguments:
+/// 1. authority_index: u32
+#[derive(Accounts)]
+#[instruction(authority_index: u32)]
+pub struct CreateManagedProgram<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        constraint = matches!(*program, UpgradeableLoaderState::Program { programdata_address } if programdata_address == program_data.key()) @MsError::InvalidManagedProgram,
+    )]
+    pub program: Account<'info, UpgradeableLoaderState>,
+
+    #[account(
+        constraint = authority_index > 0 @MsError::InvalidAuthorityIndex,
+        constraint = program_data.upgrade_authority_address == Some(crate::pda::find_authority_address(&multisig.key(), authority_index).0) @MsError::InvalidManagedProgram,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = ManagedProgram::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            program.key().as_ref(),
+            b"managedprogram"
+        ], bump
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing a program from the managed programs of the multisig
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. managed program account
+/// 3. rent collector account
+#[derive(Accounts)]
+pub struct RemoveManagedProgram<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+        constraint = managed_program.multisig == multisig.key() @MsError::InvalidInstructionAccount,
+        close = rent_collector
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    /// CHECK: only receives the rent of the managed program
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for proposing an upgrade of a managed program
+/// The upgrade is carried by a vault transaction of the upgrade authority of the program,
+/// and is tracked until upgrade_program executes with it.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. managed program account
+/// 3. transaction account
+/// 4. program upgrade account
+/// 5. creator account [signer]
+/// 6. system program
+#[derive(Accounts)]
+pub struct CreateProgramUpgrade<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.authority_index == managed_program.authority_index @MsError::InvalidManagedProgram,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ProgramUpgrade::SIZE,
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            &managed_program.upgrade_index.checked_add(1).unwrap().to_le_bytes(),
+            b"programupgrade"
+        ], bump
+    )]
+    pub program_upgrade: Account<'info, ProgramUpgrade>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), Permissions::INITIATE) @MsError::MissingPermission,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for closing a proposed upgrade of a managed program
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. managed program account
+/// 3. program upgrade account
+/// 4. rent collector account
+#[derive(Accounts)]
+pub struct CloseProgramUpgrade<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            &program_upgrade.upgrade_index.to_le_bytes(),
+            b"programupgrade"
+        ], bump = program_upgrade.bump,
+        close = rent_collector
+    )]
+    pub program_upgrade: Account<'info, ProgramUpgrade>,
+
+    /// CHECK: only receives the rent of the program upgrade
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+/// The account context for setting the rollback threshold of a managed program
+/// This is an internal instruction, the multisig must be the signer.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. managed program account
+#[derive(Accounts)]
+pub struct SetRollbackThreshold<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+}
+
+/// The account context for proposing the rollback of the last upgrade of a managed program
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. managed program account
+/// 3. program rollback account
+/// 4. member account [signer]
+/// 5. system program
+#[derive(Accounts)]
+pub struct ProposeRollback<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+        constraint = managed_program.rollback_threshold > 0 @MsError::RollbackNotApproved,
+        constraint = managed_program.rollback_buffer.is_some() @MsError::InvalidRollbackBuffer,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        init,
+        payer = member,
+        space = ProgramRollback::size(multisig.keys.len()),
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            b"programrollback"
+        ], bump
+    )]
+    pub program_rollback: Account<'info, ProgramRollback>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for approving the rollback of a managed program
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. managed program account
+/// 3. program rollback account
+/// 4. member account [signer]
+#[derive(Accounts)]
+pub struct ApproveRollback<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            b"programrollback"
+        ], bump = program_rollback.bump,
+    )]
+    pub program_rollback: Account<'info, ProgramRollback>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), Permissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for executing an approved rollback of a managed program
+/// Anyone can execute it, the vault signs the upgrade from the rollback buffer unless it's frozen or paused.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. managed program account
+/// 3. program rollback account
+/// 4. proposer account
+/// 5. authority (vault) account
+/// 6. program account
+/// 7. program data account
+/// 8. rollback buffer account
+/// 9. spill account
+/// 10. rent sysvar
+/// 11. clock sysvar
+/// 12. bpf upgradeable loader program
+#[derive(Accounts)]
+pub struct ExecuteRollback<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            managed_program.program_address.as_ref(),
+            b"managedprogram"
+        ], bump = managed_program.bump,
+        constraint = !multisig.is_frozen_for(managed_program.authority_index) @MsError::MultisigFrozen,
+        constraint = !multisig.is_paused_for(managed_program.authority_index) @MsError::MultisigPaused,
+    )]
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            b"programrollback"
+        ], bump = program_rollback.bump,
+        has_one = proposer @MsError::InvalidInstructionAccount,
+        close = proposer
+    )]
+    pub program_rollback: Account<'info, ProgramRollback>,
+
+    /// CHECK: only receives the rent of the program rollback
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// CHECK: the vault that is the upgrade authority, signed for with its seeds
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &managed_program.authority_index.to_le_bytes(),
+            b"authority"
+        ], bump
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// CHECK: validated by the upgradeable loader
+    #[account(
+        mut,
+        address = managed_program.program_address @MsError::InvalidManagedProgram,
+    )]
+    pub program: AccountInfo<'info>,
+
+    /// CHECK: validated by the upgradeable loader
+    #[account(mut)]
+    pub program_data: AccountInfo<'info>,
+
+    /// CHECK: the rollback buffer, validated by the upgradeable loader
+    #[account(
+        mut,
+        address = program_rollback.buffer @MsError::InvalidRollbackBuffer,
+    )]
+    pub buffer: AccountInfo<'info>,
+
+    /// CHECK: only receives the lamports of the buffer
+    #[account(mut)]
+    pub spill: AccountInfo<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: the address is checked to be the upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: AccountInfo<'info>,
+}
+
+/// The account context for the proposer to cancel the rollback of a managed program
+/// 
+/// Expects the following accounts:
+/// 1. managed program account
+/// 2. program rollback account
+/// 3. proposer account [signer]
+#[derive(Accounts)]
+pub struct CancelRollback<'info> {
+    pub managed_program: Account<'info, ManagedProgram>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            managed_program.key().as_ref(),
+            b"programrollback"
+        ], bump = program_rollback.bump,
+        has_one = proposer @MsError::InvalidInstructionAccount,
+        close = proposer
+    )]
+    pub program_rollback: Account<'info, ProgramRollback>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+/// The account context for pruning a member whose membership has expired.
+/// Anyone can prune an expired member.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+#[derive(Accounts)]
+pub struct PruneExpiredMember<'info> {
     #[account(
         mut,
         seeds = [
@@ -396,10 +4512,902 @@ pub struct MsAuthRealloc<'info> {
             multisig.create_key.as_ref(),
             b"multisig"
         ], bump = multisig.bump,
-        signer
     )]
     pub multisig: Box<Account<'info, Ms>>,
+}
 
-    pub rent: Sysvar<'info, Rent>,
+/// The account context for finalizing a pending member removal.
+/// Anyone can finalize the removal once the removal delay has passed.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+#[derive(Accounts)]
+pub struct FinalizeMemberRemoval<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+}
+
+/// The account context for aborting a pending member removal
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member account [signer]
+#[derive(Accounts)]
+pub struct AbortMemberRemoval<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        constraint = multisig.has_permission(member.key(), Permissions::VETO) @MsError::MissingPermission,
+        constraint = !matches!(multisig.pending_removal, Some(p) if p.member == member.key()) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for the guardian to freeze the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. guardian account [signer]
+#[derive(Accounts)]
+pub struct Freeze<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        constraint = multisig.is_guardian(guardian.key()) @MsError::NotGuardian,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for the admin to apply config changes on its own
+/// The multisig is reallocated if the changes need more space, paid for by the admin.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. admin account [signer]
+/// 3. system program
+/// 
+/// Expects the following arguments:
+/// 1. actions: Vec<MsConfigAction>
+#[derive(Accounts)]
+#[instruction(actions: Vec<MsConfigAction>)]
+pub struct AdminConfig<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        realloc = multisig.to_account_info().data_len().max(multisig.size_with_additional(actions.iter().map(|action| action.additional_len()).sum())?),
+        realloc::payer = admin,
+        realloc::zero = false,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_admin(admin.key()) @MsError::NotAdmin,
+    )]
+    pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
+
+/// The account context for the admin to renounce its powers
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. admin account [signer]
+#[derive(Accounts)]
+pub struct RenounceAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        constraint = multisig.is_admin(admin.key()) @MsError::NotAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// The account context for closing a dissolved multisig, returning its rent to the destination
+/// The transaction must be an approved internal transaction whose only instruction is this
+/// close_multisig (with the same destination), and the executor must be able to execute.
+/// The rent of the transaction and its instruction is returned to the transaction creator.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account
+/// 4. destination account
+/// 5. creator account
+/// 6. member account [signer]
+/// 
+/// Expects the following remaining accounts:
+/// 1. the vaults of the multisig, from authority 1 to the authority index
+/// 2. the transactions after the change index (other than this one), which must be closed or done.
+///    Where there is no transaction at an index, it is followed by the config transaction at that index.
+#[derive(Accounts)]
+#[instruction(destination: Pubkey)]
+pub struct CloseMultisig<'info> {
+    #[account(
+        mut,
+        close = destination_account,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.executing_transaction == 0 @MsError::ExecutionInProgress,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.authority_index == 0 @MsError::InvalidAuthorityIndex,
+        constraint = transaction.instruction_index == 1 @MsError::InvalidTransactionState,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &1u8.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        constraint = instruction.program_id == crate::ID @MsError::InvalidInstructionAccount,
+        constraint = instruction.data == anchor_lang::InstructionData::data(&crate::instruction::CloseMultisig { destination }) @MsError::InvalidInstructionAccount,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    /// CHECK: only receives the rent of the multisig, must be the approved destination
+    #[account(
+        mut,
+        address = destination @MsError::InvalidInstructionAccount,
+    )]
+    pub destination_account: AccountInfo<'info>,
+
+    /// CHECK: only receives the rent of the transaction and instruction accounts, must be the transaction creator
+    #[account(
+        mut,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        constraint = multisig.is_executor(member.key()) || multisig.has_permission(member.key(), Permissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+impl<'info> CloseMultisig<'info> {
+    /// Checks that every vault of the multisig is empty (holds no lamports, so doesn't exist),
+    /// and that the transactions after the change index are closed or done, so no approved work
+    /// is left behind. Transactions up to the change index were deprecated by a config change.
+    pub fn check_dissolved(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let ms_key = self.multisig.key();
+        let accounts = &mut remaining_accounts.iter();
+        for authority_index in 1..=self.multisig.authority_index {
+            let vault = next_account_info(accounts)?;
+            let (vault_pda, _) = crate::pda::find_authority_address(&ms_key, authority_index);
+            if vault.key != &vault_pda {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            if vault.lamports() > 0 {
+                return err!(MsError::MultisigNotEmpty);
+            }
+        }
+        let closing_index = self.transaction.transaction_index;
+        for transaction_index in (self.multisig.ms_change_index + 1)..=self.multisig.transaction_index {
+            if transaction_index == closing_index {
+                continue;
+            }
+            let transaction = next_account_info(accounts)?;
+            let (transaction_pda, _) = crate::pda::find_tx_address(&ms_key, transaction_index);
+            if transaction.key != &transaction_pda {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            // closed transactions are no longer owned by the program, and the index may be
+            // taken by a config transaction instead
+            if transaction.owner != &crate::ID {
+                let config_transaction = next_account_info(accounts)?;
+                let (config_transaction_pda, _) = crate::pda::find_config_tx_address(&ms_key, transaction_index);
+                if config_transaction.key != &config_transaction_pda {
+                    return err!(MsError::InvalidInstructionAccount);
+                }
+                if config_transaction.owner != &crate::ID {
+                    continue;
+                }
+                let config_transaction = MsConfigTransaction::try_deserialize(&mut &config_transaction.try_borrow_data()?[..])?;
+                if !config_transaction.status.is_terminal() {
+                    return err!(MsError::MultisigNotEmpty);
+                }
+                continue;
+            }
+            let transaction = MsTransaction::try_deserialize(&mut &transaction.try_borrow_data()?[..])?;
+            if !transaction.status.is_terminal() {
+                return err!(MsError::MultisigNotEmpty);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The account context for the read-only views of the multisig (ie. is_member)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+#[derive(Accounts)]
+pub struct ViewMultisig<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+}
+
+/// The account context for the read-only views of a transaction (ie. get_transaction_status)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+#[derive(Accounts)]
+pub struct ViewTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        constraint = transaction.version >= MsTransaction::VERSION @MsError::NotMigrated,
+    )]
+    pub transaction: Box<Account<'info, MsTransaction>>,
+}
+
+impl<'info> MsAuthRealloc<'info> {
+    /// Makes sure the multisig account has room for `additional_len` more bytes of state,
+    /// and reallocates space (at least 10 more members worth) if it doesn't.
+    /// If the multisig needs more lamports to stay rent exempt, they are topped up from
+    /// a signer passed in the remaining accounts, or else from the default vault if it
+    /// is passed in the remaining accounts. Otherwise the multisig must be prefunded with
+    /// enough lamports to cover the new size.
+    pub fn ensure_multisig_space(&self, additional_len: usize, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let multisig_account_info = self.multisig.to_account_info();
+        if *multisig_account_info.owner != crate::ID {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let curr_data_size = multisig_account_info.data.borrow().len();
+        let needed_len = self.multisig.size_with_additional(additional_len)?;
+        if needed_len <= curr_data_size {
+            return Ok(());
+        }
+
+        // add space for at least 10 more keys
+        let new_len = curr_data_size + (needed_len - curr_data_size).max(10 * 32);
+        // reallocate more space
+        AccountInfo::realloc(&multisig_account_info, new_len, false)?;
+        // if more lamports are needed, they must be topped up or have been transferred to the account
+        let rent_exempt_lamports = self.rent.minimum_balance(new_len).max(1);
+        let top_up_lamports = rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+        if top_up_lamports > 0 {
+            return self.top_up_multisig(top_up_lamports, remaining_accounts);
+        }
+        Ok(())
+    }
+
+    /// Transfers lamports to the multisig from a signing payer or the default vault
+    fn top_up_multisig(&self, lamports: u64, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let ms_key = self.multisig.key();
+        let vault_index = self.multisig.default_vault_index;
+        let (vault_pda, vault_bump) = Pubkey::find_program_address(
+            &[
+                b"squad",
+                ms_key.as_ref(),
+                &vault_index.to_le_bytes(),
+                b"authority",
+            ],
+            &crate::ID,
+        );
+        let payer = remaining_accounts
+            .iter()
+            .find(|a| a.is_signer && a.is_writable && a.key != &ms_key);
+        let vault = remaining_accounts.iter().find(|a| a.key == &vault_pda);
+        let vault_seeds: &[&[u8]] = &[
+            b"squad",
+            ms_key.as_ref(),
+            &vault_index.to_le_bytes(),
+            b"authority",
+            &[vault_bump],
+        ];
+        let vault_signer_seeds = [vault_seeds];
+        let (from, signer_seeds) = match (payer, vault) {
+            (Some(payer), _) => (payer, &[][..]),
+            (None, Some(vault)) => (vault, &vault_signer_seeds[..]),
+            (None, None) => return err!(MsError::NotEnoughLamports),
+        };
+        let ix = anchor_lang::solana_program::system_instruction::transfer(from.key, &ms_key, lamports);
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[
+                from.clone(),
+                self.multisig.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+impl<'info> CreateVaultAta<'info> {
+    pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+    /// Creates the associated token account of the vault for the mint, succeeding
+    /// without changes if it already exists (CreateIdempotent).
+    pub fn create_vault_ata(&self) -> Result<()> {
+        let ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: Self::ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new(*self.vault_token_account.key, false),
+                AccountMeta::new_readonly(*self.vault.key, false),
+                AccountMeta::new_readonly(*self.mint.key, false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(*self.token_program.key, false),
+            ],
+            data: vec![1],
+        };
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                self.payer.to_account_info(),
+                self.vault_token_account.clone(),
+                self.vault.clone(),
+                self.mint.clone(),
+                self.system_program.to_account_info(),
+                self.token_program.clone(),
+                self.associated_token_program.clone(),
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+impl<'info> RetireAuthority<'info> {
+    /// Transfers all lamports of the vault to the destination. The multisig is first
+    /// reallocated for its updated state if needed, with the rent topped up from the vault.
+    pub fn sweep_vault(&self, vault_seeds: &[&[u8]]) -> Result<()> {
+        let multisig_account_info = self.multisig.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        let curr_data_size = multisig_account_info.data.borrow().len();
+        let needed_len = self.multisig.size_with_additional(0)?;
+        if needed_len > curr_data_size {
+            AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
+            let rent_exempt_lamports = self.rent.minimum_balance(needed_len).max(1);
+            let top_up_lamports = rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+            if top_up_lamports > self.vault.lamports() {
+                return err!(MsError::NotEnoughLamports);
+            }
+            if top_up_lamports > 0 {
+                transfer_from_vault(
+                    &self.vault,
+                    &multisig_account_info,
+                    &system_program,
+                    &[],
+                    Pubkey::default(),
+                    top_up_lamports,
+                    vault_seeds,
+                )?;
+            }
+        }
+        let lamports = self.vault.lamports();
+        if lamports == 0 {
+            return Ok(());
+        }
+        transfer_from_vault(
+            &self.vault,
+            &self.destination,
+            &system_program,
+            &[],
+            Pubkey::default(),
+            lamports,
+            vault_seeds,
+        )
+    }
+}
+
+/// The stage of the execution an execution hook is invoked for
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionHookStage {
+    PreExecute,
+    PostExecute,
+}
+
+impl ExecutionHookStage {
+    /// the Anchor style discriminator of the hook instruction, so hooks can be written
+    /// as `pre_execute(transaction: Pubkey)` and `post_execute(transaction: Pubkey)` instructions
+    fn discriminator(self) -> [u8; 8] {
+        let preimage: &[u8] = match self {
+            ExecutionHookStage::PreExecute => b"global:pre_execute",
+            ExecutionHookStage::PostExecute => b"global:post_execute",
+        };
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&anchor_lang::solana_program::hash::hash(preimage).to_bytes()[..8]);
+        discriminator
+    }
+}
+
+/// Invokes the execution hook of the multisig (if any) for a vault transaction, passing the
+/// multisig and transaction accounts (read only) and the transaction key. The hook program
+/// must be passed in the remaining accounts. Internal transactions skip the hook, so that
+/// the multisig can always change (or remove) it.
+pub fn invoke_execution_hook<'info>(
+    multisig: &Account<'info, Ms>,
+    transaction: &AccountInfo<'info>,
+    authority_index: u32,
+    remaining_accounts: &[AccountInfo<'info>],
+    stage: ExecutionHookStage,
+) -> Result<()> {
+    let execution_hook = match multisig.execution_hook {
+        Some(execution_hook) if authority_index != 0 => execution_hook,
+        _ => return Ok(()),
+    };
+    let hook_program = remaining_accounts
+        .iter()
+        .find(|a| a.key == &execution_hook && a.executable)
+        .ok_or(MsError::MissingExecutionHook)?;
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: execution_hook,
+        accounts: vec![
+            AccountMeta::new_readonly(multisig.key(), false),
+            AccountMeta::new_readonly(*transaction.key, false),
+        ],
+        data: [&stage.discriminator()[..], transaction.key.as_ref()].concat(),
+    };
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[multisig.to_account_info(), transaction.clone(), hook_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Consults the policy program of the multisig (if any) for an action on a vault transaction,
+/// invoking its `check_policy(stage, actor)` instruction with the policy config, multisig and
+/// transaction accounts (read only). The policy config and program must be passed in the
+/// remaining accounts. Internal transactions aren't checked, so the policy can always be removed.
+pub fn consult_policy<'info>(
+    multisig: &Account<'info, Ms>,
+    transaction: &AccountInfo<'info>,
+    authority_index: u32,
+    actor: Pubkey,
+    stage: PolicyStage,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if !multisig.policy_enabled || authority_index == 0 {
+        return Ok(());
+    }
+    let (policy_config_pda, _) = crate::pda::find_policy_config_address(&multisig.key());
+    let policy_config_info = remaining_accounts
+        .iter()
+        .find(|a| a.key == &policy_config_pda)
+        .ok_or(MsError::MissingPolicy)?;
+    let policy_config: Account<PolicyConfig> = Account::try_from(policy_config_info)?;
+    if !policy_config.checks(stage) {
+        return Ok(());
+    }
+    let policy_program = remaining_accounts
+        .iter()
+        .find(|a| a.key == &policy_config.policy_program && a.executable)
+        .ok_or(MsError::MissingPolicy)?;
+
+    let mut data = anchor_lang::solana_program::hash::hash(b"global:check_policy").to_bytes()[..8].to_vec();
+    stage.serialize(&mut data)?;
+    actor.serialize(&mut data)?;
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: policy_config.policy_program,
+        accounts: vec![
+            AccountMeta::new_readonly(policy_config_pda, false),
+            AccountMeta::new_readonly(multisig.key(), false),
+            AccountMeta::new_readonly(*transaction.key, false),
+        ],
+        data,
+    };
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[
+            policy_config_info.clone(),
+            multisig.to_account_info(),
+            transaction.clone(),
+            policy_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Casts the approval or rejection of a member relayed by another key (a signed vote, a delegate
+/// or a session key) on a transaction, as approve_transaction and reject_transaction would cast it.
+/// Approvals are checked against the expiry and the policy. Returns true once the vote decides
+/// the transaction, whose open slot is then released.
+pub fn cast_relayed_vote<'info>(
+    multisig: &mut Account<'info, Ms>,
+    transaction: &mut Account<'info, MsTransaction>,
+    member: Pubkey,
+    approve: bool,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<bool> {
+    let (vote, status_changed) = if approve {
+        transaction.check_not_expired()?;
+        consult_policy(
+            multisig,
+            &transaction.to_account_info(),
+            transaction.authority_index,
+            member,
+            PolicyStage::Approve,
+            remaining_accounts,
+        )?;
+        (Vote::Approve, transaction.cast_approval(multisig, member)?)
+    } else {
+        (Vote::Reject, transaction.cast_rejection(multisig, member)?)
+    };
+    emit!(VoteCast {
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        member,
+        vote,
+    });
+    if status_changed {
+        emit!(TransactionStatusChanged {
+            multisig: multisig.key(),
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
+        multisig.release_transaction(transaction.creator, transaction.transaction_index, &transaction.status)?;
+    }
+    record_activity(
+        multisig,
+        member,
+        if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+        transaction.transaction_index,
+        remaining_accounts,
+    )?;
+    Ok(status_changed)
+}
+
+/// Advances the sequence number of the multisig and emits it
+pub fn advance_seq(multisig: &mut Account<Ms>) {
+    let seq = multisig.advance_seq();
+    emit!(SeqAdvanced {
+        multisig: multisig.key(),
+        seq,
+    });
+}
+
+/// Gets the vote weight of a member on a transaction. For vault transactions with a stake weight source,
+/// it's the weight snapshotted at activation, and the stake snapshot must be passed in the remaining accounts.
+pub fn member_vote_weight(
+    multisig: &Account<Ms>,
+    transaction: &Account<MsTransaction>,
+    member: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<u16> {
+    if multisig.stake_weight_source.is_none() || transaction.authority_index == 0 {
+        return Ok(multisig.get_member_weight(member));
+    }
+    let (stake_snapshot_pda, _) = crate::pda::find_stake_snapshot_address(&transaction.key());
+    let stake_snapshot_info = remaining_accounts
+        .iter()
+        .find(|a| a.key == &stake_snapshot_pda)
+        .ok_or(MsError::StakeSnapshotRequired)?;
+    let stake_snapshot: Account<StakeSnapshot> = Account::try_from(stake_snapshot_info)?;
+    Ok(stake_snapshot.get_weight(member))
+}
+
+/// Sets the threshold of a vault transaction from the value tiers of the multisig (if any) when it is
+/// activated, from the transfers of its instructions, which must then be passed in the remaining accounts.
+pub fn apply_value_tiers(
+    multisig: &Account<Ms>,
+    transaction: &mut Account<MsTransaction>,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if multisig.value_tiers.is_empty() || transaction.authority_index == 0 {
+        return Ok(());
+    }
+    let mut transfers: Vec<(Pubkey, u64)> = Vec::new();
+    let mut transfers_only = true;
+    for instruction_index in 1..=transaction.instruction_index {
+        let (instruction_pda, _) = crate::pda::find_ix_address(&transaction.key(), instruction_index);
+        let instruction_info = remaining_accounts
+            .iter()
+            .find(|a| a.key == &instruction_pda)
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        let instruction: Account<MsInstruction> = Account::try_from(instruction_info)?;
+        match instruction.transfer(remaining_accounts) {
+            Some((mint, amount)) => match transfers.iter_mut().find(|(m, _)| *m == mint) {
+                Some((_, total)) => *total = total.saturating_add(amount),
+                None => transfers.push((mint, amount)),
+            },
+            None => transfers_only = false,
+        }
+    }
+    transaction.threshold = multisig.tier_threshold(transaction.authority_index, &transfers, transfers_only);
+    Ok(())
+}
+
+/// Records a transaction lifecycle action in the activity log of the multisig (if any),
+/// which must then be passed (writable) in the remaining accounts.
+pub fn record_activity<'info>(
+    multisig: &Account<'info, Ms>,
+    actor: Pubkey,
+    action: ActivityAction,
+    transaction_index: u32,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if !multisig.activity_log_enabled {
+        return Ok(());
+    }
+    let (activity_log_pda, _) = crate::pda::find_activity_log_address(&multisig.key());
+    let activity_log_info = remaining_accounts
+        .iter()
+        .find(|a| a.key == &activity_log_pda && a.is_writable)
+        .ok_or(MsError::MissingActivityLog)?;
+    let mut activity_log: Account<MsActivityLog> = Account::try_from(activity_log_info)?;
+    activity_log.record(ActivityEntry {
+        actor,
+        action,
+        transaction_index,
+        slot: Clock::get()?.slot,
+    });
+    activity_log.exit(&crate::ID)
+}
+
+/// Transfers native SOL (for the default pubkey as the mint) or SPL tokens out of a vault,
+/// signing with the vault seeds. For SPL tokens, the remaining accounts are expected to be:
+/// 1. the vault token account
+/// 2. the mint
+/// 3. the token program (SPL token or Token-2022)
+/// 4. any extra accounts required by a Token-2022 mint, ie. for its transfer hook
+pub fn transfer_from_vault<'info>(
+    vault: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    mint: Pubkey,
+    amount: u64,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    if mint == Pubkey::default() {
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            vault.key,
+            destination.key,
+            amount,
+        );
+        invoke_signed(
+            &ix,
+            &[
+                vault.clone(),
+                destination.clone(),
+                system_program.clone(),
+            ],
+            &[vault_seeds],
+        )?;
+        return Ok(());
+    }
+
+    let remaining_accounts_iter = &mut remaining_accounts.iter();
+    let source_info = next_account_info(remaining_accounts_iter)?;
+    let mint_info = next_account_info(remaining_accounts_iter)?;
+    let token_program_info = next_account_info(remaining_accounts_iter)?;
+    if mint_info.key != &mint
+        || !SpendingLimit::is_token_program(token_program_info.key)
+        || mint_info.owner != token_program_info.key {
+        return err!(MsError::InvalidInstructionAccount);
+    }
+    // Token-2022 mints keep the base mint layout, followed by their extensions
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_info.try_borrow_data()?)?
+        .base
+        .decimals;
+    // transfer checked has the same layout for both token programs, and any
+    // transfer fee of the mint is withheld from the amount received
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program_info.key,
+        source_info.key,
+        mint_info.key,
+        destination.key,
+        vault.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    let mut ix_account_infos = vec![
+        source_info.clone(),
+        mint_info.clone(),
+        destination.clone(),
+        vault.clone(),
+        token_program_info.clone(),
+    ];
+    for extra_account_info in remaining_accounts_iter {
+        ix.accounts.push(if extra_account_info.is_writable {
+            AccountMeta::new(*extra_account_info.key, false)
+        } else {
+            AccountMeta::new_readonly(*extra_account_info.key, false)
+        });
+        ix_account_infos.push(extra_account_info.clone());
+    }
+    invoke_signed(&ix, &ix_account_infos, &[vault_seeds])?;
+    Ok(())
+}
+
+/// Creates and initializes the instruction account at the given index of the transaction,
+/// the same as the init constraint of AddInstruction, with the rent paid by the payer.
+pub fn create_instruction_account<'info>(
+    system_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    instruction_info: &AccountInfo<'info>,
+    transaction: Pubkey,
+    instruction_index: u8,
+    incoming_instruction: IncomingInstruction,
+) -> Result<()> {
+    let (instruction_pda, bump) = crate::pda::find_ix_address(&transaction, instruction_index);
+    if instruction_info.key != &instruction_pda {
+        return err!(MsError::InvalidInstructionAccount);
+    }
+
+    let space = 8 + incoming_instruction.get_max_size();
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.clone(),
+                to: instruction_info.clone(),
+            },
+            &[&[
+                b"squad",
+                transaction.as_ref(),
+                &instruction_index.to_le_bytes(),
+                b"instruction",
+                &[bump],
+            ]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let mut instruction = MsInstruction {
+        program_id: Pubkey::default(),
+        keys: Vec::new(),
+        data: Vec::new(),
+        instruction_index: 0,
+        bump: 0,
+        executed: false,
+    };
+    instruction.init(instruction_index, incoming_instruction, bump)?;
+    instruction.try_serialize(&mut &mut instruction_info.try_borrow_mut_data()?[..])
+}
+
+/// Closes an instruction account of the transaction, returning its rent to the creator
+pub fn close_instruction_account<'info>(
+    creator: &AccountInfo<'info>,
+    instruction_info: &AccountInfo<'info>,
+    transaction: Pubkey,
+) -> Result<()> {
+    if instruction_info.owner != &crate::ID {
+        return err!(MsError::InvalidInstructionAccount);
+    }
+    let instruction = MsInstruction::try_deserialize(&mut &instruction_info.try_borrow_data()?[..])?;
+    // the instruction account must belong to the transaction
+    let instruction_pda = Pubkey::create_program_address(
+        &[
+            b"squad",
+            transaction.as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction",
+            &[instruction.bump],
+        ],
+        &crate::ID,
+    ).map_err(|_| MsError::InvalidInstructionAccount)?;
+    if instruction_info.key != &instruction_pda {
+        return err!(MsError::InvalidInstructionAccount);
+    }
+
+    // close the account, returning the rent to the creator
+    **creator.try_borrow_mut_lamports()? = creator
+        .lamports()
+        .checked_add(instruction_info.lamports())
+        .unwrap();
+    **instruction_info.try_borrow_mut_lamports()? = 0;
+    instruction_info.assign(&anchor_lang::system_program::ID);
+    instruction_info.realloc(0, false)?;
+    Ok(())
+}
+
+/// Builds the account list of an execution left empty by the client. The instruction accounts
+/// come first in the remaining accounts (in order), followed by every other account needed by
+/// the instructions, each passed only once however many instructions reference it. Keys are
+/// resolved against the unique accounts sorted once, and are then checked by the execution
+/// like any other account list.
+pub fn resolve_account_list(remaining_accounts: &[AccountInfo], instruction_count: u8) -> Result<Vec<u8>> {
+    let instruction_count = usize::from(instruction_count);
+    if remaining_accounts.len() < instruction_count || remaining_accounts.len() > usize::from(u8::MAX) + 1 {
+        return err!(MsError::InvalidInstructionAccount);
+    }
+    let mut unique_accounts: Vec<usize> = (instruction_count..remaining_accounts.len()).collect();
+    unique_accounts.sort_unstable_by_key(|&i| remaining_accounts[i].key);
+    let lookup = |key: &Pubkey| -> Result<u8> {
+        match unique_accounts.binary_search_by(|&i| remaining_accounts[i].key.cmp(key)) {
+            Ok(position) => Ok(unique_accounts[position] as u8),
+            Err(_) => err!(MsError::InvalidInstructionAccount),
+        }
+    };
+
+    let mut account_list = Vec::new();
+    for (index, ms_ix_account) in remaining_accounts[..instruction_count].iter().enumerate() {
+        if ms_ix_account.owner != &crate::ID {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let ix_account_data = ms_ix_account.try_borrow_data()?;
+        let ms_ix = MsInstructionView::load(&ix_account_data)?;
+        account_list.reserve(ms_ix.keys_len() + 2);
+        account_list.push(index as u8);
+        account_list.push(lookup(&ms_ix.program_id)?);
+        for meta in ms_ix.account_metas() {
+            account_list.push(lookup(&meta.pubkey)?);
+        }
+    }
+    Ok(account_list)
+}
@@ -21,4 +21,116 @@ pub enum MsError {
     EmptyMembers,
     PartialExecution,
     NotEnoughLamports,
-}
\ No newline at end of file
+    VoteCapacityExceeded,
+    InvalidApprovalRequest,
+    InvalidMemberWeight,
+    MissingPermission,
+    InvalidPermissions,
+    TimeLockNotElapsed,
+    TransactionExpired,
+    InvalidExpiration,
+    InvalidSpendingLimit,
+    SpendingLimitExceeded,
+    InvalidLookupTable,
+    VetoPeriodElapsed,
+    InvalidMemberProgram,
+    MemberExpired,
+    MemberNotExpired,
+    MultisigFrozen,
+    NotGuardian,
+    MultisigPaused,
+    InvalidMetadata,
+    InvalidTransactionDescription,
+    InvalidSignedVote,
+    InvalidDelegate,
+    InvalidRecurringTransaction,
+    RecurringTransactionNotDue,
+    InvalidBuffer,
+    BufferHashMismatch,
+    AllowanceExceeded,
+    AllowanceExpired,
+    VoteRecordsEnabled,
+    VoteRecordsDisabled,
+    AlreadyMigrated,
+    ExecutionInProgress,
+    ExecutionIncomplete,
+    InstructionDataHashMismatch,
+    ProgramNotAllowed,
+    InvalidProgramAllowlist,
+    InstructionDenied,
+    InvalidDeniedInstruction,
+    RemovalPending,
+    NoPendingRemoval,
+    RemovalDelayNotElapsed,
+    RemovalDelayed,
+    InvalidRecovery,
+    RecoveryNotDue,
+    DuplicateMember,
+    InvalidRecoverySet,
+    NotRecoveryKey,
+    RecoveryAlreadyApproved,
+    RecoveryNotApproved,
+    RecoveryDelayNotElapsed,
+    InvalidConfigActions,
+    AuthorityRetired,
+    InvalidAuthorityRecord,
+    InvalidVoteAction,
+    VoteWithdrawTooLarge,
+    InvalidExecutors,
+    InvalidExecutionHook,
+    MissingExecutionHook,
+    InvalidPolicy,
+    MissingPolicy,
+    MissingActivityLog,
+    InvalidVoteMemo,
+    VoteNotCast,
+    InvalidQuorum,
+    MaxOpenTransactionsReached,
+    InvalidCoCreators,
+    InvalidGovernanceAction,
+    InvalidMembershipToken,
+    InvalidStakeLocker,
+    StakeSnapshotRequired,
+    InvalidSessionKey,
+    SessionKeyExpired,
+    InvalidValueTier,
+    InvalidPriceAccount,
+    ExecutionConditionNotMet,
+    InvalidExecutionWindow,
+    OutsideExecutionWindow,
+    InvalidAdmin,
+    NotAdmin,
+    AdminExpired,
+    InvalidManagedProgram,
+    InvalidRollbackBuffer,
+    RollbackNotApproved,
+    RollbackAlreadyApproved,
+    InvalidIdlAction,
+    AuthorityIndexOverflow,
+    MultisigNotEmpty,
+    InvalidStatusTransition,
+    ThresholdUnderflow,
+    SizeOverflow,
+    ThresholdTooLow,
+    ThresholdTooHigh,
+    RemovalBelowThreshold,
+    InvalidMaxMembers,
+    UnsortedMembers,
+    VoteRecordsRequired,
+    MemberPageFull,
+    InvalidMemberRoot,
+    InvalidMemberProof,
+    RatificationRequired,
+    InvalidRatifyingProposal,
+    NotRatified,
+    InvalidVetoCouncil,
+    NotVetoCouncilMember,
+    AlreadyVetoed,
+    InvalidMembershipGate,
+    TransactionIndexOverflow,
+    TallyOverflow,
+    TimestampOverflow,
+    BountyOverflow,
+    InstructionIndexOverflow,
+    NotMigrated,
+}
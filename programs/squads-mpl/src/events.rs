@@ -0,0 +1,226 @@
+/*
+    Squads Multisig Program - Events
+    https://github.com/squads-protocol/squads-mpl
+*/
+
+use anchor_lang::prelude::*;
+use crate::state::{MembershipGate, MsConfigAction, MsTransactionStatus, StakeWeightSource, ValueTier, ExecutionWindow, BlackoutPeriod, Ratification};
+
+/// Emitted when a new multisig is created
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub create_key: Pubkey,
+    pub threshold: u16,
+    pub members: Vec<Pubkey>,
+}
+
+/// Emitted when an internal (authority 0) instruction changes the multisig config
+#[event]
+pub struct ConfigChanged {
+    pub multisig: Pubkey,
+    pub action: ConfigAction,
+}
+
+/// The config change that was applied to the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ConfigAction {
+    AddMember { member: Pubkey },
+    RemoveMember { member: Pubkey },
+    ChangeThreshold { threshold: u16 },
+    SetMemberWeight { member: Pubkey, weight: u16 },
+    SetMemberPermissions { member: Pubkey, permissions: u8 },
+    AddAuthority { authority_index: u32 },
+    ChangeDefaultVault { vault_index: u32 },
+    ChangeTimeLock { time_lock: u32 },
+    AddSpendingLimit { spending_limit: Pubkey },
+    RemoveSpendingLimit { spending_limit: Pubkey },
+    ChangeConfigThreshold { config_threshold: u16 },
+    ChangeVetoPeriod { veto_period: u32 },
+    SetMemberProgram { member_program: Pubkey, allowed: bool },
+    SetMemberExpiration { member: Pubkey, expires_at: Option<i64> },
+    PruneExpiredMember { member: Pubkey },
+    SetGuardian { guardian: Option<Pubkey> },
+    Unfreeze,
+    Pause,
+    Unpause,
+    SetMetadata { metadata: Pubkey },
+    AddRecurringTransaction { recurring_transaction: Pubkey },
+    RemoveRecurringTransaction { recurring_transaction: Pubkey },
+    AddAllowance { allowance: Pubkey },
+    RemoveAllowance { allowance: Pubkey },
+    SetVoteRecords { enabled: bool },
+    Migrate { version: u8 },
+    SetProgramAllowlist { authority_index: u32, programs: Vec<Pubkey> },
+    SetDeniedInstruction { program_id: Pubkey, prefix: Vec<u8>, denied: bool },
+    ChangeRemovalDelay { removal_delay: u32 },
+    ScheduleMemberRemoval { member: Pubkey, remove_at: i64 },
+    AbortMemberRemoval { member: Pubkey },
+    AddRecovery { recovery: Pubkey },
+    RemoveRecovery { recovery: Pubkey },
+    SetRecoverySet { recovery_keys: Vec<Pubkey>, recovery_threshold: u16, recovery_delay: u32 },
+    ReplaceMember { old_member: Pubkey, new_member: Pubkey },
+    RetireAuthority { authority_index: u32, destination: Pubkey, lamports: u64 },
+    SetAuthorityRecord { authority_record: Pubkey },
+    SetExecutors { any_executor: bool, executors: Vec<Pubkey> },
+    SetExecutionHook { execution_hook: Option<Pubkey> },
+    AddPolicy { policy_config: Pubkey, policy_program: Pubkey, stages: u8 },
+    RemovePolicy { policy_config: Pubkey },
+    CreateActivityLog { activity_log: Pubkey },
+    CloseActivityLog { activity_log: Pubkey },
+    ChangeMinQuorum { min_quorum: u16 },
+    ChangeThresholdPercent { threshold_percent: u8, threshold: u16 },
+    ChangeMaxOpenTransactions { max_open_transactions: u16 },
+    ChangeMaxMembers { max_members: u16 },
+    CreateMemberPage { members_page: Pubkey, page_index: u8 },
+    AddPagedMember { member: Pubkey, page_index: u8 },
+    RemovePagedMember { member: Pubkey, page_index: u8 },
+    SetMemberRoot { member_root: Option<[u8; 32]>, member_count: u32 },
+    SetRatification { ratification: Option<Ratification> },
+    SetVetoCouncil { veto_council: Vec<Pubkey>, veto_council_threshold: u16 },
+    InvalidateTransaction { transaction: Pubkey },
+    SetParent { parent: Option<Pubkey> },
+    SetMembershipGate { membership_gate: Option<MembershipGate> },
+    SetStakeWeightSource { stake_weight_source: Option<StakeWeightSource> },
+    SetValueTiers { value_tiers: Vec<ValueTier> },
+    SetExecutionWindow { execution_window: Option<ExecutionWindow> },
+    SetBlackoutPeriods { blackout_periods: Vec<BlackoutPeriod> },
+    ChangeConfigTimeLock { config_time_lock: u32 },
+    SetAdmin { admin: Pubkey, admin_expires_at_slot: u64 },
+    RenounceAdmin { admin: Pubkey },
+    CreateManagedProgram { managed_program: Pubkey, program_address: Pubkey },
+    RemoveManagedProgram { managed_program: Pubkey },
+}
+
+impl From<MsConfigAction> for ConfigAction {
+    fn from(action: MsConfigAction) -> Self {
+        match action {
+            MsConfigAction::AddMember { member } => ConfigAction::AddMember { member },
+            MsConfigAction::RemoveMember { member } => ConfigAction::RemoveMember { member },
+            MsConfigAction::ReplaceMember { old_member, new_member } => ConfigAction::ReplaceMember { old_member, new_member },
+            MsConfigAction::ChangeThreshold { threshold } => ConfigAction::ChangeThreshold { threshold },
+            MsConfigAction::ChangeConfigThreshold { config_threshold } => ConfigAction::ChangeConfigThreshold { config_threshold },
+            MsConfigAction::SetMemberWeight { member, weight } => ConfigAction::SetMemberWeight { member, weight },
+            MsConfigAction::SetMemberPermissions { member, permissions } => ConfigAction::SetMemberPermissions { member, permissions },
+            MsConfigAction::SetMemberExpiration { member, expires_at } => ConfigAction::SetMemberExpiration { member, expires_at },
+            MsConfigAction::ChangeTimeLock { time_lock } => ConfigAction::ChangeTimeLock { time_lock },
+            MsConfigAction::ChangeVetoPeriod { veto_period } => ConfigAction::ChangeVetoPeriod { veto_period },
+            MsConfigAction::ChangeConfigTimeLock { config_time_lock } => ConfigAction::ChangeConfigTimeLock { config_time_lock },
+        }
+    }
+}
+
+/// Emitted on every change of the multisig or its transactions, with the new sequence number
+#[event]
+pub struct SeqAdvanced {
+    pub multisig: Pubkey,
+    pub seq: u64,
+}
+
+/// Emitted when a dissolved multisig is closed, returning its rent to the destination
+#[event]
+pub struct MultisigClosed {
+    pub multisig: Pubkey,
+    pub destination: Pubkey,
+}
+
+/// Emitted when the guardian freezes the multisig
+#[event]
+pub struct MultisigFrozen {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+}
+
+/// Emitted when the recovery key replaces the members of an inactive multisig
+#[event]
+pub struct MultisigRecovered {
+    pub multisig: Pubkey,
+    pub recovery_key: Pubkey,
+    pub threshold: u16,
+    pub members: Vec<Pubkey>,
+}
+
+/// Emitted when a transaction is created
+#[event]
+pub struct TransactionCreated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub creator: Pubkey,
+    pub transaction_index: u32,
+    pub authority_index: u32,
+    pub linked_transaction: Option<Pubkey>,
+}
+
+/// Emitted when an instruction is attached to a transaction
+#[event]
+pub struct InstructionAdded {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub instruction: Pubkey,
+    pub instruction_index: u8,
+}
+
+/// Emitted when a member approves, rejects, cancels, abstains from or vetoes a transaction
+#[event]
+pub struct VoteCast {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub member: Pubkey,
+    pub vote: Vote,
+}
+
+/// The type of vote cast on a transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Vote {
+    Approve,
+    Reject,
+    Cancel,
+    Abstain,
+    Veto,
+}
+
+/// Emitted when a member records the memo (ie. the reason of a rejection) of their vote
+#[event]
+pub struct VoteMemoAdded {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub member: Pubkey,
+    pub vote: Vote,
+    pub memo: Vec<u8>,
+}
+
+/// Emitted when a transaction moves to a new status (other than executed)
+#[event]
+pub struct TransactionStatusChanged {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a transaction is fully executed
+#[event]
+pub struct TransactionExecuted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub executor: Pubkey,
+}
+
+/// Emitted when funds are moved out of a vault under a spending limit
+#[event]
+pub struct SpendingLimitUsed {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub member: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when funds are pulled out of a vault under an allowance
+#[event]
+pub struct AllowanceUsed {
+    pub multisig: Pubkey,
+    pub allowance: Pubkey,
+    pub spender: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
@@ -6,8 +6,10 @@
 use anchor_lang::{
     prelude::*,
     solana_program::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        hash::hash,
         instruction::Instruction,
-        program::invoke_signed
+        program::{invoke, invoke_signed},
     }
 };
 
@@ -16,10 +18,15 @@ use hex::FromHex;
 use state::*;
 use errors::*;
 use account::*;
+use events::*;
+use validation::*;
 
 pub mod state;
 pub mod account;
 pub mod errors;
+pub mod events;
+pub mod pda;
+pub mod validation;
 
 #[cfg(not(feature = "no-entrypoint"))]
 use {default_env::default_env, solana_security_txt::security_txt};
@@ -41,8 +48,6 @@ declare_id!("SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu");
 #[program]
 pub mod squads_mpl {
 
-    use std::convert::TryInto;
-
     use super::*;
 
     /// Creates a new multisig account
@@ -54,109 +59,567 @@ pub mod squads_mpl {
         members: Vec<Pubkey>, // a list of members (Public Keys) to use for the multisig
         _meta: String,        // a string of metadata that can be used to describe the multisig on-chain as a memo ie. '{"name":"My Multisig","description":"This is a my multisig"}'
     ) -> Result<()> {
-        // sort the members and remove duplicates
+        // sort the members, duplicates would count twice so they're rejected by validate_config
         let mut members = members;
         members.sort();
-        members.dedup();
-
-        // check we don't exceed u16
-        let total_members = members.len();
-        if total_members < 1 {
-            return err!(MsError::EmptyMembers);
-        }
 
-        // make sure we don't exceed u16 on first call
-        if total_members > usize::from(u16::MAX) {
+        // make sure the multisig can hold the members
+        if members.len() > Ms::MAX_MEMBERS {
             return err!(MsError::MaxMembersReached);
         }
 
-        // make sure threshold is valid
-        if usize::from(threshold) < 1 || usize::from(threshold) > total_members {
-            return err!(MsError::InvalidThreshold);
-        }
-
         ctx.accounts.multisig.init(
             threshold,
             create_key,
-            members,
+            members.clone(),
             *ctx.bumps.get("multisig").unwrap(),
-        )
+        )?;
+        // there must be at least one member, and the threshold must be reachable
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(MultisigCreated {
+            multisig: ctx.accounts.multisig.key(),
+            create_key,
+            threshold,
+            members,
+        });
+        Ok(())
+    }
+
+    /// Creates a new multisig account with an admin, a bootstrap key that can apply config
+    /// changes on its own until the expiry slot (at most about 30 days away) or until it
+    /// renounces, so new teams can set up the multisig before it is fully trust-minimized.
+    pub fn create_with_admin<'info>(
+        ctx: Context<'_, '_, '_, 'info, Create<'info>>,
+        threshold: u16,
+        create_key: Pubkey,
+        members: Vec<Pubkey>,
+        meta: String,
+        admin: Pubkey,
+        admin_expires_at_slot: u64,
+    ) -> Result<()> {
+        create(Context::new(ctx.program_id, ctx.accounts, ctx.remaining_accounts, ctx.bumps.clone()), threshold, create_key, members, meta)?;
+        ctx.accounts.multisig.set_admin(admin, admin_expires_at_slot)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetAdmin { admin, admin_expires_at_slot },
+        });
+        Ok(())
     }
 
     /// The instruction to add a new member to the multisig.
     /// Adds member/key to the multisig and reallocates space if neccessary
-    /// If the multisig needs to be reallocated, it must be prefunded with
-    /// enough lamports to cover the new size.
-    pub fn add_member(ctx: Context<MsAuthRealloc>, new_member: Pubkey) -> Result<()> {
+    /// If the multisig needs to be reallocated, the rent is topped up by a signing
+    /// payer or the default vault passed as a remaining account, or the multisig
+    /// must be prefunded with enough lamports to cover the new size.
+    pub fn add_member<'info>(ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>, new_member: Pubkey) -> Result<()> {
         // if max is already reached, we can't have more members
-        if ctx.accounts.multisig.keys.len() >= usize::from(u16::MAX) {
+        if ctx.accounts.multisig.keys.len() >= ctx.accounts.multisig.max_member_count() {
             return err!(MsError::MaxMembersReached);
         }
 
-        // check if realloc is needed
-        let multisig_account_info = ctx.accounts.multisig.to_account_info();
-        if *multisig_account_info.owner != squads_mpl::ID {
-            return err!(MsError::InvalidInstructionAccount);
-        }
-        let curr_data_size = multisig_account_info.data.borrow().len();
-        let spots_left =
-            ((curr_data_size - Ms::SIZE_WITHOUT_MEMBERS) / 32) - ctx.accounts.multisig.keys.len();
-
-        // if not enough, add (10 * 32) to size - bump it up by 10 accounts
-        if spots_left < 1 {
-            // add space for 10 more keys
-            let needed_len = curr_data_size + (10 * 32);
-            // reallocate more space
-            AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
-            // if more lamports are needed, transfer them to the account
-            let rent_exempt_lamports = ctx.accounts.rent.minimum_balance(needed_len).max(1);
-            let top_up_lamports = rent_exempt_lamports
-                .saturating_sub(ctx.accounts.multisig.to_account_info().lamports());
-            if top_up_lamports > 0 {
-                return err!(MsError::NotEnoughLamports);
-            }
-        }
+        // check if realloc is needed, if there's no spot left for the key
+        // it will bump the size up by 10 accounts
+        ctx.accounts.ensure_multisig_space(32, ctx.remaining_accounts)?;
         ctx.accounts.multisig.reload()?;
+        // after the reload, which would discard it
+        advance_seq(&mut ctx.accounts.multisig);
         ctx.accounts.multisig.add_member(new_member)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddMember { member: new_member },
+        });
         let new_index = ctx.accounts.multisig.transaction_index;
         // set the change index, which will deprecate any active transactions
         ctx.accounts.multisig.set_change_index(new_index)
     }
 
+    /// The instruction to apply several config changes as one batch, ie. add a member, remove
+    /// another and raise the threshold. The changes are applied in order and the multisig is
+    /// validated once all of them are applied, then active transactions are deprecated once.
+    /// Reallocates space if neccessary, in which case the rent is topped up by a signing
+    /// payer or the default vault passed as a remaining account.
+    pub fn apply_config_actions<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        actions: Vec<MsConfigAction>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if actions.is_empty() {
+            return err!(MsError::InvalidConfigActions);
+        }
+        let additional_len = actions.iter().map(|action| action.additional_len()).sum();
+        ctx.accounts.ensure_multisig_space(additional_len, ctx.remaining_accounts)?;
+        for action in actions {
+            ctx.accounts.multisig.apply_config_action(&action)?;
+            emit!(ConfigChanged {
+                multisig: ctx.accounts.multisig.key(),
+                action: action.into(),
+            });
+        }
+        validate_config(&ctx.accounts.multisig)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction for the admin to apply config changes on its own, like apply_config_actions
+    /// without a vote, until the admin expires. Reallocates space if neccessary, paid by the admin.
+    pub fn admin_config_actions(ctx: Context<AdminConfig>, actions: Vec<MsConfigAction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.check_admin()?;
+        if actions.is_empty() {
+            return err!(MsError::InvalidConfigActions);
+        }
+        for action in actions {
+            ctx.accounts.multisig.apply_config_action(&action)?;
+            emit!(ConfigChanged {
+                multisig: ctx.accounts.multisig.key(),
+                action: action.into(),
+            });
+        }
+        validate_config(&ctx.accounts.multisig)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction for the admin to give up its powers before it expires. It can't be set again.
+    pub fn renounce_admin(ctx: Context<RenounceAdmin>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.renounce_admin()?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RenounceAdmin { admin: ctx.accounts.admin.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to allow (or disallow) PDAs of a program to be added as members.
+    /// Reallocates space if neccessary, in which case the rent is topped up by a signing
+    /// payer or the default vault passed as a remaining account.
+    pub fn set_member_program<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member_program: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(32, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_member_program(member_program, allowed)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMemberProgram { member_program, allowed },
+        });
+        Ok(())
+    }
+
+    /// The instruction to restrict the programs that instructions of transactions of an
+    /// authority (vault) may target, ie. only the token and system programs. An empty list
+    /// removes the restriction. Instructions are checked when attached and when executed.
+    pub fn set_program_allowlist<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        authority_index: u32,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(ProgramAllowlist::size(programs.len()), ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_program_allowlist(authority_index, programs.clone())?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetProgramAllowlist { authority_index, programs },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set the keys that can execute approved transactions without being
+    /// members, ie. a keeper service, or to let any key execute them. Replaces the deprecated
+    /// allow_external_execute flag.
+    pub fn set_executors<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        any_executor: bool,
+        executors: Vec<Pubkey>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(executors.len() * 32, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_executors(any_executor, executors)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetExecutors {
+                any_executor,
+                executors: ctx.accounts.multisig.executors.clone(),
+            },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set (or remove) the execution hook, a program invoked with
+    /// pre_execute and post_execute instructions around the execution of vault transactions,
+    /// which can abort the execution by failing. Internal transactions don't invoke the hook.
+    pub fn set_execution_hook<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        execution_hook: Option<Pubkey>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(32, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_execution_hook(execution_hook)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetExecutionHook { execution_hook },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set (or remove) the parent of the multisig, the vault authority of
+    /// another multisig. The parent formally proposes transactions into this multisig through
+    /// CPI, like a member with only the initiate permission, but doesn't vote.
+    pub fn set_parent<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        parent: Option<Pubkey>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(32, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_parent(parent)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetParent { parent },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set (or remove) the membership gate, the non-transferable mint or verified
+    /// NFT collection whose holders vote with token vote records, resolved at vote time, and the number
+    /// of token votes that vault transactions need besides the members. The token threshold must be at least 1. Vote records
+    /// must be enabled for token votes. Any active transactions are deprecated, as the voters change.
+    pub fn set_membership_gate<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        membership_gate: Option<MembershipGate>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(MembershipGate::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_membership_gate(membership_gate)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMembershipGate { membership_gate },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to set (or remove) the execution window, the UTC days and hours vault
+    /// transactions can be executed in, ie. weekdays from 09:00 to 18:00. Spending limits,
+    /// allowances and recurring transactions are held to it as well. Internal transactions
+    /// aren't, so the multisig can always change its schedule.
+    pub fn set_execution_window<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        execution_window: Option<ExecutionWindow>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(ExecutionWindow::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_execution_window(execution_window)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetExecutionWindow { execution_window },
+        });
+        Ok(())
+    }
+
+    /// The instruction to replace the blackout periods, the time ranges vault transactions can't
+    /// be executed in, whatever the execution window. An empty list removes the periods.
+    pub fn set_blackout_periods<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        blackout_periods: Vec<BlackoutPeriod>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(blackout_periods.len() * BlackoutPeriod::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_blackout_periods(blackout_periods.clone())?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetBlackoutPeriods { blackout_periods },
+        });
+        Ok(())
+    }
+
+    /// The instruction to replace the value tiers, the thresholds of vault transactions by the total
+    /// amount of SOL (the default pubkey as the mint) or of a mint they transfer, ie. 1 below 1 SOL
+    /// and 4 from 100 SOL. The tiers are applied when transactions are activated, so their
+    /// instruction accounts must be passed to activate them. An empty list removes the tiers.
+    pub fn set_value_tiers<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        value_tiers: Vec<ValueTier>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(value_tiers.len() * ValueTier::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_value_tiers(value_tiers.clone())?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetValueTiers { value_tiers },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set (or remove) the stake weight source, the staking program whose lockers
+    /// the vote weights of the members are read from, snapshotted when transactions are activated.
+    /// The member weights cap the stake weights, so they must be raised to give stake its influence,
+    /// and internal transactions keep the member weights.
+    /// Vote records must be enabled, as stake weights are tallied with vote records.
+    /// Any active transactions are deprecated, as the vote weights change.
+    pub fn set_stake_weight_source<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        stake_weight_source: Option<StakeWeightSource>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if stake_weight_source.is_some() && !ctx.accounts.multisig.vote_records {
+            return err!(MsError::VoteRecordsDisabled);
+        }
+        ctx.accounts.ensure_multisig_space(StakeWeightSource::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_stake_weight_source(stake_weight_source)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetStakeWeightSource { stake_weight_source },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to deny (or allow again) instructions of a program whose data starts
+    /// with the prefix, ie. SetAuthority on the token program. Instructions are checked when
+    /// attached (including appended data) and when executed.
+    pub fn set_denied_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        target_program: Pubkey,
+        prefix: Vec<u8>,
+        denied: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(DeniedInstruction::size(prefix.len()), ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_denied_instruction(target_program, prefix.clone(), denied)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetDeniedInstruction { program_id: target_program, prefix, denied },
+        });
+        Ok(())
+    }
+
+    /// The instruction to invalidate a single pending (draft, active or approved) transaction,
+    /// marking it as cancelled, without deprecating the other active transactions the way
+    /// a change of the multisig does.
+    pub fn invalidate_transaction(ctx: Context<InvalidateTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_cancelled()?;
+        // a partially executed transaction no longer holds off the others
+        let transaction_index = ctx.accounts.transaction.transaction_index;
+        ctx.accounts.multisig.release_execution(transaction_index)?;
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, transaction_index, &ctx.accounts.transaction.status)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::InvalidateTransaction { transaction: ctx.accounts.transaction.key() },
+        });
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// The instruction to decommission an authority (vault): all of its lamports are swept to
+    /// the destination and the authority is marked retired, so no transactions of it can be
+    /// created or executed anymore. The default vault can't be retired.
+    pub fn retire_authority(ctx: Context<RetireAuthority>, authority_index: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.retire_authority(authority_index)?;
+        let ms_key = ctx.accounts.multisig.key();
+        let vault_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority",
+            &[*ctx.bumps.get("vault").unwrap()],
+        ];
+        let vault_lamports = ctx.accounts.vault.lamports();
+        ctx.accounts.sweep_vault(&vault_seeds)?;
+        emit!(ConfigChanged {
+            multisig: ms_key,
+            action: ConfigAction::RetireAuthority {
+                authority_index,
+                destination: ctx.accounts.destination.key(),
+                lamports: vault_lamports.saturating_sub(ctx.accounts.vault.lamports()),
+            },
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a PDA of an allowed member program as a member.
+    /// The seeds (including the bump) must derive the PDA from the program, which then
+    /// approves, rejects and cancels transactions by signing for the PDA through CPI.
+    pub fn add_program_member<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member_program: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        // add_member advances the seq
+        if !ctx.accounts.multisig.is_member_program(member_program) {
+            return err!(MsError::InvalidMemberProgram);
+        }
+        let seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+        let new_member = Pubkey::create_program_address(&seeds, &member_program)
+            .map_err(|_| MsError::InvalidMemberProgram)?;
+        add_member(ctx, new_member)
+    }
+
     /// The instruction to remove a member from the multisig
     pub fn remove_member(ctx: Context<MsAuth>, old_member: Pubkey) -> Result<()> {
-        // if there is only one key in this multisig, reject the removal
-        if ctx.accounts.multisig.keys.len() == 1 {
-            return err!(MsError::CannotRemoveSoloMember);
+        advance_seq(&mut ctx.accounts.multisig);
+        // the remaining members must still be able to reach the thresholds, which aren't lowered
+        validate_removal(&ctx.accounts.multisig, old_member)?;
+        // with a removal delay, the removal only takes effect once finalized after the delay
+        if ctx.accounts.multisig.removal_delay > 0 {
+            let remove_at = ctx.accounts.multisig.schedule_removal(old_member)?;
+            emit!(ConfigChanged {
+                multisig: ctx.accounts.multisig.key(),
+                action: ConfigAction::ScheduleMemberRemoval { member: old_member, remove_at },
+            });
+            return Ok(());
         }
         ctx.accounts.multisig.remove_member(old_member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveMember { member: old_member },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to swap the key of a member in one step, ie. to rotate a key. The weight,
+    /// permissions and expiration of the member carry over, so the threshold is unchanged, and
+    /// active transactions are deprecated like for any change of the members.
+    pub fn replace_member(ctx: Context<MsAuth>, old_member: Pubkey, new_member: Pubkey) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.replace_member(old_member, new_member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ReplaceMember { old_member, new_member },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to finalize a pending member removal once the removal delay has passed,
+    /// which anyone can call.
+    pub fn finalize_member_removal(ctx: Context<FinalizeMemberRemoval>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.multisig.take_due_removal()?;
+        // the config could have changed during the delay, so the removal is checked again
+        validate_removal(&ctx.accounts.multisig, member)?;
+        ctx.accounts.multisig.remove_member(member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveMember { member },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to abort a pending member removal during the removal delay.
+    /// A single member with the veto permission (other than the member being removed) is enough.
+    pub fn abort_member_removal(ctx: Context<AbortMemberRemoval>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.multisig.abort_removal()?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AbortMemberRemoval { member },
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the removal delay of the multisig, the number of seconds
+    /// between a member removal being executed and taking effect. 0 removes members right away.
+    pub fn change_removal_delay(ctx: Context<MsAuth>, removal_delay: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_removal_delay(removal_delay)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeRemovalDelay { removal_delay },
+        });
+        Ok(())
+    }
 
-        // if the number of keys is now less than the threshold, adjust it
-        if ctx.accounts.multisig.keys.len() < usize::from(ctx.accounts.multisig.threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
+    /// The instruction to set when the membership of a member expires, None removes the expiration.
+    /// Expired members can no longer vote, their earlier votes no longer count and anyone
+    /// can prune them from the multisig. Reallocates space if neccessary, in which case the rent
+    /// is topped up by a signing payer or the default vault passed as a remaining account.
+    pub fn set_member_expiration<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(MemberExpiration::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_member_expiration(member, expires_at)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMemberExpiration { member, expires_at },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to remove a member whose membership has expired, which anyone can call.
+    pub fn prune_expired_member(ctx: Context<PruneExpiredMember>, member: Pubkey) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if ctx.accounts.multisig.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if !ctx.accounts.multisig.is_expired_member(member) {
+            return err!(MsError::MemberNotExpired);
         }
+        // an expired member has no weight, so this only fails if the thresholds are already unreachable
+        validate_removal(&ctx.accounts.multisig, member)?;
+        ctx.accounts.multisig.remove_member(member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::PruneExpiredMember { member },
+        });
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
         ctx.accounts.multisig.set_change_index(new_index)
     }
 
-    /// The instruction to change the threshold of the multisig and simultaneously remove a member
+    /// The instruction to change the threshold of the multisig and simultaneously remove a member.
+    /// The threshold is changed first, so it can be lowered to what the remaining members can reach.
     pub fn remove_member_and_change_threshold<'info>(
         ctx: Context<'_, '_, '_, 'info, MsAuth<'info>>,
         old_member: Pubkey,
         new_threshold: u16,
     ) -> Result<()> {
-        remove_member(
-            Context::new(
-                ctx.program_id,
-                ctx.accounts,
-                ctx.remaining_accounts,
-                ctx.bumps.clone(),
-            ),
-            old_member,
-        )?;
-        change_threshold(ctx, new_threshold)
+        advance_seq(&mut ctx.accounts.multisig);
+        // the threshold would change before the delayed removal takes effect
+        if ctx.accounts.multisig.removal_delay > 0 {
+            return err!(MsError::RemovalDelayed);
+        }
+        // the threshold must be at least 1 and reachable by the members, it is never clamped
+        ctx.accounts.multisig.change_threshold(new_threshold)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeThreshold { threshold: ctx.accounts.multisig.threshold },
+        });
+
+        // the remaining members must still be able to reach the new threshold
+        validate_removal(&ctx.accounts.multisig, old_member)?;
+        ctx.accounts.multisig.remove_member(old_member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveMember { member: old_member },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
     }
 
     /// The instruction to change the threshold of the multisig and simultaneously add a member
@@ -165,7 +628,7 @@ pub mod squads_mpl {
         new_member: Pubkey,
         new_threshold: u16,
     ) -> Result<()> {
-        // add the member
+        // add the member, which advances the seq
         add_member(
             Context::new(
                 ctx.program_id,
@@ -176,15 +639,13 @@ pub mod squads_mpl {
             new_member,
         )?;
 
-        // check that the threshold value is valid
-        if ctx.accounts.multisig.keys.len() < usize::from(new_threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
-        } else if new_threshold < 1 {
-            return err!(MsError::InvalidThreshold);
-        } else {
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
-        }
+        // the threshold must be reachable with the new member
+        ctx.accounts.multisig.change_threshold(new_threshold)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeThreshold { threshold: ctx.accounts.multisig.threshold },
+        });
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
         ctx.accounts.multisig.set_change_index(new_index)
@@ -192,15 +653,131 @@ pub mod squads_mpl {
 
     /// The instruction to change the threshold of the multisig
     pub fn change_threshold(ctx: Context<MsAuth>, new_threshold: u16) -> Result<()> {
-        // if the new threshold value is valid
-        if ctx.accounts.multisig.keys.len() < usize::from(new_threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
-        } else if new_threshold < 1 {
-            return err!(MsError::InvalidThreshold);
-        } else {
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
-        }
+        advance_seq(&mut ctx.accounts.multisig);
+        // the threshold must be at least 1 and reachable by the members, it is never clamped
+        ctx.accounts.multisig.change_threshold(new_threshold)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeThreshold { threshold: ctx.accounts.multisig.threshold },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to set the threshold as a percentage of the total vote weight (ie. 60),
+    /// which is then recomputed whenever members are added or removed or their weights change.
+    /// A threshold percent of 0 switches back to an absolute threshold, keeping the current one.
+    pub fn change_threshold_percent(ctx: Context<MsAuth>, threshold_percent: u8) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.change_threshold_percent(threshold_percent)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeThresholdPercent {
+                threshold_percent,
+                threshold: ctx.accounts.multisig.threshold,
+            },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to change the threshold for internal transactions, ie. member
+    /// and threshold changes, separately from the threshold for vault transactions.
+    /// A config threshold of 0 falls back to the regular threshold.
+    pub fn change_config_threshold(ctx: Context<MsAuth>, config_threshold: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.change_config_threshold(config_threshold)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeConfigThreshold { config_threshold },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to change the min quorum, the number of members that must have voted
+    /// (approved, rejected or abstained) before a transaction is decided, independently of the
+    /// threshold. A min quorum of 0 disables the quorum.
+    pub fn change_min_quorum(ctx: Context<MsAuth>, min_quorum: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_min_quorum(min_quorum)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeMinQuorum { min_quorum },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to cap the transactions a single creator can have open (neither executed,
+    /// rejected, cancelled, expired nor deprecated), so one member can't flood the queue of the
+    /// others. A max open transactions of 0 removes the cap. Transactions are only counted while
+    /// the cap is set, so active transactions are deprecated.
+    pub fn change_max_open_transactions(ctx: Context<MsAuth>, max_open_transactions: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_max_open_transactions(max_open_transactions)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeMaxOpenTransactions { max_open_transactions },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to cap the number of members of the multisig, enforced when members are
+    /// added. A max members of 0 removes the cap, leaving the global ceiling of Ms::MAX_MEMBERS.
+    pub fn change_max_members(ctx: Context<MsAuth>, max_members: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_max_members(max_members)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeMaxMembers { max_members },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set the vote weight of a member.
+    /// Approvals, rejections and cancellations are tallied by weight against the threshold,
+    /// members default to a weight of 1. Reallocates space if neccessary,
+    /// in which case the multisig must be prefunded with enough lamports to cover the new size.
+    pub fn set_member_weight<'info>(ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>, member: Pubkey, weight: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(MemberWeight::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_member_weight(member, weight)?;
+        // the thresholds must still be reachable with the new weights
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMemberWeight { member, weight },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to set the permissions of a member.
+    /// Permissions are a bitmask of Initiate, Vote, Execute and ChangeConfig,
+    /// members default to having all permissions. Reallocates space if neccessary,
+    /// in which case the multisig must be prefunded with enough lamports to cover the new size.
+    pub fn set_member_permissions<'info>(ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>, member: Pubkey, permissions: u8) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(MemberPermissions::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_member_permissions(member, permissions)?;
+        // the members that can still vote must be able to reach the thresholds,
+        // and at least one member must be able to execute
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMemberPermissions { member, permissions },
+        });
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
         ctx.accounts.multisig.set_change_index(new_index)
@@ -212,157 +789,1762 @@ pub mod squads_mpl {
     /// This has no functional impact on the multisig or its functionality, but
     /// can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)
     pub fn add_authority(ctx: Context<MsAuth>) -> Result<()> {
-        ctx.accounts.multisig.add_authority()
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.add_authority()?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddAuthority { authority_index: ctx.accounts.multisig.authority_index },
+        });
+        Ok(())
     }
 
-    /// Instruction to create a multisig transaction.
-    /// Each transaction is tied to a single authority, and must be specified when
-    /// creating the instruction below. authority 0 is reserved for internal
-    /// instructions, whereas authorities 1 or greater refer to a vault,
-    /// upgrade authority, or other.
-    pub fn create_transaction(ctx: Context<CreateTransaction>, authority_index: u32) -> Result<()> {
-        let ms = &mut ctx.accounts.multisig;
-        let authority_bump = match authority_index {
-            1.. => {
-                let (_, auth_bump) = Pubkey::find_program_address(
-                    &[
-                        b"squad",
-                        ms.key().as_ref(),
-                        &authority_index.to_le_bytes(),
-                        b"authority",
-                    ],
-                    ctx.program_id,
-                );
-                auth_bump
-            }
-            0 => ms.bump,
-        };
-
-        ms.transaction_index = ms.transaction_index.checked_add(1).unwrap();
-        ctx.accounts.transaction.init(
-            ctx.accounts.creator.key(),
-            ms.key(),
-            ms.transaction_index,
-            *ctx.bumps.get("transaction").unwrap(),
+    /// instruction to increase the authority value tracked in the multisig, the same as
+    /// add_authority, while also creating a record labeling the new authority with what
+    /// it is used for, ie. "ops vault" or "upgrade authority for program X".
+    pub fn add_named_authority(ctx: Context<AddNamedAuthority>, label: String, purpose: String) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.add_authority()?;
+        let authority_index = ctx.accounts.multisig.authority_index;
+        ctx.accounts.authority_record.init(
+            ctx.accounts.multisig.key(),
             authority_index,
-            authority_bump,
-        )
+            label,
+            purpose,
+            *ctx.bumps.get("authority_record").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddAuthority { authority_index },
+        });
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetAuthorityRecord { authority_record: ctx.accounts.authority_record.key() },
+        });
+        Ok(())
     }
 
-    /// Instruction to set the state of a transaction "active".
-    /// "active" transactions can then be signed off by multisig members
-    pub fn activate_transaction(ctx: Context<ActivateTransaction>) -> Result<()> {
-        ctx.accounts.transaction.activate()
+    /// instruction to update the label and purpose in the record of an authority
+    pub fn update_authority_record(ctx: Context<UpdateAuthorityRecord>, label: String, purpose: String) -> Result<()> {
+        ctx.accounts.authority_record.update(label, purpose)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetAuthorityRecord { authority_record: ctx.accounts.authority_record.key() },
+        });
+        Ok(())
     }
 
-    /// Instruction to attach an instruction to a transaction.
-    /// Transactions must be in the "draft" status, and any
-    /// signer (aside from execution payer) specified in an instruction 
-    /// must match the authority PDA specified during the transaction creation.
-    pub fn add_instruction(
-        ctx: Context<AddInstruction>,
-        incoming_instruction: IncomingInstruction,
-    ) -> Result<()> {
-        let tx = &mut ctx.accounts.transaction;
-        // make sure internal transactions have a matching program id for attached instructions
-        if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
-            return err!(MsError::InvalidAuthorityIndex);
-        }
-        tx.instruction_index = tx.instruction_index.checked_add(1).unwrap();
-        ctx.accounts.instruction.init(
-            tx.instruction_index,
-            incoming_instruction,
-            *ctx.bumps.get("instruction").unwrap(),
-        )
+    /// instruction to change the authority index designated as the default vault.
+    /// Defaults to authority 1, and must be within the authorities tracked by the multisig,
+    /// so add_authority may need to be called first.
+    pub fn change_default_vault(ctx: Context<MsAuth>, vault_index: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_default_vault(vault_index)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeDefaultVault { vault_index },
+        });
+        Ok(())
     }
 
-    /// Instruction to approve a transaction on behalf of a member.
-    /// The transaction must have an "active" status
-    pub fn approve_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
-        // if they have previously voted to reject, remove that item (change vote check)
-        if let Some(ind) = ctx
-            .accounts
-            .transaction
-            .has_voted_reject(ctx.accounts.member.key())
-        {
-            ctx.accounts.transaction.remove_reject(ind)?;
-        }
+    /// The instruction to change the time lock of the multisig, the number of seconds
+    /// a transaction must wait after being approved before it can be executed.
+    pub fn change_time_lock(ctx: Context<MsAuth>, time_lock: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_time_lock(time_lock)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeTimeLock { time_lock },
+        });
+        Ok(())
+    }
 
-        // if they haven't already approved
-        if ctx
-            .accounts
-            .transaction
-            .has_voted_approve(ctx.accounts.member.key())
-            .is_none()
-        {
-            ctx.accounts.transaction.sign(ctx.accounts.member.key())?;
-        }
+    /// The instruction to change the config time lock of the multisig, the number of seconds an
+    /// internal (config) transaction must wait after being approved before it can be executed,
+    /// independently of the time lock, so that changing the members or threshold is never instant.
+    pub fn change_config_time_lock(ctx: Context<MsAuth>, config_time_lock: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_config_time_lock(config_time_lock)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeConfigTimeLock { config_time_lock },
+        });
+        Ok(())
+    }
 
-        // if current number of signers reaches threshold, mark the transaction as execute ready
-        if ctx.accounts.transaction.approved.len() >= usize::from(ctx.accounts.multisig.threshold) {
-            ctx.accounts.transaction.ready_to_execute()?;
-        }
+    /// The instruction to change the veto period of the multisig, the number of seconds after
+    /// a transaction is approved during which a single member with the veto permission can
+    /// cancel it. Execution is held back until the veto period has passed.
+    pub fn change_veto_period(ctx: Context<MsAuth>, veto_period: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_veto_period(veto_period)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ChangeVetoPeriod { veto_period },
+        });
         Ok(())
     }
 
-    /// Instruction to reject a transaction.
-    /// The transaction must have an "active" status.
-    pub fn reject_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
-        // if they have previously voted to approve, remove that item (change vote check)
-        if let Some(ind) = ctx
-            .accounts
-            .transaction
-            .has_voted_approve(ctx.accounts.member.key())
-        {
-            ctx.accounts.transaction.remove_approve(ind)?;
-        }
-
-        // check if they haven't already voted reject
-        if ctx
-            .accounts
-            .transaction
-            .has_voted_reject(ctx.accounts.member.key())
-            .is_none()
-        {
-            ctx.accounts.transaction.reject(ctx.accounts.member.key())?;
-        }
-
-        // ie total members 7, threshold 3, cutoff = 4
-        // ie total member 8, threshold 6, cutoff = 2
-        let cutoff = ctx
-            .accounts
-            .multisig
-            .keys
-            .len()
-            .checked_sub(usize::from(ctx.accounts.multisig.threshold))
-            .unwrap();
-        if ctx.accounts.transaction.rejected.len() > cutoff {
-            ctx.accounts.transaction.set_rejected()?;
-        }
+    /// The instruction to set (or remove) the guardian of the multisig. The guardian can freeze
+    /// the multisig in an emergency, but cannot vote or move funds.
+    pub fn set_guardian(ctx: Context<MsAuth>, guardian: Option<Pubkey>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_guardian(guardian)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetGuardian { guardian },
+        });
         Ok(())
     }
 
-    /// Instruction to cancel a transaction.
-    /// Transactions must be in the "executeReady" status.
-    /// Transaction will only be cancelled if the number of
-    /// cancellations reaches the threshold. A cancelled
-    /// transaction will no longer be able to be executed.
-    pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
-        // check if they haven't cancelled yet
-        if ctx
-            .accounts
-            .transaction
-            .has_cancelled(ctx.accounts.member.key())
-            .is_none()
-        {
-            ctx.accounts.transaction.cancel(ctx.accounts.member.key())?
-        }
-
-        // if the current number of signers reaches threshold, mark the transaction as "cancelled"
-        if ctx.accounts.transaction.cancelled.len() >= usize::from(ctx.accounts.multisig.threshold)
-        {
-            ctx.accounts.transaction.set_cancelled()?;
-        }
+    /// Instruction for the guardian to freeze the multisig, which blocks activating and
+    /// executing transactions (and spending limits) until the multisig unfreezes itself.
+    /// Internal transactions are not frozen, so the members can still unfreeze or change the config.
+    pub fn freeze(ctx: Context<Freeze>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_frozen(true)?;
+        emit!(MultisigFrozen {
+            multisig: ctx.accounts.multisig.key(),
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    /// The instruction to unfreeze the multisig, which requires the normal threshold
+    pub fn unfreeze(ctx: Context<MsAuth>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_frozen(false)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::Unfreeze,
+        });
+        Ok(())
+    }
+
+    /// The instruction to pause executions of the multisig, ie. when a member key is suspected
+    /// to be compromised. Executing transactions (and spending limits) is blocked while voting
+    /// continues, internal transactions can still be executed to rotate keys and unpause.
+    pub fn pause(ctx: Context<MsAuth>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_paused(true)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::Pause,
+        });
+        Ok(())
+    }
+
+    /// The instruction to unpause executions of the multisig
+    pub fn unpause(ctx: Context<MsAuth>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_paused(false)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::Unpause,
+        });
+        Ok(())
+    }
+
+    /// The instruction to migrate a multisig of the original layout, whose authority index was
+    /// two bytes wide (while transactions sign with four byte authority indexes) and which lacks
+    /// every field introduced since. Such accounts can't be loaded with the current layout, so
+    /// this migrates them in place permissionlessly. The layout is confirmed by the create key
+    /// and bump it is parsed with deriving the address of the multisig.
+    pub fn migrate_baseline_multisig(ctx: Context<MigrateBaselineMultisig>) -> Result<()> {
+        let mut multisig = {
+            let account_data = ctx.accounts.multisig.try_borrow_data()?;
+            if matches!(Ms::try_deserialize(&mut &account_data[..]), Ok(ms) if ms.version >= Ms::VERSION) {
+                return err!(MsError::AlreadyMigrated);
+            }
+            Ms::try_deserialize_baseline(&account_data)?
+        };
+        let multisig_pda = Pubkey::create_program_address(
+            &[b"squad", multisig.create_key.as_ref(), b"multisig", &[multisig.bump]],
+            ctx.program_id,
+        ).map_err(|_| MsError::InvalidInstructionAccount)?;
+        if &multisig_pda != ctx.accounts.multisig.key {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        multisig.migrate()?;
+        let seq = multisig.advance_seq();
+        ctx.accounts.write_multisig(&multisig)?;
+        emit!(SeqAdvanced {
+            multisig: multisig_pda,
+            seq,
+        });
+        emit!(ConfigChanged {
+            multisig: multisig_pda,
+            action: ConfigAction::Migrate { version: multisig.version },
+        });
+        Ok(())
+    }
+
+    /// View of the status of a transaction, returned through the return data so that
+    /// clients and other programs can read it without deserializing the account.
+    pub fn get_transaction_status(ctx: Context<ViewTransaction>) -> Result<MsTransactionStatus> {
+        Ok(ctx.accounts.transaction.status.clone())
+    }
+
+    /// View of the vote counts of a transaction, returned through the return data.
+    pub fn get_vote_counts(ctx: Context<ViewTransaction>) -> Result<VoteCounts> {
+        Ok(ctx.accounts.transaction.vote_counts(&ctx.accounts.multisig))
+    }
+
+    /// View of whether the key is a member of the multisig, returned through the return data.
+    pub fn is_member(ctx: Context<ViewMultisig>, member: Pubkey) -> Result<bool> {
+        Ok(ctx.accounts.multisig.is_member(member).is_some())
+    }
+
+    /// Instruction to migrate a transaction account to the current layout version in place.
+    /// The account is parsed and written back with the current layout, growing it if neccessary.
+    /// Later layout versions are parsed here from the layout of the version before them.
+    /// Transactions of the original layout have no version, and are migrated with
+    /// migrate_baseline_transaction instead.
+    pub fn migrate_transaction(ctx: Context<MigrateTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let mut transaction = MsTransaction::try_deserialize(&mut &ctx.accounts.transaction.try_borrow_data()?[..])?;
+        // an unvoted transaction of the original layout reads as version 0, and can only be
+        // converted by migrate_baseline_transaction
+        if transaction.version == 0 {
+            return err!(MsError::NotMigrated);
+        }
+        transaction.migrate()?;
+        ctx.accounts.write_transaction(&transaction)
+    }
+
+    /// Instruction to migrate a transaction of the original layout, whose votes were lists of
+    /// member keys and which lacks every field introduced since. The votes are converted to
+    /// bitmaps over the keys of the multisig, which must have been migrated first.
+    pub fn migrate_baseline_transaction(ctx: Context<MigrateTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let mut transaction = {
+            let account_data = ctx.accounts.transaction.try_borrow_data()?;
+            if matches!(MsTransaction::try_deserialize(&mut &account_data[..]), Ok(tx) if tx.version >= MsTransaction::VERSION) {
+                return err!(MsError::AlreadyMigrated);
+            }
+            MsTransaction::try_deserialize_baseline(&account_data, &ctx.accounts.multisig.keys)?
+        };
+        transaction.migrate()?;
+        ctx.accounts.write_transaction(&transaction)
+    }
+
+    /// The instruction to switch the multisig between recording votes on the transaction
+    /// (the default), and recording them in per-member VoteRecord accounts, so that
+    /// multisigs with hundreds of members can vote without growing the transaction.
+    /// Votes of active transactions would be split between the two, so they're deprecated.
+    pub fn set_vote_records(ctx: Context<MsAuth>, enabled: bool) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_vote_records(enabled)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetVoteRecords { enabled },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction to create a multisig transaction.
+    /// Each transaction is tied to a single authority, and must be specified when
+    /// creating the instruction below. authority 0 is reserved for internal
+    /// instructions, whereas authorities 1 or greater refer to a vault,
+    /// upgrade authority, or other.
+    pub fn create_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTransaction<'info>>,
+        authority_index: u32,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let ms = &mut ctx.accounts.multisig;
+        // internal transactions change the multisig itself
+        if authority_index == 0 && !ms.has_permission(ctx.accounts.creator.key(), Permissions::CHANGE_CONFIG) {
+            return err!(MsError::MissingPermission);
+        }
+        if ms.is_authority_retired(authority_index) {
+            return err!(MsError::AuthorityRetired);
+        }
+        let authority_bump = ms.get_authority_bump(&ms.key(), authority_index, ctx.program_id);
+        ms.open_transaction(ctx.accounts.creator.key())?;
+
+        ms.transaction_index = ms.transaction_index.checked_add(1).ok_or(MsError::TransactionIndexOverflow)?;
+        ctx.accounts.transaction.init(
+            ctx.accounts.creator.key(),
+            ms.key(),
+            ms.transaction_index,
+            *ctx.bumps.get("transaction").unwrap(),
+            authority_index,
+            authority_bump,
+            ms.keys.len(),
+        )?;
+        emit!(TransactionCreated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            authority_index,
+            linked_transaction: None,
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Create,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to create a multisig transaction, attach its instructions and activate it
+    /// at once, so that a client failing midway doesn't leave a half-built draft behind.
+    /// The instruction accounts are passed as remaining accounts, in the same order as the
+    /// incoming instructions, followed by the activity log and policy accounts (if any).
+    /// The same rules as create_transaction, add_instructions and activate_transaction apply.
+    pub fn create_transaction_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTransaction<'info>>,
+        authority_index: u32,
+        incoming_instructions: Vec<IncomingInstruction>,
+    ) -> Result<()> {
+        if incoming_instructions.is_empty() || incoming_instructions.len() > ctx.remaining_accounts.len() {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        if ctx.accounts.multisig.is_frozen_for(authority_index) {
+            return err!(MsError::MultisigFrozen);
+        }
+        // the vote weights of vault transactions have to be snapshotted with activate_with_stake_snapshot
+        if ctx.accounts.multisig.stake_weight_source.is_some() && authority_index != 0 {
+            return err!(MsError::StakeSnapshotRequired);
+        }
+        let (instruction_infos, remaining_accounts) = ctx.remaining_accounts.split_at(incoming_instructions.len());
+        create_transaction(
+            Context::new(ctx.program_id, ctx.accounts, remaining_accounts, ctx.bumps.clone()),
+            authority_index,
+        )?;
+        let tx_key = ctx.accounts.transaction.key();
+
+        for (incoming_instruction, instruction_info) in incoming_instructions.into_iter().zip(instruction_infos.iter()) {
+            // make sure internal transactions have a matching program id for attached instructions
+            if authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+                return err!(MsError::InvalidAuthorityIndex);
+            }
+            ctx.accounts.multisig.check_program_allowed(authority_index, &incoming_instruction.program_id)?;
+            ctx.accounts.multisig.check_instruction_not_denied(&incoming_instruction.program_id, &incoming_instruction.data)?;
+            let instruction_index = ctx.accounts.transaction.instruction_index
+                .checked_add(1)
+                .ok_or(MsError::InstructionIndexOverflow)?;
+            create_instruction_account(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+                instruction_info,
+                tx_key,
+                instruction_index,
+                incoming_instruction,
+            )?;
+            ctx.accounts.transaction.instruction_index = instruction_index;
+            emit!(InstructionAdded {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: tx_key,
+                instruction: instruction_info.key(),
+                instruction_index,
+            });
+        }
+
+        // the policy program loads the new transaction from the account data
+        ctx.accounts.transaction.exit(ctx.program_id)?;
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            authority_index,
+            ctx.accounts.creator.key(),
+            PolicyStage::Activate,
+            remaining_accounts,
+        )?;
+        apply_value_tiers(&ctx.accounts.multisig, &mut ctx.accounts.transaction, ctx.remaining_accounts)?;
+        ctx.accounts.transaction.activate()?;
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: tx_key,
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Activate,
+            ctx.accounts.transaction.transaction_index,
+            remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to create a config transaction, which carries typed config actions instead
+    /// of instructions. It is active right away, and is tallied against the config threshold.
+    pub fn create_config_transaction(ctx: Context<CreateConfigTransaction>, actions: Vec<MsConfigAction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let ms = &mut ctx.accounts.multisig;
+        ms.open_transaction(ctx.accounts.creator.key())?;
+        ms.transaction_index = ms.transaction_index.checked_add(1).ok_or(MsError::TransactionIndexOverflow)?;
+        ctx.accounts.transaction.init(
+            ctx.accounts.creator.key(),
+            ms,
+            ms.key(),
+            actions,
+            *ctx.bumps.get("transaction").unwrap(),
+        )?;
+        emit!(TransactionCreated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            authority_index: 0,
+            linked_transaction: None,
+        });
+        Ok(())
+    }
+
+    /// Instruction to approve a config transaction on behalf of a member. Like transaction approvals,
+    /// it goes through the policy check, which exempts internal transactions such as this one.
+    pub fn approve_config_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteConfigTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.member.key();
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            0,
+            member,
+            PolicyStage::Approve,
+            ctx.remaining_accounts,
+        )?;
+        let ready = ctx.accounts.transaction.cast_approval(&ctx.accounts.multisig, member)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: Vote::Approve,
+        });
+        if ready {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Instruction to reject a config transaction on behalf of a member.
+    pub fn reject_config_transaction(ctx: Context<VoteConfigTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.member.key();
+        let rejected = ctx.accounts.transaction.cast_rejection(&ctx.accounts.multisig, member)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: Vote::Reject,
+        });
+        if rejected {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        Ok(())
+    }
+
+    /// Instruction to execute an approved config transaction, applying its actions as one
+    /// batch, like apply_config_actions. Reallocates the multisig if neccessary, paid by the member.
+    pub fn execute_config_transaction(ctx: Context<ExecuteConfigTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.check_time_lock(ctx.accounts.multisig.execution_delay(0))?;
+        let actions = ctx.accounts.transaction.actions.clone();
+        for action in actions {
+            ctx.accounts.multisig.apply_config_action(&action)?;
+            emit!(ConfigChanged {
+                multisig: ctx.accounts.multisig.key(),
+                action: action.into(),
+            });
+        }
+        validate_config(&ctx.accounts.multisig)?;
+        ctx.accounts.multisig.record_execution()?;
+        ctx.accounts.transaction.set_executed()?;
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction to close a dissolved multisig, returning its rent to the destination. The
+    /// closure must be approved as an internal transaction whose only instruction is this one,
+    /// which is executed here rather than through execute_transaction, as the multisig (which
+    /// signs internal instructions) can't be closed under it. All of the vaults must be empty,
+    /// and the (config) transactions after the change index closed or done.
+    pub fn close_multisig<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseMultisig<'info>>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.check_time_lock(ctx.accounts.multisig.execution_delay(0))?;
+        ctx.accounts.transaction.check_not_expired()?;
+        ctx.accounts.check_dissolved(ctx.remaining_accounts)?;
+        ctx.accounts.transaction.set_executed()?;
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+        });
+        emit!(MultisigClosed {
+            multisig: ctx.accounts.multisig.key(),
+            destination,
+        });
+        Ok(())
+    }
+
+    /// Instruction to clone a transaction into a new draft with copies of all of its attached
+    /// instructions, ie. to re-propose a transaction deprecated by a change of the multisig.
+    /// The source transaction must be executed, rejected or deprecated. The source and new
+    /// instruction accounts are passed as remaining accounts, in pairs.
+    pub fn clone_transaction<'info>(ctx: Context<'_, '_, '_, 'info, CloneTransaction<'info>>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let source = &ctx.accounts.source_transaction;
+        // the activity log (if any) follows the instruction account pairs
+        let log_accounts = usize::from(ctx.accounts.multisig.activity_log_enabled);
+        if ctx.remaining_accounts.len() != 2 * usize::from(source.instruction_index) + log_accounts {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let ms = &mut ctx.accounts.multisig;
+        // internal transactions change the multisig itself
+        if source.authority_index == 0 && !ms.has_permission(ctx.accounts.creator.key(), Permissions::CHANGE_CONFIG) {
+            return err!(MsError::MissingPermission);
+        }
+        if ms.is_authority_retired(source.authority_index) {
+            return err!(MsError::AuthorityRetired);
+        }
+        let authority_bump = ms.get_authority_bump(&ms.key(), source.authority_index, ctx.program_id);
+        ms.open_transaction(ctx.accounts.creator.key())?;
+
+        ms.transaction_index = ms.transaction_index.checked_add(1).ok_or(MsError::TransactionIndexOverflow)?;
+        ctx.accounts.transaction.init(
+            ctx.accounts.creator.key(),
+            ms.key(),
+            ms.transaction_index,
+            *ctx.bumps.get("transaction").unwrap(),
+            source.authority_index,
+            authority_bump,
+            ms.keys.len(),
+        )?;
+        emit!(TransactionCreated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            authority_index: source.authority_index,
+            linked_transaction: None,
+        });
+
+        let source_key = source.key();
+        let tx_key = ctx.accounts.transaction.key();
+        for (instruction_index, accounts) in (1..=source.instruction_index).zip(ctx.remaining_accounts.chunks(2)) {
+            let (source_ix_pda, _) = pda::find_ix_address(&source_key, instruction_index);
+            if accounts[0].key != &source_ix_pda {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            let source_ix: Account<MsInstruction> = Account::try_from(&accounts[0])?;
+            // the allowlist may have changed since the source transaction was proposed
+            ctx.accounts.multisig.check_program_allowed(source.authority_index, &source_ix.program_id)?;
+            ctx.accounts.multisig.check_instruction_not_denied(&source_ix.program_id, &source_ix.data)?;
+            create_instruction_account(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+                &accounts[1],
+                tx_key,
+                instruction_index,
+                IncomingInstruction {
+                    program_id: source_ix.program_id,
+                    keys: source_ix.keys.clone(),
+                    data: source_ix.data.clone(),
+                },
+            )?;
+            ctx.accounts.transaction.instruction_index = instruction_index;
+            emit!(InstructionAdded {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: tx_key,
+                instruction: accounts[1].key(),
+                instruction_index,
+            });
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Create,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to create a multisig transaction linked to a previously executed one.
+    /// Executions can't be reversed, but a compensating transaction can reference the
+    /// original so that corrective actions can be traced back to their cause.
+    /// The linked transaction must belong to the same multisig and be "executed".
+    pub fn create_linked_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateLinkedTransaction<'info>>,
+        authority_index: u32,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let ms = &mut ctx.accounts.multisig;
+        // internal transactions change the multisig itself
+        if authority_index == 0 && !ms.has_permission(ctx.accounts.creator.key(), Permissions::CHANGE_CONFIG) {
+            return err!(MsError::MissingPermission);
+        }
+        if ms.is_authority_retired(authority_index) {
+            return err!(MsError::AuthorityRetired);
+        }
+        let authority_bump = ms.get_authority_bump(&ms.key(), authority_index, ctx.program_id);
+        ms.open_transaction(ctx.accounts.creator.key())?;
+
+        ms.transaction_index = ms.transaction_index.checked_add(1).ok_or(MsError::TransactionIndexOverflow)?;
+        ctx.accounts.transaction.init(
+            ctx.accounts.creator.key(),
+            ms.key(),
+            ms.transaction_index,
+            *ctx.bumps.get("transaction").unwrap(),
+            authority_index,
+            authority_bump,
+            ms.keys.len(),
+        )?;
+        ctx.accounts.transaction.set_linked_transaction(ctx.accounts.linked_transaction.key())?;
+        emit!(TransactionCreated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            creator: ctx.accounts.creator.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            authority_index,
+            linked_transaction: ctx.accounts.transaction.linked_tx,
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Create,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to set the state of a transaction "active".
+    /// "active" transactions can then be signed off by multisig members
+    pub fn activate_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        // the vote weights of vault transactions have to be snapshotted with activate_with_stake_snapshot
+        if ctx.accounts.multisig.stake_weight_source.is_some() && ctx.accounts.transaction.authority_index != 0 {
+            return err!(MsError::StakeSnapshotRequired);
+        }
+        ctx.accounts.transaction.check_not_expired()?;
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.accounts.creator.key(),
+            PolicyStage::Activate,
+            ctx.remaining_accounts,
+        )?;
+        apply_value_tiers(&ctx.accounts.multisig, &mut ctx.accounts.transaction, ctx.remaining_accounts)?;
+        ctx.accounts.transaction.activate()?;
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Activate,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to set the state of a transaction "active" for multisigs with a stake weight
+    /// source, snapshotting the vote weights of the members from their staking lockers, so that
+    /// locking (or unlocking) tokens afterwards doesn't change the vote on the transaction.
+    /// The locker of every member must be passed, members without one have no weight.
+    /// Internal transactions keep the member weights, so a misconfigured source can be fixed.
+    pub fn activate_with_stake_snapshot<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateWithStakeSnapshot<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let stake_weight_source = ctx.accounts.multisig.stake_weight_source.ok_or(MsError::StakeSnapshotRequired)?;
+        if ctx.accounts.transaction.authority_index == 0 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        ctx.accounts.transaction.check_not_expired()?;
+        let mut weights = Vec::with_capacity(ctx.accounts.multisig.keys.len());
+        for member in ctx.accounts.multisig.keys.iter() {
+            let locker_address = stake_weight_source.locker_address(member);
+            let locker = ctx.remaining_accounts
+                .iter()
+                .find(|a| a.key == &locker_address)
+                .ok_or(MsError::InvalidStakeLocker)?;
+            let cap = ctx.accounts.multisig.get_member_weight(*member);
+            weights.push(MemberWeight { member: *member, weight: stake_weight_source.stake_weight(locker, cap)? });
+        }
+        let stake_snapshot = &mut ctx.accounts.stake_snapshot;
+        stake_snapshot.multisig = ctx.accounts.multisig.key();
+        stake_snapshot.transaction_index = ctx.accounts.transaction.transaction_index;
+        stake_snapshot.weights = weights;
+        stake_snapshot.bump = *ctx.bumps.get("stake_snapshot").unwrap();
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.accounts.creator.key(),
+            PolicyStage::Activate,
+            ctx.remaining_accounts,
+        )?;
+        apply_value_tiers(&ctx.accounts.multisig, &mut ctx.accounts.transaction, ctx.remaining_accounts)?;
+        ctx.accounts.transaction.activate()?;
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.creator.key(),
+            ActivityAction::Activate,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to close the stake snapshot of a transaction once voting on it has ended,
+    /// returning the rent to the creator.
+    pub fn close_stake_snapshot(_ctx: Context<CloseStakeSnapshot>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction to set an expiration on a transaction.
+    /// Transactions must be in the "draft" status, and only the creator can set the expiration.
+    /// Once the expiration has passed the transaction can no longer be activated, approved
+    /// or executed, and anyone can expire it to return the rent to the creator.
+    pub fn set_transaction_expiration(ctx: Context<ActivateTransaction>, expires_at: i64) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_expiration(expires_at)
+    }
+
+    /// Instruction to tag a transaction with a category and a reference, ie. for reporting on
+    /// payroll, grants or config transactions. Transactions must be in the "draft" status, and
+    /// only the creator can set the tag, so it can be set along with the creation and is fixed
+    /// by the time members vote. The tag is at a fixed offset to filter transactions with memcmp.
+    pub fn set_transaction_tag(ctx: Context<ActivateTransaction>, tag: TransactionTag) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_tag(tag)
+    }
+
+    /// Instruction to set (or remove) an oracle price condition on a transaction, ie. execute only
+    /// while SOL/USD is above a floor, so an approved proposal doesn't execute into a crashed market.
+    /// The condition is checked on execution, with the price account in the remaining accounts.
+    /// Transactions must be in the "draft" status, and only the creator can set the condition,
+    /// so it is fixed by the time members vote.
+    pub fn set_execution_condition(ctx: Context<ActivateTransaction>, execution_condition: Option<ExecutionCondition>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_execution_condition(execution_condition)
+    }
+
+    /// Instruction to set (or remove) the governance proposal ratifying a transaction, for multisigs
+    /// requiring the ratification of their token holders. The proposal must have succeeded when the
+    /// transaction is executed, and is passed in the remaining accounts.
+    /// Transactions must be in the "draft" status, and only the creator can set the proposal,
+    /// so the members approve the transaction along with it.
+    pub fn set_ratifying_proposal(ctx: Context<ActivateTransaction>, ratifying_proposal: Option<Pubkey>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_ratifying_proposal(ratifying_proposal)
+    }
+
+    /// Instruction to set the address lookup tables of a transaction.
+    /// Transactions must be in the "draft" status, and only the creator can set the tables.
+    /// Transactions with many unique accounts can then be executed with a versioned
+    /// transaction loading the accounts through these tables.
+    pub fn set_lookup_tables(ctx: Context<SetLookupTables>, lookup_tables: Vec<Pubkey>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_lookup_tables(lookup_tables)
+    }
+
+    /// Instruction to set the co-creators of a transaction, members that can attach
+    /// instructions to it along with the creator while it is a draft, so that complex
+    /// proposals can be assembled together. Only the creator can set them, or activate it.
+    pub fn set_co_creators(ctx: Context<SetCoCreators>, co_creators: Vec<Pubkey>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_co_creators(&ctx.accounts.multisig, co_creators)
+    }
+
+    /// Instruction to set the title and description of a transaction.
+    /// Transactions must be in the "draft" status, and only the creator can set them,
+    /// so the context members approve is bound to the proposal account.
+    pub fn set_transaction_description(ctx: Context<SetTransactionDescription>, title: String, description: String) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.set_description(title, description)
+    }
+
+    /// Instruction to fund a bounty for executing a transaction.
+    /// Transactions must be in the "draft" status, and only the creator can fund the bounty.
+    /// The lamports are held by the transaction account and paid out to the executor,
+    /// and any key can execute a transaction with a bounty.
+    pub fn fund_execution_bounty(ctx: Context<FundExecutionBounty>, amount: u64) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            ctx.accounts.creator.key,
+            &ctx.accounts.transaction.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        ctx.accounts.transaction.add_execution_bounty(amount)
+    }
+
+    /// Instruction to expire a transaction whose expiration has passed.
+    /// Marks the transaction as expired and closes the account, returning the rent to the creator.
+    pub fn expire_transaction(ctx: Context<ExpireTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if !ctx.accounts.transaction.is_expired()? {
+            return err!(MsError::InvalidTransactionState);
+        }
+        ctx.accounts.transaction.set_expired()?;
+        let transaction_index = ctx.accounts.transaction.transaction_index;
+        ctx.accounts.multisig.release_execution(transaction_index)?;
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        Ok(())
+    }
+
+    /// Instruction to attach an instruction to a transaction.
+    /// Transactions must be in the "draft" status, and any
+    /// signer (aside from execution payer) specified in an instruction 
+    /// must match the authority PDA specified during the transaction creation.
+    pub fn add_instruction(
+        ctx: Context<AddInstruction>,
+        incoming_instruction: IncomingInstruction,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let tx = &mut ctx.accounts.transaction;
+        // make sure internal transactions have a matching program id for attached instructions
+        if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        // and that vault instructions only target the programs allowed for the authority
+        ctx.accounts.multisig.check_program_allowed(tx.authority_index, &incoming_instruction.program_id)?;
+        ctx.accounts.multisig.check_instruction_not_denied(&incoming_instruction.program_id, &incoming_instruction.data)?;
+        tx.instruction_index = tx.instruction_index.checked_add(1).ok_or(MsError::InstructionIndexOverflow)?;
+        ctx.accounts.instruction.init(
+            tx.instruction_index,
+            incoming_instruction,
+            *ctx.bumps.get("instruction").unwrap(),
+        )?;
+        emit!(InstructionAdded {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            instruction: ctx.accounts.instruction.key(),
+            instruction_index: ctx.accounts.instruction.instruction_index,
+        });
+        Ok(())
+    }
+
+    /// Instruction to attach an instruction built from a typed action to a vault transaction,
+    /// with the vault of the transaction as the signing authority, so the instruction doesn't
+    /// have to be encoded by hand. The actions are:
+    /// - stake: delegate, deactivate, withdraw, split or merge a stake account whose stake and
+    ///   withdraw authority is the vault
+    /// - vote: authorize a new withdrawer or withdraw from a vote account, withdrawals are
+    ///   checked again when executed to leave the vote account rent exempt
+    /// - idl: set the IDL authority, set the IDL buffer or close the IDL account of a program
+    ///   whose IDL authority is the vault
+    /// - governance: an SPL Governance instruction with the vault as the realm authority,
+    ///   governing token owner or governing token mint authority, ie. to vote in a realm where
+    ///   the vault holds the council tokens. The governance program is passed, as realms run
+    ///   their own deployments
+    /// 
+    /// The same rules as for add_instruction apply.
+    pub fn add_typed_instruction(ctx: Context<AddTypedInstruction>, action: TypedAction) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        action.check()?;
+        let ms_key = ctx.accounts.multisig.key();
+        let tx = &mut ctx.accounts.transaction;
+        let vault = tx.get_authority_address(ctx.program_id)?;
+        let incoming_instruction = action.to_instruction(&vault);
+        ctx.accounts.multisig.check_program_allowed(tx.authority_index, &incoming_instruction.program_id)?;
+        ctx.accounts.multisig.check_instruction_not_denied(&incoming_instruction.program_id, &incoming_instruction.data)?;
+        tx.instruction_index = tx.instruction_index.checked_add(1).ok_or(MsError::InstructionIndexOverflow)?;
+        ctx.accounts.instruction.init(
+            tx.instruction_index,
+            incoming_instruction,
+            *ctx.bumps.get("instruction").unwrap(),
+        )?;
+        emit!(InstructionAdded {
+            multisig: ms_key,
+            transaction: ctx.accounts.transaction.key(),
+            instruction: ctx.accounts.instruction.key(),
+            instruction_index: ctx.accounts.instruction.instruction_index,
+        });
+        Ok(())
+    }
+
+    /// Instruction to remove the last instruction attached to a transaction, returning
+    /// its rent to the creator. Transactions must be in the "draft" status.
+    pub fn remove_instruction(ctx: Context<RemoveInstruction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let tx = &mut ctx.accounts.transaction;
+        tx.instruction_index = tx.instruction_index.checked_sub(1).ok_or(MsError::InstructionIndexOverflow)?;
+        Ok(())
+    }
+
+    /// Instruction to replace an instruction attached to a transaction, with the same
+    /// rules as add_instruction. Transactions must be in the "draft" status.
+    pub fn replace_instruction(
+        ctx: Context<ReplaceInstruction>,
+        incoming_instruction: IncomingInstruction,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        // make sure internal transactions have a matching program id for attached instructions
+        if ctx.accounts.transaction.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        ctx.accounts.multisig.check_program_allowed(ctx.accounts.transaction.authority_index, &incoming_instruction.program_id)?;
+        ctx.accounts.multisig.check_instruction_not_denied(&incoming_instruction.program_id, &incoming_instruction.data)?;
+        let instruction_index = ctx.accounts.instruction.instruction_index;
+        let bump = ctx.accounts.instruction.bump;
+        ctx.accounts.instruction.init(instruction_index, incoming_instruction, bump)
+    }
+
+    /// Instruction to append a chunk of data to an attached instruction, so that instruction
+    /// data bigger than a single Solana transaction can be uploaded in chunks before activation.
+    /// The last chunk should pass the hash of the full data, to check the upload is complete.
+    pub fn append_instruction_data(
+        ctx: Context<AppendInstructionData>,
+        data: Vec<u8>,
+        final_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.instruction.append_data(data, final_hash)?;
+        // the appended data could complete a denied prefix
+        let instruction = &ctx.accounts.instruction;
+        ctx.accounts.multisig.check_instruction_not_denied(&instruction.program_id, &instruction.data)
+    }
+
+    /// Instruction to attach multiple instructions to a transaction at once.
+    /// The instruction accounts are passed as remaining accounts, in the same order
+    /// as the incoming instructions, and the same rules as add_instruction apply.
+    pub fn add_instructions<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddInstructions<'info>>,
+        incoming_instructions: Vec<IncomingInstruction>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if incoming_instructions.is_empty() || incoming_instructions.len() != ctx.remaining_accounts.len() {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let tx_key = ctx.accounts.transaction.key();
+
+        for (incoming_instruction, instruction_info) in incoming_instructions.into_iter().zip(ctx.remaining_accounts.iter()) {
+            let tx = &mut ctx.accounts.transaction;
+            // make sure internal transactions have a matching program id for attached instructions
+            if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+                return err!(MsError::InvalidAuthorityIndex);
+            }
+            ctx.accounts.multisig.check_program_allowed(tx.authority_index, &incoming_instruction.program_id)?;
+            ctx.accounts.multisig.check_instruction_not_denied(&incoming_instruction.program_id, &incoming_instruction.data)?;
+            let instruction_index = tx.instruction_index
+                .checked_add(1)
+                .ok_or(MsError::InstructionIndexOverflow)?;
+            create_instruction_account(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+                instruction_info,
+                tx_key,
+                instruction_index,
+                incoming_instruction,
+            )?;
+            tx.instruction_index = instruction_index;
+            emit!(InstructionAdded {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: tx_key,
+                instruction: instruction_info.key(),
+                instruction_index,
+            });
+        }
+        Ok(())
+    }
+
+    /// Instruction to approve a transaction on behalf of a member.
+    /// The transaction must have an "active" status
+    pub fn approve_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.transaction.check_not_expired()?;
+        let member = ctx.accounts.member.key();
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            member,
+            PolicyStage::Approve,
+            ctx.remaining_accounts,
+        )?;
+        let ready = ctx.accounts.transaction.cast_approval(&ctx.accounts.multisig, member)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: Vote::Approve,
+        });
+        if ready {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            ActivityAction::Approve,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to cast the final approval of a transaction and execute it in the same call,
+    /// so there's no window between the approval and the execution. The approval is cast as by
+    /// approve_transaction, and if the transaction isn't ready to execute yet, that's all it does.
+    /// Otherwise it is executed as by execute_transaction, with the same account list, which fails
+    /// for multisigs with a time lock or veto period, as their transactions can't be executed right away.
+    pub fn approve_and_execute<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveAndExecute<'info>>,
+        account_list: Vec<u8>,
+    ) -> Result<()> {
+        let mut vote_accounts = VoteTransaction {
+            multisig: (*ctx.accounts.multisig).clone(),
+            transaction: ctx.accounts.transaction.clone(),
+            member: ctx.accounts.member.clone(),
+        };
+        approve_transaction(Context::new(
+            ctx.program_id,
+            &mut vote_accounts,
+            ctx.remaining_accounts,
+            ctx.bumps.clone(),
+        ))?;
+        if vote_accounts.transaction.status != MsTransactionStatus::ExecuteReady {
+            *ctx.accounts.multisig = vote_accounts.multisig;
+            ctx.accounts.transaction = vote_accounts.transaction;
+            return Ok(());
+        }
+
+        // the internal instructions of the transaction load the multisig from the account data
+        vote_accounts.multisig.exit(ctx.program_id)?;
+        vote_accounts.transaction.exit(ctx.program_id)?;
+        let mut execute_accounts = ExecuteTransaction {
+            multisig: Box::new(vote_accounts.multisig),
+            transaction: vote_accounts.transaction,
+            member: vote_accounts.member,
+        };
+        execute_transaction(
+            Context::new(
+                ctx.program_id,
+                &mut execute_accounts,
+                ctx.remaining_accounts,
+                ctx.bumps.clone(),
+            ),
+            account_list,
+        )?;
+        ctx.accounts.multisig = execute_accounts.multisig;
+        ctx.accounts.transaction = execute_accounts.transaction;
+        Ok(())
+    }
+
+    /// Instruction to reject a transaction.
+    /// The transaction must have an "active" status.
+    pub fn reject_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.member.key();
+        let rejected = ctx.accounts.transaction.cast_rejection(&ctx.accounts.multisig, member)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: Vote::Reject,
+        });
+        if rejected {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            ActivityAction::Reject,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to relay the approval or rejection of a member who signed the vote offline.
+    /// The preceding instruction must verify the member's ed25519 signature over the signed
+    /// vote message of the transaction, any key can then submit the vote. The message holds the
+    /// vote sequence of the transaction, so the vote has to be signed again after any other vote.
+    /// The transaction must have an "active" status.
+    pub fn submit_signed_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        member: Pubkey,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.check_voter(member)?;
+        // a vote signed before a later vote on the transaction (ie. the member changing their
+        // vote on-chain) is signed over an earlier vote sequence, so it can't be replayed
+        let message = ctx.accounts.transaction.to_signed_vote_message(approve)?;
+        ctx.accounts.verify_signature(member, &message)?;
+
+        cast_relayed_vote(
+            &mut ctx.accounts.multisig,
+            &mut ctx.accounts.transaction,
+            member,
+            approve,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to relay the approval of a member who signed the approval request of the
+    /// transaction offline, ie. on a hardware wallet that displays the attached instructions.
+    /// The attached instruction accounts must come first in the remaining accounts, in order, and
+    /// the preceding instruction must verify the member's ed25519 signature over the approval
+    /// request built from them, so a tampered instruction set fails. Like a signed vote, the
+    /// request holds the vote sequence of the transaction.
+    /// The transaction must have an "active" status.
+    pub fn submit_approval_request<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        member: Pubkey,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.check_voter(member)?;
+        let instruction_count = usize::from(ctx.accounts.transaction.instruction_index);
+        if ctx.remaining_accounts.len() < instruction_count {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let (instruction_infos, remaining_accounts) = ctx.remaining_accounts.split_at(instruction_count);
+        ctx.accounts.verify_approval_request(member, instruction_infos)?;
+
+        cast_relayed_vote(
+            &mut ctx.accounts.multisig,
+            &mut ctx.accounts.transaction,
+            member,
+            true,
+            remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to relay the approvals or rejections of several members who signed the same
+    /// vote offline, ie. to land a whole quorum at once. Each member's ed25519 signature over the
+    /// signed vote message must be verified by its own instruction, directly preceding this one
+    /// and in the order of the members. The members sign the same message, over the vote sequence
+    /// before the batch, so a stale signature fails the whole batch. Votes after the one deciding
+    /// the transaction are ignored.
+    /// The transaction must have an "active" status.
+    pub fn submit_signed_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitSignedVote<'info>>,
+        members: Vec<Pubkey>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if members.is_empty() {
+            return err!(MsError::InvalidSignedVote);
+        }
+        // the signatures are all checked against the vote sequence before the votes advance it
+        let message = ctx.accounts.transaction.to_signed_vote_message(approve)?;
+        ctx.accounts.verify_signatures(&members, &message)?;
+
+        for member in members {
+            ctx.accounts.multisig.check_voter(member)?;
+            let status_changed = cast_relayed_vote(
+                &mut ctx.accounts.multisig,
+                &mut ctx.accounts.transaction,
+                member,
+                approve,
+                ctx.remaining_accounts,
+            )?;
+            if status_changed {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Instruction for a member to delegate their approve/reject votes to another key,
+    /// ie. a hot key while travelling. Doesn't change the membership.
+    pub fn add_delegate(ctx: Context<AddDelegate>, delegate: Pubkey) -> Result<()> {
+        ctx.accounts.delegation.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.member.key(),
+            delegate,
+            *ctx.bumps.get("delegation").unwrap(),
+        )
+    }
+
+    /// Instruction for a member to revoke the delegation of their votes
+    pub fn remove_delegate(_ctx: Context<RemoveDelegate>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction for a delegate to approve or reject a transaction on behalf of a member.
+    /// The vote counts as the member's own vote, and replaces any previous vote of the member.
+    /// The transaction must have an "active" status.
+    pub fn submit_delegated_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitDelegatedVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.delegation.member;
+        ctx.accounts.multisig.check_voter(member)?;
+
+        cast_relayed_vote(
+            &mut ctx.accounts.multisig,
+            &mut ctx.accounts.transaction,
+            member,
+            approve,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction for a member to register a session key, a short-lived key (ie. a browser hot key)
+    /// that can vote on their behalf, restricted to the given actions and until the expiry slot.
+    /// Doesn't change the membership.
+    pub fn add_session_key(ctx: Context<AddSessionKey>, session_key: Pubkey, actions: u8, expires_at_slot: u64) -> Result<()> {
+        ctx.accounts.session.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.member.key(),
+            session_key,
+            actions,
+            expires_at_slot,
+            *ctx.bumps.get("session").unwrap(),
+        )
+    }
+
+    /// Instruction for a member to revoke a session key, or to close it once expired
+    pub fn remove_session_key(_ctx: Context<RemoveSessionKey>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction for a session key to approve or reject a transaction on behalf of a member.
+    /// The vote counts as the member's own vote, and replaces any previous vote of the member.
+    /// The transaction must have an "active" status.
+    pub fn submit_session_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitSessionVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.session.check_vote(approve, ctx.accounts.transaction.authority_index)?;
+        let member = ctx.accounts.session.member;
+        ctx.accounts.multisig.check_voter(member)?;
+
+        cast_relayed_vote(
+            &mut ctx.accounts.multisig,
+            &mut ctx.accounts.transaction,
+            member,
+            approve,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to vote on a transaction with a vote record, for multisigs with vote
+    /// records enabled. The vote is tallied on the transaction with the weight of the member,
+    /// or the snapshotted stake weight for vault transactions with a stake weight source.
+    /// The transaction must have an "active" status.
+    pub fn create_vote_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if approve {
+            ctx.accounts.transaction.check_not_expired()?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                ctx.accounts.member.key(),
+                PolicyStage::Approve,
+                ctx.remaining_accounts,
+            )?;
+        }
+        let member = ctx.accounts.member.key();
+        let weight = member_vote_weight(&ctx.accounts.multisig, &ctx.accounts.transaction, member, ctx.remaining_accounts)?;
+        ctx.accounts.vote_record.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.transaction_index,
+            member,
+            *ctx.bumps.get("vote_record").unwrap(),
+        )?;
+        ctx.accounts.vote_record.set_vote(approve, weight)?;
+        let decided = ctx.accounts.transaction.tally_vote(&ctx.accounts.multisig, None, approve, weight)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: if approve { Vote::Approve } else { Vote::Reject },
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction for a member of a page of additional members to vote on a transaction with a
+    /// vote record. Paged members vote with a weight of 1.
+    /// The transaction must have an "active" status.
+    pub fn create_paged_vote_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePagedVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.member.key();
+        if approve {
+            ctx.accounts.transaction.check_not_expired()?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                member,
+                PolicyStage::Approve,
+                ctx.remaining_accounts,
+            )?;
+        }
+        ctx.accounts.vote_record.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.transaction_index,
+            member,
+            *ctx.bumps.get("vote_record").unwrap(),
+        )?;
+        ctx.accounts.vote_record.set_vote(approve, 1)?;
+        let decided = ctx.accounts.transaction.tally_vote(&ctx.accounts.multisig, None, approve, 1)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: if approve { Vote::Approve } else { Vote::Reject },
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction for a member of the set of the member root to vote on a transaction with a vote
+    /// record, along with the inclusion proof of its key. Members of the root vote with a weight of 1.
+    /// The transaction must have an "active" status.
+    pub fn create_proof_vote_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProofVoteRecord<'info>>,
+        approve: bool,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let member = ctx.accounts.member.key();
+        if !ctx.accounts.multisig.is_root_member(member, &proof) {
+            return err!(MsError::InvalidMemberProof);
+        }
+        if approve {
+            ctx.accounts.transaction.check_not_expired()?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                member,
+                PolicyStage::Approve,
+                ctx.remaining_accounts,
+            )?;
+        }
+        ctx.accounts.vote_record.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.transaction_index,
+            member,
+            *ctx.bumps.get("vote_record").unwrap(),
+        )?;
+        ctx.accounts.vote_record.set_vote(approve, 1)?;
+        let decided = ctx.accounts.transaction.tally_vote(&ctx.accounts.multisig, None, approve, 1)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: if approve { Vote::Approve } else { Vote::Reject },
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction for a holder of a membership token to vote on a transaction with a token vote
+    /// record, for multisigs with vote records enabled and a membership gate. Token votes are
+    /// tallied apart from the member weights, against the token threshold of the gate, and can't
+    /// be changed. Each holder of a token of the mint, or each NFT of the collection, votes once.
+    /// Token votes only decide vault transactions, along with the members.
+    /// The transaction must have an "active" status.
+    pub fn create_token_vote_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTokenVoteRecord<'info>>,
+        voting_key: Pubkey,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let voter = ctx.accounts.voter.key();
+        let membership_gate = ctx.accounts.multisig.membership_gate.ok_or(MsError::InvalidMembershipToken)?;
+        if membership_gate.voting_key(voter, &ctx.accounts.token_account, ctx.remaining_accounts)? != voting_key {
+            return err!(MsError::InvalidMembershipToken);
+        }
+        if approve {
+            ctx.accounts.transaction.check_not_expired()?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                voter,
+                PolicyStage::Approve,
+                ctx.remaining_accounts,
+            )?;
+        }
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.multisig = ctx.accounts.multisig.key();
+        vote_record.transaction_index = ctx.accounts.transaction.transaction_index;
+        vote_record.voting_key = voting_key;
+        vote_record.voter = voter;
+        vote_record.approve = approve;
+        vote_record.bump = *ctx.bumps.get("vote_record").unwrap();
+        let decided = ctx.accounts.transaction.tally_token_vote(&ctx.accounts.multisig, approve)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: voter,
+            vote: if approve { Vote::Approve } else { Vote::Reject },
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            voter,
+            if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to close a token vote record once voting on the transaction has ended,
+    /// returning the rent to the voter.
+    pub fn close_token_vote_record(_ctx: Context<CloseTokenVoteRecord>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction to change the vote of a vote record, replacing the previous vote in the tallies.
+    /// The transaction must have an "active" status.
+    pub fn change_vote_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeVoteRecord<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if approve {
+            ctx.accounts.transaction.check_not_expired()?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                ctx.accounts.member.key(),
+                PolicyStage::Approve,
+                ctx.remaining_accounts,
+            )?;
+        }
+        let member = ctx.accounts.member.key();
+        let weight = member_vote_weight(&ctx.accounts.multisig, &ctx.accounts.transaction, member, ctx.remaining_accounts)?;
+        let decided = ctx.accounts.transaction.tally_vote(
+            &ctx.accounts.multisig,
+            Some(&ctx.accounts.vote_record),
+            approve,
+            weight,
+        )?;
+        ctx.accounts.vote_record.set_vote(approve, weight)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote: if approve { Vote::Approve } else { Vote::Reject },
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            member,
+            if approve { ActivityAction::Approve } else { ActivityAction::Reject },
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to close a vote record once the transaction is no longer active,
+    /// returning the rent to the member.
+    pub fn close_vote_record(_ctx: Context<CloseVoteRecord>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction for a member to record a short memo (or the hash of a memo) with their vote,
+    /// ie. the reason for rejecting a transaction, so it is part of the audit trail. The member
+    /// must have cast the vote (approve, reject, cancel or abstain), and can record one memo per vote.
+    pub fn add_vote_memo(ctx: Context<AddVoteMemo>, vote: Vote, memo: Vec<u8>) -> Result<()> {
+        let member = ctx.accounts.member.key();
+        let member_index = ctx.accounts.multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        if !ctx.accounts.transaction.has_cast(vote, member_index) {
+            return err!(MsError::VoteNotCast);
+        }
+        ctx.accounts.vote_memo.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.transaction_index,
+            member,
+            vote,
+            memo.clone(),
+            *ctx.bumps.get("vote_memo").unwrap(),
+        )?;
+        emit!(VoteMemoAdded {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member,
+            vote,
+            memo,
+        });
+        Ok(())
+    }
+
+    /// Instruction to formally abstain from a transaction.
+    /// The transaction must have an "active" status. Abstaining replaces a previous
+    /// approval or rejection, and doesn't count towards either, but counts towards the quorum.
+    /// Multisigs voting with vote records can't abstain, as a vote record holds an approval or rejection.
+    pub fn abstain_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        // abstentions count towards the quorum, so they can decide the transaction
+        let decided = ctx.accounts.transaction.cast_abstention(&ctx.accounts.multisig, ctx.accounts.member.key())?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            vote: Vote::Abstain,
+        });
+        if decided {
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        Ok(())
+    }
+
+    /// Instruction to cancel a transaction.
+    /// Transactions must be in the "executeReady" status.
+    /// Transaction will only be cancelled if the number of
+    /// cancellations reaches the threshold. A cancelled
+    /// transaction will no longer be able to be executed.
+    /// A cancellation replaces the approval of the member, like any change of vote.
+    pub fn cancel_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let cancelled_weight = ctx.accounts.transaction.cast_cancellation(&ctx.accounts.multisig, ctx.accounts.member.key())?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            vote: Vote::Cancel,
+        });
+
+        // if the weight of current signers reaches threshold, mark the transaction as "cancelled"
+        let threshold = ctx.accounts.transaction.vote_threshold(&ctx.accounts.multisig);
+        if cancelled_weight >= usize::from(threshold) {
+            ctx.accounts.transaction.set_cancelled()?;
+            // a partially executed transaction no longer holds off the others
+            let transaction_index = ctx.accounts.transaction.transaction_index;
+            ctx.accounts.multisig.release_execution(transaction_index)?;
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.member.key(),
+            ActivityAction::Cancel,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to veto a transaction.
+    /// Transactions must be in the "executeReady" status and within the veto period
+    /// of the multisig. A single member with the veto permission is enough to cancel it.
+    pub fn veto_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if !ctx.accounts.multisig.has_permission(ctx.accounts.member.key(), Permissions::VETO) {
+            return err!(MsError::MissingPermission);
+        }
+        if !ctx.accounts.transaction.in_veto_period(ctx.accounts.multisig.veto_period)? {
+            return err!(MsError::VetoPeriodElapsed);
+        }
+        ctx.accounts.transaction.set_cancelled()?;
+        let transaction_index = ctx.accounts.transaction.transaction_index;
+        ctx.accounts.multisig.release_execution(transaction_index)?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.member.key(),
+            vote: Vote::Veto,
+        });
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        Ok(())
+    }
+
+    /// Instruction for a key of the veto council to veto a transaction. The council can't approve,
+    /// but once its vetoes reach the veto council threshold the transaction is rejected, even after
+    /// it was approved. Transactions must be in the "active" or "executeReady" status, recurring
+    /// transactions are vetoed with council_veto_recurring_transaction.
+    pub fn council_veto_transaction(ctx: Context<CouncilVetoTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let rejected = ctx.accounts.transaction.council_veto(&ctx.accounts.multisig, ctx.accounts.council_member.key())?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.council_member.key(),
+            vote: Vote::Veto,
+        });
+        if rejected {
+            ctx.accounts.transaction.set_rejected()?;
+            let transaction_index = ctx.accounts.transaction.transaction_index;
+            ctx.accounts.multisig.release_execution(transaction_index)?;
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, transaction_index, &ctx.accounts.transaction.status)?;
+        }
+        Ok(())
+    }
+
+    /// Instruction for a key of the veto council to veto a recurring transaction. Once the vetoes
+    /// reach the veto council threshold the transaction is rejected and its schedule ends, the rent
+    /// of the recurring transaction being returned to the creator of the transaction.
+    pub fn council_veto_recurring_transaction(ctx: Context<CouncilVetoRecurringTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let rejected = ctx.accounts.transaction.council_veto(&ctx.accounts.multisig, ctx.accounts.council_member.key())?;
+        emit!(VoteCast {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            member: ctx.accounts.council_member.key(),
+            vote: Vote::Veto,
+        });
+        if rejected {
+            ctx.accounts.transaction.set_rejected()?;
+            emit!(ConfigChanged {
+                multisig: ctx.accounts.multisig.key(),
+                action: ConfigAction::RemoveRecurringTransaction { recurring_transaction: ctx.accounts.recurring_transaction.key() },
+            });
+            emit!(TransactionStatusChanged {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+            ctx.accounts.recurring_transaction.close(ctx.accounts.creator.to_account_info())?;
+        }
         Ok(())
     }
 
@@ -371,22 +2553,967 @@ pub mod squads_mpl {
     /// the unique indexed accounts in the following manner: 
     /// [ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]
     /// 
+    /// The account list can also be left empty, in which case the remaining accounts must start
+    /// with the instruction accounts in order, followed by the accounts of the instructions
+    /// deduplicated (in any order), and each instruction key is resolved from them.
+    /// 
     /// Refer to the README for more information on how to construct the account list.
     pub fn execute_transaction<'info>(
         ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
         account_list: Vec<u8>,
     ) -> Result<()> {
-        // check that we are provided at least one instruction
-        if ctx.accounts.transaction.instruction_index < 1 {
-            // if no instructions were found, mark it as executed and move on
+        // the review window (and veto period) must have passed since the transaction was approved
+        ctx.accounts.transaction.check_time_lock(ctx.accounts.multisig.execution_delay(ctx.accounts.transaction.authority_index))?;
+        ctx.accounts.transaction.check_not_expired()?;
+        // the oracle price condition (if any) must hold
+        ctx.accounts.transaction.check_execution_condition(ctx.remaining_accounts)?;
+        // the token holders must have ratified the transaction, if the multisig requires it
+        ctx.accounts.multisig.check_ratification(ctx.accounts.transaction.ratifying_proposal, ctx.remaining_accounts)?;
+        // the lookup tables (if any) must be passed along with the remaining accounts
+        ctx.accounts.transaction.check_lookup_tables(ctx.remaining_accounts)?;
+
+        // check that we are provided at least one instruction
+        if ctx.accounts.transaction.instruction_index < 1 {
+            // if no instructions were found, mark it as executed and move on
+            ctx.accounts.transaction.set_executed()?;
+            ctx.accounts.multisig.record_execution()?;
+            ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+            emit!(TransactionExecuted {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                executor: ctx.accounts.member.key(),
+            });
+            record_activity(
+                &ctx.accounts.multisig,
+                ctx.accounts.member.key(),
+                ActivityAction::Execute,
+                ctx.accounts.transaction.transaction_index,
+                ctx.remaining_accounts,
+            )?;
+            advance_seq(&mut ctx.accounts.multisig);
+            return ctx.accounts.pay_execution_bounty();
+        }
+
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.accounts.member.key(),
+            PolicyStage::Execute,
+            ctx.remaining_accounts,
+        )?;
+        // the execution hook (if any) can abort the execution
+        invoke_execution_hook(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+            ExecutionHookStage::PreExecute,
+        )?;
+
+        // use for derivation for the authority
+        let ms_key = ctx.accounts.multisig.key();
+
+        // default authority seeds to auth index > 0
+        let authority_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &ctx.accounts.transaction.authority_index.to_le_bytes(),
+            b"authority",
+            &[ctx.accounts.transaction.authority_bump],
+        ];
+        // if auth index < 1
+        let ms_authority_seeds = [
+            b"squad",
+            ctx.accounts.multisig.create_key.as_ref(),
+            b"multisig",
+            &[ctx.accounts.multisig.bump],
+        ];
+
+        // an empty account list is resolved from the deduplicated remaining accounts
+        let account_list = if account_list.is_empty() {
+            resolve_account_list(ctx.remaining_accounts, ctx.accounts.transaction.instruction_index)?
+        } else {
+            account_list
+        };
+
+        // unroll account infos from account_list, without cloning them up front
+        let ix_iter = &mut account_list
+            .iter()
+            .map(|&i| &ctx.remaining_accounts[usize::from(i)]);
+
+        (1..=ctx.accounts.transaction.instruction_index).try_for_each(|i: u8| {
+            // each ix block starts with the ms_ix account
+            let ms_ix_account: &AccountInfo = next_account_info(ix_iter)?;
+
+            // if the attached instruction doesn't belong to this program, throw error
+            if ms_ix_account.owner != ctx.program_id {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+
+            // get the instruction account pda - seeded from transaction account + the transaction accounts instruction index
+            let (ix_pda, _) = pda::find_ix_address(&ctx.accounts.transaction.key(), i);
+            // check the instruction account key maches the derived pda
+            if &ix_pda != ms_ix_account.key {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+
+            // read the msIx in place, rather than deserializing it into heap vectors
+            let ix_account_data = ms_ix_account.try_borrow_data()?;
+            let ms_ix = MsInstructionView::load(&ix_account_data)?;
+
+            // get the instructions program account
+            let ix_program_info: &AccountInfo = next_account_info(ix_iter)?;
+            // check that it matches the submitted account
+            if &ms_ix.program_id != ix_program_info.key {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+
+            // the instruction account vec, with the program account first
+            let mut ix_account_infos: Vec<AccountInfo> = Vec::with_capacity(ms_ix.keys_len() + 1);
+            ix_account_infos.push(ix_program_info.clone());
+            let mut ix_account_metas: Vec<AccountMeta> = Vec::with_capacity(ms_ix.keys_len());
+
+            // loop through the provided remaining accounts
+            for ix_key in ms_ix.account_metas() {
+                let ix_account_info = next_account_info(ix_iter)?;
+
+                // check that the ix account keys match the submitted account keys
+                if *ix_account_info.key != ix_key.pubkey {
+                    return err!(MsError::InvalidInstructionAccount);
+                }
+
+                ix_account_infos.push(ix_account_info.clone());
+                ix_account_metas.push(ix_key);
+            }
+
+            // the denied instructions may have changed since the instruction was attached,
+            // checked on the borrowed data before anything is copied out of it
+            ctx.accounts.multisig.check_instruction_not_denied(&ms_ix.program_id, ms_ix.data())?;
+
+            // create the instruction to invoke from the saved ms ix account
+            let ix: Instruction = ms_ix.to_instruction_with(ix_account_metas);
+            drop(ix_account_data);
+
+            VoteAction::check_withdraw(&ix, &ix_account_infos)?;
+
+            // execute the ix
+            match ctx.accounts.transaction.authority_index {
+                // if its a 0 authority, use the MS pda seeds
+                0 => {
+                    if &ix.program_id != ctx.program_id {
+                        return err!(MsError::InvalidAuthorityIndex);
+                    }
+                    // Prevent recursive call on execute_transaction/instruction that could create issues
+                    let execute_transaction = Vec::from_hex("e7ad315beb184413").unwrap();
+                    let execute_instruction = Vec::from_hex("301228284b4a936e").unwrap();
+                    if Some(execute_transaction.as_slice()) == ix.data.get(0..8) ||
+                        Some(execute_instruction.as_slice()) == ix.data.get(0..8) {
+                        return err!(MsError::InvalidAuthorityIndex);
+                    }
+
+                    invoke_signed(&ix, &ix_account_infos, &[&ms_authority_seeds])?;
+                }
+                // if its > 1 authority, use the derived authority seeds
+                1.. => {
+                    // the allowlist may have changed since the instruction was attached
+                    ctx.accounts.multisig.check_program_allowed(ctx.accounts.transaction.authority_index, &ix.program_id)?;
+                    invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
+                }
+            };
+            Ok(())
+        })?;
+        invoke_execution_hook(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+            ExecutionHookStage::PostExecute,
+        )?;
+        // set the executed index
+        ctx.accounts.transaction.executed_index = ctx.accounts.transaction.instruction_index;
+        // mark it as executed
+        ctx.accounts.transaction.set_executed()?;
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+        });
+        // reload any multisig changes
+        ctx.accounts.multisig.reload()?;
+        ctx.accounts.multisig.record_execution()?;
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.member.key(),
+            ActivityAction::Execute,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        // after the reload, so the changes of internal instructions are sequenced first
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.pay_execution_bounty()
+    }
+
+    /// Instruction to sequentially execute attached instructions.
+    /// Instructions executed in this matter must be executed in order,
+    /// this may be helpful for processing large batch transfers.
+    /// This instruction can only be used for transactions with an authority
+    /// index of 1 or greater. Once the first instruction is executed, other non-internal
+    /// transactions of the multisig can't be executed until this one is finalized with
+    /// finalize_execution (or cancelled/expired).
+    /// 
+    /// NOTE - do not use this instruction if there is not total clarity around
+    /// potential side effects, as this instruction implies that the approved
+    /// transaction will be executed partially, and potentially spread out over
+    /// a period of time. This could introduce problems with state and failed
+    /// transactions. For example: a program invoked in one of these instructions
+    /// may be upgraded between executions and potentially leave one of the 
+    /// necessary accounts in an invalid state.
+    pub fn execute_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteInstruction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        // the review window (and veto period) must have passed since the transaction was approved
+        ctx.accounts.transaction.check_time_lock(ctx.accounts.multisig.execution_delay(ctx.accounts.transaction.authority_index))?;
+        ctx.accounts.transaction.check_not_expired()?;
+        // the lookup tables (if any) must be passed along with the remaining accounts
+        ctx.accounts.transaction.check_lookup_tables(ctx.remaining_accounts)?;
+        // the policy (if any) and execution hook (if any) are consulted, and the execution condition
+        // and ratification (if any) checked, before the first instruction only
+        if ctx.accounts.transaction.executed_index == 0 {
+            ctx.accounts.transaction.check_execution_condition(ctx.remaining_accounts)?;
+            ctx.accounts.multisig.check_ratification(ctx.accounts.transaction.ratifying_proposal, ctx.remaining_accounts)?;
+            consult_policy(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                ctx.accounts.member.key(),
+                PolicyStage::Execute,
+                ctx.remaining_accounts,
+            )?;
+            invoke_execution_hook(
+                &ctx.accounts.multisig,
+                &ctx.accounts.transaction.to_account_info(),
+                ctx.accounts.transaction.authority_index,
+                ctx.remaining_accounts,
+                ExecutionHookStage::PreExecute,
+            )?;
+        }
+
+        let ms_key = &ctx.accounts.multisig.key();
+        let tx = &mut ctx.accounts.transaction;
+
+        // To prevent potential failure with the Squad account auth 0 can't be executed in a specific instruction
+        if tx.authority_index == 0 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+
+        // setup the authority seeds
+        let authority_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &tx.authority_index.to_le_bytes(),
+            b"authority",
+            &[tx.authority_bump],
+        ];
+
+        // read the msIx header and slices in place, so the size of the instruction data
+        // isn't bound by the heap needed to deserialize the whole account
+        let ix_account_data = ctx.accounts.instruction.try_borrow_data()?;
+        let ms_ix = MsInstructionView::load(&ix_account_data)?;
+        // it should be the next expected instruction account to be executed
+        if ms_ix.instruction_index != tx.executed_index.checked_add(1).unwrap() {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        // collect the accounts needed from remaining accounts (order matters)
+        let mut ix_account_infos: Vec<AccountInfo> = Vec::with_capacity(ms_ix.keys_len());
+        let mut ix_account_metas: Vec<AccountMeta> = Vec::with_capacity(ms_ix.keys_len());
+        let ix_account_iter = &mut ctx.remaining_accounts.iter();
+        // the first account in the submitted list should be the program
+        let ix_program_account = next_account_info(ix_account_iter)?;
+        // check that the programs match
+        if ix_program_account.key != &ms_ix.program_id {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        // loop through the provided remaining accounts - check they match the saved instruction accounts
+        for ix_key in ms_ix.account_metas() {
+            let ix_account_info = next_account_info(ix_account_iter)?;
+            // check that the ix account keys match the submitted account keys
+            if ix_account_info.key != &ix_key.pubkey {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            ix_account_infos.push(ix_account_info.clone());
+            ix_account_metas.push(ix_key);
+        }
+
+        ctx.accounts.multisig.check_program_allowed(tx.authority_index, &ms_ix.program_id)?;
+        ctx.accounts.multisig.check_instruction_not_denied(&ms_ix.program_id, ms_ix.data())?;
+
+        // map the saved instruction account data to the instruction to be invoked
+        let ix: Instruction = ms_ix.to_instruction_with(ix_account_metas);
+        let instruction_index = ms_ix.instruction_index;
+        drop(ix_account_data);
+        VoteAction::check_withdraw(&ix, &ix_account_infos)?;
+
+        invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
+
+        // set the executed index to match
+        tx.executed_index = instruction_index;
+        // hold off other transactions until this one is finalized
+        let transaction_index = tx.transaction_index;
+        ctx.accounts.multisig.start_execution(transaction_index)
+    }
+
+    /// Instruction to finalize a transaction executed instruction by instruction, once
+    /// all of its instructions have been executed. Marks the transaction as executed, and
+    /// lets other transactions execute again. The execution hook (if any) must be passed
+    /// in the remaining accounts.
+    pub fn finalize_execution<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeExecution<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let transaction_index = ctx.accounts.transaction.transaction_index;
+        ctx.accounts.multisig.release_execution(transaction_index)?;
+        invoke_execution_hook(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+            ExecutionHookStage::PostExecute,
+        )?;
+        ctx.accounts.multisig.record_execution()?;
+        ctx.accounts.transaction.set_executed()?;
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.member.key(),
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.member.key(),
+            ActivityAction::Execute,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    /// Instruction to close the instruction accounts of an executed transaction.
+    /// The instruction accounts are passed as remaining accounts, and their rent
+    /// is returned to the creator of the transaction. Anyone can close them.
+    pub fn close_executed_instructions<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExecutedInstructions<'info>>,
+    ) -> Result<()> {
+        if ctx.remaining_accounts.is_empty() {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let tx_key = ctx.accounts.transaction.key();
+        let creator_info = ctx.accounts.creator.to_account_info();
+        ctx.remaining_accounts
+            .iter()
+            .try_for_each(|instruction_info| close_instruction_account(&creator_info, instruction_info, tx_key))
+    }
+
+    /// Instruction for the creator to close their transaction while it is still a draft (so no
+    /// one has voted on it), ie. an abandoned proposal. All of its instruction accounts must be
+    /// passed as remaining accounts, they're closed along with the transaction and the rent is
+    /// returned to the creator.
+    pub fn close_draft_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseDraftTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if ctx.remaining_accounts.len() != usize::from(ctx.accounts.transaction.instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let tx_key = ctx.accounts.transaction.key();
+        let creator_info = ctx.accounts.creator.to_account_info();
+        ctx.remaining_accounts
+            .iter()
+            .try_for_each(|instruction_info| close_instruction_account(&creator_info, instruction_info, tx_key))?;
+        // the abandoned draft is done with as if cancelled
+        ctx.accounts.transaction.set_cancelled()?;
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)
+    }
+
+    /// Instruction to close a stale transaction, one deprecated by a change of the multisig
+    /// that wasn't executed, reclaiming the rent for its creator. Anyone can close them.
+    /// All of its instruction accounts must be passed as remaining accounts, they're closed
+    /// along with the transaction.
+    pub fn close_stale_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseStaleTransaction<'info>>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if ctx.remaining_accounts.len() != usize::from(ctx.accounts.transaction.instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let tx_key = ctx.accounts.transaction.key();
+        let creator_info = ctx.accounts.creator.to_account_info();
+        ctx.remaining_accounts
+            .iter()
+            .try_for_each(|instruction_info| close_instruction_account(&creator_info, instruction_info, tx_key))
+    }
+
+    /// The instruction to add a spending limit to an authority (vault).
+    /// The designated member can then spend up to the amount of the mint
+    /// per period from the vault without a proposal. The native SOL limit uses
+    /// the default pubkey as the mint. A period of 0 makes the amount a one-time budget.
+    pub fn add_spending_limit(
+        ctx: Context<AddSpendingLimit>,
+        authority_index: u32,
+        mint: Pubkey,
+        member: Pubkey,
+        amount: u64,
+        period: i64,
+    ) -> Result<()> {
+        ctx.accounts.spending_limit.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            mint,
+            member,
+            amount,
+            period,
+            *ctx.bumps.get("spending_limit").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddSpendingLimit { spending_limit: ctx.accounts.spending_limit.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove a spending limit, returning the rent to the rent collector
+    pub fn remove_spending_limit(ctx: Context<RemoveSpendingLimit>) -> Result<()> {
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveSpendingLimit { spending_limit: ctx.accounts.spending_limit.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to add the inactivity recovery of the multisig. If no transaction is
+    /// executed for the number of epochs, the recovery key can replace all of the members.
+    pub fn add_recovery(ctx: Context<AddRecovery>, recovery_key: Pubkey, inactivity_epochs: u64) -> Result<()> {
+        ctx.accounts.recovery.init(
+            ctx.accounts.multisig.key(),
+            recovery_key,
+            inactivity_epochs,
+            *ctx.bumps.get("recovery").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddRecovery { recovery: ctx.accounts.recovery.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove the inactivity recovery, returning the rent to the rent collector
+    pub fn remove_recovery(ctx: Context<RemoveRecovery>) -> Result<()> {
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveRecovery { recovery: ctx.accounts.recovery.key() },
+        });
+        Ok(())
+    }
+
+    /// Instruction for the recovery key to replace the members and threshold of a multisig
+    /// that hasn't executed a transaction for the inactivity epochs of its recovery.
+    /// Reallocates space if neccessary, paid by the recovery key.
+    pub fn recover(ctx: Context<Recover>, members: Vec<Pubkey>, threshold: u16) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.recovery.check_due(ctx.accounts.multisig.last_execution_epoch)?;
+        ctx.accounts.multisig.recover(members, threshold)?;
+        emit!(MultisigRecovered {
+            multisig: ctx.accounts.multisig.key(),
+            recovery_key: ctx.accounts.recovery_key.key(),
+            threshold,
+            members: ctx.accounts.multisig.keys.clone(),
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to set the recovery set of the multisig, keys that can together replace
+    /// a single lost member key after the recovery delay, but can't change anything else.
+    /// An empty set with a threshold of 0 disables member recovery.
+    pub fn set_recovery_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        recovery_keys: Vec<Pubkey>,
+        recovery_threshold: u16,
+        recovery_delay: u32,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(32 * recovery_keys.len(), ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_recovery_set(recovery_keys, recovery_threshold, recovery_delay)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetRecoverySet {
+                recovery_keys: ctx.accounts.multisig.recovery_keys.clone(),
+                recovery_threshold,
+                recovery_delay,
+            },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set the veto council of the multisig, keys apart from the members that
+    /// can together reject any transaction before it is executed, but can't approve (nor spend).
+    /// An empty council with a threshold of 0 removes it.
+    pub fn set_veto_council<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        veto_council: Vec<Pubkey>,
+        veto_council_threshold: u16,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(32 * veto_council.len(), ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_veto_council(veto_council, veto_council_threshold)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetVetoCouncil {
+                veto_council: ctx.accounts.multisig.veto_council.clone(),
+                veto_council_threshold,
+            },
+        });
+        Ok(())
+    }
+
+    /// Instruction for a key of the recovery set to propose replacing a lost member key,
+    /// which counts as its approval.
+    pub fn propose_member_recovery(ctx: Context<ProposeMemberRecovery>, old_member: Pubkey, new_member: Pubkey) -> Result<()> {
+        if ctx.accounts.multisig.is_member(old_member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if ctx.accounts.multisig.is_member(new_member).is_some() {
+            return err!(MsError::DuplicateMember);
+        }
+        ctx.accounts.member_recovery.init(
+            ctx.accounts.multisig.key(),
+            old_member,
+            new_member,
+            ctx.accounts.recovery_key.key(),
+            *ctx.bumps.get("member_recovery").unwrap(),
+        )?;
+        ctx.accounts.member_recovery.approve(&ctx.accounts.multisig, ctx.accounts.recovery_key.key())
+    }
+
+    /// Instruction for a key of the recovery set to approve a member recovery
+    pub fn approve_member_recovery(ctx: Context<ApproveMemberRecovery>) -> Result<()> {
+        ctx.accounts.member_recovery.approve(&ctx.accounts.multisig, ctx.accounts.recovery_key.key())
+    }
+
+    /// Instruction to execute an approved member recovery once the recovery delay has passed,
+    /// which anyone can call. The rent of the recovery is returned to the proposer.
+    pub fn execute_member_recovery(ctx: Context<ExecuteMemberRecovery>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.member_recovery.check_ready(&ctx.accounts.multisig)?;
+        let old_member = ctx.accounts.member_recovery.old_member;
+        let new_member = ctx.accounts.member_recovery.new_member;
+        ctx.accounts.multisig.replace_member(old_member, new_member)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::ReplaceMember { old_member, new_member },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction for the old member to cancel a recovery of their key, proving it isn't lost.
+    /// The rent of the recovery is returned to the proposer.
+    pub fn cancel_member_recovery(_ctx: Context<CancelMemberRecovery>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction to move funds out of a vault under a spending limit.
+    /// Only the member designated in the spending limit can spend, and the amount
+    /// counts towards the limit of the current period.
+    pub fn spend_from_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, SpendFromVault<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.spending_limit.spend(amount)?;
+        emit!(SpendingLimitUsed {
+            multisig: ctx.accounts.multisig.key(),
+            spending_limit: ctx.accounts.spending_limit.key(),
+            member: ctx.accounts.member.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+        });
+
+        let ms_key = ctx.accounts.multisig.key();
+        let authority_index = ctx.accounts.spending_limit.authority_index;
+        let vault_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority",
+            &[*ctx.bumps.get("vault").unwrap()],
+        ];
+
+        transfer_from_vault(
+            &ctx.accounts.vault,
+            &ctx.accounts.destination,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.accounts.spending_limit.mint,
+            amount,
+            &vault_seeds,
+        )
+    }
+
+    /// Instruction to create the associated token account of a vault for a mint, so the vault
+    /// can receive a new token without a proposal. Anyone can call it, paying the rent.
+    pub fn create_vault_ata(ctx: Context<CreateVaultAta>, _authority_index: u32) -> Result<()> {
+        ctx.accounts.create_vault_ata()
+    }
+
+    /// The instruction to add an allowance from an authority (vault). The key, which doesn't
+    /// have to be a member, can then pull up to the cap of the mint from the vault until the
+    /// optional expiration, ie. an expense account. The native SOL allowance uses the default pubkey as the mint.
+    pub fn add_allowance(
+        ctx: Context<AddAllowance>,
+        authority_index: u32,
+        mint: Pubkey,
+        spender: Pubkey,
+        cap: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.allowance.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            mint,
+            spender,
+            cap,
+            expires_at,
+            *ctx.bumps.get("allowance").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddAllowance { allowance: ctx.accounts.allowance.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove an allowance, returning the rent to the rent collector
+    pub fn remove_allowance(ctx: Context<RemoveAllowance>) -> Result<()> {
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveAllowance { allowance: ctx.accounts.allowance.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a policy config, registering an external policy program that
+    /// is consulted when vault transactions are activated, approved and/or executed (per the
+    /// stages mask), and can reject them by failing. A multisig has at most one policy config.
+    pub fn add_policy(ctx: Context<AddPolicy>, policy_program: Pubkey, stages: u8) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.policy_config.init(
+            ctx.accounts.multisig.key(),
+            policy_program,
+            stages,
+            *ctx.bumps.get("policy_config").unwrap(),
+        )?;
+        ctx.accounts.multisig.policy_enabled = true;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddPolicy {
+                policy_config: ctx.accounts.policy_config.key(),
+                policy_program,
+                stages,
+            },
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove the policy config, returning the rent to the rent collector
+    pub fn remove_policy(ctx: Context<RemovePolicy>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.policy_enabled = false;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemovePolicy { policy_config: ctx.accounts.policy_config.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to create the activity log, a ring buffer of the most recent transaction
+    /// lifecycle actions (create, activate, approve, reject, cancel and execute) of the multisig.
+    /// Once created, the log must be passed in the remaining accounts of these instructions.
+    pub fn create_activity_log(ctx: Context<CreateActivityLog>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.activity_log.init(
+            ctx.accounts.multisig.key(),
+            *ctx.bumps.get("activity_log").unwrap(),
+        )?;
+        ctx.accounts.multisig.activity_log_enabled = true;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::CreateActivityLog { activity_log: ctx.accounts.activity_log.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to close the activity log, returning the rent to the rent collector
+    pub fn close_activity_log(ctx: Context<CloseActivityLog>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.activity_log_enabled = false;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::CloseActivityLog { activity_log: ctx.accounts.activity_log.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to create the next page of additional members, for rosters that don't fit
+    /// in the multisig account. Paged members vote with vote records, which must be enabled.
+    pub fn create_member_page(ctx: Context<CreateMemberPage>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        let page_index = ctx.accounts.multisig.add_member_page()?;
+        ctx.accounts.members_page.init(
+            ctx.accounts.multisig.key(),
+            page_index,
+            *ctx.bumps.get("members_page").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::CreateMemberPage { members_page: ctx.accounts.members_page.key(), page_index },
+        });
+        Ok(())
+    }
+
+    /// The instruction to set (or remove) the merkle root of an additional member set, for signer
+    /// sets too large for member pages. Its members vote with vote records and an inclusion proof.
+    /// The member count of the set can't be checked on-chain, the members approving the root vouch for it.
+    pub fn set_member_root(ctx: Context<MsAuth>, member_root: Option<[u8; 32]>, member_count: u32) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.multisig.set_member_root(member_root, member_count)?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMemberRoot { member_root, member_count },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to set (or remove) the governance whose token holders must ratify every
+    /// transaction, so that the members can only execute what a proposal of the governance passed.
+    /// Internal transactions are held to it as well, so the requirement can only be lifted with
+    /// the token holders. Active transactions are deprecated, as they may lack a proposal.
+    pub fn set_ratification<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        ratification: Option<Ratification>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.ensure_multisig_space(Ratification::SIZE, ctx.remaining_accounts)?;
+        ctx.accounts.multisig.set_ratification(ratification)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetRatification { ratification },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to add a member to a page of additional members
+    pub fn add_paged_member(ctx: Context<MsMembersAuth>, new_member: Pubkey) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.members_page.add_member(new_member)?;
+        ctx.accounts.multisig.add_paged_member()?;
+        validate_config(&ctx.accounts.multisig)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddPagedMember { member: new_member, page_index: ctx.accounts.members_page.page_index },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// The instruction to remove a member from a page of additional members. Like remove_member,
+    /// the remaining members must still be able to reach the thresholds.
+    pub fn remove_paged_member(ctx: Context<MsMembersAuth>, old_member: Pubkey) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.members_page.remove_member(old_member)?;
+        validate_paged_removal(&ctx.accounts.multisig)?;
+        ctx.accounts.multisig.remove_paged_member()?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemovePagedMember { member: old_member, page_index: ctx.accounts.members_page.page_index },
+        });
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)
+    }
+
+    /// Instruction for the key of an allowance to pull funds out of the vault.
+    /// The amount counts towards the cap of the allowance. The accounts are passed
+    /// the same way as for spend_from_vault.
+    pub fn spend_allowance<'info>(
+        ctx: Context<'_, '_, '_, 'info, SpendAllowance<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.allowance.spend(amount)?;
+        emit!(AllowanceUsed {
+            multisig: ctx.accounts.multisig.key(),
+            allowance: ctx.accounts.allowance.key(),
+            spender: ctx.accounts.spender.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+        });
+
+        let ms_key = ctx.accounts.multisig.key();
+        let authority_index = ctx.accounts.allowance.authority_index;
+        let vault_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority",
+            &[*ctx.bumps.get("vault").unwrap()],
+        ];
+        transfer_from_vault(
+            &ctx.accounts.vault,
+            &ctx.accounts.destination,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.accounts.allowance.mint,
+            amount,
+            &vault_seeds,
+        )
+    }
+
+    /// The instruction to create the metadata account of the multisig, labeling it with a
+    /// name, a short description and an image/URI hash for wallets and explorers.
+    pub fn create_metadata(
+        ctx: Context<CreateMetadata>,
+        name: String,
+        description: String,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.metadata.init(
+            ctx.accounts.multisig.key(),
+            name,
+            description,
+            uri_hash,
+            *ctx.bumps.get("metadata").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMetadata { metadata: ctx.accounts.metadata.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to update the metadata account of the multisig
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: String,
+        description: String,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.metadata.update(name, description, uri_hash)?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::SetMetadata { metadata: ctx.accounts.metadata.key() },
+        });
+        Ok(())
+    }
+
+    /// The instruction to make an approved vault transaction recurring, ie. a monthly payroll.
+    /// Instead of being executed once, the instructions of the transaction are executed every
+    /// interval (in seconds) from start_at, by anyone cranking the recurring transaction.
+    pub fn create_recurring_transaction(
+        ctx: Context<CreateRecurringTransaction>,
+        interval: i64,
+        start_at: i64,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.recurring_transaction.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.transaction.key(),
+            interval,
+            start_at,
+            *ctx.bumps.get("recurring_transaction").unwrap(),
+        )?;
+        ctx.accounts.transaction.set_recurring()?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::AddRecurringTransaction { recurring_transaction: ctx.accounts.recurring_transaction.key() },
+        });
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// The instruction to end the schedule of a recurring transaction, returning the rent to the
+    /// rent collector. The transaction is marked as executed if it was executed at least once,
+    /// otherwise as cancelled.
+    pub fn remove_recurring_transaction(ctx: Context<RemoveRecurringTransaction>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        if ctx.accounts.recurring_transaction.executions > 0 {
             ctx.accounts.transaction.set_executed()?;
-            return Ok(());
+        } else {
+            ctx.accounts.transaction.set_cancelled()?;
         }
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveRecurringTransaction { recurring_transaction: ctx.accounts.recurring_transaction.key() },
+        });
+        emit!(TransactionStatusChanged {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        ctx.accounts.multisig.release_transaction(ctx.accounts.transaction.creator, ctx.accounts.transaction.transaction_index, &ctx.accounts.transaction.status)?;
+        Ok(())
+    }
 
-        // use for derivation for the authority
-        let ms_key = ctx.accounts.multisig.key();
+    /// Instruction to execute a recurring transaction once it's due, which anyone can crank.
+    /// The execution condition and ratification (if any) are checked on every run.
+    /// The accounts are passed the same way as for execute_transaction.
+    pub fn execute_recurring_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteRecurringTransaction<'info>>,
+        account_list: Vec<u8>,
+    ) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        // the review window (and veto period) still applies to the first execution
+        ctx.accounts.transaction.check_time_lock(ctx.accounts.multisig.execution_delay(ctx.accounts.transaction.authority_index))?;
+        // the schedule ends at the expiration of the transaction, if any
+        ctx.accounts.transaction.check_not_expired()?;
+        // the oracle price condition (if any) must hold on every run
+        ctx.accounts.transaction.check_execution_condition(ctx.remaining_accounts)?;
+        // the token holders must have ratified the transaction, if the multisig requires it
+        ctx.accounts.multisig.check_ratification(ctx.accounts.transaction.ratifying_proposal, ctx.remaining_accounts)?;
+        ctx.accounts.transaction.check_lookup_tables(ctx.remaining_accounts)?;
+        ctx.accounts.recurring_transaction.schedule_next()?;
+        consult_policy(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.accounts.cranker.key(),
+            PolicyStage::Execute,
+            ctx.remaining_accounts,
+        )?;
+        invoke_execution_hook(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+            ExecutionHookStage::PreExecute,
+        )?;
 
-        // default authority seeds to auth index > 0
+        let ms_key = ctx.accounts.multisig.key();
         let authority_seeds = [
             b"squad",
             ms_key.as_ref(),
@@ -394,193 +3521,246 @@ pub mod squads_mpl {
             b"authority",
             &[ctx.accounts.transaction.authority_bump],
         ];
-        // if auth index < 1
-        let ms_authority_seeds = [
-            b"squad",
-            ctx.accounts.multisig.create_key.as_ref(),
-            b"multisig",
-            &[ctx.accounts.multisig.bump],
-        ];
+
+        // an empty account list is resolved from the deduplicated remaining accounts
+        let account_list = if account_list.is_empty() {
+            resolve_account_list(ctx.remaining_accounts, ctx.accounts.transaction.instruction_index)?
+        } else {
+            account_list
+        };
 
         // unroll account infos from account_list
-        let mapped_remaining_accounts: Vec<AccountInfo> = account_list
+        let ix_iter = &mut account_list
             .iter()
-            .map(|&i| {
-                let index = usize::from(i);
-                ctx.remaining_accounts[index].clone()
-            })
-            .collect();
-
-        // iterator for remaining accounts
-        let ix_iter = &mut mapped_remaining_accounts.iter();
+            .map(|&i| &ctx.remaining_accounts[usize::from(i)]);
 
         (1..=ctx.accounts.transaction.instruction_index).try_for_each(|i: u8| {
-            // each ix block starts with the ms_ix account
+            // each ix block starts with the ms_ix account, followed by the program and its accounts
             let ms_ix_account: &AccountInfo = next_account_info(ix_iter)?;
-
-            // if the attached instruction doesn't belong to this program, throw error
             if ms_ix_account.owner != ctx.program_id {
                 return err!(MsError::InvalidInstructionAccount);
             }
-
-            // deserialize the msIx
-            let mut ix_account_data: &[u8] = &ms_ix_account.try_borrow_mut_data()?;
-            let ms_ix: MsInstruction = MsInstruction::try_deserialize(&mut ix_account_data)?;
-
-            // get the instruction account pda - seeded from transaction account + the transaction accounts instruction index
-            let (ix_pda, _) = Pubkey::find_program_address(
-                &[
-                    b"squad",
-                    ctx.accounts.transaction.key().as_ref(),
-                    &i.to_le_bytes(),
-                    b"instruction",
-                ],
-                ctx.program_id,
-            );
-            // check the instruction account key maches the derived pda
+            let (ix_pda, _) = pda::find_ix_address(&ctx.accounts.transaction.key(), i);
             if &ix_pda != ms_ix_account.key {
                 return err!(MsError::InvalidInstructionAccount);
             }
-            // get the instructions program account
+            // read the msIx in place, rather than deserializing it into heap vectors
+            let ix_account_data = ms_ix_account.try_borrow_data()?;
+            let ms_ix = MsInstructionView::load(&ix_account_data)?;
             let ix_program_info: &AccountInfo = next_account_info(ix_iter)?;
-            // check that it matches the submitted account
             if &ms_ix.program_id != ix_program_info.key {
                 return err!(MsError::InvalidInstructionAccount);
             }
 
-            let ix_keys = ms_ix.keys.clone();
-            // create the instruction to invoke from the saved ms ix account
-            let ix: Instruction = Instruction::from(ms_ix);
-            // the instruction account vec, with the program account first
-            let mut ix_account_infos: Vec<AccountInfo> = vec![ix_program_info.clone()];
-
-            // loop through the provided remaining accounts
-            for account_index in 0..ix_keys.len() {
-                let ix_account_info = next_account_info(ix_iter)?.clone();
+            ctx.accounts.multisig.check_program_allowed(ctx.accounts.transaction.authority_index, &ms_ix.program_id)?;
+            ctx.accounts.multisig.check_instruction_not_denied(&ms_ix.program_id, ms_ix.data())?;
 
+            let mut ix_account_infos: Vec<AccountInfo> = Vec::with_capacity(ms_ix.keys_len() + 1);
+            ix_account_infos.push(ix_program_info.clone());
+            let mut ix_account_metas: Vec<AccountMeta> = Vec::with_capacity(ms_ix.keys_len());
+            for ix_key in ms_ix.account_metas() {
+                let ix_account_info = next_account_info(ix_iter)?;
                 // check that the ix account keys match the submitted account keys
-                if *ix_account_info.key != ix_keys[account_index].pubkey {
+                if *ix_account_info.key != ix_key.pubkey {
                     return err!(MsError::InvalidInstructionAccount);
                 }
-
                 ix_account_infos.push(ix_account_info.clone());
+                ix_account_metas.push(ix_key);
             }
-
-            // execute the ix
-            match ctx.accounts.transaction.authority_index {
-                // if its a 0 authority, use the MS pda seeds
-                0 => {
-                    if &ix.program_id != ctx.program_id {
-                        return err!(MsError::InvalidAuthorityIndex);
-                    }
-                    // Prevent recursive call on execute_transaction/instruction that could create issues
-                    let execute_transaction = Vec::from_hex("e7ad315beb184413").unwrap();
-                    let execute_instruction = Vec::from_hex("301228284b4a936e").unwrap();
-                    if Some(execute_transaction.as_slice()) == ix.data.get(0..8) ||
-                        Some(execute_instruction.as_slice()) == ix.data.get(0..8) {
-                        return err!(MsError::InvalidAuthorityIndex);
-                    }
-
-                    invoke_signed(&ix, &ix_account_infos, &[&ms_authority_seeds])?;
-                }
-                // if its > 1 authority, use the derived authority seeds
-                1.. => {
-                    invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
-                }
-            };
+            let ix: Instruction = ms_ix.to_instruction_with(ix_account_metas);
+            drop(ix_account_data);
+            VoteAction::check_withdraw(&ix, &ix_account_infos)?;
+            invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
             Ok(())
         })?;
-        // set the executed index
-        ctx.accounts.transaction.executed_index = ctx.accounts.transaction.instruction_index;
-        // mark it as executed
-        ctx.accounts.transaction.set_executed()?;
-        // reload any multisig changes
-        ctx.accounts.multisig.reload()?;
+        invoke_execution_hook(
+            &ctx.accounts.multisig,
+            &ctx.accounts.transaction.to_account_info(),
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+            ExecutionHookStage::PostExecute,
+        )?;
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.cranker.key(),
+        });
+        record_activity(
+            &ctx.accounts.multisig,
+            ctx.accounts.cranker.key(),
+            ActivityAction::Execute,
+            ctx.accounts.transaction.transaction_index,
+            ctx.remaining_accounts,
+        )?;
         Ok(())
     }
 
-    /// Instruction to sequentially execute attached instructions.
-    /// Instructions executed in this matter must be executed in order,
-    /// this may be helpful for processing large batch transfers.
-    /// This instruction can only be used for transactions with an authority
-    /// index of 1 or greater.
-    /// 
-    /// NOTE - do not use this instruction if there is not total clarity around
-    /// potential side effects, as this instruction implies that the approved
-    /// transaction will be executed partially, and potentially spread out over
-    /// a period of time. This could introduce problems with state and failed
-    /// transactions. For example: a program invoked in one of these instructions
-    /// may be upgraded between executions and potentially leave one of the 
-    /// necessary accounts in an invalid state.
-    pub fn execute_instruction<'info>(
-        ctx: Context<'_, '_, '_, 'info, ExecuteInstruction<'info>>,
-    ) -> Result<()> {
-        let ms_key = &ctx.accounts.multisig.key();
-        let ms_ix = &mut ctx.accounts.instruction;
-        let tx = &mut ctx.accounts.transaction;
-
-        // To prevent potential failure with the Squad account auth 0 can't be executed in a specific instruction
-        if tx.authority_index == 0 {
-            return err!(MsError::InvalidAuthorityIndex);
+    /// Instruction to upgrade a program from a buffer, checking that the sha256 hash of the
+    /// program in the buffer matches the expected hash. Attached to a vault transaction (the vault
+    /// being the upgrade authority), it binds the approval to the buffer contents, so the buffer
+    /// can't be swapped between approval and execution. A buffer of the vault holding the current
+    /// program data can be passed (with the managed program) as a remaining account, and is retained
+    /// so the upgrade can be rolled back with propose_rollback.
+    pub fn upgrade_program<'info>(ctx: Context<'_, '_, '_, 'info, UpgradeProgram<'info>>, expected_hash: [u8; 32]) -> Result<()> {
+        let buffer_hash = {
+            let buffer_data = ctx.accounts.buffer.try_borrow_data()?;
+            let program_bytes = buffer_data
+                .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+                .ok_or(MsError::InvalidBuffer)?;
+            hash(program_bytes).to_bytes()
+        };
+        if buffer_hash != expected_hash {
+            return err!(MsError::BufferHashMismatch);
         }
 
-        // setup the authority seeds
-        let authority_seeds = [
-            b"squad",
-            ms_key.as_ref(),
-            &tx.authority_index.to_le_bytes(),
-            b"authority",
-            &[tx.authority_bump],
-        ];
+        // the program data is replaced by the upgrade, so it is compared to the rollback buffer first
+        let rollback = ctx.accounts.snapshot_rollback(ctx.remaining_accounts)?;
+        let upgrade_ix = bpf_loader_upgradeable::upgrade(
+            ctx.accounts.program.key,
+            ctx.accounts.buffer.key,
+            ctx.accounts.authority.key,
+            ctx.accounts.spill.key,
+        );
+        // the authority already signed, the signature carries over to the loader
+        invoke(
+            &upgrade_ix,
+            &[
+                ctx.accounts.program_data.to_account_info(),
+                ctx.accounts.program.to_account_info(),
+                ctx.accounts.buffer.to_account_info(),
+                ctx.accounts.spill.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.bpf_loader_upgradeable_program.to_account_info(),
+            ],
+        )?;
+        ctx.accounts.record_upgrade(expected_hash, rollback, ctx.remaining_accounts)
+    }
 
-        // map the saved instruction account data to the instruction to be invoked
-        let ix: Instruction = Instruction {
-            accounts: ms_ix
-                .keys
-                .iter()
-                .map(|k| AccountMeta {
-                    pubkey: k.pubkey,
-                    is_signer: k.is_signer,
-                    is_writable: k.is_writable,
-                })
-                .collect(),
-            data: ms_ix.data.clone(),
-            program_id: ms_ix.program_id,
-        };
+    /// The instruction to add a program whose upgrade authority is an authority (vault) of the
+    /// multisig to its managed programs, replacing the separate program manager program.
+    pub fn create_managed_program(ctx: Context<CreateManagedProgram>, authority_index: u32, name: String) -> Result<()> {
+        ctx.accounts.managed_program.init(
+            ctx.accounts.multisig.key(),
+            ctx.accounts.program.key(),
+            authority_index,
+            name,
+            *ctx.bumps.get("managed_program").unwrap(),
+        )?;
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::CreateManagedProgram {
+                managed_program: ctx.accounts.managed_program.key(),
+                program_address: ctx.accounts.program.key(),
+            },
+        });
+        Ok(())
+    }
 
-        // collect the accounts needed from remaining accounts (order matters)
-        let mut ix_account_infos: Vec<AccountInfo> = Vec::<AccountInfo>::new();
-        let ix_account_iter = &mut ctx.remaining_accounts.iter();
-        // the first account in the submitted list should be the program
-        let ix_program_account = next_account_info(ix_account_iter)?;
-        // check that the programs match
-        if ix_program_account.key != &ix.program_id {
-            return err!(MsError::InvalidInstructionAccount);
-        }
+    /// The instruction to remove a program from the managed programs, returning the rent to the rent collector
+    pub fn remove_managed_program(ctx: Context<RemoveManagedProgram>) -> Result<()> {
+        emit!(ConfigChanged {
+            multisig: ctx.accounts.multisig.key(),
+            action: ConfigAction::RemoveManagedProgram { managed_program: ctx.accounts.managed_program.key() },
+        });
+        Ok(())
+    }
 
-        // loop through the provided remaining accounts - check they match the saved instruction accounts
-        for account_index in 0..ms_ix.keys.len() {
-            let ix_account_info = next_account_info(ix_account_iter)?;
-            // check that the ix account keys match the submitted account keys
-            if ix_account_info.key != &ms_ix.keys[account_index].pubkey {
-                return err!(MsError::InvalidInstructionAccount);
-            }
-            ix_account_infos.push(ix_account_info.clone());
-        }
+    /// Instruction to propose an upgrade of a managed program, tracking the vault transaction that
+    /// carries the upgrade_program instruction and the buffer it upgrades from. The upgrade is marked
+    /// as upgraded when upgrade_program executes with the managed program and the upgrade passed as
+    /// remaining accounts.
+    pub fn create_program_upgrade(ctx: Context<CreateProgramUpgrade>, buffer: Pubkey, expected_hash: [u8; 32]) -> Result<()> {
+        let managed_program = &mut ctx.accounts.managed_program;
+        let upgrade_index = managed_program.upgrade_index.checked_add(1).unwrap();
+        ctx.accounts.program_upgrade.init(
+            managed_program.key(),
+            upgrade_index,
+            ctx.accounts.transaction.key(),
+            buffer,
+            expected_hash,
+            *ctx.bumps.get("program_upgrade").unwrap(),
+        )?;
+        managed_program.upgrade_index = upgrade_index;
+        Ok(())
+    }
 
-        if tx.authority_index < 1 && &ix.program_id != ctx.program_id {
-            return err!(MsError::InvalidAuthorityIndex);
-        }
+    /// The instruction to close a proposed upgrade of a managed program, returning the rent to the rent collector
+    pub fn close_program_upgrade(_ctx: Context<CloseProgramUpgrade>) -> Result<()> {
+        Ok(())
+    }
 
-        invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
+    /// The instruction to set the number of member approvals needed to roll back the last upgrade
+    /// of a managed program, meant to be lower than the threshold. 0 disables rollbacks.
+    pub fn set_rollback_threshold(ctx: Context<SetRollbackThreshold>, rollback_threshold: u16) -> Result<()> {
+        ctx.accounts.managed_program.set_rollback_threshold(&ctx.accounts.multisig, rollback_threshold)
+    }
 
-        // set the executed index to match
-        tx.executed_index = ms_ix.instruction_index;
-        // this is the last instruction - set the transaction as executed
-        if ctx.accounts.instruction.instruction_index == ctx.accounts.transaction.instruction_index
-        {
-            ctx.accounts.transaction.set_executed()?;
-        }
+    /// Instruction for a member to propose rolling back the last upgrade of a managed program
+    /// from the buffer it retained, which counts as their approval.
+    pub fn propose_rollback(ctx: Context<ProposeRollback>) -> Result<()> {
+        ctx.accounts.program_rollback.init(
+            ctx.accounts.managed_program.key(),
+            ctx.accounts.managed_program.rollback_buffer.unwrap(),
+            ctx.accounts.member.key(),
+            *ctx.bumps.get("program_rollback").unwrap(),
+        )?;
+        ctx.accounts.program_rollback.approve(ctx.accounts.member.key())
+    }
+
+    /// Instruction for a member to approve the rollback of a managed program
+    pub fn approve_rollback(ctx: Context<ApproveRollback>) -> Result<()> {
+        ctx.accounts.program_rollback.approve(ctx.accounts.member.key())
+    }
+
+    /// Instruction to execute an approved rollback, which anyone can call. It is the expedited path
+    /// to undo a bad deploy, so it only needs the rollback threshold of approvals and isn't subject
+    /// to the time lock, but the vault can't sign while it's frozen or paused. The rent of the
+    /// rollback is returned to the proposer.
+    pub fn execute_rollback(ctx: Context<ExecuteRollback>) -> Result<()> {
+        advance_seq(&mut ctx.accounts.multisig);
+        ctx.accounts.program_rollback.check_ready(&ctx.accounts.multisig, &ctx.accounts.managed_program)?;
+        let ms_key = ctx.accounts.multisig.key();
+        let authority_index = ctx.accounts.managed_program.authority_index;
+        let vault_seeds: &[&[u8]] = &[
+            b"squad",
+            ms_key.as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority",
+            &[*ctx.bumps.get("authority").unwrap()],
+        ];
+        let upgrade_ix = bpf_loader_upgradeable::upgrade(
+            ctx.accounts.program.key,
+            ctx.accounts.buffer.key,
+            ctx.accounts.authority.key,
+            ctx.accounts.spill.key,
+        );
+        invoke_signed(
+            &upgrade_ix,
+            &[
+                ctx.accounts.program_data.to_account_info(),
+                ctx.accounts.program.to_account_info(),
+                ctx.accounts.buffer.to_account_info(),
+                ctx.accounts.spill.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.bpf_loader_upgradeable_program.to_account_info(),
+            ],
+            &[vault_seeds],
+        )?;
+        // the rollback buffer is consumed by the upgrade
+        let managed_program = &mut ctx.accounts.managed_program;
+        managed_program.last_upgrade = Clock::get()?.unix_timestamp;
+        managed_program.rollback_buffer = None;
+        managed_program.rollback_hash = [0; 32];
+        Ok(())
+    }
+
+    /// Instruction for the proposer to cancel a rollback, ie. one made stale by a later upgrade.
+    /// The rent of the rollback is returned to the proposer.
+    pub fn cancel_rollback(_ctx: Context<CancelRollback>) -> Result<()> {
         Ok(())
     }
 }
@@ -0,0 +1,106 @@
+/*
+    Squads Multisig Program - PDA seeds
+    https://github.com/squads-protocol/squads-mpl
+*/
+
+use anchor_lang::prelude::*;
+
+/// The prefix of every squads-mpl PDA
+pub const SEED_PREFIX: &[u8] = b"squad";
+pub const SEED_MULTISIG: &[u8] = b"multisig";
+pub const SEED_TRANSACTION: &[u8] = b"transaction";
+pub const SEED_INSTRUCTION: &[u8] = b"instruction";
+pub const SEED_AUTHORITY: &[u8] = b"authority";
+pub const SEED_SPENDING_LIMIT: &[u8] = b"spendinglimit";
+pub const SEED_ALLOWANCE: &[u8] = b"allowance";
+pub const SEED_DELEGATE: &[u8] = b"delegate";
+pub const SEED_METADATA: &[u8] = b"metadata";
+pub const SEED_RECURRING: &[u8] = b"recurring";
+pub const SEED_VOTE: &[u8] = b"vote";
+pub const SEED_RECOVERY: &[u8] = b"recovery";
+pub const SEED_MEMBER_RECOVERY: &[u8] = b"memberrecovery";
+pub const SEED_CONFIG_TRANSACTION: &[u8] = b"configtransaction";
+pub const SEED_AUTHORITY_RECORD: &[u8] = b"authorityrecord";
+pub const SEED_POLICY_CONFIG: &[u8] = b"policyconfig";
+pub const SEED_ACTIVITY_LOG: &[u8] = b"activitylog";
+pub const SEED_VOTE_MEMO: &[u8] = b"votememo";
+pub const SEED_TOKEN_VOTE: &[u8] = b"tokenvote";
+pub const SEED_STAKE_SNAPSHOT: &[u8] = b"stakesnapshot";
+pub const SEED_SESSION_KEY: &[u8] = b"sessionkey";
+pub const SEED_MANAGED_PROGRAM: &[u8] = b"managedprogram";
+pub const SEED_PROGRAM_UPGRADE: &[u8] = b"programupgrade";
+pub const SEED_MEMBERS: &[u8] = b"members";
+
+/// The multisig account, seeded by its create key
+pub fn find_ms_address(create_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, create_key.as_ref(), SEED_MULTISIG], &crate::ID)
+}
+
+/// The transaction account at the given index of the multisig
+pub fn find_tx_address(multisig: &Pubkey, transaction_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), &transaction_index.to_le_bytes(), SEED_TRANSACTION],
+        &crate::ID,
+    )
+}
+
+/// The config transaction at the given index of the multisig, which shares the index with the transactions
+pub fn find_config_tx_address(multisig: &Pubkey, transaction_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), &transaction_index.to_le_bytes(), SEED_CONFIG_TRANSACTION],
+        &crate::ID,
+    )
+}
+
+/// The instruction account at the given index of the transaction, indexed from 1
+pub fn find_ix_address(transaction: &Pubkey, instruction_index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, transaction.as_ref(), &instruction_index.to_le_bytes(), SEED_INSTRUCTION],
+        &crate::ID,
+    )
+}
+
+/// The authority (vault) at the given index of the multisig. Authority 0 is the
+/// multisig account itself, which isn't derived this way.
+pub fn find_authority_address(multisig: &Pubkey, authority_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), &authority_index.to_le_bytes(), SEED_AUTHORITY],
+        &crate::ID,
+    )
+}
+
+/// The page of additional members of the multisig at the given index, indexed from 0
+pub fn find_members_address(multisig: &Pubkey, page_index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, multisig.as_ref(), &[page_index], SEED_MEMBERS], &crate::ID)
+}
+
+/// The policy config of the multisig, there is at most one
+pub fn find_policy_config_address(multisig: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, multisig.as_ref(), SEED_POLICY_CONFIG], &crate::ID)
+}
+
+/// The activity log of the multisig, there is at most one
+pub fn find_activity_log_address(multisig: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, multisig.as_ref(), SEED_ACTIVITY_LOG], &crate::ID)
+}
+
+/// The stake weight snapshot of a transaction, taken when it is activated
+pub fn find_stake_snapshot_address(transaction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX, transaction.as_ref(), SEED_STAKE_SNAPSHOT], &crate::ID)
+}
+
+/// The managed program entry of the multisig for the program
+pub fn find_managed_program_address(multisig: &Pubkey, program_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), program_address.as_ref(), SEED_MANAGED_PROGRAM],
+        &crate::ID,
+    )
+}
+
+/// The upgrade at the given index of the managed program, indexed from 1
+pub fn find_program_upgrade_address(managed_program: &Pubkey, upgrade_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, managed_program.as_ref(), &upgrade_index.to_le_bytes(), SEED_PROGRAM_UPGRADE],
+        &crate::ID,
+    )
+}
@@ -3,17 +3,27 @@
     https://github.com/squads-protocol/squads-mpl
 */
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, Discriminator};
 use anchor_lang::solana_program::borsh::get_instance_packed_len;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use spl_token_2022::extension::{non_transferable::NonTransferableAccount, BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Account as TokenAccount;
+
+use crate::errors::*;
+use crate::events::Vote;
+use crate::validation::{validate_config, validate_config_actions};
 
 /// Ms is the basic state account for a multisig.
+/// The fields introduced since the original layout follow the keys, and later layout versions
+/// append theirs after them, so that an account of an older version reads the fields it lacks as zero.
 #[account]
 pub struct Ms {
     pub threshold: u16,                 // threshold for signatures to execute.
 
-    pub authority_index: u16,           // luxury field to help track how many authorities are currently used.
+    pub authority_index: u32,           // luxury field to help track how many authorities are currently used.
 
     pub transaction_index: u32,         // look up and seed reference for transactions.
 
@@ -26,24 +36,235 @@ pub struct Ms {
     pub bump: u8,                       // bump for the multisig seed.
 
     pub create_key: Pubkey,             // random key(or not) used to seed the multisig pda.
-                                   
-    pub allow_external_execute: bool,   // DEPRECATED - allow non-member keys to execute txs
+
+    pub allow_external_execute: bool,   // DEPRECATED - replaced by any_executor and executors
 
     pub keys: Vec<Pubkey>,              // keys of the members/owners of the multisig.
+
+    pub version: u8,                    // layout version of the account, upgraded in place by migrate_baseline_multisig.
+
+    pub default_vault_index: u32,       // the authority index treated as the canonical treasury/vault.
+
+    pub member_weights: Vec<MemberWeight>,  // vote weight overrides, sorted by member.
+                                            // members without an entry have a weight of 1.
+
+    pub member_permissions: Vec<MemberPermissions>, // permission overrides, sorted by member.
+                                                    // members without an entry have all permissions.
+
+    pub time_lock: u32,                 // seconds a transaction must wait after reaching ExecuteReady
+                                        // before it can be executed.
+
+    pub config_threshold: u16,          // threshold for internal (authority 0) transactions,
+                                        // 0 to use the regular threshold.
+
+    pub veto_period: u32,               // seconds after reaching ExecuteReady during which a single
+                                        // member with the veto permission can cancel a transaction.
+
+    pub member_programs: Vec<Pubkey>,   // programs whose PDAs can be added as members, ie. a governance
+                                        // program voting through CPI.
+
+    pub member_expirations: Vec<MemberExpiration>,  // membership expirations, sorted by member.
+                                                    // members without an entry never expire.
+
+    pub guardian: Option<Pubkey>,       // key that can freeze the multisig, but cannot vote or move funds.
+
+    pub frozen: bool,                   // blocks activating and executing transactions, except
+                                        // internal ones so the multisig can unfreeze itself.
+
+    pub paused: bool,                   // blocks executing transactions, except internal ones.
+                                        // voting is unaffected.
+
+    pub vote_records: bool,             // votes are recorded in per-member VoteRecord accounts and
+                                        // tallied on the transaction, instead of the vote bitmaps.
+
+    pub executing_transaction: u32,     // index of the transaction being executed instruction by instruction,
+                                        // 0 when none. Other transactions can't execute in the meantime.
+
+    pub program_allowlists: Vec<ProgramAllowlist>,  // programs that instructions of an authority may target,
+                                                    // sorted by authority index. Authorities without an
+                                                    // entry are unrestricted.
+
+    pub denied_instructions: Vec<DeniedInstruction>,    // program and data prefix pairs that can't be attached
+                                                        // or executed, sorted by program and prefix.
+
+    pub removal_delay: u32,             // seconds between a member removal being executed and taking effect,
+                                        // during which a member with the veto permission can abort it.
+
+    pub pending_removal: Option<PendingRemoval>,    // the member removal waiting for the removal delay, if any.
+
+    pub last_execution_epoch: u64,      // epoch a transaction was last executed in, for the inactivity
+                                        // recovery. Recurring executions don't count.
+
+    pub recovery_threshold: u16,        // approvals of recovery keys needed to replace a lost member key,
+                                        // 0 when there is no recovery set.
+
+    pub recovery_delay: u32,            // seconds between a member recovery being approved and executable,
+                                        // during which the old member can cancel it.
+
+    pub recovery_keys: Vec<Pubkey>,     // the recovery set, keys that can together replace a single lost
+                                        // member key, sorted.
+
+    pub retired_authorities: Vec<u32>,  // indexes of authorities (vaults) that were swept and retired, sorted.
+                                        // Their transactions can't be created or executed anymore.
+
+    pub any_executor: bool,             // any key can execute approved transactions, not just members.
+
+    pub executors: Vec<Pubkey>,         // non-member keys that can execute approved transactions, ie. a
+                                        // keeper service, sorted.
+
+    pub execution_hook: Option<Pubkey>, // program invoked before and after executing vault transactions,
+                                        // which can abort the execution by returning an error.
+
+    pub policy_enabled: bool,           // a policy config is set, so the policy program must be consulted
+                                        // when vault transactions are activated, approved and executed.
+
+    pub activity_log_enabled: bool,     // an activity log is kept, so it must be passed to the
+                                        // instructions that record activity.
+
+    pub seq: u64,                       // sequence number, advanced on every change of the multisig or
+                                        // its transactions, so indexers can detect missed updates.
+
+    pub min_quorum: u16,                // the number of members that must have voted (approved, rejected or
+                                        // abstained) before a transaction can be decided, whatever the threshold.
+
+    pub threshold_percent: u8,          // when set (1 to 100), the threshold is this percentage of the total vote
+                                        // weight, recomputed whenever the members or their weights change.
+
+    pub max_open_transactions: u16,     // when set, the cap on the transactions of a single creator that are
+                                        // neither executed, rejected, cancelled, expired nor deprecated.
+
+    pub open_transactions: Vec<OpenTransactions>,   // the open transactions of each creator, sorted by creator.
+                                                    // only tracked while max open transactions is set.
+
+    pub parent: Option<Pubkey>,         // the vault authority of a parent multisig, which can propose transactions
+                                        // (through CPI) like a member with only the initiate permission.
+
+    pub membership_gate: Option<MembershipGate>,    // when set, holders of a token of the mint or an NFT of the collection
+                                                    // vote with token vote records, tallied apart from the members.
+
+    pub stake_weight_source: Option<StakeWeightSource>, // when set, the vote weights of the members are read from
+                                                        // their staking lockers, snapshotted at activation.
+
+    pub value_tiers: Vec<ValueTier>,    // thresholds by the amount transferred by vault transactions, sorted by
+                                        // mint and min amount. Applied when transactions are activated.
+
+    pub execution_window: Option<ExecutionWindow>,  // when set, the UTC days and hours vault transactions can be
+                                                    // executed (and vaults spent from) in.
+
+    pub blackout_periods: Vec<BlackoutPeriod>,  // time ranges vault transactions can't be executed (nor vaults
+                                                // spent from) in, sorted by start.
+
+    pub config_time_lock: u32,          // seconds an internal (config) transaction must wait after being approved
+                                        // before it can be executed, independently of the time lock.
+
+    pub admin: Option<Pubkey>,          // bootstrap key set at creation that can apply config changes on its own,
+                                        // until the admin expiry slot or until it renounces.
+
+    pub admin_expires_at_slot: u64,     // slot from which the admin can't apply config changes anymore.
+
+    pub max_members: u16,               // when set, the most members the multisig can have, at most Ms::MAX_MEMBERS.
+
+    pub member_pages: u8,               // the number of MsMembers pages of additional members, indexed from 0.
+
+    pub paged_member_count: u32,        // the members in the pages, which vote with vote records and a weight of 1.
+
+    pub member_root: Option<[u8; 32]>,  // when set, the merkle root of an additional member set, whose members vote
+                                        // with vote records and an inclusion proof, with a weight of 1.
+
+    pub root_member_count: u32,         // the members of the set of the member root, vouched for by the members
+                                        // that approved the root as it can't be checked on-chain.
+
+    pub ratification: Option<Ratification>, // when set, transactions can only be executed once a proposal of the
+                                            // SPL governance is ratified by its token holders as well.
+
+    pub veto_council_threshold: u16,    // the vetoes of the veto council that reject a transaction, 0 without a council.
+
+    pub veto_council: Vec<Pubkey>,      // the veto council, keys apart from the members that can together reject
+                                        // a transaction (even an approved one) but can't approve, sorted.
 }
 
 impl Ms {
     pub const SIZE_WITHOUT_MEMBERS: usize = 8 + // Anchor disriminator
     2 +         // threshold value
-    2 +         // authority index
+    4 +         // authority index
     4 +         // transaction index
     4 +         // processed internal transaction index
     1 +         // PDA bump
     32 +        // creator
     1 +         // allow external execute
-    4;          // for vec length
+    4 +         // for vec length
+    1 +         // version
+    4 +         // default vault index
+    4 +         // for member weights vec length
+    4 +         // for member permissions vec length
+    4 +         // time lock
+    2 +         // config threshold
+    4 +         // veto period
+    4 +         // for member programs vec length
+    4 +         // for member expirations vec length
+    (1 + 32) +  // guardian
+    1 +         // frozen
+    1 +         // paused
+    1 +         // vote records
+    4 +         // executing transaction
+    4 +         // for program allowlists vec length
+    4 +         // for denied instructions vec length
+    4 +         // removal delay
+    (1 + PendingRemoval::SIZE) +    // pending removal
+    8 +         // last execution epoch
+    2 +         // recovery threshold
+    4 +         // recovery delay
+    4 +         // for recovery keys vec length
+    4 +         // for retired authorities vec length
+    1 +         // any executor
+    4 +         // for executors vec length
+    (1 + 32) +  // execution hook
+    1 +         // policy enabled
+    1 +         // activity log enabled
+    8 +         // seq
+    2 +         // min quorum
+    1 +         // threshold percent
+    2 +         // max open transactions
+    4 +         // for open transactions vec length
+    (1 + 32) +  // parent
+    (1 + MembershipGate::SIZE) +    // membership gate
+    (1 + StakeWeightSource::SIZE) + // stake weight source
+    4 +         // for value tiers vec length
+    (1 + ExecutionWindow::SIZE) +   // execution window
+    4 +         // for blackout periods vec length
+    4 +         // config time lock
+    (1 + 32) +  // admin
+    8 +         // admin expiry slot
+    2 +         // max members
+    1 +         // member pages
+    4 +         // paged member count
+    (1 + 32) +  // member root
+    4 +         // root member count
+    (1 + Ratification::SIZE) +      // ratification
+    2 +         // veto council threshold
+    4;          // for veto council vec length
 
-    /// Initializes the new multisig account
+    /// the current layout version of the multisig account
+    pub const VERSION: u8 = 1;
+    /// the maximum number of keys in the recovery set
+    pub const MAX_RECOVERY_KEYS: usize = 10;
+    /// the maximum number of keys in the veto council
+    pub const MAX_VETO_COUNCIL: usize = 10;
+    /// the maximum number of non-member executors
+    pub const MAX_EXECUTORS: usize = 10;
+    /// the maximum number of value tiers
+    pub const MAX_VALUE_TIERS: usize = 10;
+    /// the maximum number of blackout periods
+    pub const MAX_BLACKOUT_PERIODS: usize = 10;
+    /// the maximum lifetime of the admin, about 30 days of slots
+    pub const MAX_ADMIN_DURATION_SLOTS: u64 = 6_480_000;
+    /// the most members the keys of any multisig can hold, pages of members aside. The multisig is
+    /// created, and the stake snapshots are allocated, with room for every member in a single
+    /// instruction, which can't allocate more than MAX_PERMITTED_DATA_INCREASE bytes.
+    pub const MAX_MEMBERS: usize = (MAX_PERMITTED_DATA_INCREASE - Ms::SIZE_WITHOUT_MEMBERS) / MemberWeight::SIZE;
+
+    /// Initializes the new multisig account. The members must be sorted and unique,
+    /// which create checks with validate_config once initialized.
     pub fn init (&mut self, threshold: u16, create_key: Pubkey, members: Vec<Pubkey>, bump: u8) -> Result<()> {
         self.threshold = threshold;
         self.keys = members;
@@ -53,9 +274,79 @@ impl Ms {
         self.bump = bump;
         self.create_key = create_key;
         self.allow_external_execute = false;
+        self.default_vault_index = 1;   // authority 1 is the default vault by convention
+        self.time_lock = 0;
+        self.config_threshold = 0;
+        self.veto_period = 0;
+        self.guardian = None;
+        self.frozen = false;
+        self.paused = false;
+        self.vote_records = false;
+        self.version = Ms::VERSION;
+        self.executing_transaction = 0;
+        self.removal_delay = 0;
+        self.pending_removal = None;
+        self.last_execution_epoch = Clock::get()?.epoch;
+        self.member_weights = Vec::new();
+        self.member_permissions = Vec::new();
+        self.member_programs = Vec::new();
+        self.member_expirations = Vec::new();
+        self.program_allowlists = Vec::new();
+        self.denied_instructions = Vec::new();
+        self.recovery_threshold = 0;
+        self.recovery_delay = 0;
+        self.recovery_keys = Vec::new();
+        self.retired_authorities = Vec::new();
+        self.any_executor = false;
+        self.executors = Vec::new();
+        self.open_transactions = Vec::new();
+        self.execution_hook = None;
+        self.policy_enabled = false;
+        self.activity_log_enabled = false;
+        self.seq = 0;
+        self.min_quorum = 0;
+        self.threshold_percent = 0;
+        self.max_open_transactions = 0;
+        self.parent = None;
+        self.membership_gate = None;
+        self.stake_weight_source = None;
+        self.value_tiers = Vec::new();
+        self.execution_window = None;
+        self.blackout_periods = Vec::new();
+        self.config_time_lock = 0;
+        self.admin = None;
+        self.admin_expires_at_slot = 0;
+        self.max_members = 0;
+        self.member_pages = 0;
+        self.paged_member_count = 0;
+        self.member_root = None;
+        self.root_member_count = 0;
+        self.ratification = None;
+        self.veto_council = Vec::new();
+        self.veto_council_threshold = 0;
         Ok(())
     }
 
+    /// Finds the bump for the authority PDA of the given index.
+    /// Authority 0 is the multisig itself, so its own bump is used.
+    pub fn get_authority_bump(&self, ms_key: &Pubkey, authority_index: u32, program_id: &Pubkey) -> u8 {
+        match authority_index {
+            1.. => {
+                let (_, auth_bump) = Pubkey::find_program_address(
+                    &[
+                        b"squad",
+                        ms_key.as_ref(),
+                        &authority_index.to_le_bytes(),
+                        b"authority",
+                    ],
+                    program_id,
+                );
+                auth_bump
+            }
+            0 => self.bump,
+        }
+    }
+
     /// Checks to see if the key is a member of the multisig
     pub fn is_member(&self, member: Pubkey) -> Option<usize> {
         match self.keys.binary_search(&member) {
@@ -68,6 +359,8 @@ impl Ms {
     /// that have an index lower than the change index
     pub fn set_change_index(&mut self, index: u32) -> Result<()>{
         self.ms_change_index = index;
+        // the transactions up to the change index are deprecated, so none of them are open anymore
+        self.open_transactions.clear();
         Ok(())
     }
 
@@ -75,265 +368,4211 @@ impl Ms {
     /// This has no effect on the multisig functionality, but is used
     /// to track authorities for clients to use (ie, vault 1, vault 2, program authority 3, etc).
     pub fn add_authority(&mut self) -> Result<()>{
-        self.authority_index = self.authority_index.checked_add(1).unwrap();
+        self.authority_index = self.authority_index
+            .checked_add(1)
+            .ok_or(MsError::AuthorityIndexOverflow)?;
         Ok(())
     }
 
-    /// Adds a member to the multisig. Is a no-op if the member is already in the multisig.
-    pub fn add_member(&mut self, member: Pubkey) -> Result<()>{
-        if matches!(self.is_member(member), None) {
-            self.keys.push(member);
-            self.keys.sort();
+    /// sets the authority index used as the default vault.
+    /// The index must be one of the authorities tracked by the multisig (1 to authority_index),
+    /// as authority 0 is reserved for internal transactions.
+    pub fn set_default_vault(&mut self, vault_index: u32) -> Result<()>{
+        if vault_index < 1 || vault_index > self.authority_index {
+            return err!(MsError::InvalidAuthorityIndex);
         }
+        self.default_vault_index = vault_index;
         Ok(())
     }
 
-    /// Removes a member from the multisig. Is a no-op if the member is not in the multisig.
-    pub fn remove_member(&mut self, member: Pubkey) -> Result<()>{
-        if let Some(ind) = self.is_member(member) {
-            self.keys.remove(ind);
-            if self.keys.len() < usize::from(self.threshold) {
-                self.threshold = self.keys.len().try_into().unwrap();
+    /// sets the number of seconds a transaction must wait after being approved before it can be executed
+    pub fn set_time_lock(&mut self, time_lock: u32) -> Result<()>{
+        self.time_lock = time_lock;
+        Ok(())
+    }
+
+    /// records that a transaction was executed, which pushes back the inactivity recovery
+    pub fn record_execution(&mut self) -> Result<()>{
+        self.last_execution_epoch = Clock::get()?.epoch;
+        Ok(())
+    }
+
+    /// the size of the account with `additional_len` more bytes of state
+    pub fn size_with_additional(&self, additional_len: usize) -> Result<usize> {
+        8usize
+            .checked_add(get_instance_packed_len(self)?)
+            .and_then(|len| len.checked_add(additional_len))
+            .ok_or_else(|| error!(MsError::SizeOverflow))
+    }
+
+    /// the size of the account with the keys replaced by `keys_len` keys, and the other state unchanged
+    pub fn size_with_keys(&self, keys_len: usize) -> Result<usize> {
+        let keys_size = keys_len.checked_mul(32).ok_or(MsError::SizeOverflow)?;
+        self.size_with_additional(0)?
+            .saturating_sub(32 * self.keys.len())
+            .checked_add(keys_size)
+            .ok_or_else(|| error!(MsError::SizeOverflow))
+    }
+
+    /// replaces the full membership and threshold of the multisig, for the inactivity recovery.
+    /// The member overrides (weights, permissions, expirations) belonged to the old members, so
+    /// they are cleared along with the config threshold and any pending removal or execution,
+    /// and the min quorum is capped at the new member count.
+    pub fn recover(&mut self, mut members: Vec<Pubkey>, threshold: u16) -> Result<()>{
+        members.sort();
+        self.min_quorum = self.min_quorum.min(u16::try_from(members.len()).unwrap_or(u16::MAX));
+        self.keys = members;
+        self.threshold = threshold;
+        self.config_threshold = 0;
+        self.member_weights = Vec::new();
+        self.member_permissions = Vec::new();
+        self.member_expirations = Vec::new();
+        self.pending_removal = None;
+        self.executing_transaction = 0;
+        validate_config(self)?;
+        self.record_execution()
+    }
+
+    /// Replaces the key of a member, keeping its weight, permissions and expiration overrides
+    /// (and any pending removal), so that the threshold and the security posture are unchanged.
+    pub fn replace_member(&mut self, old_member: Pubkey, new_member: Pubkey) -> Result<()>{
+        let ind = self.is_member(old_member).ok_or(MsError::KeyNotInMultisig)?;
+        if self.is_member(new_member).is_some() {
+            return err!(MsError::DuplicateMember);
+        }
+        self.keys[ind] = new_member;
+        self.keys.sort();
+        if let Ok(weight_ind) = self.member_weights.binary_search_by_key(&old_member, |w| w.member) {
+            let weight = self.member_weights.remove(weight_ind).weight;
+            let new_ind = self.member_weights.binary_search_by_key(&new_member, |w| w.member).unwrap_err();
+            self.member_weights.insert(new_ind, MemberWeight { member: new_member, weight });
+        }
+        if let Ok(permissions_ind) = self.member_permissions.binary_search_by_key(&old_member, |p| p.member) {
+            let permissions = self.member_permissions.remove(permissions_ind).permissions;
+            let new_ind = self.member_permissions.binary_search_by_key(&new_member, |p| p.member).unwrap_err();
+            self.member_permissions.insert(new_ind, MemberPermissions { member: new_member, permissions });
+        }
+        if let Ok(expiration_ind) = self.member_expirations.binary_search_by_key(&old_member, |e| e.member) {
+            let expires_at = self.member_expirations.remove(expiration_ind).expires_at;
+            let new_ind = self.member_expirations.binary_search_by_key(&new_member, |e| e.member).unwrap_err();
+            self.member_expirations.insert(new_ind, MemberExpiration { member: new_member, expires_at });
+        }
+        if let Some(pending_removal) = self.pending_removal.as_mut() {
+            if pending_removal.member == old_member {
+                pending_removal.member = new_member;
             }
         }
         Ok(())
     }
 
-    /// sets the threshold for the multisig.
-    pub fn change_threshold(&mut self, threshold: u16) -> Result<()>{
-        self.threshold = threshold;
+    /// applies a typed config change. The multisig is only validated with validate_config once all
+    /// of the changes of a batch are applied, so they can pass through intermediate states.
+    pub fn apply_config_action(&mut self, action: &MsConfigAction) -> Result<()>{
+        match *action {
+            MsConfigAction::AddMember { member } => {
+                if self.keys.len() >= self.max_member_count() {
+                    return err!(MsError::MaxMembersReached);
+                }
+                self.add_member(member)
+            }
+            MsConfigAction::RemoveMember { member } => {
+                // delayed removals only take effect after the batch, so can't be part of it
+                if self.removal_delay > 0 {
+                    return err!(MsError::RemovalDelayed);
+                }
+                self.remove_member(member)
+            }
+            MsConfigAction::ReplaceMember { old_member, new_member } => self.replace_member(old_member, new_member),
+            MsConfigAction::ChangeThreshold { threshold } => self.change_threshold(threshold),
+            MsConfigAction::ChangeConfigThreshold { config_threshold } => self.change_config_threshold(config_threshold),
+            MsConfigAction::SetMemberWeight { member, weight } => self.set_member_weight(member, weight),
+            MsConfigAction::SetMemberPermissions { member, permissions } => self.set_member_permissions(member, permissions),
+            MsConfigAction::SetMemberExpiration { member, expires_at } => self.set_member_expiration(member, expires_at),
+            MsConfigAction::ChangeTimeLock { time_lock } => self.set_time_lock(time_lock),
+            MsConfigAction::ChangeVetoPeriod { veto_period } => self.set_veto_period(veto_period),
+            MsConfigAction::ChangeConfigTimeLock { config_time_lock } => self.set_config_time_lock(config_time_lock),
+        }
+    }
+
+    /// checks if the key is in the recovery set
+    pub fn is_recovery_key(&self, key: Pubkey) -> bool {
+        self.recovery_keys.binary_search(&key).is_ok()
+    }
+
+    /// sets the recovery set, its threshold and delay. An empty set (with a threshold of 0)
+    /// disables the recovery of member keys.
+    pub fn set_recovery_set(&mut self, mut recovery_keys: Vec<Pubkey>, recovery_threshold: u16, recovery_delay: u32) -> Result<()>{
+        recovery_keys.sort();
+        recovery_keys.dedup();
+        if recovery_keys.len() > Ms::MAX_RECOVERY_KEYS {
+            return err!(MsError::InvalidRecoverySet);
+        }
+        let valid_threshold = match recovery_keys.len() {
+            0 => recovery_threshold == 0,
+            len => recovery_threshold >= 1 && usize::from(recovery_threshold) <= len,
+        };
+        if !valid_threshold {
+            return err!(MsError::InvalidRecoverySet);
+        }
+        self.recovery_keys = recovery_keys;
+        self.recovery_threshold = recovery_threshold;
+        self.recovery_delay = recovery_delay;
         Ok(())
     }
 
-}
+    /// checks if the key is in the veto council
+    pub fn is_veto_council_member(&self, key: Pubkey) -> bool {
+        self.veto_council.binary_search(&key).is_ok()
+    }
 
-/// MsTransactionStatus enum of the current status of the Multisig Transaction.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum MsTransactionStatus {
-    Draft,          // Transaction default state
-    Active,         // Transaction is live and ready
-    ExecuteReady,   // Transaction has been approved and is pending execution
-    Executed,       // Transaction has been executed
-    Rejected,       // Transaction has been rejected
-    Cancelled,      // Transaction has been cancelled
-}
+    /// sets the veto council and the number of its vetoes that reject a transaction. An empty
+    /// council (with a threshold of 0) disables it.
+    pub fn set_veto_council(&mut self, mut veto_council: Vec<Pubkey>, veto_council_threshold: u16) -> Result<()>{
+        veto_council.sort();
+        veto_council.dedup();
+        if veto_council.len() > Ms::MAX_VETO_COUNCIL {
+            return err!(MsError::InvalidVetoCouncil);
+        }
+        let valid_threshold = match veto_council.len() {
+            0 => veto_council_threshold == 0,
+            len => veto_council_threshold >= 1 && usize::from(veto_council_threshold) <= len,
+        };
+        if !valid_threshold {
+            return err!(MsError::InvalidVetoCouncil);
+        }
+        self.veto_council = veto_council;
+        self.veto_council_threshold = veto_council_threshold;
+        Ok(())
+    }
 
-/// The MsTransaction is the state account for a multisig transaction
-#[account]
-pub struct MsTransaction {
-    pub creator: Pubkey,                // creator, used to seed pda
-    pub ms: Pubkey,                     // the multisig this belongs to
-    pub transaction_index: u32,         // used for seed
-    pub authority_index: u32,           // index to use for the cpi signing PDA (authority)
-    pub authority_bump: u8,             // the bump corresponding to the PDA (authority)
-    pub status: MsTransactionStatus,    // the status of the transaction
-    pub instruction_index: u8,          // index of this instruction
-    pub bump: u8,                       // bump for the seed
-    pub approved: Vec<Pubkey>,          // keys that have approved/signed
-    pub rejected: Vec<Pubkey>,          // keys that have rejected
-    pub cancelled: Vec<Pubkey>,         // keys that have cancelled (ExecuteReady only)
-    pub executed_index: u8              // if Tx is executed sequentially, tracks which ix
-                                        // has been executed so far.
-}
+    /// sets the number of seconds between a member removal being executed and taking effect
+    pub fn set_removal_delay(&mut self, removal_delay: u32) -> Result<()>{
+        self.removal_delay = removal_delay;
+        Ok(())
+    }
 
-impl MsTransaction {
-    // the minimum size without the approved/rejected vecs
-    pub const MINIMUM_SIZE: usize = 32 +    // the creator pubkey
-        32 +                                // the multisig key
-        4 +                                 // the transaction index
-        4 +                                 // the authority index (for this proposal)
-        1 +                                 // the authority bump
-        (1 + 12) +                          // the enum size
-        1 +                                 // the number of instructions (attached)
-        1 +                                 // space for tx bump
-        1;                                  // track index if executed sequentially
+    /// schedules the removal of a member after the removal delay. Only one removal can be
+    /// pending at a time.
+    pub fn schedule_removal(&mut self, member: Pubkey) -> Result<i64>{
+        if self.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if self.pending_removal.is_some() {
+            return err!(MsError::RemovalPending);
+        }
+        let remove_at = Clock::get()?.unix_timestamp.checked_add(i64::from(self.removal_delay)).ok_or(MsError::TimestampOverflow)?;
+        self.pending_removal = Some(PendingRemoval { member, remove_at });
+        Ok(remove_at)
+    }
 
-    pub fn initial_size_with_members(members_len: usize) -> usize {
-        MsTransaction::MINIMUM_SIZE + (3 * (4 + (members_len * 32) ) )
+    /// takes the pending removal once the removal delay has passed, returning the member to remove
+    pub fn take_due_removal(&mut self) -> Result<Pubkey>{
+        let pending_removal = self.pending_removal.ok_or(MsError::NoPendingRemoval)?;
+        if Clock::get()?.unix_timestamp < pending_removal.remove_at {
+            return err!(MsError::RemovalDelayNotElapsed);
+        }
+        self.pending_removal = None;
+        Ok(pending_removal.member)
     }
 
-    /// initializes the transaction account
-    pub fn init(&mut self, creator: Pubkey, multisig: Pubkey, transaction_index: u32, bump: u8, authority_index: u32, authority_bump: u8) -> Result<()>{
-        self.creator = creator;
-        self.ms = multisig;
-        self.transaction_index = transaction_index;
-        self.authority_index = authority_index;
-        self.authority_bump = authority_bump;
-        self.status = MsTransactionStatus::Draft;
-        self.instruction_index = 0;
-        self.approved = Vec::new();
-        self.rejected = Vec::new();
-        self.cancelled = Vec::new();
-        self.bump = bump;
-        self.executed_index = 0;
+    /// aborts the pending removal, returning the member that was to be removed
+    pub fn abort_removal(&mut self) -> Result<Pubkey>{
+        let pending_removal = self.pending_removal.take().ok_or(MsError::NoPendingRemoval)?;
+        Ok(pending_removal.member)
+    }
+
+    /// sets the number of seconds after approval during which a transaction can be vetoed
+    pub fn set_veto_period(&mut self, veto_period: u32) -> Result<()>{
+        self.veto_period = veto_period;
         Ok(())
     }
 
-    /// change status to Active
-    pub fn activate(&mut self)-> Result<()>{
-        self.status = MsTransactionStatus::Active;
+    /// sets the number of seconds an internal (config) transaction must wait after being approved
+    /// before it can be executed
+    pub fn set_config_time_lock(&mut self, config_time_lock: u32) -> Result<()>{
+        self.config_time_lock = config_time_lock;
         Ok(())
     }
 
-    /// change status to ExecuteReady
-    pub fn ready_to_execute(&mut self)-> Result<()>{
-        self.status = MsTransactionStatus::ExecuteReady;
+    /// the number of seconds a transaction of the authority must wait after being approved before
+    /// it can be executed, covering the time lock, the veto period and for internal (config)
+    /// transactions the config time lock
+    pub fn execution_delay(&self, authority_index: u32) -> u32 {
+        let delay = self.time_lock.max(self.veto_period);
+        match authority_index {
+            0 => delay.max(self.config_time_lock),
+            _ => delay,
+        }
+    }
+
+    /// sets the admin of a new multisig, which can apply config changes on its own until the
+    /// expiry slot, at most MAX_ADMIN_DURATION_SLOTS from now
+    pub fn set_admin(&mut self, admin: Pubkey, admin_expires_at_slot: u64) -> Result<()>{
+        let slot = Clock::get()?.slot;
+        if admin_expires_at_slot <= slot || admin_expires_at_slot - slot > Ms::MAX_ADMIN_DURATION_SLOTS {
+            return err!(MsError::InvalidAdmin);
+        }
+        self.admin = Some(admin);
+        self.admin_expires_at_slot = admin_expires_at_slot;
         Ok(())
     }
 
-    /// set status to Rejected
-    pub fn set_rejected(&mut self) -> Result<()>{
-        self.status = MsTransactionStatus::Rejected;
+    /// checks if the key is the admin of the multisig
+    pub fn is_admin(&self, key: Pubkey) -> bool {
+        self.admin == Some(key)
+    }
+
+    /// checks that the admin hasn't expired
+    pub fn check_admin(&self) -> Result<()>{
+        if Clock::get()?.slot >= self.admin_expires_at_slot {
+            return err!(MsError::AdminExpired);
+        }
         Ok(())
     }
 
-    /// set status to Cancelled
-    pub fn set_cancelled(&mut self) -> Result<()>{
-        self.status = MsTransactionStatus::Cancelled;
+    /// removes the admin, which can't be set again
+    pub fn renounce_admin(&mut self) -> Result<()>{
+        self.admin = None;
+        self.admin_expires_at_slot = 0;
         Ok(())
     }
 
-    /// set status to executed
-    pub fn set_executed(&mut self) -> Result<()>{
-        self.status = MsTransactionStatus::Executed;
+    /// sets the guardian of the multisig, None removes the guardian
+    pub fn set_guardian(&mut self, guardian: Option<Pubkey>) -> Result<()>{
+        self.guardian = guardian;
         Ok(())
     }
 
-    /// sign to approve a transaction
-    pub fn sign(&mut self, member: Pubkey) -> Result<()>{
-        self.approved.push(member);
-        self.approved.sort();
+    /// checks if the key is the guardian of the multisig
+    pub fn is_guardian(&self, key: Pubkey) -> bool {
+        self.guardian == Some(key)
+    }
+
+    /// freezes or unfreezes the multisig
+    pub fn set_frozen(&mut self, frozen: bool) -> Result<()>{
+        self.frozen = frozen;
         Ok(())
     }
 
-    /// sign to reject the transaction
-    pub fn reject(&mut self, member: Pubkey) -> Result<()> {
-        self.rejected.push(member);
-        self.rejected.sort();
+    /// checks if transactions of the authority are blocked by a freeze,
+    /// internal transactions are never frozen so the multisig can unfreeze itself
+    pub fn is_frozen_for(&self, authority_index: u32) -> bool {
+        self.frozen && authority_index != 0
+    }
+
+    /// checks if the key can execute approved transactions without being a member with the
+    /// execute permission
+    pub fn is_executor(&self, key: Pubkey) -> bool {
+        self.any_executor || self.executors.binary_search(&key).is_ok()
+    }
+
+    /// sets the keys besides members that can execute approved transactions, or lets any
+    /// key execute them
+    pub fn set_executors(&mut self, any_executor: bool, mut executors: Vec<Pubkey>) -> Result<()>{
+        executors.sort();
+        executors.dedup();
+        if executors.len() > Ms::MAX_EXECUTORS {
+            return err!(MsError::InvalidExecutors);
+        }
+        self.any_executor = any_executor;
+        self.executors = executors;
         Ok(())
     }
 
-    /// sign to cancel the transaction if execute_ready
-    pub fn cancel(&mut self, member: Pubkey) -> Result<()> {
-        self.cancelled.push(member);
-        self.cancelled.sort();
+    /// sets the number of members that must have voted before a transaction can be decided,
+    /// which can't exceed the number of members that can vote
+    pub fn set_min_quorum(&mut self, min_quorum: u16) -> Result<()>{
+        if usize::from(min_quorum) > self.voter_count() {
+            return err!(MsError::InvalidQuorum);
+        }
+        self.min_quorum = min_quorum;
         Ok(())
     }
 
+    /// advances the sequence number, returning the new one
+    pub fn advance_seq(&mut self) -> u64 {
+        self.seq = self.seq.wrapping_add(1);
+        self.seq
+    }
 
-    /// check if a user has voted already
-    pub fn has_voted(&self, member: Pubkey) -> bool {
-        let approved = self.approved.binary_search(&member).is_ok();
-        let rejected = self.rejected.binary_search(&member).is_ok();
-        approved || rejected
+    /// sets (or removes) the program invoked before and after executing vault transactions
+    pub fn set_execution_hook(&mut self, execution_hook: Option<Pubkey>) -> Result<()>{
+        if execution_hook == Some(crate::ID) {
+            return err!(MsError::InvalidExecutionHook);
+        }
+        self.execution_hook = execution_hook;
+        Ok(())
     }
 
-    /// check if a user has signed to approve
-    pub fn has_voted_approve(&self, member: Pubkey) -> Option<usize> {
-        self.approved.binary_search(&member).ok()
+    /// sets (or removes) the vault authority of the parent multisig
+    pub fn set_parent(&mut self, parent: Option<Pubkey>) -> Result<()>{
+        self.parent = parent;
+        Ok(())
     }
 
-    /// check if a use has signed to reject
-    pub fn has_voted_reject(&self, member: Pubkey) -> Option<usize> {
-        self.rejected.binary_search(&member).ok()
+    /// checks if the key can create and fill transactions: a member with the initiate
+    /// permission, or the vault authority of the parent multisig
+    pub fn can_initiate(&self, key: Pubkey) -> bool {
+        self.has_permission(key, Permissions::INITIATE) || self.parent == Some(key)
     }
 
-    /// check if a user has signed to cancel
-    pub fn has_cancelled(&self, member: Pubkey) -> Option<usize> {
-        self.cancelled.binary_search(&member).ok()
+    /// checks if the authority (vault) was retired
+    pub fn is_authority_retired(&self, authority_index: u32) -> bool {
+        self.retired_authorities.binary_search(&authority_index).is_ok()
     }
 
-    /// removes the key from the rejected vec based on index.
-    /// used when changing from rejected to approved
-    pub fn remove_reject(&mut self, index: usize) -> Result<()>{
-        self.rejected.remove(index);
+    /// marks the authority as retired, internal transactions (authority 0) can't be retired
+    pub fn retire_authority(&mut self, authority_index: u32) -> Result<()>{
+        if authority_index == 0 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        match self.retired_authorities.binary_search(&authority_index) {
+            Ok(_) => err!(MsError::AuthorityRetired),
+            Err(ind) => {
+                self.retired_authorities.insert(ind, authority_index);
+                Ok(())
+            }
+        }
+    }
+
+    /// pauses or unpauses executions of the multisig
+    pub fn set_paused(&mut self, paused: bool) -> Result<()>{
+        self.paused = paused;
         Ok(())
     }
 
-    /// removes the key from the approved vec based on index
-    /// used when changing from approved to rejected
-    pub fn remove_approve(&mut self, index: usize) -> Result<()>{
-        self.approved.remove(index);
+    /// checks if executions of the authority are blocked by a pause,
+    /// internal transactions are never paused so the multisig can unpause itself
+    pub fn is_paused_for(&self, authority_index: u32) -> bool {
+        self.paused && authority_index != 0
+    }
+
+    /// checks if executions of the authority are blocked by the execution window or a blackout period,
+    /// internal transactions are never blocked so the multisig can change its schedule
+    pub fn is_outside_execution_window(&self, authority_index: u32) -> bool {
+        if authority_index == 0 || (self.execution_window.is_none() && self.blackout_periods.is_empty()) {
+            return false;
+        }
+        let now = match Clock::get() {
+            Ok(clock) => clock.unix_timestamp,
+            Err(_) => return true,
+        };
+        let in_window = self.execution_window.is_none_or(|w| w.contains(now));
+        !in_window || self.blackout_periods.iter().any(|b| b.start <= now && now < b.end)
+    }
+
+    /// sets (or removes) the execution window of vault transactions
+    pub fn set_execution_window(&mut self, execution_window: Option<ExecutionWindow>) -> Result<()>{
+        if let Some(window) = execution_window {
+            window.check()?;
+        }
+        self.execution_window = execution_window;
         Ok(())
     }
 
-}
+    /// sets (or removes) the governance whose token holders must ratify the transactions
+    pub fn set_ratification(&mut self, ratification: Option<Ratification>) -> Result<()>{
+        self.ratification = ratification;
+        Ok(())
+    }
 
-/// The state account for an instruction that is attached to a transaction.
-/// Almost analagous to the native Instruction struct for solana, but with an extra
-/// field for the bump.
-#[account]
-pub struct MsInstruction {
-    pub program_id: Pubkey,
-    pub keys: Vec<MsAccountMeta>,
-    pub data: Vec<u8>,
-    pub instruction_index: u8,
-    pub bump: u8,
-    pub executed: bool, // deprecated in favor for executed_index in the MsTransaction
-}
+    /// checks that the transaction is ratified, if the multisig requires it: the proposal recorded on
+    /// it must be in the remaining accounts, and have succeeded in the governance
+    pub fn check_ratification(&self, ratifying_proposal: Option<Pubkey>, accounts: &[AccountInfo]) -> Result<()>{
+        let ratification = match &self.ratification {
+            Some(ratification) => ratification,
+            None => return Ok(()),
+        };
+        let proposal_key = ratifying_proposal.ok_or(MsError::RatificationRequired)?;
+        let proposal = accounts
+            .iter()
+            .find(|a| a.key == &proposal_key)
+            .ok_or(MsError::InvalidRatifyingProposal)?;
+        ratification.check(proposal)
+    }
 
-impl MsInstruction {
-    pub const MAXIMUM_SIZE: usize = 1280;   // no longer used but kept for reference, was previously a client side limitation for sizing.
+    /// replaces the blackout periods, whose starts must be before their ends
+    pub fn set_blackout_periods(&mut self, mut blackout_periods: Vec<BlackoutPeriod>) -> Result<()>{
+        if blackout_periods.len() > Ms::MAX_BLACKOUT_PERIODS || blackout_periods.iter().any(|b| b.start >= b.end) {
+            return err!(MsError::InvalidExecutionWindow);
+        }
+        blackout_periods.sort_by_key(|b| b.start);
+        self.blackout_periods = blackout_periods;
+        Ok(())
+    }
 
-    /// Initializes the instruction account
-    pub fn init(&mut self, instruction_index: u8, incoming_instruction: IncomingInstruction, bump: u8) -> Result<()> {
-        self.bump = bump;
-        self.instruction_index = instruction_index;
-        self.program_id = incoming_instruction.program_id;
-        self.keys = incoming_instruction.keys;
-        self.data = incoming_instruction.data;
-        self.executed = false;
+    /// upgrades the multisig from an older layout version to the current one. The fields a
+    /// version introduced are read as zero from older accounts, so only those that default to
+    /// something else are filled in.
+    pub fn migrate(&mut self) -> Result<()>{
+        if self.version >= Ms::VERSION {
+            return err!(MsError::AlreadyMigrated);
+        }
+        // v0: the original layout, parsed by try_deserialize_baseline, whose authority index is
+        //     two bytes wide and which ends with the keys. The fields introduced by v1 are zero,
+        //     except for the default vault, authority 1, and the last execution epoch, now so that
+        //     the inactivity recovery isn't due right away
+        if self.version < 1 {
+            self.default_vault_index = 1;
+            self.last_execution_epoch = Clock::get()?.epoch;
+        }
+        self.version = Ms::VERSION;
         Ok(())
     }
-}
 
-impl From<MsInstruction> for Instruction {
-    /// Converts the MsInstruction to a native Instruction
-    fn from(instruction: MsInstruction) -> Self {
-        Instruction {
-            program_id: instruction.program_id,
-            accounts: instruction
-                .keys
-                .iter()
-                .map(|account| AccountMeta {
-                    pubkey: account.pubkey,
-                    is_signer: account.is_signer,
-                    is_writable: account.is_writable,
-                })
-                .collect(),
-            data: instruction.data.clone(),
+    /// parses a multisig account of the original layout, which can't be loaded as is: its
+    /// authority index is two bytes wide, and it ends with the keys. The authority index is
+    /// widened in a copy of the account data, which is cut after the keys (dropping the stale
+    /// bytes of removed keys) and padded with zeros for the fields introduced since.
+    pub fn try_deserialize_baseline(account_data: &[u8]) -> Result<Ms> {
+        if account_data.get(..8) != Some(&Ms::discriminator()[..]) {
+            return err!(MsError::InvalidInstructionAccount);
         }
+        // the authority index follows the discriminator and the threshold
+        let index_end = 8 + 2 + 2;
+        // then the transaction and change indexes, the bump, the create key, the external
+        // execute flag, and the keys
+        let keys_len_at = index_end + 4 + 4 + 1 + 32 + 1;
+        let keys_len = account_data
+            .get(keys_len_at..keys_len_at + 4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize)
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        let keys_end = keys_len
+            .checked_mul(32)
+            .and_then(|len| len.checked_add(keys_len_at + 4))
+            .filter(|end| *end <= account_data.len())
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        let mut widened: Vec<u8> = Vec::with_capacity(keys_end + 2 + Ms::SIZE_WITHOUT_MEMBERS);
+        widened.extend_from_slice(&account_data[..index_end]);
+        widened.extend_from_slice(&[0, 0]);
+        widened.extend_from_slice(&account_data[index_end..keys_end]);
+        // the fields introduced since take no more than their size without members once zeroed
+        widened.resize(widened.len() + Ms::SIZE_WITHOUT_MEMBERS, 0);
+        Ms::try_deserialize(&mut widened.as_slice())
     }
-}
 
-/// Wrapper for our internal MsInstruction key serialization schema
-/// MsAccount meta is identical to the AccountMeta struct, but defined
-/// here for serialization purposes.
-#[derive(AnchorSerialize,AnchorDeserialize, Copy, Clone)]
-pub struct MsAccountMeta {
-    pub pubkey: Pubkey,
-    pub is_signer: bool,
-    pub is_writable: bool
-}
+    /// checks if executing the transaction would interleave with the sequential execution
+    /// of another transaction. Internal transactions are exempt, so the multisig can always
+    /// change itself.
+    pub fn is_executing_other(&self, transaction_index: u32, authority_index: u32) -> bool {
+        authority_index != 0 && self.executing_transaction != 0 && self.executing_transaction != transaction_index
+    }
 
-/// Incoming instruction schema, used as an argument in the attach_instruction.
-/// Identical to the solana struct for Instruction, but uses the MsAccountMeta.
-/// Provided for de/serialization purposes.
-#[derive(AnchorSerialize,AnchorDeserialize, Clone)]
-pub struct IncomingInstruction {
+    /// marks the transaction as the one being executed instruction by instruction
+    pub fn start_execution(&mut self, transaction_index: u32) -> Result<()>{
+        self.executing_transaction = transaction_index;
+        Ok(())
+    }
+
+    /// sets the most members the multisig can have, 0 leaves only the Ms::MAX_MEMBERS ceiling.
+    /// The cap can't be below the current member count.
+    pub fn set_max_members(&mut self, max_members: u16) -> Result<()>{
+        if usize::from(max_members) > Ms::MAX_MEMBERS || (max_members > 0 && usize::from(max_members) < self.keys.len()) {
+            return err!(MsError::InvalidMaxMembers);
+        }
+        self.max_members = max_members;
+        Ok(())
+    }
+
+    /// the most members the multisig can have, its own max members if set, or Ms::MAX_MEMBERS
+    pub fn max_member_count(&self) -> usize {
+        match self.max_members {
+            0 => Ms::MAX_MEMBERS,
+            max_members => usize::from(max_members).min(Ms::MAX_MEMBERS),
+        }
+    }
+
+    /// sets the cap on the open transactions of a single creator, 0 removes the cap
+    pub fn set_max_open_transactions(&mut self, max_open_transactions: u16) -> Result<()>{
+        self.max_open_transactions = max_open_transactions;
+        Ok(())
+    }
+
+    /// the size of the account once the creator's open transactions are tracked,
+    /// for the realloc when a transaction is created
+    pub fn size_with_open_transaction(&self, creator: Pubkey) -> Result<usize> {
+        let tracked = self.open_transactions.binary_search_by_key(&creator, |o| o.creator).is_ok();
+        if self.max_open_transactions == 0 || tracked {
+            return self.size_with_additional(0);
+        }
+        self.size_with_additional(OpenTransactions::SIZE)
+    }
+
+    /// counts a new transaction of the creator, failing if the creator already has the
+    /// max open transactions. A no-op when there's no cap.
+    pub fn open_transaction(&mut self, creator: Pubkey) -> Result<()>{
+        if self.max_open_transactions == 0 {
+            return Ok(());
+        }
+        match self.open_transactions.binary_search_by_key(&creator, |o| o.creator) {
+            Ok(ind) if self.open_transactions[ind].count >= self.max_open_transactions => {
+                err!(MsError::MaxOpenTransactionsReached)
+            }
+            Ok(ind) => {
+                self.open_transactions[ind].count += 1;
+                Ok(())
+            }
+            Err(ind) => {
+                self.open_transactions.insert(ind, OpenTransactions { creator, count: 1 });
+                Ok(())
+            }
+        }
+    }
+
+    /// stops counting a transaction (or config transaction) of the creator once it's done, ie. executed,
+    /// rejected, cancelled or expired. An approved transaction is still open until it's executed,
+    /// cancelled or expired, and deprecated transactions were no longer counted from the change of the multisig.
+    pub fn release_transaction(&mut self, creator: Pubkey, transaction_index: u32, status: &MsTransactionStatus) -> Result<()>{
+        if !status.is_terminal() || transaction_index <= self.ms_change_index {
+            return Ok(());
+        }
+        if let Ok(ind) = self.open_transactions.binary_search_by_key(&creator, |o| o.creator) {
+            self.open_transactions[ind].count = self.open_transactions[ind].count.saturating_sub(1);
+            if self.open_transactions[ind].count == 0 {
+                self.open_transactions.remove(ind);
+            }
+        }
+        Ok(())
+    }
+
+    /// clears the sequential execution of the transaction, if it was the one being executed
+    pub fn release_execution(&mut self, transaction_index: u32) -> Result<()>{
+        if self.executing_transaction == transaction_index {
+            self.executing_transaction = 0;
+        }
+        Ok(())
+    }
+
+    /// switches between recording votes in VoteRecord accounts and in the transaction vote bitmaps
+    pub fn set_vote_records(&mut self, vote_records: bool) -> Result<()>{
+        // paged members and members of the member root can only vote with vote records
+        if !vote_records && (self.member_pages > 0 || self.member_root.is_some()) {
+            return err!(MsError::VoteRecordsRequired);
+        }
+        self.vote_records = vote_records;
+        Ok(())
+    }
+
+    /// counts a new page of members, returning its index. Pages are only voted from with vote records.
+    pub fn add_member_page(&mut self) -> Result<u8>{
+        if !self.vote_records {
+            return err!(MsError::VoteRecordsRequired);
+        }
+        let page_index = self.member_pages;
+        self.member_pages = page_index.checked_add(1).ok_or(MsError::MaxMembersReached)?;
+        Ok(page_index)
+    }
+
+    /// sets (or removes) the merkle root of an additional member set along with its member count,
+    /// which the members approving the root vouch for. Its members vote with vote records.
+    pub fn set_member_root(&mut self, member_root: Option<[u8; 32]>, member_count: u32) -> Result<()>{
+        match member_root {
+            Some(_) if !self.vote_records => return err!(MsError::VoteRecordsRequired),
+            Some(_) if member_count == 0 => return err!(MsError::InvalidMemberRoot),
+            None if member_count > 0 => return err!(MsError::InvalidMemberRoot),
+            _ => {}
+        }
+        self.member_root = member_root;
+        self.root_member_count = member_count;
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// checks the inclusion proof of the member in the set of the member root. The leaves are the
+    /// hashes of the member keys and each node hashes the sorted pair of its children, with domain
+    /// prefixes of 0 for the leaves and 1 for the nodes.
+    pub fn is_root_member(&self, member: Pubkey, proof: &[[u8; 32]]) -> bool {
+        let member_root = match self.member_root {
+            Some(member_root) => member_root,
+            None => return false,
+        };
+        let leaf = hashv(&[&[0], member.as_ref()]).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            hashv(&[&[1], &left, &right]).to_bytes()
+        });
+        root == member_root
+    }
+
+    /// counts a member added to a page
+    pub fn add_paged_member(&mut self) -> Result<()>{
+        self.paged_member_count = self.paged_member_count.checked_add(1).ok_or(MsError::MaxMembersReached)?;
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// stops counting a member removed from a page
+    pub fn remove_paged_member(&mut self) -> Result<()>{
+        self.paged_member_count = self.paged_member_count.checked_sub(1).ok_or(MsError::KeyNotInMultisig)?;
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// sets (or removes) the mint or collection whose token holders can vote, the token threshold must be at least 1
+    pub fn set_membership_gate(&mut self, membership_gate: Option<MembershipGate>) -> Result<()>{
+        if membership_gate.is_some_and(|gate| gate.threshold() == 0) {
+            return err!(MsError::InvalidMembershipGate);
+        }
+        self.membership_gate = membership_gate;
+        Ok(())
+    }
+
+    /// replaces the value tiers, which must have reachable thresholds and distinct mint and min amount pairs
+    pub fn set_value_tiers(&mut self, mut value_tiers: Vec<ValueTier>) -> Result<()>{
+        if value_tiers.len() > Ms::MAX_VALUE_TIERS {
+            return err!(MsError::InvalidValueTier);
+        }
+        let max_threshold = self.total_weight().min(self.voting_weight());
+        if value_tiers.iter().any(|t| t.threshold < 1 || usize::from(t.threshold) > max_threshold) {
+            return err!(MsError::InvalidValueTier);
+        }
+        value_tiers.sort_by_key(|t| (t.mint, t.min_amount));
+        if value_tiers.windows(2).any(|w| w[0].mint == w[1].mint && w[0].min_amount == w[1].min_amount) {
+            return err!(MsError::InvalidValueTier);
+        }
+        self.value_tiers = value_tiers;
+        Ok(())
+    }
+
+    /// the threshold of a vault transaction from the total amount it transfers of each mint (the
+    /// default pubkey for SOL). Each mint gets the tier with the largest min amount it reaches, and
+    /// the highest of their thresholds applies. Tiers only lower the threshold of transactions made
+    /// up of transfers of mints with a tier, so other instructions can't ride along on a low tier.
+    pub fn tier_threshold(&self, authority_index: u32, transfers: &[(Pubkey, u64)], transfers_only: bool) -> u16 {
+        let default_threshold = self.threshold_for(authority_index);
+        let mut lowers = transfers_only;
+        let mut tier_threshold: Option<u16> = None;
+        for (mint, amount) in transfers {
+            let tier = self.value_tiers
+                .iter()
+                .rev()
+                .find(|t| t.mint == *mint && t.min_amount <= *amount);
+            match tier {
+                Some(tier) => tier_threshold = Some(tier_threshold.unwrap_or(0).max(tier.threshold)),
+                None => lowers = false,
+            }
+        }
+        let threshold = match tier_threshold {
+            Some(threshold) if lowers => threshold,
+            Some(threshold) => threshold.max(default_threshold),
+            None => default_threshold,
+        };
+        // members may have been removed since the tiers were set
+        usize::from(threshold).clamp(1, self.total_weight().max(1)) as u16
+    }
+
+    /// sets (or removes) the staking program the vote weights of the members are read from
+    pub fn set_stake_weight_source(&mut self, stake_weight_source: Option<StakeWeightSource>) -> Result<()>{
+        if let Some(source) = stake_weight_source {
+            source.check()?;
+        }
+        self.stake_weight_source = stake_weight_source;
+        Ok(())
+    }
+
+    /// checks if PDAs of the program can be added as members
+    pub fn is_member_program(&self, program_id: Pubkey) -> bool {
+        self.member_programs.binary_search(&program_id).is_ok()
+    }
+
+    /// checks if instructions of transactions of the authority may target the program.
+    /// Internal transactions (authority 0) can only target this program, which is checked separately.
+    pub fn is_program_allowed(&self, authority_index: u32, program_id: &Pubkey) -> bool {
+        match self.program_allowlists.binary_search_by_key(&authority_index, |a| a.authority_index) {
+            Ok(ind) => self.program_allowlists[ind].programs.contains(program_id),
+            _ => true
+        }
+    }
+
+    /// errors if instructions of transactions of the authority may not target the program
+    pub fn check_program_allowed(&self, authority_index: u32, program_id: &Pubkey) -> Result<()> {
+        if !self.is_program_allowed(authority_index, program_id) {
+            return err!(MsError::ProgramNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// sets the programs that instructions of transactions of the authority may target.
+    /// An empty list removes the allowlist, so the authority is unrestricted.
+    pub fn set_program_allowlist(&mut self, authority_index: u32, mut programs: Vec<Pubkey>) -> Result<()>{
+        if authority_index == 0 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        programs.sort();
+        programs.dedup();
+        if programs.len() > ProgramAllowlist::MAX_PROGRAMS {
+            return err!(MsError::InvalidProgramAllowlist);
+        }
+        match self.program_allowlists.binary_search_by_key(&authority_index, |a| a.authority_index) {
+            Ok(ind) if programs.is_empty() => {
+                self.program_allowlists.remove(ind);
+            }
+            Ok(ind) => self.program_allowlists[ind].programs = programs,
+            Err(_) if programs.is_empty() => {}
+            Err(ind) => self.program_allowlists.insert(ind, ProgramAllowlist { authority_index, programs }),
+        }
+        Ok(())
+    }
+
+    /// errors if the instruction matches a denied program and data prefix pair
+    pub fn check_instruction_not_denied(&self, program_id: &Pubkey, data: &[u8]) -> Result<()> {
+        if self
+            .denied_instructions
+            .iter()
+            .any(|d| &d.program_id == program_id && data.starts_with(&d.prefix))
+        {
+            return err!(MsError::InstructionDenied);
+        }
+        Ok(())
+    }
+
+    /// denies or allows again instructions of the program whose data starts with the prefix,
+    /// ie. the discriminator of an instruction. An empty prefix denies the whole program.
+    pub fn set_denied_instruction(&mut self, program_id: Pubkey, prefix: Vec<u8>, denied: bool) -> Result<()>{
+        if prefix.len() > DeniedInstruction::MAX_PREFIX_LEN {
+            return err!(MsError::InvalidDeniedInstruction);
+        }
+        let ind = self
+            .denied_instructions
+            .binary_search_by(|d| (&d.program_id, &d.prefix).cmp(&(&program_id, &prefix)));
+        match (ind, denied) {
+            (Err(ind), true) => self.denied_instructions.insert(ind, DeniedInstruction { program_id, prefix }),
+            (Ok(ind), false) => {
+                self.denied_instructions.remove(ind);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// allows or disallows adding PDAs of the program as members.
+    /// Disallowing a program doesn't remove its PDAs that are already members.
+    pub fn set_member_program(&mut self, program_id: Pubkey, allowed: bool) -> Result<()>{
+        match (self.member_programs.binary_search(&program_id), allowed) {
+            (Err(ind), true) => self.member_programs.insert(ind, program_id),
+            (Ok(ind), false) => {
+                self.member_programs.remove(ind);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Adds a member to the multisig, keeping the keys sorted. Fails if the member is already in the multisig.
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()>{
+        let ind = self.keys.binary_search(&member).err().ok_or(MsError::DuplicateMember)?;
+        self.keys.insert(ind, member);
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// Removes a member from the multisig. Is a no-op if the member is not in the multisig.
+    pub fn remove_member(&mut self, member: Pubkey) -> Result<()>{
+        if let Some(ind) = self.is_member(member) {
+            self.keys.remove(ind);
+            if matches!(self.pending_removal, Some(p) if p.member == member) {
+                self.pending_removal = None;
+            }
+            if let Ok(weight_ind) = self.member_weights.binary_search_by_key(&member, |w| w.member) {
+                self.member_weights.remove(weight_ind);
+            }
+            if let Ok(permissions_ind) = self.member_permissions.binary_search_by_key(&member, |p| p.member) {
+                self.member_permissions.remove(permissions_ind);
+            }
+            if let Ok(expiration_ind) = self.member_expirations.binary_search_by_key(&member, |e| e.member) {
+                self.member_expirations.remove(expiration_ind);
+            }
+            self.recompute_threshold();
+            // the thresholds and the quorum are left as they are, it's up to validate_config to
+            // reject a removal the remaining members can't reach them after
+        }
+        Ok(())
+    }
+
+    /// Gets the vote weight of a member, members without an override have a weight of 1
+    /// and expired members have no weight.
+    pub fn get_member_weight(&self, member: Pubkey) -> u16 {
+        if self.is_expired_member(member) {
+            return 0;
+        }
+        match self.member_weights.binary_search_by_key(&member, |w| w.member) {
+            Ok(ind) => self.member_weights[ind].weight,
+            _ => 1
+        }
+    }
+
+    /// The combined vote weight of the given keys
+    pub fn weight_of(&self, keys: &[Pubkey]) -> usize {
+        keys.iter().map(|k| usize::from(self.get_member_weight(*k))).sum()
+    }
+
+    /// The combined vote weight of the members set in a transaction vote bitmap
+    pub fn weight_of_bitmap(&self, bitmap: &[u8]) -> usize {
+        self.keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| MsTransaction::has_bit(bitmap, *i))
+            .map(|(_, k)| usize::from(self.get_member_weight(*k)))
+            .sum()
+    }
+
+    /// The combined vote weight of all members, which bounds the threshold.
+    /// Paged members and members of the member root have a weight of 1.
+    pub fn total_weight(&self) -> usize {
+        self.weight_of(&self.keys) + self.additional_member_count()
+    }
+
+    /// The number of members outside of the keys, in the member pages and the set of the member root
+    pub fn additional_member_count(&self) -> usize {
+        self.paged_member_count as usize + self.root_member_count as usize
+    }
+
+    /// Sets the vote weight of a member. A weight of 1 is the default and removes the override.
+    pub fn set_member_weight(&mut self, member: Pubkey, weight: u16) -> Result<()>{
+        if self.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if weight < 1 {
+            return err!(MsError::InvalidMemberWeight);
+        }
+        match self.member_weights.binary_search_by_key(&member, |w| w.member) {
+            Ok(ind) if weight == 1 => {
+                self.member_weights.remove(ind);
+            }
+            Ok(ind) => self.member_weights[ind].weight = weight,
+            Err(_) if weight == 1 => {}
+            Err(ind) => self.member_weights.insert(ind, MemberWeight { member, weight }),
+        }
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// Gets the permission mask of a member, members without an override have all permissions.
+    /// Returns None if the key is not a member.
+    pub fn get_member_permissions(&self, member: Pubkey) -> Option<u8> {
+        self.is_member(member)?;
+        match self.member_permissions.binary_search_by_key(&member, |p| p.member) {
+            Ok(ind) => Some(self.member_permissions[ind].permissions),
+            _ => Some(Permissions::ALL)
+        }
+    }
+
+    /// Checks that the key is a member and has the given permission(s)
+    pub fn has_permission(&self, member: Pubkey, permission: u8) -> bool {
+        matches!(self.get_member_permissions(member), Some(p) if p & permission == permission)
+    }
+
+    /// Sets the permission mask of a member. Setting all permissions removes the override.
+    pub fn set_member_permissions(&mut self, member: Pubkey, permissions: u8) -> Result<()>{
+        if self.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if permissions & !Permissions::ALL != 0 {
+            return err!(MsError::InvalidPermissions);
+        }
+        match self.member_permissions.binary_search_by_key(&member, |p| p.member) {
+            Ok(ind) if permissions == Permissions::ALL => {
+                self.member_permissions.remove(ind);
+            }
+            Ok(ind) => self.member_permissions[ind].permissions = permissions,
+            Err(_) if permissions == Permissions::ALL => {}
+            Err(ind) => self.member_permissions.insert(ind, MemberPermissions { member, permissions }),
+        }
+        Ok(())
+    }
+
+    /// Checks whether the membership of the key has expired
+    pub fn is_expired_member(&self, member: Pubkey) -> bool {
+        match self.member_expirations.binary_search_by_key(&member, |e| e.member) {
+            Ok(ind) => Clock::get()
+                .map(|clock| clock.unix_timestamp >= self.member_expirations[ind].expires_at)
+                .unwrap_or(false),
+            _ => false
+        }
+    }
+
+    /// Checks that the key is a member whose membership hasn't expired, with the vote permission
+    pub fn check_voter(&self, member: Pubkey) -> Result<()> {
+        if self.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if self.is_expired_member(member) {
+            return err!(MsError::MemberExpired);
+        }
+        if !self.has_permission(member, Permissions::VOTE) {
+            return err!(MsError::MissingPermission);
+        }
+        Ok(())
+    }
+
+    /// Sets when the membership of a member expires, None removes the expiration.
+    pub fn set_member_expiration(&mut self, member: Pubkey, expires_at: Option<i64>) -> Result<()>{
+        if self.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        let ind = self.member_expirations.binary_search_by_key(&member, |e| e.member);
+        match (ind, expires_at) {
+            (_, Some(expires_at)) if expires_at <= Clock::get()?.unix_timestamp => {
+                return err!(MsError::InvalidExpiration);
+            }
+            (Ok(ind), Some(expires_at)) => self.member_expirations[ind].expires_at = expires_at,
+            (Err(ind), Some(expires_at)) => self.member_expirations.insert(ind, MemberExpiration { member, expires_at }),
+            (Ok(ind), None) => {
+                self.member_expirations.remove(ind);
+            }
+            (Err(_), None) => {}
+        }
+        Ok(())
+    }
+
+    /// The combined vote weight of the members that are allowed to vote
+    pub fn voting_weight(&self) -> usize {
+        let voters = self
+            .keys
+            .iter()
+            .filter(|k| self.has_permission(**k, Permissions::VOTE))
+            .copied()
+            .collect::<Vec<Pubkey>>();
+        self.weight_of(&voters) + self.additional_member_count()
+    }
+
+    /// The number of members that are allowed to vote, additional members included
+    pub fn voter_count(&self) -> usize {
+        self.keys
+            .iter()
+            .filter(|k| self.has_permission(**k, Permissions::VOTE))
+            .count() + self.additional_member_count()
+    }
+
+    /// sets the threshold for the multisig. An absolute threshold replaces a percentage threshold.
+    pub fn change_threshold(&mut self, threshold: u16) -> Result<()>{
+        self.threshold = threshold;
+        self.threshold_percent = 0;
+        Ok(())
+    }
+
+    /// sets the threshold as a percentage (1 to 100) of the total vote weight, and computes it.
+    /// 0 switches back to an absolute threshold, keeping the current one.
+    pub fn change_threshold_percent(&mut self, threshold_percent: u8) -> Result<()>{
+        if threshold_percent > 100 {
+            return err!(MsError::InvalidThreshold);
+        }
+        self.threshold_percent = threshold_percent;
+        self.recompute_threshold();
+        Ok(())
+    }
+
+    /// recomputes a percentage threshold from the total vote weight. A no-op for absolute thresholds.
+    fn recompute_threshold(&mut self) {
+        self.threshold = self.threshold_at_weight(self.total_weight());
+    }
+
+    /// the threshold for the given total vote weight, which a percentage threshold is computed from,
+    /// rounding up, ie. 60% of 7 is 5. An absolute threshold doesn't depend on it.
+    pub fn threshold_at_weight(&self, total_weight: usize) -> u16 {
+        if self.threshold_percent == 0 {
+            return self.threshold;
+        }
+        let percent_weight = total_weight * usize::from(self.threshold_percent);
+        let threshold = percent_weight.div_ceil(100);
+        threshold.clamp(1, usize::from(u16::MAX)) as u16
+    }
+
+    /// sets the threshold for internal transactions, 0 falls back to the regular threshold
+    pub fn change_config_threshold(&mut self, config_threshold: u16) -> Result<()>{
+        self.config_threshold = config_threshold;
+        Ok(())
+    }
+
+    /// the threshold that applies to transactions of the given authority,
+    /// internal transactions (authority 0) use the config threshold if set
+    pub fn threshold_for(&self, authority_index: u32) -> u16 {
+        match authority_index {
+            0 if self.config_threshold > 0 => self.config_threshold,
+            _ => self.threshold,
+        }
+    }
+
+    /// whether enough members have voted (approved, rejected or abstained) for a vote to be decided
+    pub fn has_quorum(&self, voter_count: usize) -> bool {
+        voter_count >= usize::from(self.min_quorum)
+    }
+
+    /// decides a vote on a transaction or config transaction once the quorum has voted: ExecuteReady
+    /// if the approved weight reaches the threshold, Rejected if the rejected weight means the
+    /// threshold can no longer be reached, none while the vote is still open
+    pub fn decide_vote(&self, threshold: u16, voter_count: usize, approved_weight: usize, rejected_weight: usize) -> Option<MsTransactionStatus> {
+        if !self.has_quorum(voter_count) {
+            return None;
+        }
+        let threshold = usize::from(threshold);
+        if approved_weight >= threshold {
+            return Some(MsTransactionStatus::ExecuteReady);
+        }
+        // ie voting weight 7, threshold 3, cutoff = 4
+        // ie voting weight 8, threshold 6, cutoff = 2
+        // only members that can vote can still approve, and expired members can leave the
+        // voting weight below the threshold until they're pruned
+        let cutoff = self.voting_weight().saturating_sub(threshold);
+        if rejected_weight > cutoff {
+            return Some(MsTransactionStatus::Rejected);
+        }
+        None
+    }
+
+    /// decides a vote recorded in the vote bitmaps of a transaction or config transaction, with the
+    /// weights of the members that approved and rejected, where every vote counts towards the quorum
+    pub fn decide_bitmap_vote(&self, threshold: u16, approved: &[u8], rejected: &[u8], abstained: &[u8]) -> Option<MsTransactionStatus> {
+        let voter_count = MsTransaction::count_votes(&[approved, rejected, abstained]);
+        self.decide_vote(threshold, voter_count, self.weight_of_bitmap(approved), self.weight_of_bitmap(rejected))
+    }
+
+}
+
+/// Vote weight override for a member of the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct MemberWeight {
+    pub member: Pubkey,
+    pub weight: u16,
+}
+
+impl MemberWeight {
+    pub const SIZE: usize = 32 +    // the member key
+        2;                          // the weight
+}
+
+/// The threshold for vault transactions transferring at least the min amount of the mint
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct ValueTier {
+    pub mint: Pubkey,                       // the mint of the transfers, the default pubkey for SOL
+    pub min_amount: u64,                    // the total amount (in base units) from which the tier applies
+    pub threshold: u16,                     // the threshold of the transactions in the tier
+}
+
+impl ValueTier {
+    pub const SIZE: usize = 32 +            // the mint
+        8 +                                 // the min amount
+        2;                                  // the threshold
+}
+
+/// The UTC days and hours vault transactions can be executed in, ie. weekdays from 09:00 to 18:00.
+/// A window ending before it starts spans midnight, ie. from 22:00 to 06:00.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct ExecutionWindow {
+    pub days: u8,                           // bitmask of the days, bit 0 for Monday to bit 6 for Sunday
+    pub start_minute: u16,                  // minute of the (UTC) day the window opens at
+    pub end_minute: u16,                    // minute of the (UTC) day the window closes at
+}
+
+impl ExecutionWindow {
+    pub const SIZE: usize = 1 +             // the days
+        2 +                                 // the start minute
+        2;                                  // the end minute
+
+    pub const MINUTES_PER_DAY: u16 = 24 * 60;
+
+    pub fn check(&self) -> Result<()> {
+        if self.days == 0
+            || self.days >= 1 << 7
+            || self.start_minute >= Self::MINUTES_PER_DAY
+            || self.end_minute >= Self::MINUTES_PER_DAY
+            || self.start_minute == self.end_minute
+        {
+            return err!(MsError::InvalidExecutionWindow);
+        }
+        Ok(())
+    }
+
+    /// checks if the unix timestamp is in the window. For a window spanning midnight,
+    /// the day is the one the window opened on.
+    pub fn contains(&self, timestamp: i64) -> bool {
+        let mut days = timestamp.div_euclid(86_400);
+        let minute = (timestamp.rem_euclid(86_400) / 60) as u16;
+        if self.start_minute < self.end_minute {
+            if minute < self.start_minute || minute >= self.end_minute {
+                return false;
+            }
+        } else if minute < self.end_minute {
+            days -= 1;
+        } else if minute < self.start_minute {
+            return false;
+        }
+        // the unix epoch was a Thursday
+        let weekday = (days + 3).rem_euclid(7);
+        self.days & (1 << weekday) != 0
+    }
+}
+
+/// A time range (unix timestamps, end excluded) vault transactions can't be executed in
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct BlackoutPeriod {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl BlackoutPeriod {
+    pub const SIZE: usize = 8 +     // the start timestamp
+        8;                          // the end timestamp
+}
+
+/// A member removal waiting for the removal delay of the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct PendingRemoval {
+    pub member: Pubkey,
+    pub remove_at: i64,
+}
+
+impl PendingRemoval {
+    pub const SIZE: usize = 32 +    // the member key
+        8;                          // the timestamp the removal can be finalized at
+}
+
+/// Membership expiration of a member of the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct MemberExpiration {
+    pub member: Pubkey,
+    pub expires_at: i64,
+}
+
+impl MemberExpiration {
+    pub const SIZE: usize = 32 +    // the member key
+        8;                          // the expiration timestamp
+}
+
+/// The number of open transactions of a creator, for the max open transactions
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct OpenTransactions {
+    pub creator: Pubkey,
+    pub count: u16,
+}
+
+impl OpenTransactions {
+    pub const SIZE: usize = 32 +    // the creator key
+        2;                          // the number of open transactions
+}
+
+/// Programs that the instructions of transactions of an authority may target
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProgramAllowlist {
+    pub authority_index: u32,
+    pub programs: Vec<Pubkey>,
+}
+
+impl ProgramAllowlist {
+    pub const MAX_PROGRAMS: usize = 16;
+
+    pub fn size(programs_len: usize) -> usize {
+        4 +                         // the authority index
+        4 +                         // for programs vec length
+        32 * programs_len           // the program ids
+    }
+}
+
+/// A program and instruction data prefix (ie. a discriminator) pair that is denied
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DeniedInstruction {
     pub program_id: Pubkey,
-    pub keys: Vec<MsAccountMeta>,
-    pub data: Vec<u8>
+    pub prefix: Vec<u8>,
 }
 
-impl IncomingInstruction {
-    /// Calculates how much space will be needed to allocate to the instruction
-    /// to be attached to the transaction.
-    pub fn get_max_size(&self) -> usize {
-        // add three the size to correlate with the saved instruction account
-        // there are 3 extra bytes in a saved instruction account: index, bump, executed
-        // this is used to determine how much space the incoming instruction
-        // will used when saved
+impl DeniedInstruction {
+    pub const MAX_PREFIX_LEN: usize = 32;
+
+    pub fn size(prefix_len: usize) -> usize {
+        32 +                        // the program id
+        4 +                         // for prefix vec length
+        prefix_len                  // the prefix bytes
+    }
+}
+
+/// Permission bits that can be granted to a member of the multisig
+pub struct Permissions;
+
+impl Permissions {
+    pub const INITIATE: u8 = 1 << 0;        // create, attach instructions to and activate transactions
+    pub const VOTE: u8 = 1 << 1;            // approve, reject and cancel transactions
+    pub const EXECUTE: u8 = 1 << 2;         // execute approved transactions
+    pub const CHANGE_CONFIG: u8 = 1 << 3;   // create internal (authority 0) transactions
+    pub const VETO: u8 = 1 << 4;            // veto approved transactions during the veto period
+    pub const ALL: u8 = Self::INITIATE | Self::VOTE | Self::EXECUTE | Self::CHANGE_CONFIG | Self::VETO;
+}
+
+/// Permission override for a member of the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct MemberPermissions {
+    pub member: Pubkey,
+    pub permissions: u8,
+}
+
+impl MemberPermissions {
+    pub const SIZE: usize = 32 +    // the member key
+        1;                          // the permission mask
+}
+
+/// A typed change of the multisig config, for applying several changes as one batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MsConfigAction {
+    AddMember { member: Pubkey },
+    RemoveMember { member: Pubkey },
+    ReplaceMember { old_member: Pubkey, new_member: Pubkey },
+    ChangeThreshold { threshold: u16 },
+    ChangeConfigThreshold { config_threshold: u16 },
+    SetMemberWeight { member: Pubkey, weight: u16 },
+    SetMemberPermissions { member: Pubkey, permissions: u8 },
+    SetMemberExpiration { member: Pubkey, expires_at: Option<i64> },
+    ChangeTimeLock { time_lock: u32 },
+    ChangeVetoPeriod { veto_period: u32 },
+    ChangeConfigTimeLock { config_time_lock: u32 },
+}
+
+impl MsConfigAction {
+    /// the most the action can grow the multisig account by
+    pub fn additional_len(&self) -> usize {
+        match self {
+            MsConfigAction::AddMember { .. } => 32,
+            MsConfigAction::SetMemberWeight { .. } => MemberWeight::SIZE,
+            MsConfigAction::SetMemberPermissions { .. } => MemberPermissions::SIZE,
+            MsConfigAction::SetMemberExpiration { .. } => MemberExpiration::SIZE,
+            _ => 0,
+        }
+    }
+}
+
+/// MsTransactionStatus enum of the current status of the Multisig Transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MsTransactionStatus {
+    Draft,          // Transaction default state
+    Active,         // Transaction is live and ready
+    ExecuteReady,   // Transaction has been approved and is pending execution
+    Executed,       // Transaction has been executed
+    Rejected,       // Transaction has been rejected
+    Cancelled,      // Transaction has been cancelled
+    Expired,        // Transaction passed its expiration before being executed
+    Recurring,      // Transaction is executed repeatedly on the schedule of its recurring transaction
+}
+
+impl MsTransactionStatus {
+    /// whether a transaction in this status can move to the next one. Drafts are activated, then
+    /// decided by the votes, and approved transactions are executed (or scheduled as recurring), unless
+    /// the veto council rejects them.
+    /// Transactions can be cancelled or expire until they're executed, and recurring ones end as
+    /// executed or cancelled once their schedule is removed.
+    pub fn can_transition_to(&self, next: &MsTransactionStatus) -> bool {
+        use MsTransactionStatus::*;
+        matches!(
+            (self, next),
+            (Draft, Active)
+                | (Active, ExecuteReady | Rejected)
+                | (ExecuteReady, Executed | Recurring | Rejected)
+                | (Draft | Active | ExecuteReady, Cancelled | Expired)
+                | (Recurring, Executed | Cancelled | Rejected)
+        )
+    }
+
+    /// whether the transaction is done, no longer moving to another status
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            MsTransactionStatus::Executed
+                | MsTransactionStatus::Rejected
+                | MsTransactionStatus::Cancelled
+                | MsTransactionStatus::Expired
+        )
+    }
+
+    /// moves to the next status, if the transition is allowed
+    pub fn transition(&mut self, next: MsTransactionStatus) -> Result<()> {
+        if !self.can_transition_to(&next) {
+            return err!(MsError::InvalidStatusTransition);
+        }
+        *self = next;
+        Ok(())
+    }
+}
+
+/// The tag of a transaction, ie. the "PAYR" category with the payroll run as the reference.
+/// Untagged transactions have a zero category.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
+pub struct TransactionTag {
+    pub category: [u8; 4],
+    pub reference: u32,
+}
+
+impl TransactionTag {
+    pub const SIZE: usize = 4 +     // the category
+        4;                          // the reference
+}
+
+/// The MsTransaction is the state account for a multisig transaction
+#[account]
+pub struct MsTransaction {
+    pub creator: Pubkey,                // creator, used to seed pda
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub transaction_index: u32,         // used for seed
+    pub authority_index: u32,           // index to use for the cpi signing PDA (authority)
+    pub authority_bump: u8,             // the bump corresponding to the PDA (authority)
+    pub status: MsTransactionStatus,    // the status of the transaction
+    pub instruction_index: u8,          // index of this instruction
+    pub bump: u8,                       // bump for the seed
+    pub version: u8,                    // layout version of the account, upgraded in place by migrate_transaction.
+                                        // The layout is frozen up to here, later fields are added after it.
+    pub tag: TransactionTag,            // category and reference for reporting, at a fixed offset
+                                        // so transactions can be filtered with memcmp
+    pub approved: Vec<u8>,              // bitmap of the members that have approved/signed
+    pub rejected: Vec<u8>,              // bitmap of the members that have rejected
+    pub cancelled: Vec<u8>,             // bitmap of the members that have cancelled (ExecuteReady only)
+    pub executed_index: u8,             // if Tx is executed sequentially, tracks which ix
+                                        // has been executed so far.
+    pub linked_tx: Option<Pubkey>,      // an executed transaction this one compensates for/follows up on
+    pub ready_at: i64,                  // unix timestamp of when the transaction became ExecuteReady
+    pub expires_at: Option<i64>,        // optional unix timestamp after which the transaction can no longer
+                                        // be activated, approved or executed
+    pub execution_bounty: u64,          // lamports paid to whoever executes the transaction. When set,
+                                        // any key can execute the transaction
+    pub lookup_tables: Vec<Pubkey>,     // address lookup tables used to pass the accounts at execution
+    pub abstained: Vec<u8>,             // bitmap of the members that have formally abstained
+    pub title: String,                  // short human readable title of the proposal
+    pub description: String,            // human readable description of the proposal
+    pub approved_weight: u32,           // tally of the approving VoteRecords (vote records mode only)
+    pub rejected_weight: u32,           // tally of the rejecting VoteRecords (vote records mode only)
+    pub vote_record_count: u16,         // number of VoteRecords tallied, for the quorum (vote records mode only)
+    pub co_creators: Vec<Pubkey>,       // members that can attach instructions to the draft along with the creator, sorted
+    pub threshold: u16,                 // the threshold set from the value tiers at activation, 0 for the multisig threshold
+    pub execution_condition: Option<ExecutionCondition>,    // oracle price condition checked when executing, if any
+    pub ratifying_proposal: Option<Pubkey>, // the governance proposal ratifying the transaction, when the multisig requires one
+    pub council_vetoes: Vec<Pubkey>,    // the keys of the veto council that vetoed, sorted
+    pub token_approved: u32,            // tally of the approving TokenVoteRecords, apart from the member weights
+    pub token_rejected: u32,            // tally of the rejecting TokenVoteRecords, apart from the member weights
+    pub vote_seq: u32,                  // advanced on every vote recorded in the bitmaps, signed votes are
+                                        // signed over it so they can't be replayed after a later vote
+}
+
+impl MsTransaction {
+    // the minimum size without the vote bitmaps
+    pub const MINIMUM_SIZE: usize = 32 +    // the creator pubkey
+        32 +                                // the multisig key
+        4 +                                 // the transaction index
+        4 +                                 // the authority index (for this proposal)
+        1 +                                 // the authority bump
+        (1 + 12) +                          // the enum size
+        1 +                                 // the number of instructions (attached)
+        1 +                                 // space for tx bump
+        1 +                                 // the layout version
+        TransactionTag::SIZE +              // the tag
+        1 +                                 // track index if executed sequentially
+        (1 + 32) +                          // the optional linked transaction
+        8 +                                 // the execute ready timestamp
+        (1 + 8) +                           // the optional expiration timestamp
+        8 +                                 // the execution bounty
+        4 +                                 // the lookup tables vec length
+        4 +                                 // the title length
+        4 +                                 // the description length
+        4 +                                 // the approved weight tally
+        4 +                                 // the rejected weight tally
+        2 +                                 // the vote record count
+        4 +                                 // the co-creators vec length
+        2 +                                 // the tiered threshold
+        (1 + ExecutionCondition::SIZE) +    // the optional execution condition
+        (1 + 32) +                          // the optional ratifying proposal
+        4 +                                 // the council vetoes vec length
+        4 +                                 // the approving token votes
+        4 +                                 // the rejecting token votes
+        4;                                  // the vote sequence
+
+    pub const MAX_LOOKUP_TABLES: usize = 4;
+    pub const MAX_CO_CREATORS: usize = 10;
+    /// the current layout version of the transaction account
+    pub const VERSION: u8 = 1;
+    /// the offset of the layout version in the account data, the same for every version
+    pub const VERSION_OFFSET: usize = 8 + 32 + 32 + 4 + 4 + 1 + 1 + 1 + 1;
+    /// the offset of the tag in the account data (after the discriminator), for memcmp filters.
+    /// Transactions of the original layout have their votes there until they are migrated, which
+    /// writes them back with a zero (untagged) tag at this offset.
+    pub const TAG_OFFSET: usize = MsTransaction::VERSION_OFFSET + 1;
+    pub const MAX_TITLE_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 512;
+    pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+    pub fn initial_size_with_members(members_len: usize) -> usize {
+        MsTransaction::MINIMUM_SIZE + (4 * (4 + MsTransaction::bitmap_len(members_len)))
+    }
+
+    /// the number of bytes in a vote bitmap, one bit per member. Bit i of a bitmap is
+    /// set when the member at index i of the (sorted) multisig keys has voted that way.
+    /// Changing the members deprecates active transactions, so the indexes stay stable
+    /// for as long as the transaction can be voted on.
+    pub fn bitmap_len(members_len: usize) -> usize {
+        members_len.div_ceil(8)
+    }
+
+    /// initializes the transaction account
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(&mut self, creator: Pubkey, multisig: Pubkey, transaction_index: u32, bump: u8, authority_index: u32, authority_bump: u8, members_len: usize) -> Result<()>{
+        self.creator = creator;
+        self.ms = multisig;
+        self.transaction_index = transaction_index;
+        self.authority_index = authority_index;
+        self.authority_bump = authority_bump;
+        self.status = MsTransactionStatus::Draft;
+        self.instruction_index = 0;
+        self.approved = vec![0; MsTransaction::bitmap_len(members_len)];
+        self.rejected = vec![0; MsTransaction::bitmap_len(members_len)];
+        self.cancelled = vec![0; MsTransaction::bitmap_len(members_len)];
+        self.bump = bump;
+        self.version = MsTransaction::VERSION;
+        self.tag = TransactionTag::default();
+        self.executed_index = 0;
+        self.linked_tx = None;
+        self.ready_at = 0;
+        self.expires_at = None;
+        self.execution_bounty = 0;
+        self.lookup_tables = Vec::new();
+        self.abstained = vec![0; MsTransaction::bitmap_len(members_len)];
+        self.title = String::new();
+        self.description = String::new();
+        self.approved_weight = 0;
+        self.rejected_weight = 0;
+        self.vote_record_count = 0;
+        self.co_creators = Vec::new();
+        self.threshold = 0;
+        self.execution_condition = None;
+        self.ratifying_proposal = None;
+        self.council_vetoes = Vec::new();
+        self.token_approved = 0;
+        self.token_rejected = 0;
+        self.vote_seq = 0;
+        Ok(())
+    }
+
+    /// upgrades the transaction from an older layout version to the current one. The fields a
+    /// version introduced are left to their defaults by the parser of the older layout, so only
+    /// those that default to something else are filled in.
+    pub fn migrate(&mut self) -> Result<()>{
+        if self.version >= MsTransaction::VERSION {
+            return err!(MsError::AlreadyMigrated);
+        }
+        // v0: the original layout, parsed by try_deserialize_baseline. Its votes are converted
+        //     to bitmaps, and the fields introduced by v1 are zero or empty
+        self.version = MsTransaction::VERSION;
+        Ok(())
+    }
+
+    /// parses a transaction account of the original layout, which can't be loaded as is: its
+    /// votes are lists of member keys rather than bitmaps, and it ends with the executed index.
+    /// The votes are converted to bitmaps over the (sorted) keys of the multisig, votes of keys
+    /// that are no longer members are dropped (their transactions are deprecated anyway).
+    pub fn try_deserialize_baseline(account_data: &[u8], keys: &[Pubkey]) -> Result<MsTransaction> {
+        if account_data.get(..8) != Some(&MsTransaction::discriminator()[..]) {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let data = &mut &account_data[8..];
+        let mut transaction = MsTransaction {
+            creator: MsTransaction::read_field(data)?,
+            ms: MsTransaction::read_field(data)?,
+            transaction_index: MsTransaction::read_field(data)?,
+            authority_index: MsTransaction::read_field(data)?,
+            authority_bump: MsTransaction::read_field(data)?,
+            status: MsTransaction::read_field(data)?,
+            instruction_index: MsTransaction::read_field(data)?,
+            bump: MsTransaction::read_field(data)?,
+            version: 0,
+            tag: TransactionTag::default(),
+            approved: Vec::new(),
+            rejected: Vec::new(),
+            cancelled: Vec::new(),
+            executed_index: 0,
+            linked_tx: None,
+            ready_at: 0,
+            expires_at: None,
+            execution_bounty: 0,
+            lookup_tables: Vec::new(),
+            abstained: vec![0; MsTransaction::bitmap_len(keys.len())],
+            title: String::new(),
+            description: String::new(),
+            approved_weight: 0,
+            rejected_weight: 0,
+            vote_record_count: 0,
+            co_creators: Vec::new(),
+            threshold: 0,
+            execution_condition: None,
+            ratifying_proposal: None,
+            council_vetoes: Vec::new(),
+            token_approved: 0,
+            token_rejected: 0,
+            vote_seq: 0,
+        };
+        let to_bitmap = |voters: Vec<Pubkey>| -> Result<Vec<u8>> {
+            let mut bitmap = vec![0; MsTransaction::bitmap_len(keys.len())];
+            for member_index in voters.iter().filter_map(|k| keys.binary_search(k).ok()) {
+                MsTransaction::set_bit(&mut bitmap, member_index, true)?;
+            }
+            Ok(bitmap)
+        };
+        transaction.approved = to_bitmap(MsTransaction::read_field(data)?)?;
+        transaction.rejected = to_bitmap(MsTransaction::read_field(data)?)?;
+        transaction.cancelled = to_bitmap(MsTransaction::read_field(data)?)?;
+        transaction.executed_index = MsTransaction::read_field(data)?;
+        Ok(transaction)
+    }
+
+    /// reads the next field of a transaction account of an older layout
+    fn read_field<T: AnchorDeserialize>(data: &mut &[u8]) -> Result<T> {
+        T::deserialize(data).map_err(|_| error!(MsError::InvalidInstructionAccount))
+    }
+
+    /// links this transaction to a previously executed transaction, ie. a compensating
+    /// action for a mistaken execution. This is metadata only, for audit trails and UIs.
+    pub fn set_linked_transaction(&mut self, linked_tx: Pubkey) -> Result<()>{
+        self.linked_tx = Some(linked_tx);
+        Ok(())
+    }
+
+    /// sets the unix timestamp after which the transaction can no longer be activated, approved or executed.
+    /// The expiration must be in the future.
+    pub fn set_expiration(&mut self, expires_at: i64) -> Result<()>{
+        if expires_at <= Clock::get()?.unix_timestamp {
+            return err!(MsError::InvalidExpiration);
+        }
+        self.expires_at = Some(expires_at);
+        Ok(())
+    }
+
+    /// sets the tag (category and reference) of the transaction
+    pub fn set_tag(&mut self, tag: TransactionTag) -> Result<()>{
+        self.tag = tag;
+        Ok(())
+    }
+
+    /// sets (or removes) the oracle price condition the execution of the transaction is subject to
+    pub fn set_execution_condition(&mut self, execution_condition: Option<ExecutionCondition>) -> Result<()>{
+        self.execution_condition = execution_condition;
+        Ok(())
+    }
+
+    /// sets (or removes) the governance proposal that ratifies the transaction
+    pub fn set_ratifying_proposal(&mut self, ratifying_proposal: Option<Pubkey>) -> Result<()>{
+        self.ratifying_proposal = ratifying_proposal;
+        Ok(())
+    }
+
+    /// the number of vetoes by keys that are still in the veto council
+    pub fn council_veto_count(&self, multisig: &Ms) -> usize {
+        self.council_vetoes.iter().filter(|k| multisig.is_veto_council_member(**k)).count()
+    }
+
+    /// records the veto of a key of the veto council, returning whether the vetoes reached
+    /// the veto council threshold
+    pub fn council_veto(&mut self, multisig: &Ms, council_member: Pubkey) -> Result<bool>{
+        match self.council_vetoes.binary_search(&council_member) {
+            Ok(_) => return err!(MsError::AlreadyVetoed),
+            Err(ind) => self.council_vetoes.insert(ind, council_member),
+        }
+        Ok(self.council_veto_count(multisig) >= usize::from(multisig.veto_council_threshold))
+    }
+
+    /// checks the execution condition of the transaction (if any) against its price account,
+    /// which must then be passed in the remaining accounts
+    pub fn check_execution_condition(&self, accounts: &[AccountInfo]) -> Result<()>{
+        let condition = match &self.execution_condition {
+            Some(condition) => condition,
+            None => return Ok(()),
+        };
+        let price_account = accounts
+            .iter()
+            .find(|a| a.key == &condition.price_account)
+            .ok_or(MsError::InvalidPriceAccount)?;
+        condition.check(price_account)
+    }
+
+    /// checks whether the transaction can be cloned into a new draft: it was executed or
+    /// rejected, or it was deprecated by a change of the multisig before it was executed
+    pub fn is_clonable(&self, ms_change_index: u32) -> bool {
+        match self.status {
+            MsTransactionStatus::Executed | MsTransactionStatus::Rejected => true,
+            MsTransactionStatus::Draft | MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady => {
+                self.transaction_index <= ms_change_index
+            }
+            _ => false,
+        }
+    }
+
+    /// checks whether the transaction is dead and can be closed by anyone: it was deprecated by a
+    /// change of the multisig and wasn't executed. Approved transactions can still be executed, and
+    /// transactions with vote records are kept so that the records can still be closed.
+    pub fn is_stale(&self, ms_change_index: u32) -> bool {
+        let closable_status = matches!(
+            self.status,
+            MsTransactionStatus::Draft
+                | MsTransactionStatus::Active
+                | MsTransactionStatus::Rejected
+                | MsTransactionStatus::Cancelled
+                | MsTransactionStatus::Expired
+        );
+        closable_status
+            && self.transaction_index <= ms_change_index
+            && self.vote_record_count == 0
+            && self.token_approved == 0
+            && self.token_rejected == 0
+    }
+
+    /// checks whether the expiration of the transaction (if any) has passed
+    pub fn is_expired(&self) -> Result<bool>{
+        match self.expires_at {
+            Some(expires_at) => Ok(Clock::get()?.unix_timestamp >= expires_at),
+            None => Ok(false),
+        }
+    }
+
+    /// fails if the transaction has expired
+    pub fn check_not_expired(&self) -> Result<()>{
+        if self.is_expired()? {
+            return err!(MsError::TransactionExpired);
+        }
+        Ok(())
+    }
+
+    /// adds to the bounty paid out to the executor of the transaction
+    pub fn add_execution_bounty(&mut self, amount: u64) -> Result<()>{
+        self.execution_bounty = self.execution_bounty
+            .checked_add(amount)
+            .ok_or(MsError::BountyOverflow)?;
+        Ok(())
+    }
+
+    /// sets the address lookup tables the executor must pass the accounts for execution through
+    pub fn set_lookup_tables(&mut self, lookup_tables: Vec<Pubkey>) -> Result<()>{
+        if lookup_tables.len() > MsTransaction::MAX_LOOKUP_TABLES {
+            return err!(MsError::InvalidLookupTable);
+        }
+        self.lookup_tables = lookup_tables;
+        Ok(())
+    }
+
+    /// sets the members that can attach instructions to the draft along with the creator
+    pub fn set_co_creators(&mut self, multisig: &Ms, mut co_creators: Vec<Pubkey>) -> Result<()>{
+        co_creators.sort();
+        co_creators.dedup();
+        if co_creators.len() > MsTransaction::MAX_CO_CREATORS
+            || co_creators.iter().any(|k| *k == self.creator || multisig.is_member(*k).is_none()) {
+            return err!(MsError::InvalidCoCreators);
+        }
+        self.co_creators = co_creators;
+        Ok(())
+    }
+
+    /// checks if the key can attach instructions to the transaction, ie. the creator or a co-creator
+    pub fn is_author(&self, key: Pubkey) -> bool {
+        self.creator == key || self.co_creators.binary_search(&key).is_ok()
+    }
+
+    /// sets the title and description of the transaction
+    pub fn set_description(&mut self, title: String, description: String) -> Result<()>{
+        if title.len() > MsTransaction::MAX_TITLE_LEN || description.len() > MsTransaction::MAX_DESCRIPTION_LEN {
+            return err!(MsError::InvalidTransactionDescription);
+        }
+        self.title = title;
+        self.description = description;
+        Ok(())
+    }
+
+    /// checks that the lookup tables of the transaction (if any) were provided and are
+    /// actual address lookup tables. The accounts loaded through them are checked against
+    /// the attached instructions during execution, like any other account.
+    pub fn check_lookup_tables(&self, accounts: &[AccountInfo]) -> Result<()>{
+        self.lookup_tables.iter().try_for_each(|table| {
+            match accounts.iter().find(|a| a.key == table) {
+                Some(table_info) if *table_info.owner == MsTransaction::ADDRESS_LOOKUP_TABLE_PROGRAM_ID => Ok(()),
+                _ => err!(MsError::InvalidLookupTable),
+            }
+        })
+    }
+
+    /// set status to Expired
+    pub fn set_expired(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Expired)?;
+        Ok(())
+    }
+
+    /// change status to Active
+    /// the threshold the transaction is decided with, the tiered threshold if set at activation
+    pub fn vote_threshold(&self, multisig: &Ms) -> u16 {
+        match self.threshold {
+            0 => multisig.threshold_for(self.authority_index),
+            threshold => threshold,
+        }
+    }
+
+    pub fn activate(&mut self)-> Result<()>{
+        self.status.transition(MsTransactionStatus::Active)?;
+        Ok(())
+    }
+
+    /// change status to ExecuteReady, recording when it happened for the time lock
+    pub fn ready_to_execute(&mut self)-> Result<()>{
+        self.status.transition(MsTransactionStatus::ExecuteReady)?;
+        self.ready_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// checks whether the transaction is still within the veto period of the multisig
+    pub fn in_veto_period(&self, veto_period: u32) -> Result<bool>{
+        let veto_ends_at = self.ready_at.saturating_add(i64::from(veto_period));
+        Ok(Clock::get()?.unix_timestamp < veto_ends_at)
+    }
+
+    /// checks that the time lock of the multisig has elapsed since the transaction became ExecuteReady
+    pub fn check_time_lock(&self, time_lock: u32) -> Result<()>{
+        let unlocks_at = self.ready_at.saturating_add(i64::from(time_lock));
+        if Clock::get()?.unix_timestamp < unlocks_at {
+            return err!(MsError::TimeLockNotElapsed);
+        }
+        Ok(())
+    }
+
+    /// set status to Rejected
+    pub fn set_rejected(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Rejected)?;
+        Ok(())
+    }
+
+    /// set status to Cancelled
+    pub fn set_cancelled(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Cancelled)?;
+        Ok(())
+    }
+
+    /// set status to Recurring
+    pub fn set_recurring(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Recurring)?;
+        Ok(())
+    }
+
+    /// derives the authority (vault) PDA the transaction signs with, from its stored bump
+    pub fn get_authority_address(&self, program_id: &Pubkey) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                b"squad",
+                self.ms.as_ref(),
+                &self.authority_index.to_le_bytes(),
+                b"authority",
+                &[self.authority_bump],
+            ],
+            program_id,
+        ).map_err(|_| error!(MsError::InvalidAuthorityIndex))
+    }
+
+    /// set status to executed
+    pub fn set_executed(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Executed)?;
+        Ok(())
+    }
+
+    /// sign to approve a transaction
+    pub fn sign(&mut self, member_index: usize) -> Result<()>{
+        MsTransaction::set_bit(&mut self.approved, member_index, true)
+    }
+
+    /// sign to reject the transaction
+    pub fn reject(&mut self, member_index: usize) -> Result<()> {
+        MsTransaction::set_bit(&mut self.rejected, member_index, true)
+    }
+
+    /// sign to cancel the transaction if execute_ready
+    pub fn cancel(&mut self, member_index: usize) -> Result<()> {
+        MsTransaction::set_bit(&mut self.cancelled, member_index, true)
+    }
+
+    /// sign to abstain from the transaction
+    pub fn abstain(&mut self, member_index: usize) -> Result<()> {
+        MsTransaction::set_bit(&mut self.abstained, member_index, true)
+    }
+
+    /// checks if the bit for the member index is set in the bitmap
+    fn has_bit(bitmap: &[u8], member_index: usize) -> bool {
+        match bitmap.get(member_index / 8) {
+            Some(byte) => byte & (1 << (member_index % 8)) != 0,
+            None => false
+        }
+    }
+
+    /// sets or clears the bit for the member index in the bitmap. The bitmaps are sized
+    /// for the members at creation, so a member index past the end can't be recorded.
+    fn set_bit(bitmap: &mut [u8], member_index: usize, value: bool) -> Result<()> {
+        let byte = match bitmap.get_mut(member_index / 8) {
+            Some(byte) => byte,
+            None => return err!(MsError::VoteCapacityExceeded)
+        };
+        if value {
+            *byte |= 1 << (member_index % 8);
+        } else {
+            *byte &= !(1 << (member_index % 8));
+        }
+        Ok(())
+    }
+
+    /// records the approval of the member, replacing a previous rejection or abstention.
+    /// Marks the transaction as ExecuteReady and returns true once the approvals reach the threshold
+    /// and the quorum is met.
+    pub fn cast_approval(&mut self, multisig: &Ms, member: Pubkey) -> Result<bool>{
+        if multisig.vote_records {
+            return err!(MsError::VoteRecordsEnabled);
+        }
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        // if they have previously voted to reject or abstained, clear that vote (change vote check)
+        self.replace_vote(member_index, multisig.keys.len())?;
+        self.sign(member_index)?;
+        // if the weight of current signers reaches threshold, mark the transaction as execute ready
+        self.check_votes(multisig)
+    }
+
+    /// records the rejection of the member, replacing a previous approval or abstention.
+    /// Marks the transaction as Rejected and returns true once the threshold can no longer be reached
+    /// and the quorum is met.
+    pub fn cast_rejection(&mut self, multisig: &Ms, member: Pubkey) -> Result<bool>{
+        if multisig.vote_records {
+            return err!(MsError::VoteRecordsEnabled);
+        }
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        // if they have previously voted to approve or abstained, clear that vote (change vote check)
+        self.replace_vote(member_index, multisig.keys.len())?;
+        self.reject(member_index)?;
+        self.check_votes(multisig)
+    }
+
+    /// records the abstention of the member, replacing a previous approval or rejection.
+    /// Abstentions count towards the quorum, so this can decide the transaction as well.
+    pub fn cast_abstention(&mut self, multisig: &Ms, member: Pubkey) -> Result<bool>{
+        if multisig.vote_records {
+            return err!(MsError::VoteRecordsEnabled);
+        }
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        // if they have previously voted to approve or reject, clear that vote (change vote check)
+        self.replace_vote(member_index, multisig.keys.len())?;
+        self.abstain(member_index)?;
+        self.check_votes(multisig)
+    }
+
+    /// records the cancellation of the member, replacing their approval (or other vote), and returns
+    /// the weight of the cancellations. Only ExecuteReady transactions are cancelled, so the approvals
+    /// no longer decide anything.
+    pub fn cast_cancellation(&mut self, multisig: &Ms, member: Pubkey) -> Result<usize>{
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        self.replace_vote(member_index, multisig.keys.len())?;
+        self.cancel(member_index)?;
+        Ok(multisig.weight_of_bitmap(&self.cancelled))
+    }
+
+    /// the combined number of votes held in the approved, rejected, cancelled and abstained bitmaps
+    pub fn vote_count(&self) -> usize {
+        MsTransaction::count_votes(&[&self.approved, &self.rejected, &self.cancelled, &self.abstained])
+    }
+
+    /// the combined number of votes held in the vote bitmaps
+    fn count_votes(bitmaps: &[&[u8]]) -> usize {
+        bitmaps
+            .iter()
+            .flat_map(|bitmap| bitmap.iter())
+            .map(|b| b.count_ones() as usize)
+            .sum()
+    }
+
+    /// checks that another vote can be recorded, once the previous vote of the member is cleared.
+    /// Each vote replaces the previous one, so a member is in at most one of the bitmaps and
+    /// together they can never hold more votes than the member count. The padding bits past the
+    /// last member aren't votes either.
+    pub fn check_vote_capacity(&self, member_index: usize, members_len: usize) -> Result<()> {
+        MsTransaction::check_capacity(self.vote_count(), member_index, members_len)
+    }
+
+    /// checks that the vote bitmaps holding the vote count have room for the vote of the member
+    fn check_capacity(vote_count: usize, member_index: usize, members_len: usize) -> Result<()> {
+        if member_index >= members_len || vote_count >= members_len {
+            return err!(MsError::VoteCapacityExceeded);
+        }
+        Ok(())
+    }
+
+    /// clears every vote of the member, before recording the one replacing it once checked that it
+    /// fits. Advances the vote sequence, so that no vote signed offline before it can be relayed.
+    fn replace_vote(&mut self, member_index: usize, members_len: usize) -> Result<()>{
+        self.remove_approve(member_index)?;
+        self.remove_reject(member_index)?;
+        self.remove_abstain(member_index)?;
+        MsTransaction::set_bit(&mut self.cancelled, member_index, false)?;
+        self.check_vote_capacity(member_index, members_len)?;
+        self.vote_seq = self.vote_seq.wrapping_add(1);
+        Ok(())
+    }
+
+    /// marks the transaction as ExecuteReady or Rejected from its bitmaps (or tallies in vote
+    /// records mode) and returns true once the vote decides it
+    fn check_votes(&mut self, multisig: &Ms) -> Result<bool>{
+        let threshold = self.vote_threshold(multisig);
+        let decision = if multisig.vote_records {
+            multisig.decide_vote(
+                threshold,
+                self.voter_count(multisig),
+                self.approved_weight as usize,
+                self.rejected_weight as usize,
+            )
+        } else {
+            multisig.decide_bitmap_vote(threshold, &self.approved, &self.rejected, &self.abstained)
+        };
+        self.decide(multisig, decision)
+    }
+
+    /// the number of members that have voted (approved, rejected or abstained), for the quorum
+    pub fn voter_count(&self, multisig: &Ms) -> usize {
+        let abstained = self.abstained.iter().map(|b| b.count_ones() as usize).sum::<usize>();
+        if multisig.vote_records {
+            usize::from(self.vote_record_count) + abstained
+        } else {
+            MsTransaction::count_votes(&[&self.approved, &self.rejected]) + abstained
+        }
+    }
+
+    /// records a member's VoteRecord in the transaction tallies, replacing the previous
+    /// vote of the record (if any). Marks the transaction as ExecuteReady or Rejected and
+    /// returns true once the vote decides it.
+    pub fn tally_vote(&mut self, multisig: &Ms, previous: Option<&VoteRecord>, approve: bool, weight: u16) -> Result<bool>{
+        if !multisig.vote_records {
+            return err!(MsError::VoteRecordsDisabled);
+        }
+        if let Some(record) = previous {
+            if record.approve {
+                self.approved_weight = self.approved_weight.saturating_sub(u32::from(record.weight));
+            } else {
+                self.rejected_weight = self.rejected_weight.saturating_sub(u32::from(record.weight));
+            }
+        } else {
+            self.vote_record_count = self.vote_record_count.checked_add(1).ok_or(MsError::TallyOverflow)?;
+        }
+        if approve {
+            self.approved_weight = self.approved_weight.checked_add(u32::from(weight)).ok_or(MsError::TallyOverflow)?;
+        } else {
+            self.rejected_weight = self.rejected_weight.checked_add(u32::from(weight)).ok_or(MsError::TallyOverflow)?;
+        }
+        self.check_votes(multisig)
+    }
+
+    /// records the vote of a token holder in the token tallies, which are kept apart from the
+    /// member weights and the quorum. Marks the transaction as ExecuteReady or Rejected and
+    /// returns true once the vote decides it.
+    pub fn tally_token_vote(&mut self, multisig: &Ms, approve: bool) -> Result<bool>{
+        if !multisig.vote_records {
+            return err!(MsError::VoteRecordsDisabled);
+        }
+        if approve {
+            self.token_approved = self.token_approved.checked_add(1).ok_or(MsError::TallyOverflow)?;
+        } else {
+            self.token_rejected = self.token_rejected.checked_add(1).ok_or(MsError::TallyOverflow)?;
+        }
+        self.check_votes(multisig)
+    }
+
+    /// marks the transaction as ExecuteReady if the members decided to approve it, or Rejected if they
+    /// decided to reject it, once the quorum is met. Vault transactions of a multisig with a membership
+    /// gate need the token approvals to reach the token threshold as well, and are rejected once the
+    /// token rejections reach it. Internal transactions are only decided by the members.
+    fn decide(&mut self, multisig: &Ms, decision: Option<MsTransactionStatus>) -> Result<bool>{
+        if !multisig.has_quorum(self.voter_count(multisig)) {
+            return Ok(false);
+        }
+        let token_threshold = match multisig.membership_gate {
+            Some(gate) if self.authority_index > 0 => Some(u32::from(gate.threshold())),
+            _ => None,
+        };
+        if decision == Some(MsTransactionStatus::ExecuteReady) && token_threshold.is_none_or(|t| self.token_approved >= t) {
+            self.ready_to_execute()?;
+            return Ok(true);
+        }
+        if decision == Some(MsTransactionStatus::Rejected) || token_threshold.is_some_and(|t| self.token_rejected >= t) {
+            self.set_rejected()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Builds the message a member signs offline to approve or reject this transaction,
+    /// which can then be relayed with the ed25519 signature by any key. The message holds the
+    /// current vote sequence, so it can only be relayed until the next vote on the transaction.
+    pub fn to_signed_vote_message(&self, approve: bool) -> Result<Vec<u8>> {
+        let vote = SignedVote {
+            multisig: self.ms,
+            transaction_index: self.transaction_index,
+            vote_seq: self.vote_seq,
+            approve,
+        };
+        let mut message = SignedVote::PREFIX.to_vec();
+        message.extend(vote.try_to_vec()?);
+        Ok(message)
+    }
+
+    /// Builds the portable approval request blob for this transaction, so that offline
+    /// signers (hardware wallets, air-gapped devices) can display and sign it.
+    /// The instructions must be all of the attached instructions, in order. Like a signed vote,
+    /// the blob holds the current vote sequence, so it has to be signed again after any other vote.
+    pub fn to_approval_request(&self, threshold: u16, instructions: &[MsInstruction]) -> Result<Vec<u8>> {
+        if instructions.len() != usize::from(self.instruction_index) {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        let instruction_hashes = instructions
+            .iter()
+            .enumerate()
+            .map(|(i, ix)| {
+                // instructions are indexed from 1
+                if usize::from(ix.instruction_index) != i + 1 {
+                    return err!(MsError::InvalidInstructionAccount);
+                }
+                Ok(ix.hash())
+            })
+            .collect::<Result<Vec<[u8; 32]>>>()?;
+
+        let request = ApprovalRequest {
+            multisig: self.ms,
+            transaction_index: self.transaction_index,
+            authority_index: self.authority_index,
+            threshold,
+            vote_seq: self.vote_seq,
+            instruction_hashes,
+        };
+        let mut blob = ApprovalRequest::PREFIX.to_vec();
+        blob.extend(request.try_to_vec()?);
+        Ok(blob)
+    }
+
+    /// counts the votes on the transaction, from the bitmaps or from the tallies of the
+    /// vote records, depending on how the multisig records votes
+    pub fn vote_counts(&self, multisig: &Ms) -> VoteCounts {
+        let count = |bitmap: &[u8]| bitmap.iter().map(|b| b.count_ones() as u16).sum();
+        let (approved_weight, rejected_weight) = if multisig.vote_records {
+            (self.approved_weight, self.rejected_weight)
+        } else {
+            (
+                multisig.weight_of_bitmap(&self.approved) as u32,
+                multisig.weight_of_bitmap(&self.rejected) as u32,
+            )
+        };
+        VoteCounts {
+            approved: count(&self.approved),
+            rejected: count(&self.rejected),
+            cancelled: count(&self.cancelled),
+            abstained: count(&self.abstained),
+            approved_weight,
+            rejected_weight,
+            threshold: self.vote_threshold(multisig),
+        }
+    }
+
+    /// check if a user has voted already
+    pub fn has_voted(&self, member_index: usize) -> bool {
+        self.has_voted_approve(member_index) || self.has_voted_reject(member_index)
+    }
+
+    /// check if a user has signed to approve
+    pub fn has_voted_approve(&self, member_index: usize) -> bool {
+        MsTransaction::has_bit(&self.approved, member_index)
+    }
+
+    /// check if a use has signed to reject
+    pub fn has_voted_reject(&self, member_index: usize) -> bool {
+        MsTransaction::has_bit(&self.rejected, member_index)
+    }
+
+    /// check if a user has abstained
+    pub fn has_abstained(&self, member_index: usize) -> bool {
+        MsTransaction::has_bit(&self.abstained, member_index)
+    }
+
+    /// check if a user has signed to cancel
+    pub fn has_cancelled(&self, member_index: usize) -> bool {
+        MsTransaction::has_bit(&self.cancelled, member_index)
+    }
+
+    /// check if a user has cast the vote (vetoes aren't tracked per member)
+    pub fn has_cast(&self, vote: Vote, member_index: usize) -> bool {
+        match vote {
+            Vote::Approve => self.has_voted_approve(member_index),
+            Vote::Reject => self.has_voted_reject(member_index),
+            Vote::Cancel => self.has_cancelled(member_index),
+            Vote::Abstain => self.has_abstained(member_index),
+            Vote::Veto => false,
+        }
+    }
+
+    /// clears the member from the rejected bitmap.
+    /// used when changing from rejected to approved
+    pub fn remove_reject(&mut self, member_index: usize) -> Result<()>{
+        MsTransaction::set_bit(&mut self.rejected, member_index, false)
+    }
+
+    /// clears the member from the approved bitmap
+    /// used when changing from approved to rejected
+    pub fn remove_approve(&mut self, member_index: usize) -> Result<()>{
+        MsTransaction::set_bit(&mut self.approved, member_index, false)
+    }
+
+    /// clears the member from the abstained bitmap
+    /// used when changing from abstained to approved/rejected
+    pub fn remove_abstain(&mut self, member_index: usize) -> Result<()>{
+        MsTransaction::set_bit(&mut self.abstained, member_index, false)
+    }
+
+}
+
+/// A config transaction holds typed config actions, rather than instructions for the multisig
+/// to invoke on itself, so that signer UIs can render exactly what is changed. The actions are
+/// checked against the multisig when proposed, and applied as one batch when executed.
+#[account]
+pub struct MsConfigTransaction {
+    pub creator: Pubkey,                // creator, used to seed pda
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub transaction_index: u32,         // used for seed
+    pub status: MsTransactionStatus,    // the status of the transaction
+    pub actions: Vec<MsConfigAction>,   // the config changes applied at execution, in order
+    pub approved: Vec<u8>,              // bitmap of the members that approved
+    pub rejected: Vec<u8>,              // bitmap of the members that rejected
+    pub ready_at: i64,                  // unix timestamp of when the transaction became ExecuteReady
+    pub bump: u8,                       // the bump corresponding to the bespoke pda
+}
+
+impl MsConfigTransaction {
+    /// the maximum number of actions in a config transaction
+    pub const MAX_ACTIONS: usize = 10;
+
+    /// the most applying the actions can grow the multisig account by
+    pub fn additional_len(&self) -> usize {
+        self.actions.iter().map(|action| action.additional_len()).sum()
+    }
+
+    pub fn size(actions: &[MsConfigAction], members_len: usize) -> usize {
+        let actions_len: usize = actions
+            .iter()
+            .map(|action| get_instance_packed_len(action).unwrap_or(0))
+            .sum();
+        8 +                                 // the anchor discriminator
+        32 +                                // the creator pubkey
+        32 +                                // the multisig key
+        4 +                                 // the transaction index
+        1 +                                 // the transaction status
+        4 + actions_len +                   // the actions
+        2 * (4 + MsTransaction::bitmap_len(members_len)) +  // the approved and rejected bitmaps
+        8 +                                 // ready at
+        1                                   // the bump
+    }
+
+    /// initializes the config transaction, which is active right away, checking that the
+    /// actions can be applied to the multisig as it is now
+    pub fn init(&mut self, creator: Pubkey, multisig: &Ms, ms_key: Pubkey, actions: Vec<MsConfigAction>, bump: u8) -> Result<()> {
+        if multisig.vote_records {
+            return err!(MsError::VoteRecordsEnabled);
+        }
+        if actions.is_empty() || actions.len() > MsConfigTransaction::MAX_ACTIONS {
+            return err!(MsError::InvalidConfigActions);
+        }
+        validate_config_actions(multisig, &actions)?;
+
+        self.creator = creator;
+        self.ms = ms_key;
+        self.transaction_index = multisig.transaction_index;
+        self.status = MsTransactionStatus::Active;
+        self.actions = actions;
+        self.approved = vec![0; MsTransaction::bitmap_len(multisig.keys.len())];
+        self.rejected = vec![0; MsTransaction::bitmap_len(multisig.keys.len())];
+        self.ready_at = 0;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// records the approval of the member, replacing a previous rejection.
+    /// Marks the transaction as ExecuteReady and returns true once the approvals reach the config threshold
+    /// and the quorum has voted.
+    pub fn cast_approval(&mut self, multisig: &Ms, member: Pubkey) -> Result<bool>{
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        self.replace_vote(multisig, member_index)?;
+        MsTransaction::set_bit(&mut self.approved, member_index, true)?;
+        self.check_votes(multisig)
+    }
+
+    /// records the rejection of the member, replacing a previous approval.
+    /// Marks the transaction as Rejected and returns true once the config threshold can no longer be reached
+    /// and the quorum has voted.
+    pub fn cast_rejection(&mut self, multisig: &Ms, member: Pubkey) -> Result<bool>{
+        let member_index = multisig.is_member(member).ok_or(MsError::KeyNotInMultisig)?;
+        self.replace_vote(multisig, member_index)?;
+        MsTransaction::set_bit(&mut self.rejected, member_index, true)?;
+        self.check_votes(multisig)
+    }
+
+    /// clears the previous vote of the member, before recording the one replacing it once checked
+    /// that it fits, as for transactions. Votes are only kept in bitmaps, so multisigs voting with
+    /// vote records can't vote on config transactions.
+    fn replace_vote(&mut self, multisig: &Ms, member_index: usize) -> Result<()>{
+        if multisig.vote_records {
+            return err!(MsError::VoteRecordsEnabled);
+        }
+        MsTransaction::set_bit(&mut self.approved, member_index, false)?;
+        MsTransaction::set_bit(&mut self.rejected, member_index, false)?;
+        let vote_count = MsTransaction::count_votes(&[&self.approved, &self.rejected]);
+        MsTransaction::check_capacity(vote_count, member_index, multisig.keys.len())
+    }
+
+    /// marks the transaction as ExecuteReady or Rejected from its bitmaps, with the config threshold
+    /// and once the quorum is met, and returns true once the vote decides it
+    fn check_votes(&mut self, multisig: &Ms) -> Result<bool>{
+        match multisig.decide_bitmap_vote(multisig.threshold_for(0), &self.approved, &self.rejected, &[]) {
+            Some(MsTransactionStatus::ExecuteReady) => {
+                self.status.transition(MsTransactionStatus::ExecuteReady)?;
+                self.ready_at = Clock::get()?.unix_timestamp;
+            }
+            Some(status) => self.status.transition(status)?,
+            None => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// checks that the time lock (and veto period) of the multisig has elapsed since the transaction became ExecuteReady
+    pub fn check_time_lock(&self, time_lock: u32) -> Result<()>{
+        let unlocks_at = self.ready_at.saturating_add(i64::from(time_lock));
+        if Clock::get()?.unix_timestamp < unlocks_at {
+            return err!(MsError::TimeLockNotElapsed);
+        }
+        Ok(())
+    }
+
+    /// set status to Executed
+    pub fn set_executed(&mut self) -> Result<()>{
+        self.status.transition(MsTransactionStatus::Executed)?;
+        Ok(())
+    }
+}
+
+/// The state account for an instruction that is attached to a transaction.
+/// The oracle that publishes the price account of an execution condition
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OracleKind {
+    Pyth,                                   // a Pyth (v2) price account
+    Switchboard,                            // a Switchboard (v2) aggregator account
+}
+
+/// How the oracle price is compared to the price of an execution condition
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PriceComparison {
+    Above,                                  // the oracle price must be greater than the price
+    Below,                                  // the oracle price must be less than the price
+}
+
+/// A condition on an oracle price that must hold when a transaction is executed, ie. only
+/// rebalance the treasury while SOL/USD is above a floor. The price is price * 10^expo,
+/// and the oracle price must have been updated within the max age.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionCondition {
+    pub oracle: OracleKind,                 // the oracle of the price account
+    pub price_account: Pubkey,              // the price (or aggregator) account
+    pub comparison: PriceComparison,        // whether the oracle price must be above or below the price
+    pub price: i64,                         // the price mantissa
+    pub expo: i32,                          // the price exponent, ie. -2 for cents
+    pub max_age: u32,                       // seconds since the last oracle update after which the price is stale
+}
+
+impl ExecutionCondition {
+    pub const SIZE: usize = 1 +             // the oracle
+        32 +                                // the price account
+        1 +                                 // the comparison
+        8 +                                 // the price
+        4 +                                 // the exponent
+        4;                                  // the max age
+
+    pub const PYTH_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+    pub const SWITCHBOARD_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("SW1TCH7qEPTdLsDHRgPuMQjbQxKdH2aBStViMFnt64f");
+
+    /// reads the price, exponent and update time of the oracle price account
+    fn read_price(&self, price_account: &AccountInfo) -> Option<(i128, i32, i64)> {
+        let data = price_account.try_borrow_data().ok()?;
+        let read = |at: usize, len: usize| data.get(at..at + len);
+        let read_i64 = |at: usize| read(at, 8).map(|b| i64::from_le_bytes(b.try_into().unwrap()));
+        let read_u32 = |at: usize| read(at, 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+        match self.oracle {
+            OracleKind::Pyth => {
+                // magic, exponent, publish time, then the aggregate price and its status (1 = trading)
+                if *price_account.owner != Self::PYTH_PROGRAM_ID || read_u32(0)? != 0xa1b2c3d4 || read_u32(224)? != 1 {
+                    return None;
+                }
+                let expo = read_u32(20)? as i32;
+                Some((i128::from(read_i64(208)?), expo, read_i64(96)?))
+            }
+            OracleKind::Switchboard => {
+                // the latest confirmed round follows the aggregator config, with its open timestamp
+                // and the result as a mantissa and scale
+                if *price_account.owner != Self::SWITCHBOARD_PROGRAM_ID {
+                    return None;
+                }
+                let mantissa = read(366, 16).map(|b| i128::from_le_bytes(b.try_into().unwrap()))?;
+                let scale = read_u32(382)?;
+                Some((mantissa, -(scale as i32), read_i64(358)?))
+            }
+        }
+    }
+
+    /// checks that the oracle price is fresh and compares as required to the price
+    pub fn check(&self, price_account: &AccountInfo) -> Result<()> {
+        let (oracle_price, oracle_expo, updated_at) = self.read_price(price_account).ok_or(MsError::InvalidPriceAccount)?;
+        if Clock::get()?.unix_timestamp.saturating_sub(updated_at) > i64::from(self.max_age) {
+            return err!(MsError::InvalidPriceAccount);
+        }
+        // scale both prices to the smaller exponent
+        let scale = |price: i128, expo: i32| {
+            10i128.checked_pow((expo - oracle_expo.min(self.expo)) as u32).and_then(|factor| price.checked_mul(factor))
+        };
+        let oracle_price = scale(oracle_price, oracle_expo).ok_or(MsError::InvalidPriceAccount)?;
+        let price = scale(i128::from(self.price), self.expo).ok_or(MsError::InvalidPriceAccount)?;
+        let met = match self.comparison {
+            PriceComparison::Above => oracle_price > price,
+            PriceComparison::Below => oracle_price < price,
+        };
+        if !met {
+            return err!(MsError::ExecutionConditionNotMet);
+        }
+        Ok(())
+    }
+}
+
+/// Almost analagous to the native Instruction struct for solana, but with an extra
+/// field for the bump.
+#[account]
+pub struct MsInstruction {
+    pub program_id: Pubkey,
+    pub keys: Vec<MsAccountMeta>,
+    pub data: Vec<u8>,
+    pub instruction_index: u8,
+    pub bump: u8,
+    pub executed: bool, // deprecated in favor for executed_index in the MsTransaction
+}
+
+impl MsInstruction {
+    pub const MAXIMUM_SIZE: usize = 1280;   // no longer used but kept for reference, was previously a client side limitation for sizing.
+
+    /// the mint (the default pubkey for SOL) and amount of a system or token transfer instruction,
+    /// none for other instructions. The mint of a token transfer without it is read from the source
+    /// token account, which must then be passed in the remaining accounts.
+    pub fn transfer(&self, remaining_accounts: &[AccountInfo]) -> Option<(Pubkey, u64)> {
+        let read_u64 = |at: usize| self.data.get(at..at + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()));
+        if self.program_id == anchor_lang::solana_program::system_program::ID {
+            // system transfer, the lamports follow the u32 instruction index
+            if self.data.get(..4)? != 2u32.to_le_bytes() {
+                return None;
+            }
+            return read_u64(4).map(|lamports| (Pubkey::default(), lamports));
+        }
+        if !SpendingLimit::is_token_program(&self.program_id) {
+            return None;
+        }
+        match self.data.first()? {
+            // transfer checked, with the mint as second account
+            12 => Some((self.keys.get(1)?.pubkey, read_u64(1)?)),
+            // transfer, the mint is read from the source token account
+            3 => {
+                let source = self.keys.first()?.pubkey;
+                let source_info = remaining_accounts
+                    .iter()
+                    .find(|a| a.key == &source && SpendingLimit::is_token_program(a.owner))?;
+                let data = source_info.try_borrow_data().ok()?;
+                let token = StateWithExtensions::<TokenAccount>::unpack(&data).ok()?.base;
+                Some((token.mint, read_u64(1)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Initializes the instruction account
+    pub fn init(&mut self, instruction_index: u8, incoming_instruction: IncomingInstruction, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.instruction_index = instruction_index;
+        self.program_id = incoming_instruction.program_id;
+        self.keys = incoming_instruction.keys;
+        self.data = incoming_instruction.data;
+        self.executed = false;
+        Ok(())
+    }
+}
+
+impl MsInstruction {
+    /// Appends a chunk to the instruction data, for payloads that don't fit in a single
+    /// Solana transaction. When final_hash is given, the full data must hash to it.
+    pub fn append_data(&mut self, data: Vec<u8>, final_hash: Option<[u8; 32]>) -> Result<()> {
+        self.data.extend(data);
+        if let Some(final_hash) = final_hash {
+            if hash(&self.data).to_bytes() != final_hash {
+                return err!(MsError::InstructionDataHashMismatch);
+            }
+        }
+        Ok(())
+    }
+
+    /// Hash of the program id, account metas and data of the instruction, used to
+    /// commit to the exact instruction contents when signing an approval request.
+    /// The account metas and the data are prefixed with their lengths (u32 LE), so that
+    /// bytes can't be moved from one to the other without changing the hash.
+    pub fn hash(&self) -> [u8; 32] {
+        let keys = self
+            .keys
+            .iter()
+            .flat_map(|k| [k.pubkey.as_ref(), &[u8::from(k.is_signer), u8::from(k.is_writable)]].concat())
+            .collect::<Vec<u8>>();
+        hashv(&[
+            self.program_id.as_ref(),
+            &(self.keys.len() as u32).to_le_bytes(),
+            &keys,
+            &(self.data.len() as u32).to_le_bytes(),
+            &self.data,
+        ]).to_bytes()
+    }
+}
+
+impl From<MsInstruction> for Instruction {
+    /// Converts the MsInstruction to a native Instruction
+    fn from(instruction: MsInstruction) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .keys
+                .into_iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+/// Borrowed view over the raw data of an MsInstruction account. Used at execution
+/// time to check the account keys and build the native Instruction straight from
+/// the account data, rather than deserializing it into an intermediate MsInstruction
+/// and cloning its key and data vectors.
+pub struct MsInstructionView<'a> {
+    pub program_id: Pubkey,
+    keys: &'a [u8],
+    data: &'a [u8],
+    pub instruction_index: u8,
+}
+
+impl<'a> MsInstructionView<'a> {
+    // pubkey + is_signer + is_writable
+    const ACCOUNT_META_SIZE: usize = 32 + 1 + 1;
+
+    /// Parses the view out of the MsInstruction account data
+    pub fn load(account_data: &'a [u8]) -> Result<Self> {
+        if account_data.get(..8) != Some(MsInstruction::DISCRIMINATOR.as_ref()) {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let program_id = Self::read_pubkey(account_data, 8)?;
+        let (keys, data_offset) = Self::read_slice(account_data, 40, Self::ACCOUNT_META_SIZE)?;
+        let (data, index_offset) = Self::read_slice(account_data, data_offset, 1)?;
+        let instruction_index = *account_data
+            .get(index_offset)
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        Ok(Self { program_id, keys, data, instruction_index })
+    }
+
+    /// The number of account metas in the instruction
+    pub fn keys_len(&self) -> usize {
+        self.keys.len() / Self::ACCOUNT_META_SIZE
+    }
+
+    /// The account metas of the instruction, in order
+    pub fn account_metas(&self) -> impl Iterator<Item = AccountMeta> + 'a {
+        self.keys.chunks_exact(Self::ACCOUNT_META_SIZE).map(|meta| AccountMeta {
+            pubkey: Pubkey::new_from_array(meta[..32].try_into().unwrap()),
+            is_signer: meta[32] != 0,
+            is_writable: meta[33] != 0,
+        })
+    }
+
+    /// The instruction data, borrowed from the account data
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Builds the native Instruction to invoke
+    pub fn to_instruction(&self) -> Instruction {
+        self.to_instruction_with(self.account_metas().collect())
+    }
+
+    /// Builds the native Instruction to invoke from account metas already collected
+    /// (ie. while checking them against the submitted accounts), so the only copy
+    /// left is the instruction data that the invoke needs to own
+    pub fn to_instruction_with(&self, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.data.to_vec(),
+        }
+    }
+
+    fn read_pubkey(account_data: &[u8], offset: usize) -> Result<Pubkey> {
+        match account_data.get(offset..offset + 32) {
+            Some(key) => Ok(Pubkey::new_from_array(key.try_into().unwrap())),
+            None => err!(MsError::InvalidInstructionAccount),
+        }
+    }
+
+    // reads a borsh vec of fixed size items, returning its bytes and the offset following it
+    fn read_slice(account_data: &'a [u8], offset: usize, item_size: usize) -> Result<(&'a [u8], usize)> {
+        let len = match account_data.get(offset..offset + 4) {
+            Some(len) => u32::from_le_bytes(len.try_into().unwrap()) as usize,
+            None => return err!(MsError::InvalidInstructionAccount),
+        };
+        let start = offset + 4;
+        let end = len
+            .checked_mul(item_size)
+            .and_then(|size| start.checked_add(size))
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        match account_data.get(start..end) {
+            Some(slice) => Ok((slice, end)),
+            None => err!(MsError::InvalidInstructionAccount),
+        }
+    }
+}
+
+/// Self-describing summary of a transaction that is serialized (after a fixed prefix)
+/// to form the approval request blob signed by offline signers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ApprovalRequest {
+    pub multisig: Pubkey,                   // the multisig the transaction belongs to
+    pub transaction_index: u32,             // the index of the transaction
+    pub authority_index: u32,               // the authority the transaction will sign with
+    pub threshold: u16,                     // the threshold at the time of the request
+    pub vote_seq: u32,                      // the vote sequence of the transaction at the time of the request
+    pub instruction_hashes: Vec<[u8; 32]>,  // hashes of the attached instructions, in order
+}
+
+impl ApprovalRequest {
+    pub const PREFIX: &'static [u8] = b"squads-mpl approval request v2";
+}
+
+/// Vote of a member that is serialized (after a fixed prefix) to form the message
+/// signed offline and verified through the ed25519 program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct SignedVote {
+    pub multisig: Pubkey,                   // the multisig the transaction belongs to
+    pub transaction_index: u32,             // the index of the transaction
+    pub vote_seq: u32,                      // the vote sequence of the transaction when signed
+    pub approve: bool,                      // true to approve, false to reject
+}
+
+impl SignedVote {
+    pub const PREFIX: &'static [u8] = b"squads-mpl signed vote v2";
+}
+
+/// The vote counts of a transaction, returned by the get_vote_counts view.
+/// With vote records enabled, votes are only tallied by weight, so the member counts are zero.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct VoteCounts {
+    pub approved: u16,                      // number of members that approved
+    pub rejected: u16,                      // number of members that rejected
+    pub cancelled: u16,                     // number of members that voted to cancel
+    pub abstained: u16,                     // number of members that abstained
+    pub approved_weight: u32,               // total weight of the approvals
+    pub rejected_weight: u32,               // total weight of the rejections
+    pub threshold: u16,                     // the threshold that applies to the transaction
+}
+
+/// Wrapper for our internal MsInstruction key serialization schema
+/// MsAccount meta is identical to the AccountMeta struct, but defined
+/// here for serialization purposes.
+#[derive(AnchorSerialize,AnchorDeserialize, Copy, Clone)]
+pub struct MsAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool
+}
+
+/// Incoming instruction schema, used as an argument in the attach_instruction.
+/// Identical to the solana struct for Instruction, but uses the MsAccountMeta.
+/// Provided for de/serialization purposes.
+#[derive(AnchorSerialize,AnchorDeserialize, Clone)]
+pub struct IncomingInstruction {
+    pub program_id: Pubkey,
+    pub keys: Vec<MsAccountMeta>,
+    pub data: Vec<u8>
+}
+
+impl IncomingInstruction {
+    /// Calculates how much space will be needed to allocate to the instruction
+    /// to be attached to the transaction.
+    pub fn get_max_size(&self) -> usize {
+        // add three the size to correlate with the saved instruction account
+        // there are 3 extra bytes in a saved instruction account: index, bump, executed
+        // this is used to determine how much space the incoming instruction
+        // will used when saved
         get_instance_packed_len(&self).unwrap_or_default().checked_add(3).unwrap_or_default()
     }
 }
+
+impl From<Instruction> for IncomingInstruction {
+    /// Converts a native Instruction to an IncomingInstruction
+    fn from(instruction: Instruction) -> Self {
+        IncomingInstruction {
+            program_id: instruction.program_id,
+            keys: instruction
+                .accounts
+                .iter()
+                .map(|account| MsAccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+/// Typed stake program actions for a stake account whose stake and withdraw authority
+/// is a vault, so the instruction doesn't have to be encoded by hand.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum StakeAction {
+    Delegate { stake: Pubkey, vote: Pubkey },
+    Deactivate { stake: Pubkey },
+    Withdraw { stake: Pubkey, destination: Pubkey, lamports: u64 },
+    Split { stake: Pubkey, split_stake: Pubkey, lamports: u64 },
+    Merge { destination_stake: Pubkey, source_stake: Pubkey },
+}
+
+impl StakeAction {
+    /// builds the stake program instruction for the action, signed by the vault.
+    /// The split stake account must already be allocated and owned by the stake program.
+    pub fn to_instruction(&self, vault: &Pubkey) -> IncomingInstruction {
+        use anchor_lang::solana_program::stake::{self, instruction as stake_instruction};
+        let instruction = match self {
+            StakeAction::Delegate { stake, vote } => stake_instruction::delegate_stake(stake, vault, vote),
+            StakeAction::Deactivate { stake } => stake_instruction::deactivate_stake(stake, vault),
+            StakeAction::Withdraw { stake, destination, lamports } => {
+                stake_instruction::withdraw(stake, vault, destination, *lamports, None)
+            }
+            StakeAction::Split { stake, split_stake, lamports } => Instruction::new_with_bincode(
+                stake::program::ID,
+                &stake_instruction::StakeInstruction::Split(*lamports),
+                vec![
+                    AccountMeta::new(*stake, false),
+                    AccountMeta::new(*split_stake, false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+            StakeAction::Merge { destination_stake, source_stake } => {
+                // merge is a single instruction, returned in a vec by the stake program
+                stake_instruction::merge(destination_stake, source_stake, vault).remove(0)
+            }
+        };
+        instruction.into()
+    }
+}
+
+/// Typed vote program actions for a vote account whose withdraw authority is a vault,
+/// so validator teams don't have to encode the instruction by hand.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VoteAction {
+    AuthorizeWithdrawer { vote_account: Pubkey, new_withdrawer: Pubkey },
+    Withdraw { vote_account: Pubkey, destination: Pubkey, lamports: u64 },
+}
+
+impl VoteAction {
+    /// variants of the vote program instruction enum
+    const AUTHORIZE_INSTRUCTION: u32 = 1;
+    const WITHDRAW_INSTRUCTION: u32 = 3;
+    /// variant of the vote program VoteAuthorize enum for the withdraw authority
+    const VOTE_AUTHORIZE_WITHDRAWER: u32 = 1;
+
+    /// checks the action can be attached, the withdrawal must be of a non zero amount to
+    /// another account, and the new withdrawer can't be the default pubkey
+    pub fn check(&self) -> Result<()>{
+        let valid = match self {
+            VoteAction::AuthorizeWithdrawer { new_withdrawer, .. } => *new_withdrawer != Pubkey::default(),
+            VoteAction::Withdraw { vote_account, destination, lamports } => {
+                *lamports > 0 && destination != vote_account
+            }
+        };
+        if !valid {
+            return err!(MsError::InvalidVoteAction);
+        }
+        Ok(())
+    }
+
+    /// builds the vote program instruction for the action, signed by the vault
+    pub fn to_instruction(&self, vault: &Pubkey) -> IncomingInstruction {
+        let (data, accounts) = match self {
+            VoteAction::AuthorizeWithdrawer { vote_account, new_withdrawer } => (
+                [
+                    &Self::AUTHORIZE_INSTRUCTION.to_le_bytes()[..],
+                    new_withdrawer.as_ref(),
+                    &Self::VOTE_AUTHORIZE_WITHDRAWER.to_le_bytes(),
+                ].concat(),
+                vec![
+                    AccountMeta::new(*vote_account, false),
+                    AccountMeta::new_readonly(anchor_lang::solana_program::sysvar::clock::ID, false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+            VoteAction::Withdraw { vote_account, destination, lamports } => (
+                [&Self::WITHDRAW_INSTRUCTION.to_le_bytes()[..], &lamports.to_le_bytes()].concat(),
+                vec![
+                    AccountMeta::new(*vote_account, false),
+                    AccountMeta::new(*destination, false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+        };
+        Instruction {
+            program_id: anchor_lang::solana_program::vote::program::ID,
+            accounts,
+            data,
+        }.into()
+    }
+
+    /// checks a vote program withdrawal when it is executed, the vote account has to stay
+    /// rent exempt so withdrawals through the multisig never close it. Other instructions
+    /// are unaffected.
+    pub fn check_withdraw(ix: &Instruction, account_infos: &[AccountInfo]) -> Result<()>{
+        if ix.program_id != anchor_lang::solana_program::vote::program::ID || ix.data.len() != 12
+            || ix.data[..4] != Self::WITHDRAW_INSTRUCTION.to_le_bytes() {
+            return Ok(());
+        }
+        let lamports = u64::from_le_bytes(ix.data[4..12].try_into().unwrap());
+        let vote_account = ix
+            .accounts
+            .first()
+            .and_then(|meta| account_infos.iter().find(|a| a.key == &meta.pubkey))
+            .ok_or(MsError::InvalidInstructionAccount)?;
+        let available = vote_account
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(vote_account.data_len()));
+        if lamports > available {
+            return err!(MsError::VoteWithdrawTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// The SPL governance whose token holders must ratify the transactions of a multisig, on top of
+/// the approvals of the members. A transaction records a proposal of the governance, voted on with
+/// the governing token, which must have succeeded by the time the transaction is executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct Ratification {
+    pub governance_program: Pubkey,         // the governance program (instance) owning the proposals
+    pub governance: Pubkey,                 // the governance the proposals must belong to
+    pub governing_token_mint: Pubkey,       // the mint the proposals must be voted with, ie. the community token
+}
+
+impl Ratification {
+    pub const SIZE: usize = 32 +            // the governance program
+        32 +                                // the governance
+        32;                                 // the governing token mint
+
+    /// variants of the governance program GovernanceAccountType enum
+    const PROPOSAL_V1: u8 = 5;
+    const PROPOSAL_V2: u8 = 14;
+    /// variants of the governance program ProposalState enum
+    const SUCCEEDED: u8 = 3;
+    const EXECUTING: u8 = 4;
+    const COMPLETED: u8 = 5;
+
+    /// checks that the proposal belongs to the governance and was voted with the governing token,
+    /// and that it succeeded. A proposal that is executing or completed succeeded as well.
+    pub fn check(&self, proposal: &AccountInfo) -> Result<()> {
+        if *proposal.owner != self.governance_program {
+            return err!(MsError::InvalidRatifyingProposal);
+        }
+        let data = proposal.try_borrow_data()?;
+        // the account type, the governance, the governing token mint, then the state
+        if data.len() < 1 + 32 + 32 + 1
+            || !matches!(data[0], Self::PROPOSAL_V1 | Self::PROPOSAL_V2)
+            || data[1..33] != self.governance.to_bytes()
+            || data[33..65] != self.governing_token_mint.to_bytes()
+        {
+            return err!(MsError::InvalidRatifyingProposal);
+        }
+        if !matches!(data[65], Self::SUCCEEDED | Self::EXECUTING | Self::COMPLETED) {
+            return err!(MsError::NotRatified);
+        }
+        Ok(())
+    }
+}
+
+/// Typed SPL Governance actions for a vault that is the authority of a realm, or that holds
+/// governing (ie. council) tokens in it, so proposals translate into realm instructions
+/// without encoding them by hand. Realms with voter weight addins aren't supported.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum GovernanceAction {
+    SetRealmAuthority { realm: Pubkey, new_realm_authority: Option<Pubkey> },
+    DepositGoverningTokens { realm: Pubkey, governing_token_mint: Pubkey, governing_token_source: Pubkey, amount: u64 },
+    WithdrawGoverningTokens { realm: Pubkey, governing_token_mint: Pubkey, governing_token_destination: Pubkey },
+    SetGovernanceDelegate { realm: Pubkey, governing_token_mint: Pubkey, new_governance_delegate: Option<Pubkey> },
+    CastVote { realm: Pubkey, governance: Pubkey, proposal: Pubkey, proposal_owner_record: Pubkey, governing_token_mint: Pubkey, approve: bool },
+    RelinquishVote { realm: Pubkey, governance: Pubkey, proposal: Pubkey, governing_token_mint: Pubkey },
+    RevokeGoverningTokens { realm: Pubkey, governing_token_mint: Pubkey, governing_token_owner: Pubkey, amount: u64 },
+}
+
+impl GovernanceAction {
+    /// variants of the governance program instruction enum
+    const DEPOSIT_GOVERNING_TOKENS_INSTRUCTION: u8 = 1;
+    const WITHDRAW_GOVERNING_TOKENS_INSTRUCTION: u8 = 2;
+    const SET_GOVERNANCE_DELEGATE_INSTRUCTION: u8 = 3;
+    const CAST_VOTE_INSTRUCTION: u8 = 13;
+    const RELINQUISH_VOTE_INSTRUCTION: u8 = 15;
+    const SET_REALM_AUTHORITY_INSTRUCTION: u8 = 21;
+    const REVOKE_GOVERNING_TOKENS_INSTRUCTION: u8 = 26;
+    /// variants of the governance program SetRealmAuthorityAction enum
+    const SET_REALM_AUTHORITY_UNCHECKED: u8 = 0;
+    const REMOVE_REALM_AUTHORITY: u8 = 2;
+    /// variants of the governance program Vote enum
+    const VOTE_APPROVE: u8 = 0;
+    const VOTE_DENY: u8 = 1;
+
+    /// checks the action can be attached, the deposited or revoked amount can't be zero
+    pub fn check(&self) -> Result<()>{
+        let valid = match self {
+            GovernanceAction::DepositGoverningTokens { amount, .. }
+            | GovernanceAction::RevokeGoverningTokens { amount, .. } => *amount > 0,
+            _ => true,
+        };
+        if !valid {
+            return err!(MsError::InvalidGovernanceAction);
+        }
+        Ok(())
+    }
+
+    /// the token owner record of the owner in the realm, for the governing token mint
+    fn token_owner_record(program_id: &Pubkey, realm: &Pubkey, governing_token_mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"governance", realm.as_ref(), governing_token_mint.as_ref(), owner.as_ref()],
+            program_id,
+        ).0
+    }
+
+    /// the account holding the deposited governing tokens of the realm
+    fn governing_token_holding(program_id: &Pubkey, realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"governance", realm.as_ref(), governing_token_mint.as_ref()], program_id).0
+    }
+
+    /// the config account of the realm
+    fn realm_config(program_id: &Pubkey, realm: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"realm-config", realm.as_ref()], program_id).0
+    }
+
+    /// builds the governance program instruction for the action, signed (and paid for) by the vault
+    /// as the realm authority, the governing token owner or the governing token mint authority
+    pub fn to_instruction(&self, governance_program: &Pubkey, vault: &Pubkey) -> IncomingInstruction {
+        let system_program = anchor_lang::solana_program::system_program::ID;
+        let (data, accounts) = match self {
+            GovernanceAction::SetRealmAuthority { realm, new_realm_authority } => {
+                let mut accounts = vec![
+                    AccountMeta::new(*realm, false),
+                    AccountMeta::new_readonly(*vault, true),
+                ];
+                let action = match new_realm_authority {
+                    Some(new_realm_authority) => {
+                        accounts.push(AccountMeta::new_readonly(*new_realm_authority, false));
+                        Self::SET_REALM_AUTHORITY_UNCHECKED
+                    }
+                    None => Self::REMOVE_REALM_AUTHORITY,
+                };
+                (vec![Self::SET_REALM_AUTHORITY_INSTRUCTION, action], accounts)
+            }
+            GovernanceAction::DepositGoverningTokens { realm, governing_token_mint, governing_token_source, amount } => (
+                [&[Self::DEPOSIT_GOVERNING_TOKENS_INSTRUCTION][..], &amount.to_le_bytes()].concat(),
+                vec![
+                    AccountMeta::new_readonly(*realm, false),
+                    AccountMeta::new(Self::governing_token_holding(governance_program, realm, governing_token_mint), false),
+                    AccountMeta::new(*governing_token_source, false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(Self::token_owner_record(governance_program, realm, governing_token_mint, vault), false),
+                    AccountMeta::new(*vault, true),
+                    AccountMeta::new_readonly(system_program, false),
+                    AccountMeta::new_readonly(SpendingLimit::TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(Self::realm_config(governance_program, realm), false),
+                ],
+            ),
+            GovernanceAction::WithdrawGoverningTokens { realm, governing_token_mint, governing_token_destination } => (
+                vec![Self::WITHDRAW_GOVERNING_TOKENS_INSTRUCTION],
+                vec![
+                    AccountMeta::new_readonly(*realm, false),
+                    AccountMeta::new(Self::governing_token_holding(governance_program, realm, governing_token_mint), false),
+                    AccountMeta::new(*governing_token_destination, false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(Self::token_owner_record(governance_program, realm, governing_token_mint, vault), false),
+                    AccountMeta::new_readonly(SpendingLimit::TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(Self::realm_config(governance_program, realm), false),
+                ],
+            ),
+            GovernanceAction::SetGovernanceDelegate { realm, governing_token_mint, new_governance_delegate } => {
+                let mut data = vec![Self::SET_GOVERNANCE_DELEGATE_INSTRUCTION];
+                match new_governance_delegate {
+                    Some(delegate) => {
+                        data.push(1);
+                        data.extend_from_slice(delegate.as_ref());
+                    }
+                    None => data.push(0),
+                }
+                (data, vec![
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(Self::token_owner_record(governance_program, realm, governing_token_mint, vault), false),
+                ])
+            }
+            GovernanceAction::CastVote { realm, governance, proposal, proposal_owner_record, governing_token_mint, approve } => {
+                // an approval gives the whole weight to the single choice of a yes/no proposal
+                let vote = if *approve {
+                    [&[Self::VOTE_APPROVE][..], &1u32.to_le_bytes(), &[0, 100]].concat()
+                } else {
+                    vec![Self::VOTE_DENY]
+                };
+                let voter_record = Self::token_owner_record(governance_program, realm, governing_token_mint, vault);
+                let (vote_record, _) = Pubkey::find_program_address(
+                    &[b"governance", proposal.as_ref(), voter_record.as_ref()],
+                    governance_program,
+                );
+                ([&[Self::CAST_VOTE_INSTRUCTION][..], &vote].concat(), vec![
+                    AccountMeta::new_readonly(*realm, false),
+                    AccountMeta::new(*governance, false),
+                    AccountMeta::new(*proposal, false),
+                    AccountMeta::new(*proposal_owner_record, false),
+                    AccountMeta::new(voter_record, false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(vote_record, false),
+                    AccountMeta::new_readonly(*governing_token_mint, false),
+                    AccountMeta::new(*vault, true),
+                    AccountMeta::new_readonly(system_program, false),
+                    AccountMeta::new_readonly(Self::realm_config(governance_program, realm), false),
+                ])
+            }
+            GovernanceAction::RelinquishVote { realm, governance, proposal, governing_token_mint } => {
+                let voter_record = Self::token_owner_record(governance_program, realm, governing_token_mint, vault);
+                let (vote_record, _) = Pubkey::find_program_address(
+                    &[b"governance", proposal.as_ref(), voter_record.as_ref()],
+                    governance_program,
+                );
+                (vec![Self::RELINQUISH_VOTE_INSTRUCTION], vec![
+                    AccountMeta::new_readonly(*realm, false),
+                    AccountMeta::new_readonly(*governance, false),
+                    AccountMeta::new(*proposal, false),
+                    AccountMeta::new(voter_record, false),
+                    AccountMeta::new(vote_record, false),
+                    AccountMeta::new_readonly(*governing_token_mint, false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(*vault, false),
+                ])
+            }
+            GovernanceAction::RevokeGoverningTokens { realm, governing_token_mint, governing_token_owner, amount } => (
+                [&[Self::REVOKE_GOVERNING_TOKENS_INSTRUCTION][..], &amount.to_le_bytes()].concat(),
+                vec![
+                    AccountMeta::new_readonly(*realm, false),
+                    AccountMeta::new(Self::governing_token_holding(governance_program, realm, governing_token_mint), false),
+                    AccountMeta::new(Self::token_owner_record(governance_program, realm, governing_token_mint, governing_token_owner), false),
+                    AccountMeta::new(*governing_token_mint, false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new_readonly(Self::realm_config(governance_program, realm), false),
+                    AccountMeta::new_readonly(SpendingLimit::TOKEN_PROGRAM_ID, false),
+                ],
+            ),
+        };
+        Instruction {
+            program_id: *governance_program,
+            accounts,
+            data,
+        }.into()
+    }
+}
+
+/// Typed Anchor IDL actions for a program whose IDL authority is a vault, so the rarely used
+/// IDL instructions don't have to be encoded by hand. Closing the IDL account needs the program
+/// to be built with an Anchor version that supports it (0.29 or later).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum IdlAction {
+    SetAuthority { program_id: Pubkey, new_authority: Pubkey },
+    SetBufferAuthority { program_id: Pubkey, buffer: Pubkey, new_authority: Pubkey },
+    SetBuffer { program_id: Pubkey, buffer: Pubkey },
+    Close { program_id: Pubkey, destination: Pubkey },
+}
+
+impl IdlAction {
+    /// variants of the Anchor IdlInstruction enum
+    const SET_BUFFER_INSTRUCTION: u8 = 3;
+    const SET_AUTHORITY_INSTRUCTION: u8 = 4;
+    const CLOSE_INSTRUCTION: u8 = 5;
+
+    /// checks the action can be attached, the buffer and destination can't be the IDL account
+    pub fn check(&self) -> Result<()>{
+        let valid = match self {
+            IdlAction::SetAuthority { .. } => true,
+            IdlAction::SetBufferAuthority { program_id, buffer, .. } | IdlAction::SetBuffer { program_id, buffer } => {
+                *buffer != anchor_lang::idl::IdlAccount::address(program_id)
+            }
+            IdlAction::Close { program_id, destination } => *destination != anchor_lang::idl::IdlAccount::address(program_id),
+        };
+        if !valid {
+            return err!(MsError::InvalidIdlAction);
+        }
+        Ok(())
+    }
+
+    /// builds the IDL instruction of the program for the action, signed by the vault
+    pub fn to_instruction(&self, vault: &Pubkey) -> IncomingInstruction {
+        let tag = anchor_lang::idl::IDL_IX_TAG.to_le_bytes();
+        let (program_id, data, accounts) = match self {
+            IdlAction::SetAuthority { program_id, new_authority } => (
+                program_id,
+                [&tag[..], &[Self::SET_AUTHORITY_INSTRUCTION], new_authority.as_ref()].concat(),
+                vec![
+                    AccountMeta::new(anchor_lang::idl::IdlAccount::address(program_id), false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+            // the IDL buffer is an IDL account, so its authority is set the same way
+            IdlAction::SetBufferAuthority { program_id, buffer, new_authority } => (
+                program_id,
+                [&tag[..], &[Self::SET_AUTHORITY_INSTRUCTION], new_authority.as_ref()].concat(),
+                vec![
+                    AccountMeta::new(*buffer, false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+            IdlAction::SetBuffer { program_id, buffer } => (
+                program_id,
+                [&tag[..], &[Self::SET_BUFFER_INSTRUCTION]].concat(),
+                vec![
+                    AccountMeta::new(*buffer, false),
+                    AccountMeta::new(anchor_lang::idl::IdlAccount::address(program_id), false),
+                    AccountMeta::new_readonly(*vault, true),
+                ],
+            ),
+            IdlAction::Close { program_id, destination } => (
+                program_id,
+                [&tag[..], &[Self::CLOSE_INSTRUCTION]].concat(),
+                vec![
+                    AccountMeta::new(anchor_lang::idl::IdlAccount::address(program_id), false),
+                    AccountMeta::new_readonly(*vault, true),
+                    AccountMeta::new(*destination, false),
+                ],
+            ),
+        };
+        Instruction {
+            program_id: *program_id,
+            accounts,
+            data,
+        }.into()
+    }
+}
+
+/// The typed actions that can be attached to a vault transaction with add_typed_instruction,
+/// each building its instruction with the vault of the transaction as the signing authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum TypedAction {
+    Stake { action: StakeAction },
+    Vote { action: VoteAction },
+    Idl { action: IdlAction },
+    Governance { governance_program: Pubkey, action: GovernanceAction },
+}
+
+impl TypedAction {
+    /// checks the action can be attached
+    pub fn check(&self) -> Result<()>{
+        match self {
+            TypedAction::Stake { .. } => Ok(()),
+            TypedAction::Vote { action } => action.check(),
+            TypedAction::Idl { action } => action.check(),
+            TypedAction::Governance { action, .. } => action.check(),
+        }
+    }
+
+    /// builds the instruction for the action, signed by the vault
+    pub fn to_instruction(&self, vault: &Pubkey) -> IncomingInstruction {
+        match self {
+            TypedAction::Stake { action } => action.to_instruction(vault),
+            TypedAction::Vote { action } => action.to_instruction(vault),
+            TypedAction::Idl { action } => action.to_instruction(vault),
+            TypedAction::Governance { governance_program, action } => action.to_instruction(governance_program, vault),
+        }
+    }
+}
+
+/// A SpendingLimit allows a designated key to move funds out of a vault (authority)
+/// without a full proposal, up to `amount` per `period`.
+#[account]
+pub struct SpendingLimit {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub authority_index: u32,           // the authority (vault) the funds are spent from
+    pub mint: Pubkey,                   // the mint being spent, the default pubkey for native SOL
+    pub member: Pubkey,                 // the key allowed to spend under the limit
+    pub amount: u64,                    // the amount that can be spent per period
+    pub period: i64,                    // the length of a period in seconds, 0 for a one-time budget
+    pub spent: u64,                     // the amount spent in the current period
+    pub last_reset: i64,                // unix timestamp of when the current period started
+    pub bump: u8,                       // bump for the seed
+}
+
+impl SpendingLimit {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        32 +                            // the mint
+        32 +                            // the member allowed to spend
+        8 +                             // the amount per period
+        8 +                             // the period length
+        8 +                             // the amount spent
+        8 +                             // the period start
+        1;                              // bump
+
+    pub const TOKEN_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    pub const TOKEN_2022_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+    /// initializes the spending limit, starting the first period now
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, mint: Pubkey, member: Pubkey, amount: u64, period: i64, bump: u8) -> Result<()>{
+        if authority_index < 1 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        if period < 0 {
+            return err!(MsError::InvalidSpendingLimit);
+        }
+        self.multisig = multisig;
+        self.authority_index = authority_index;
+        self.mint = mint;
+        self.member = member;
+        self.amount = amount;
+        self.period = period;
+        self.spent = 0;
+        self.last_reset = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// checks if the key is the SPL token program or the Token-2022 program
+    pub fn is_token_program(key: &Pubkey) -> bool {
+        spl_token_2022::check_spl_token_program_account(key).is_ok()
+    }
+
+    /// records an amount spent, starting a new period first if the current one has passed
+    pub fn spend(&mut self, amount: u64) -> Result<()>{
+        let now = Clock::get()?.unix_timestamp;
+        if self.period > 0 && now >= self.last_reset.saturating_add(self.period) {
+            self.spent = 0;
+            self.last_reset = now;
+        }
+        let spent = self.spent.checked_add(amount).ok_or(MsError::SpendingLimitExceeded)?;
+        if spent > self.amount {
+            return err!(MsError::SpendingLimitExceeded);
+        }
+        self.spent = spent;
+        Ok(())
+    }
+}
+
+/// A RecurringTransaction executes the instructions of an approved transaction repeatedly,
+/// every `interval` seconds. Anyone can crank the execution once it's due.
+#[account]
+pub struct RecurringTransaction {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub transaction: Pubkey,            // the transaction whose instructions are executed
+    pub interval: i64,                  // the number of seconds between executions
+    pub next_execution_at: i64,         // unix timestamp from which the next execution is due
+    pub executions: u32,                // the number of times the transaction has been executed
+    pub bump: u8,                       // bump for the seed
+}
+
+impl RecurringTransaction {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the transaction key
+        8 +                             // the interval
+        8 +                             // the next execution timestamp
+        4 +                             // the number of executions
+        1;                              // bump
+
+    /// initializes the schedule, with the first execution due at start_at
+    pub fn init(&mut self, multisig: Pubkey, transaction: Pubkey, interval: i64, start_at: i64, bump: u8) -> Result<()>{
+        if interval < 1 {
+            return err!(MsError::InvalidRecurringTransaction);
+        }
+        self.multisig = multisig;
+        self.transaction = transaction;
+        self.interval = interval;
+        self.next_execution_at = start_at;
+        self.executions = 0;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// checks that an execution is due and schedules the next one. Missed executions
+    /// are skipped rather than executed back to back.
+    pub fn schedule_next(&mut self) -> Result<()>{
+        let now = Clock::get()?.unix_timestamp;
+        if now < self.next_execution_at {
+            return err!(MsError::RecurringTransactionNotDue);
+        }
+        let elapsed_intervals = (now - self.next_execution_at) / self.interval + 1;
+        self.next_execution_at = self.next_execution_at.saturating_add(elapsed_intervals.saturating_mul(self.interval));
+        self.executions = self.executions.saturating_add(1);
+        Ok(())
+    }
+}
+
+/// A Delegate lets the delegate key of a member approve and reject transactions on their behalf,
+/// without changing the membership. The member can revoke it at any time by closing it.
+#[account]
+pub struct Delegate {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub member: Pubkey,                 // the member delegating their vote
+    pub delegate: Pubkey,               // the key allowed to vote on behalf of the member
+    pub bump: u8,                       // bump for the seed
+}
+
+impl Delegate {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the member
+        32 +                            // the delegate
+        1;                              // bump
+
+    /// initializes the delegation of the member's vote
+    pub fn init(&mut self, multisig: Pubkey, member: Pubkey, delegate: Pubkey, bump: u8) -> Result<()>{
+        if delegate == member {
+            return err!(MsError::InvalidDelegate);
+        }
+        self.multisig = multisig;
+        self.member = member;
+        self.delegate = delegate;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// A SessionKey lets a short-lived key of a member (ie. a browser hot key) vote on its behalf,
+/// restricted to the actions of the session and until the expiry slot, so the cold member
+/// key doesn't have to sign. The member can revoke it at any time by closing it.
+#[account]
+pub struct SessionKey {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub member: Pubkey,                 // the member the session acts for
+    pub session_key: Pubkey,            // the short-lived key that signs for the member
+    pub actions: u8,                    // bitmask of the actions the session key can take
+    pub expires_at_slot: u64,           // the last slot the session key can be used in
+    pub bump: u8,                       // bump for the seed
+}
+
+impl SessionKey {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the member
+        32 +                            // the session key
+        1 +                             // the actions
+        8 +                             // the expiry slot
+        1;                              // bump
+
+    pub const APPROVE: u8 = 1 << 0;     // approve transactions
+    pub const REJECT: u8 = 1 << 1;      // reject transactions
+    pub const CONFIG: u8 = 1 << 2;      // vote on internal (config) transactions, besides vault ones
+    pub const ALL: u8 = Self::APPROVE | Self::REJECT | Self::CONFIG;
+
+    /// the maximum lifetime of a session key, about a week of slots
+    pub const MAX_DURATION_SLOTS: u64 = 1_512_000;
+
+    /// initializes the session key of the member
+    pub fn init(&mut self, multisig: Pubkey, member: Pubkey, session_key: Pubkey, actions: u8, expires_at_slot: u64, bump: u8) -> Result<()>{
+        let slot = Clock::get()?.slot;
+        if session_key == member
+            || actions & !Self::ALL != 0
+            || actions & (Self::APPROVE | Self::REJECT) == 0
+            || expires_at_slot < slot
+            || expires_at_slot - slot > Self::MAX_DURATION_SLOTS
+        {
+            return err!(MsError::InvalidSessionKey);
+        }
+        self.multisig = multisig;
+        self.member = member;
+        self.session_key = session_key;
+        self.actions = actions;
+        self.expires_at_slot = expires_at_slot;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// checks that the session key hasn't expired and can vote on transactions of the authority
+    pub fn check_vote(&self, approve: bool, authority_index: u32) -> Result<()>{
+        if Clock::get()?.slot > self.expires_at_slot {
+            return err!(MsError::SessionKeyExpired);
+        }
+        let action = if approve { Self::APPROVE } else { Self::REJECT };
+        if self.actions & action == 0 || (authority_index == 0 && self.actions & Self::CONFIG == 0) {
+            return err!(MsError::InvalidSessionKey);
+        }
+        Ok(())
+    }
+}
+
+/// A Recovery lets the recovery key rotate the full membership of a multisig that hasn't
+/// executed a transaction for a number of epochs, ie. when its members lost their keys.
+/// The recovery key can be a vault of another multisig, to recover with a backup member set.
+#[account]
+pub struct Recovery {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub recovery_key: Pubkey,           // the key allowed to recover the multisig
+    pub inactivity_epochs: u64,         // the number of epochs without executions before recovery is allowed
+    pub bump: u8,                       // bump for the seed
+}
+
+impl Recovery {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the recovery key
+        8 +                             // the inactivity epochs
+        1;                              // bump
+
+    /// initializes the recovery of the multisig
+    pub fn init(&mut self, multisig: Pubkey, recovery_key: Pubkey, inactivity_epochs: u64, bump: u8) -> Result<()>{
+        if inactivity_epochs < 1 {
+            return err!(MsError::InvalidRecovery);
+        }
+        self.multisig = multisig;
+        self.recovery_key = recovery_key;
+        self.inactivity_epochs = inactivity_epochs;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// errors if the multisig executed a transaction within the inactivity epochs
+    pub fn check_due(&self, last_execution_epoch: u64) -> Result<()>{
+        let due_epoch = last_execution_epoch.saturating_add(self.inactivity_epochs);
+        if Clock::get()?.epoch < due_epoch {
+            return err!(MsError::RecoveryNotDue);
+        }
+        Ok(())
+    }
+}
+
+/// A MemberRecovery is a proposal of the recovery set to replace a single lost member key.
+/// Once approved by the recovery threshold, anyone can execute the replacement after the
+/// recovery delay, unless the old member (who still holds their key) cancels it.
+#[account]
+pub struct MemberRecovery {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub old_member: Pubkey,             // the lost member key
+    pub new_member: Pubkey,             // the key replacing it
+    pub proposer: Pubkey,               // the recovery key that proposed the recovery and paid the rent
+    pub approvals: Vec<Pubkey>,         // the recovery keys that approved, sorted
+    pub ready_at: i64,                  // unix timestamp the recovery can be executed at, 0 until approved
+    pub bump: u8,                       // bump for the seed
+}
+
+impl MemberRecovery {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the old member
+        32 +                            // the new member
+        32 +                            // the proposer
+        4 + (32 * Ms::MAX_RECOVERY_KEYS) +  // the approvals
+        8 +                             // ready at
+        1;                              // bump
+
+    /// initializes the recovery of the old member key
+    pub fn init(&mut self, multisig: Pubkey, old_member: Pubkey, new_member: Pubkey, proposer: Pubkey, bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.old_member = old_member;
+        self.new_member = new_member;
+        self.proposer = proposer;
+        self.approvals = Vec::new();
+        self.ready_at = 0;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// the number of approvals by keys that are still in the recovery set
+    pub fn approval_count(&self, multisig: &Ms) -> usize {
+        self.approvals.iter().filter(|k| multisig.is_recovery_key(**k)).count()
+    }
+
+    /// records the approval of a recovery key, starting the recovery delay once the
+    /// approvals reach the recovery threshold
+    pub fn approve(&mut self, multisig: &Ms, recovery_key: Pubkey) -> Result<()>{
+        if !multisig.is_recovery_key(recovery_key) {
+            return err!(MsError::NotRecoveryKey);
+        }
+        match self.approvals.binary_search(&recovery_key) {
+            Ok(_) => return err!(MsError::RecoveryAlreadyApproved),
+            Err(ind) => self.approvals.insert(ind, recovery_key),
+        }
+        if self.ready_at == 0 && self.approval_count(multisig) >= usize::from(multisig.recovery_threshold) {
+            self.ready_at = Clock::get()?.unix_timestamp.checked_add(i64::from(multisig.recovery_delay)).unwrap();
+        }
+        Ok(())
+    }
+
+    /// errors if the recovery isn't approved by the (current) recovery set or the recovery delay hasn't passed
+    pub fn check_ready(&self, multisig: &Ms) -> Result<()>{
+        if self.ready_at == 0
+            || multisig.recovery_threshold == 0
+            || self.approval_count(multisig) < usize::from(multisig.recovery_threshold)
+        {
+            return err!(MsError::RecoveryNotApproved);
+        }
+        if Clock::get()?.unix_timestamp < self.ready_at {
+            return err!(MsError::RecoveryDelayNotElapsed);
+        }
+        Ok(())
+    }
+}
+
+/// The mint or NFT collection whose token holders can vote on the vault transactions of a multisig.
+/// Tokens of a mint must be non-transferable (Token-2022), as a balance that can be split or moved
+/// between wallets would vote again.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MembershipGate {
+    Mint {
+        mint: Pubkey,                       // any holder of a non-transferable token of the mint, one vote per holder
+        threshold: u16,                     // the number of token votes that approves (or rejects) a transaction
+    },
+    Collection {
+        collection: Pubkey,                 // any holder of an NFT of the verified collection, one vote per NFT
+        threshold: u16,                     // the number of NFT votes that approves (or rejects) a transaction
+    },
+}
+
+impl MembershipGate {
+    pub const SIZE: usize = 1 +             // variant
+        32 +                                // the mint or collection
+        2;                                  // the token threshold
+
+    pub const METADATA_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+    /// the number of token votes that approves (or rejects) a transaction
+    pub fn threshold(&self) -> u16 {
+        match self {
+            MembershipGate::Mint { threshold, .. } | MembershipGate::Collection { threshold, .. } => *threshold,
+        }
+    }
+
+    /// checks that the voter holds a token that passes the gate, and returns the key the vote is
+    /// recorded under: the voter for a mint, as its tokens can't be moved to another wallet, and
+    /// the NFT mint for a collection, so that a transferred NFT can't vote twice.
+    /// The metadata of the NFT is found in the remaining accounts.
+    pub fn voting_key(&self, voter: Pubkey, token_account: &AccountInfo, remaining_accounts: &[AccountInfo]) -> Result<Pubkey> {
+        if !SpendingLimit::is_token_program(token_account.owner) {
+            return err!(MsError::InvalidMembershipToken);
+        }
+        // Token-2022 accounts keep the base account layout, followed by their extensions
+        let data = token_account.try_borrow_data()?;
+        let token_state = StateWithExtensions::<TokenAccount>::unpack(&data)
+            .map_err(|_| MsError::InvalidMembershipToken)?;
+        let token = token_state.base;
+        if token.owner != voter || token.amount == 0 {
+            return err!(MsError::InvalidMembershipToken);
+        }
+        match self {
+            MembershipGate::Mint { mint, .. } => {
+                // accounts of a non-transferable mint carry the extension, and their owner can't be changed
+                if token.mint != *mint || token_state.get_extension::<NonTransferableAccount>().is_err() {
+                    return err!(MsError::InvalidMembershipToken);
+                }
+                Ok(voter)
+            }
+            MembershipGate::Collection { collection, .. } => {
+                let (metadata_pda, _) = Pubkey::find_program_address(
+                    &[b"metadata", Self::METADATA_PROGRAM_ID.as_ref(), token.mint.as_ref()],
+                    &Self::METADATA_PROGRAM_ID,
+                );
+                let metadata = remaining_accounts
+                    .iter()
+                    .find(|a| a.key == &metadata_pda && a.owner == &Self::METADATA_PROGRAM_ID)
+                    .ok_or(MsError::InvalidMembershipToken)?;
+                // an NFT has a supply of one, so the holder is the only owner
+                if token.amount != 1 || Self::verified_collection(&metadata.try_borrow_data()?) != Some(*collection) {
+                    return err!(MsError::InvalidMembershipToken);
+                }
+                Ok(token.mint)
+            }
+        }
+    }
+
+    /// reads the verified collection (if any) of a token metadata account. The collection
+    /// follows the variable length name, symbol, uri and creators.
+    fn verified_collection(data: &[u8]) -> Option<Pubkey> {
+        let mut offset = 1 + 32 + 32;   // key, update authority and mint
+        let read_u32 = |at: usize| data.get(at..at + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize);
+        for _ in 0..3 {
+            // name, symbol and uri
+            offset += 4 + read_u32(offset)?;
+        }
+        offset += 2;                    // seller fee basis points
+        if *data.get(offset)? == 1 {
+            // creators, 34 bytes each
+            offset += 1 + 4 + 34 * read_u32(offset + 1)?;
+        } else {
+            offset += 1;
+        }
+        offset += 2;                    // primary sale happened and is mutable
+        for _ in 0..2 {
+            // edition nonce and token standard
+            offset += if *data.get(offset)? == 1 { 2 } else { 1 };
+        }
+        if *data.get(offset)? != 1 || *data.get(offset + 1)? != 1 {
+            return None;
+        }
+        data.get(offset + 2..offset + 34).map(|key| Pubkey::new_from_array(key.try_into().unwrap()))
+    }
+}
+
+/// The staking (ie. vote escrow) program whose locker accounts the vote weights of the members
+/// are read from. The locker of a member is the PDA of the program with the seeds
+/// [seed prefix, registrar, member], and holds the locked amount as a u64 at the amount offset.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct StakeWeightSource {
+    pub program: Pubkey,                    // the staking program that owns the lockers
+    pub registrar: Pubkey,                  // the registrar (or locker config) the lockers are derived from
+    pub seed_prefix: [u8; 8],               // the first seed of the lockers, padded with zeros
+    pub amount_offset: u16,                 // offset of the locked amount in the locker account data
+    pub amount_per_vote: u64,               // the locked amount that counts for a weight of 1
+}
+
+impl StakeWeightSource {
+    pub const SIZE: usize = 32 +            // the staking program
+        32 +                                // the registrar
+        8 +                                 // the seed prefix
+        2 +                                 // the amount offset
+        8;                                  // the amount per vote
+
+    pub fn check(&self) -> Result<()> {
+        if self.amount_per_vote == 0 {
+            return err!(MsError::InvalidStakeLocker);
+        }
+        Ok(())
+    }
+
+    /// the address of the locker of the member
+    pub fn locker_address(&self, member: &Pubkey) -> Pubkey {
+        let prefix_len = self.seed_prefix.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        Pubkey::find_program_address(
+            &[&self.seed_prefix[..prefix_len], self.registrar.as_ref(), member.as_ref()],
+            &self.program,
+        ).0
+    }
+
+    /// reads the vote weight of a member from its locker, capped by the member weight so
+    /// that the thresholds stay reachable. A member without a locker has no weight.
+    pub fn stake_weight(&self, locker: &AccountInfo, cap: u16) -> Result<u16> {
+        if *locker.owner != self.program {
+            return Ok(0);
+        }
+        let data = locker.try_borrow_data()?;
+        let offset = usize::from(self.amount_offset);
+        let amount = data
+            .get(offset..offset + 8)
+            .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+            .ok_or(MsError::InvalidStakeLocker)?;
+        Ok((amount / self.amount_per_vote).min(u64::from(cap)) as u16)
+    }
+}
+
+/// A StakeSnapshot holds the vote weights of the members read from their staking lockers when
+/// the transaction was activated, so that locking more tokens afterwards doesn't change the vote.
+#[account]
+pub struct StakeSnapshot {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub transaction_index: u32,         // the index of the transaction the weights apply to
+    pub weights: Vec<MemberWeight>,     // the weights of the members, sorted by member
+    pub bump: u8,                       // bump for the seed
+}
+
+impl StakeSnapshot {
+    pub fn size(members: usize) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the transaction index
+        4 +                             // the weights vec length
+        members * MemberWeight::SIZE +  // the weights
+        1                               // bump
+    }
+
+    /// the snapshotted weight of the member, members added since have no weight
+    pub fn get_weight(&self, member: Pubkey) -> u16 {
+        match self.weights.binary_search_by_key(&member, |w| w.member) {
+            Ok(ind) => self.weights[ind].weight,
+            Err(_) => 0,
+        }
+    }
+}
+
+/// A TokenVoteRecord holds the vote of a token holder on a transaction of a multisig with a
+/// membership gate. It is recorded under the voting key, so each holder of a token of the mint
+/// (or each NFT of the collection) votes once.
+#[account]
+pub struct TokenVoteRecord {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub transaction_index: u32,         // the index of the transaction voted on
+    pub voting_key: Pubkey,             // the holder of the mint token, or the mint of the NFT that voted
+    pub voter: Pubkey,                  // the holder that voted, who gets the rent back
+    pub approve: bool,                  // the choice of the holder, approve or reject
+    pub bump: u8,                       // bump for the seed
+}
+
+impl TokenVoteRecord {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the transaction index
+        32 +                            // the voting key
+        32 +                            // the voter
+        1 +                             // the choice
+        1;                              // bump
+}
+
+/// A VoteRecord holds the vote of a single member on a transaction, for multisigs with
+/// vote records enabled. The transaction only keeps the tallies, so it doesn't grow with
+/// the number of voting members.
+#[account]
+pub struct VoteRecord {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub transaction_index: u32,         // the index of the transaction voted on
+    pub member: Pubkey,                 // the member that voted
+    pub approve: bool,                  // the choice of the member, approve or reject
+    pub weight: u16,                    // the weight the vote was tallied with
+    pub bump: u8,                       // bump for the seed
+}
+
+impl VoteRecord {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the transaction index
+        32 +                            // the member
+        1 +                             // the choice
+        2 +                             // the weight
+        1;                              // bump
+
+    /// initializes the vote record of the member
+    pub fn init(&mut self, multisig: Pubkey, transaction_index: u32, member: Pubkey, bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.transaction_index = transaction_index;
+        self.member = member;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// sets the vote recorded for the member
+    pub fn set_vote(&mut self, approve: bool, weight: u16) -> Result<()>{
+        self.approve = approve;
+        self.weight = weight;
+        Ok(())
+    }
+}
+
+/// A VoteMemo holds the memo a member recorded along with their vote on a transaction,
+/// ie. the reason of a rejection, or the hash of a longer memo kept elsewhere.
+#[account]
+pub struct VoteMemo {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub transaction_index: u32,         // the index of the transaction voted on
+    pub member: Pubkey,                 // the member that voted
+    pub vote: Vote,                     // the vote the memo is about
+    pub memo: Vec<u8>,                  // the memo (text or hash)
+    pub bump: u8,                       // bump for the seed
+}
+
+impl VoteMemo {
+    pub const MAX_MEMO_LEN: usize = 64;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the transaction index
+        32 +                            // the member
+        1 +                             // the vote
+        4 + Self::MAX_MEMO_LEN +        // the memo
+        1;                              // bump
+
+    /// initializes the memo of the vote of the member
+    pub fn init(&mut self, multisig: Pubkey, transaction_index: u32, member: Pubkey, vote: Vote, memo: Vec<u8>, bump: u8) -> Result<()>{
+        if memo.is_empty() || memo.len() > Self::MAX_MEMO_LEN {
+            return err!(MsError::InvalidVoteMemo);
+        }
+        self.multisig = multisig;
+        self.transaction_index = transaction_index;
+        self.member = member;
+        self.vote = vote;
+        self.memo = memo;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// An Allowance lets a key that doesn't have to be a member pull funds out of a vault
+/// (authority), up to a total `cap` and until the optional expiration.
+#[account]
+pub struct Allowance {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub authority_index: u32,           // the authority (vault) the funds are pulled from
+    pub mint: Pubkey,                   // the mint being spent, the default pubkey for native SOL
+    pub spender: Pubkey,                // the key allowed to spend the allowance
+    pub cap: u64,                       // the total amount that can be spent
+    pub spent: u64,                     // the amount spent so far
+    pub expires_at: Option<i64>,        // optional unix timestamp after which the allowance can't be spent
+    pub bump: u8,                       // bump for the seed
+}
+
+impl Allowance {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        32 +                            // the mint
+        32 +                            // the spender
+        8 +                             // the cap
+        8 +                             // the amount spent
+        (1 + 8) +                       // the optional expiration timestamp
+        1;                              // bump
+
+    /// initializes the allowance
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, mint: Pubkey, spender: Pubkey, cap: u64, expires_at: Option<i64>, bump: u8) -> Result<()>{
+        if authority_index < 1 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        if matches!(expires_at, Some(expires_at) if expires_at <= Clock::get()?.unix_timestamp) {
+            return err!(MsError::InvalidExpiration);
+        }
+        self.multisig = multisig;
+        self.authority_index = authority_index;
+        self.mint = mint;
+        self.spender = spender;
+        self.cap = cap;
+        self.spent = 0;
+        self.expires_at = expires_at;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// records an amount spent, which must stay within the cap before the expiration
+    pub fn spend(&mut self, amount: u64) -> Result<()>{
+        if matches!(self.expires_at, Some(expires_at) if Clock::get()?.unix_timestamp >= expires_at) {
+            return err!(MsError::AllowanceExpired);
+        }
+        let spent = self.spent.checked_add(amount).ok_or(MsError::AllowanceExceeded)?;
+        if spent > self.cap {
+            return err!(MsError::AllowanceExceeded);
+        }
+        self.spent = spent;
+        Ok(())
+    }
+}
+
+/// MsMetadata is an optional account labeling a multisig for wallets and explorers.
+/// It can only be created and updated through the multisig itself.
+#[account]
+pub struct MsMetadata {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub name: String,                   // display name of the multisig
+    pub description: String,            // short description of the multisig
+    pub uri_hash: [u8; 32],             // hash of the image/URI for the multisig
+    pub bump: u8,                       // bump for the seed
+}
+
+impl MsMetadata {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_DESCRIPTION_LEN: usize = 128;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 + Self::MAX_NAME_LEN +        // the name
+        4 + Self::MAX_DESCRIPTION_LEN + // the description
+        32 +                            // the image/URI hash
+        1;                              // bump
+
+    /// initializes the metadata of the multisig
+    pub fn init(&mut self, multisig: Pubkey, name: String, description: String, uri_hash: [u8; 32], bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.bump = bump;
+        self.update(name, description, uri_hash)
+    }
+
+    /// updates the metadata, the name and description are bounded so the account never grows
+    pub fn update(&mut self, name: String, description: String, uri_hash: [u8; 32]) -> Result<()>{
+        if name.len() > Self::MAX_NAME_LEN || description.len() > Self::MAX_DESCRIPTION_LEN {
+            return err!(MsError::InvalidMetadata);
+        }
+        self.name = name;
+        self.description = description;
+        self.uri_hash = uri_hash;
+        Ok(())
+    }
+}
+
+/// AuthorityRecord is an optional account labeling an authority (vault) of the multisig,
+/// so clients know what each authority index is used for. It can only be created and
+/// updated through the multisig itself.
+#[account]
+pub struct AuthorityRecord {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub authority_index: u32,           // the authority this labels
+    pub label: String,                  // display name of the authority, ie. "ops vault"
+    pub purpose: String,                // what the authority is used for, ie. "upgrade authority for program X"
+    pub bump: u8,                       // bump for the seed
+}
+
+impl AuthorityRecord {
+    pub const MAX_LABEL_LEN: usize = 32;
+    pub const MAX_PURPOSE_LEN: usize = 128;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        4 + Self::MAX_LABEL_LEN +       // the label
+        4 + Self::MAX_PURPOSE_LEN +     // the purpose
+        1;                              // bump
+
+    /// initializes the record of the authority
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, label: String, purpose: String, bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.authority_index = authority_index;
+        self.bump = bump;
+        self.update(label, purpose)
+    }
+
+    /// updates the record, the label and purpose are bounded so the account never grows
+    pub fn update(&mut self, label: String, purpose: String) -> Result<()>{
+        if label.len() > Self::MAX_LABEL_LEN || purpose.len() > Self::MAX_PURPOSE_LEN {
+            return err!(MsError::InvalidAuthorityRecord);
+        }
+        self.label = label;
+        self.purpose = purpose;
+        Ok(())
+    }
+}
+
+/// The stage of a vault transaction a policy program is consulted for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyStage {
+    Activate,
+    Approve,
+    Execute,
+}
+
+impl PolicyStage {
+    pub const ALL: u8 = (1 << PolicyStage::Activate as u8) | (1 << PolicyStage::Approve as u8) | (1 << PolicyStage::Execute as u8);
+
+    /// the bit of the stage in the stages mask of a policy config
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A PolicyConfig registers an external policy program for a multisig, ie. for spending rules,
+/// KYC gating or risk scoring. The policy program is invoked with a `check_policy(stage, actor)`
+/// instruction (Anchor style discriminator) and the policy config, multisig and transaction
+/// accounts (read only) at the stages in its mask, and rejects the action by failing.
+/// Internal (authority 0) transactions aren't checked, so the multisig can always remove it.
+#[account]
+pub struct PolicyConfig {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub policy_program: Pubkey,         // the program consulted
+    pub stages: u8,                     // mask of the stages the program is consulted for
+    pub bump: u8,                       // bump for the seed
+}
+
+impl PolicyConfig {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the policy program
+        1 +                             // the stages mask
+        1;                              // bump
+
+    /// initializes the policy config, the policy can't be this program or consult nothing
+    pub fn init(&mut self, multisig: Pubkey, policy_program: Pubkey, stages: u8, bump: u8) -> Result<()>{
+        if policy_program == crate::ID || stages == 0 || stages & !PolicyStage::ALL != 0 {
+            return err!(MsError::InvalidPolicy);
+        }
+        self.multisig = multisig;
+        self.policy_program = policy_program;
+        self.stages = stages;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// checks if the policy program is consulted for the stage
+    pub fn checks(&self, stage: PolicyStage) -> bool {
+        self.stages & stage.mask() != 0
+    }
+}
+
+/// The transaction lifecycle actions recorded in the activity log
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityAction {
+    Create,
+    Activate,
+    Approve,
+    Reject,
+    Cancel,
+    Execute,
+}
+
+/// An entry of the activity log, the actor is the member (or executor/cranker) taking the action
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ActivityEntry {
+    pub actor: Pubkey,
+    pub action: ActivityAction,
+    pub transaction_index: u32,
+    pub slot: u64,
+}
+
+impl ActivityEntry {
+    pub const SIZE: usize = 32 +    // the actor
+        1 +                         // the action
+        4 +                         // the transaction index
+        8;                          // the slot
+}
+
+/// The MsActivityLog is an optional ring buffer of the most recent transaction lifecycle actions
+/// of a multisig, so explorers and auditors can show the recent history without replaying it.
+/// Once full, the entry at `head` is the oldest and is overwritten next.
+#[account]
+pub struct MsActivityLog {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub bump: u8,                       // bump for the seed
+    pub head: u16,                      // the entry written next once the log is full
+    pub entries: Vec<ActivityEntry>,    // the most recent entries, up to the capacity
+}
+
+impl MsActivityLog {
+    pub const CAPACITY: usize = 32;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        1 +                             // bump
+        2 +                             // the head
+        4 + Self::CAPACITY * ActivityEntry::SIZE; // the entries
+
+    /// initializes the empty activity log
+    pub fn init(&mut self, multisig: Pubkey, bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.bump = bump;
+        self.head = 0;
+        self.entries = Vec::new();
+        Ok(())
+    }
+
+    /// records an entry, overwriting the oldest one once the log is full
+    pub fn record(&mut self, entry: ActivityEntry) {
+        if self.entries.len() < Self::CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[usize::from(self.head)] = entry;
+            self.head = ((usize::from(self.head) + 1) % Self::CAPACITY) as u16;
+        }
+    }
+}
+
+/// An MsMembers page holds additional members of a multisig, for rosters that don't fit in the
+/// keys of the multisig account. Paged members can only vote, with vote records and a weight of 1,
+/// they can't initiate or execute transactions. A key shared with the multisig keys still votes
+/// once per transaction, as the vote records are seeded by the key.
+#[account]
+pub struct MsMembers {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub page_index: u8,                 // the index of the page, from 0
+    pub bump: u8,                       // bump for the seed
+    pub keys: Vec<Pubkey>,              // the members of the page, sorted
+}
+
+impl MsMembers {
+    /// the most members of a page, which is allocated at its full size when created
+    pub const CAPACITY: usize = (MAX_PERMITTED_DATA_INCREASE - (8 + 32 + 1 + 1 + 4)) / 32;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        1 +                             // the page index
+        1 +                             // bump
+        4 + Self::CAPACITY * 32;        // the members
+
+    /// initializes the empty page
+    pub fn init(&mut self, multisig: Pubkey, page_index: u8, bump: u8) -> Result<()>{
+        self.multisig = multisig;
+        self.page_index = page_index;
+        self.bump = bump;
+        self.keys = Vec::new();
+        Ok(())
+    }
+
+    /// checks if the key is a member of the page
+    pub fn is_member(&self, member: Pubkey) -> bool {
+        self.keys.binary_search(&member).is_ok()
+    }
+
+    /// adds a member to the page, keeping the keys sorted
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()>{
+        let ind = self.keys.binary_search(&member).err().ok_or(MsError::DuplicateMember)?;
+        if self.keys.len() >= Self::CAPACITY {
+            return err!(MsError::MemberPageFull);
+        }
+        self.keys.insert(ind, member);
+        Ok(())
+    }
+
+    /// removes a member from the page
+    pub fn remove_member(&mut self, member: Pubkey) -> Result<()>{
+        let ind = self.keys.binary_search(&member).map_err(|_| MsError::KeyNotInMultisig)?;
+        self.keys.remove(ind);
+        Ok(())
+    }
+}
+
+/// A ManagedProgram lists a program whose upgrade authority is an authority (vault) of the
+/// multisig, so clients can show the programs of a squad along with their upgrade history.
+/// It can only be created and removed through the multisig itself.
+#[account]
+pub struct ManagedProgram {
+    pub multisig: Pubkey,               // the multisig this belongs to
+    pub program_address: Pubkey,        // the managed program
+    pub authority_index: u32,           // the authority (vault) that is the upgrade authority of the program
+    pub upgrade_index: u32,             // the number of upgrades proposed, seed reference for the upgrades
+    pub last_upgrade: i64,              // unix timestamp the program was last upgraded at, 0 if never
+    pub last_upgrade_index: u32,        // the index of the last executed upgrade, 0 if none
+    pub bump: u8,                       // bump for the seed
+    pub rollback_threshold: u16,        // approvals of members needed to roll back the last upgrade, 0 disables rollbacks
+    pub rollback_buffer: Option<Pubkey>,    // buffer holding the program data from before the last upgrade, if retained
+    pub rollback_hash: [u8; 32],        // sha256 hash of the program data from before the last upgrade
+    pub name: String,                   // display name of the program
+}
+
+impl ManagedProgram {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the program address
+        4 +                             // the authority index
+        4 +                             // the upgrade index
+        8 +                             // the last upgrade timestamp
+        4 +                             // the last upgrade index
+        1 +                             // bump
+        2 +                             // the rollback threshold
+        (1 + 32) +                      // the rollback buffer
+        32 +                            // the rollback hash
+        4 + Self::MAX_NAME_LEN;         // the name
+
+    /// initializes the managed program
+    pub fn init(&mut self, multisig: Pubkey, program_address: Pubkey, authority_index: u32, name: String, bump: u8) -> Result<()>{
+        if name.len() > Self::MAX_NAME_LEN {
+            return err!(MsError::InvalidManagedProgram);
+        }
+        self.multisig = multisig;
+        self.program_address = program_address;
+        self.authority_index = authority_index;
+        self.upgrade_index = 0;
+        self.last_upgrade = 0;
+        self.last_upgrade_index = 0;
+        self.bump = bump;
+        self.rollback_threshold = 0;
+        self.rollback_buffer = None;
+        self.rollback_hash = [0; 32];
+        self.name = name;
+        Ok(())
+    }
+
+    /// sets the number of member approvals needed to roll back the last upgrade, which is
+    /// meant to be lower than the threshold so bad deploys can be undone quickly. 0 disables rollbacks.
+    pub fn set_rollback_threshold(&mut self, multisig: &Ms, rollback_threshold: u16) -> Result<()>{
+        if usize::from(rollback_threshold) > multisig.keys.len() {
+            return err!(MsError::InvalidThreshold);
+        }
+        self.rollback_threshold = rollback_threshold;
+        Ok(())
+    }
+}
+
+/// A ProgramRollback is a proposal of the members to restore a managed program from the buffer
+/// retained by its last upgrade. Once approved by the rollback threshold of the managed program,
+/// anyone can execute it, without the time lock of vault transactions.
+#[account]
+pub struct ProgramRollback {
+    pub managed_program: Pubkey,        // the managed program being rolled back
+    pub buffer: Pubkey,                 // the rollback buffer the program is restored from
+    pub proposer: Pubkey,               // the member that proposed the rollback and paid the rent
+    pub approvals: Vec<Pubkey>,         // the members that approved, sorted
+    pub bump: u8,                       // bump for the seed
+}
+
+impl ProgramRollback {
+    pub fn size(members_len: usize) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the managed program key
+        32 +                            // the buffer key
+        32 +                            // the proposer
+        4 + (32 * members_len) +        // the approvals
+        1                               // bump
+    }
+
+    /// initializes the rollback of the managed program
+    pub fn init(&mut self, managed_program: Pubkey, buffer: Pubkey, proposer: Pubkey, bump: u8) -> Result<()>{
+        self.managed_program = managed_program;
+        self.buffer = buffer;
+        self.proposer = proposer;
+        self.approvals = Vec::new();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// records the approval of a member
+    pub fn approve(&mut self, member: Pubkey) -> Result<()>{
+        match self.approvals.binary_search(&member) {
+            Ok(_) => err!(MsError::RollbackAlreadyApproved),
+            Err(ind) => {
+                self.approvals.insert(ind, member);
+                Ok(())
+            }
+        }
+    }
+
+    /// errors if the rollback isn't approved by enough (current) members, or the buffer
+    /// isn't the one retained by the last upgrade anymore
+    pub fn check_ready(&self, multisig: &Ms, managed_program: &ManagedProgram) -> Result<()>{
+        if managed_program.rollback_buffer != Some(self.buffer) {
+            return err!(MsError::InvalidRollbackBuffer);
+        }
+        let approval_count = self.approvals.iter().filter(|k| multisig.is_member(**k).is_some()).count();
+        if managed_program.rollback_threshold == 0 || approval_count < usize::from(managed_program.rollback_threshold) {
+            return err!(MsError::RollbackNotApproved);
+        }
+        Ok(())
+    }
+}
+
+/// A ProgramUpgrade tracks a proposed upgrade of a managed program, ie. the vault transaction
+/// carrying the upgrade_program instruction and the buffer it upgrades from. It is marked as
+/// upgraded when upgrade_program executes with the managed program and the upgrade passed as
+/// remaining accounts.
+#[account]
+pub struct ProgramUpgrade {
+    pub managed_program: Pubkey,        // the managed program being upgraded
+    pub upgrade_index: u32,             // the index of the upgrade for the managed program, from 1
+    pub transaction: Pubkey,            // the vault transaction carrying the upgrade
+    pub buffer: Pubkey,                 // the buffer the program is upgraded from
+    pub expected_hash: [u8; 32],        // sha256 hash of the program in the buffer
+    pub created_at: i64,                // unix timestamp the upgrade was proposed at
+    pub upgraded_at: i64,               // unix timestamp the upgrade was executed at, 0 until then
+    pub bump: u8,                       // bump for the seed
+}
+
+impl ProgramUpgrade {
+    pub const SIZE: usize = 8 +          // Anchor discriminator
+        32 +                            // the managed program key
+        4 +                             // the upgrade index
+        32 +                            // the transaction key
+        32 +                            // the buffer key
+        32 +                            // the expected hash
+        8 +                             // the creation timestamp
+        8 +                             // the upgrade timestamp
+        1;                              // bump
+
+    /// initializes the proposed upgrade
+    pub fn init(&mut self, managed_program: Pubkey, upgrade_index: u32, transaction: Pubkey, buffer: Pubkey, expected_hash: [u8; 32], bump: u8) -> Result<()>{
+        self.managed_program = managed_program;
+        self.upgrade_index = upgrade_index;
+        self.transaction = transaction;
+        self.buffer = buffer;
+        self.expected_hash = expected_hash;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.upgraded_at = 0;
+        self.bump = bump;
+        Ok(())
+    }
+}
@@ -0,0 +1,307 @@
+/*
+    Squads Multisig Program - Config Validation
+    https://github.com/squads-protocol/squads-mpl
+*/
+
+use anchor_lang::prelude::*;
+use crate::errors::MsError;
+use crate::state::{Ms, MsConfigAction, Permissions};
+
+/// Checks the invariants that every change of the members or thresholds must leave the
+/// multisig in. Config changes never adjust the thresholds to pass these checks, a change
+/// that would break them fails instead.
+pub fn validate_config(ms: &Ms) -> Result<()> {
+    // there must be at least one member
+    if ms.keys.is_empty() {
+        return err!(MsError::EmptyMembers);
+    }
+    // the keys must be sorted for the binary searches of is_member, and unique so that
+    // no member counts twice toward the thresholds
+    for pair in ms.keys.windows(2) {
+        if pair[0] == pair[1] {
+            return err!(MsError::DuplicateMember);
+        }
+        if pair[0] > pair[1] {
+            return err!(MsError::UnsortedMembers);
+        }
+    }
+    // and there can't be more members than the multisig's own cap, or the global ceiling
+    if ms.keys.len() > ms.max_member_count() {
+        return err!(MsError::MaxMembersReached);
+    }
+    // the threshold must be at least 1, a config threshold of 0 falls back to it
+    if ms.threshold < 1 {
+        return err!(MsError::ThresholdTooLow);
+    }
+    // the members that can vote must be able to reach both thresholds
+    let max_threshold = usize::from(ms.threshold.max(ms.config_threshold));
+    if ms.total_weight() < max_threshold || ms.voting_weight() < max_threshold {
+        return err!(MsError::ThresholdTooHigh);
+    }
+    // the members that can vote must be able to reach the quorum
+    if usize::from(ms.min_quorum) > ms.voter_count() {
+        return err!(MsError::InvalidQuorum);
+    }
+    // at least one member must be able to execute
+    if !ms.keys.iter().any(|k| ms.has_permission(*k, Permissions::EXECUTE)) {
+        return err!(MsError::InvalidPermissions);
+    }
+    // and the veto council, which can't approve, must be apart from the members
+    if ms.veto_council.iter().any(|k| ms.is_member(*k).is_some()) {
+        return err!(MsError::InvalidVetoCouncil);
+    }
+    Ok(())
+}
+
+/// Checks that the member can be removed without breaking the invariants, from the weight the
+/// removal takes off. The remaining members must still be able to reach the thresholds and the
+/// quorum, which have to be lowered before (or along with) the removal.
+pub fn validate_removal(ms: &Ms, member: Pubkey) -> Result<()> {
+    if ms.is_member(member).is_none() {
+        return err!(MsError::KeyNotInMultisig);
+    }
+    if ms.keys.len() == 1 {
+        return err!(MsError::CannotRemoveSoloMember);
+    }
+    let is_voter = ms.has_permission(member, Permissions::VOTE);
+    if usize::from(ms.min_quorum) > ms.voter_count() - usize::from(is_voter) {
+        return err!(MsError::InvalidQuorum);
+    }
+    let weight = usize::from(ms.get_member_weight(member));
+    let voting_weight = if is_voter { weight } else { 0 };
+    validate_remaining_weight(ms, ms.total_weight() - weight, ms.voting_weight() - voting_weight)?;
+    // at least one of the remaining members must be able to execute
+    if !ms.keys.iter().any(|k| *k != member && ms.has_permission(*k, Permissions::EXECUTE)) {
+        return err!(MsError::InvalidPermissions);
+    }
+    Ok(())
+}
+
+/// Checks that a member can be removed from a page of members without breaking the invariants,
+/// like validate_removal. Paged members vote with a weight of 1.
+pub fn validate_paged_removal(ms: &Ms) -> Result<()> {
+    if ms.paged_member_count == 0 {
+        return err!(MsError::KeyNotInMultisig);
+    }
+    if usize::from(ms.min_quorum) >= ms.voter_count() {
+        return err!(MsError::InvalidQuorum);
+    }
+    validate_remaining_weight(ms, ms.total_weight() - 1, ms.voting_weight() - 1)
+}
+
+/// Checks that the weight left once a member is removed can still reach the thresholds, a
+/// percentage threshold being recomputed from it, telling such a removal apart from the other invariants
+fn validate_remaining_weight(ms: &Ms, total_weight: usize, voting_weight: usize) -> Result<()> {
+    let max_threshold = usize::from(ms.threshold_at_weight(total_weight).max(ms.config_threshold));
+    if total_weight < max_threshold || voting_weight < max_threshold {
+        return err!(MsError::RemovalBelowThreshold);
+    }
+    Ok(())
+}
+
+/// A member as the actions of a config transaction leave it
+#[derive(Clone, Copy)]
+struct PreviewMember {
+    weight: u16,                // the weight override, 1 by default
+    permissions: u8,            // the permission mask
+    expired: bool,              // expired members have no weight
+}
+
+impl PreviewMember {
+    /// the member as it is in the multisig
+    fn of(ms: &Ms, member: Pubkey) -> Self {
+        let weight = ms
+            .member_weights
+            .binary_search_by_key(&member, |w| w.member)
+            .map_or(1, |ind| ms.member_weights[ind].weight);
+        PreviewMember {
+            weight,
+            permissions: ms.get_member_permissions(member).unwrap_or(Permissions::ALL),
+            expired: ms.is_expired_member(member),
+        }
+    }
+
+    fn vote_weight(&self) -> usize {
+        if self.expired {
+            0
+        } else {
+            usize::from(self.weight)
+        }
+    }
+
+    fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+/// The members, weights and thresholds of a multisig as the actions of a config transaction
+/// change them. Only the members the actions change are tracked, the others are read from the multisig.
+struct ConfigPreview<'a> {
+    ms: &'a Ms,
+    members: Vec<(Pubkey, Option<PreviewMember>)>,  // the changed members, none once removed
+    member_count: usize,
+    total_weight: usize,
+    voting_weight: usize,
+    voter_count: usize,
+    threshold: u16,
+    threshold_percent: u8,
+    config_threshold: u16,
+}
+
+impl<'a> ConfigPreview<'a> {
+    fn new(ms: &'a Ms) -> Self {
+        ConfigPreview {
+            ms,
+            members: Vec::new(),
+            member_count: ms.keys.len(),
+            total_weight: ms.total_weight(),
+            voting_weight: ms.voting_weight(),
+            voter_count: ms.voter_count(),
+            threshold: ms.threshold,
+            threshold_percent: ms.threshold_percent,
+            config_threshold: ms.config_threshold,
+        }
+    }
+
+    fn member(&self, key: Pubkey) -> Option<PreviewMember> {
+        match self.members.iter().find(|(k, _)| *k == key) {
+            Some((_, member)) => *member,
+            None => self.ms.is_member(key).map(|_| PreviewMember::of(self.ms, key)),
+        }
+    }
+
+    /// replaces the member (none removes it), moving its weight and vote in the totals
+    fn set_member(&mut self, key: Pubkey, member: Option<PreviewMember>) {
+        if let Some(previous) = self.member(key) {
+            self.member_count -= 1;
+            self.total_weight -= previous.vote_weight();
+            if previous.has_permission(Permissions::VOTE) {
+                self.voting_weight -= previous.vote_weight();
+                self.voter_count -= 1;
+            }
+        }
+        if let Some(member) = member {
+            self.member_count += 1;
+            self.total_weight += member.vote_weight();
+            if member.has_permission(Permissions::VOTE) {
+                self.voting_weight += member.vote_weight();
+                self.voter_count += 1;
+            }
+        }
+        match self.members.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = member,
+            None => self.members.push((key, member)),
+        }
+    }
+
+    /// follows the action as Ms::apply_config_action applies it, with the same errors
+    fn apply(&mut self, action: &MsConfigAction) -> Result<()> {
+        match *action {
+            MsConfigAction::AddMember { member } => {
+                if self.member_count >= self.ms.max_member_count() {
+                    return err!(MsError::MaxMembersReached);
+                }
+                if self.member(member).is_some() {
+                    return err!(MsError::DuplicateMember);
+                }
+                self.set_member(member, Some(PreviewMember { weight: 1, permissions: Permissions::ALL, expired: false }));
+            }
+            MsConfigAction::RemoveMember { member } => {
+                if self.ms.removal_delay > 0 {
+                    return err!(MsError::RemovalDelayed);
+                }
+                if self.member(member).is_some() {
+                    self.set_member(member, None);
+                }
+            }
+            MsConfigAction::ReplaceMember { old_member, new_member } => {
+                let member = self.member(old_member).ok_or(MsError::KeyNotInMultisig)?;
+                if self.member(new_member).is_some() {
+                    return err!(MsError::DuplicateMember);
+                }
+                self.set_member(old_member, None);
+                self.set_member(new_member, Some(member));
+            }
+            MsConfigAction::ChangeThreshold { threshold } => {
+                self.threshold = threshold;
+                self.threshold_percent = 0;
+            }
+            MsConfigAction::ChangeConfigThreshold { config_threshold } => self.config_threshold = config_threshold,
+            MsConfigAction::SetMemberWeight { member, weight } => {
+                let mut changed = self.member(member).ok_or(MsError::KeyNotInMultisig)?;
+                if weight < 1 {
+                    return err!(MsError::InvalidMemberWeight);
+                }
+                changed.weight = weight;
+                self.set_member(member, Some(changed));
+            }
+            MsConfigAction::SetMemberPermissions { member, permissions } => {
+                let mut changed = self.member(member).ok_or(MsError::KeyNotInMultisig)?;
+                if permissions & !Permissions::ALL != 0 {
+                    return err!(MsError::InvalidPermissions);
+                }
+                changed.permissions = permissions;
+                self.set_member(member, Some(changed));
+            }
+            MsConfigAction::SetMemberExpiration { member, expires_at } => {
+                let mut changed = self.member(member).ok_or(MsError::KeyNotInMultisig)?;
+                if matches!(expires_at, Some(expires_at) if expires_at <= Clock::get()?.unix_timestamp) {
+                    return err!(MsError::InvalidExpiration);
+                }
+                // the expiration is in the future, or removed
+                changed.expired = false;
+                self.set_member(member, Some(changed));
+            }
+            MsConfigAction::ChangeTimeLock { .. }
+            | MsConfigAction::ChangeVetoPeriod { .. }
+            | MsConfigAction::ChangeConfigTimeLock { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// checks the invariants of validate_config that the actions can break
+    fn validate(&self) -> Result<()> {
+        if self.member_count == 0 {
+            return err!(MsError::EmptyMembers);
+        }
+        // a percentage threshold follows the total weight
+        let threshold = match self.threshold_percent {
+            0 => self.threshold,
+            _ => self.ms.threshold_at_weight(self.total_weight),
+        };
+        if threshold < 1 {
+            return err!(MsError::ThresholdTooLow);
+        }
+        let max_threshold = usize::from(threshold.max(self.config_threshold));
+        if self.total_weight < max_threshold || self.voting_weight < max_threshold {
+            return err!(MsError::ThresholdTooHigh);
+        }
+        if usize::from(self.ms.min_quorum) > self.voter_count {
+            return err!(MsError::InvalidQuorum);
+        }
+        let changed = |key: &Pubkey| self.members.iter().any(|(k, _)| k == key);
+        let can_execute = self
+            .members
+            .iter()
+            .any(|(_, member)| member.is_some_and(|m| m.has_permission(Permissions::EXECUTE)))
+            || self.ms.keys.iter().any(|k| !changed(k) && self.ms.has_permission(*k, Permissions::EXECUTE));
+        if !can_execute {
+            return err!(MsError::InvalidPermissions);
+        }
+        if self.members.iter().any(|(k, member)| member.is_some() && self.ms.is_veto_council_member(*k)) {
+            return err!(MsError::InvalidVetoCouncil);
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the actions of a config transaction can be applied to the multisig as it is now,
+/// following how they change the members, their weights and the thresholds rather than applying
+/// them to a copy of the multisig. The multisig is validated again once they're executed.
+pub fn validate_config_actions(ms: &Ms, actions: &[MsConfigAction]) -> Result<()> {
+    let mut preview = ConfigPreview::new(ms);
+    for action in actions {
+        preview.apply(action)?;
+    }
+    preview.validate()
+}
@@ -43,7 +43,7 @@ impl<'info> Track<'info> {
                 if transaction.creator == signer.key() && transaction.ms == multisig.key() {
                     return Ok(());
                 };
-                return err!(ErrorCode::InvalidTransaction);
+                err!(ErrorCode::InvalidTransaction)
             }
             None => err!(ErrorCode::Unauthorized),
         }
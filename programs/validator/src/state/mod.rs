@@ -1,2 +1,2 @@
-pub use validator::*;
+pub use self::validator::*;
 pub mod validator;
\ No newline at end of file
@@ -96,3 +96,31 @@ export const getProgramUpgradePDA = (
     ],
     programId
   );
+
+export const getActivityLogPDA = (msPDA: PublicKey, programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      utils.bytes.utf8.encode("activitylog"),
+    ],
+    programId
+  );
+
+// the vote is the index of the Vote enum (approve 0, reject 1, cancel 2, abstain 3)
+export const getVoteMemoPDA = (
+  txPDA: PublicKey,
+  member: PublicKey,
+  vote: number,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      txPDA.toBuffer(),
+      member.toBuffer(),
+      Buffer.from([vote]),
+      utils.bytes.utf8.encode("votememo"),
+    ],
+    programId
+  );
@@ -6,3 +6,5 @@ export const DEFAULT_MULTISIG_PROGRAM_ID = new PublicKey(
 export const DEFAULT_PROGRAM_MANAGER_PROGRAM_ID = new PublicKey(
   "SMPLKTQhrgo22hFCVq2VGX1KAktTWjeizkhrdB1eauK"
 );
+// offset of the tag (category and reference) in the transaction account data, for memcmp filters
+export const TRANSACTION_TAG_OFFSET = 85;
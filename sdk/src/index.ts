@@ -9,6 +9,7 @@ import {
 import {
   DEFAULT_MULTISIG_PROGRAM_ID,
   DEFAULT_PROGRAM_MANAGER_PROGRAM_ID,
+  TRANSACTION_TAG_OFFSET,
 } from "./constants";
 import squadsMplJSON from "../../target/idl/squads_mpl.json";
 import {SquadsMpl} from "../../idl/squads_mpl";
@@ -26,6 +27,7 @@ import {
   TransactionAccount,
 } from "./types";
 import {
+  getActivityLogPDA,
   getAuthorityPDA,
   getIxPDA,
   getManagedProgramPDA,
@@ -33,6 +35,7 @@ import {
   getProgramManagerPDA,
   getProgramUpgradePDA,
   getTxPDA,
+  getVoteMemoPDA,
 } from "./address";
 import BN from "bn.js";
 import * as anchor from "@coral-xyz/anchor";
@@ -158,6 +161,18 @@ class Squads {
     );
   }
 
+  // the activity log must be passed to the instructions that record activity, once created
+  private async _activityLogAccounts(
+      multisigPDA: PublicKey
+  ): Promise<anchor.web3.AccountMeta[]> {
+    const multisig = await this.getMultisig(multisigPDA);
+    if (!multisig.activityLogEnabled) {
+      return [];
+    }
+    const [activityLogPDA] = getActivityLogPDA(multisigPDA, this.multisigProgramId);
+    return [{pubkey: activityLogPDA, isSigner: false, isWritable: true}];
+  }
+
   async getTransactionBuilder(
       multisigPDA: PublicKey,
       authorityIndex: number
@@ -210,6 +225,18 @@ class Squads {
     ) as (TransactionAccount | null)[];
   }
 
+  // the transactions of the multisig tagged with the (4 byte) category
+  async getTransactionsByTag(
+      multisigPDA: PublicKey,
+      category: Buffer
+  ): Promise<TransactionAccount[]> {
+    const accounts = await this.multisig.account.msTransaction.all([
+      {memcmp: {offset: 8 + 32, bytes: multisigPDA.toBase58()}},
+      {memcmp: {offset: TRANSACTION_TAG_OFFSET, bytes: anchor.utils.bytes.bs58.encode(category)}},
+    ]);
+    return accounts.map(({publicKey, account}) => ({...account, publicKey}));
+  }
+
   async getInstruction(address: PublicKey): Promise<InstructionAccount> {
     const accountData = await this.multisig.account.msInstruction.fetch(
         address,
@@ -386,14 +413,16 @@ class Squads {
         multisig: multisigPDA,
         transaction: transactionPDA,
         creator: this.wallet.publicKey,
-      }),
+      }).remainingAccounts(await this._activityLogAccounts(multisigPDA)),
       transactionPDA,
     ];
   }
 
+  // the tag (if any) is set in the same transaction as the creation
   async createTransaction(
       multisigPDA: PublicKey,
-      authorityIndex: number
+      authorityIndex: number,
+      tag?: {category: number[], reference: number}
   ): Promise<TransactionAccount> {
     const nextTransactionIndex = await this.getNextTransactionIndex(
         multisigPDA
@@ -403,7 +432,16 @@ class Squads {
         authorityIndex,
         nextTransactionIndex
     );
-    await methods.rpc();
+    if (tag) {
+      const setTagIx = await this.multisig.methods.setTransactionTag(tag).accounts({
+        multisig: multisigPDA,
+        transaction: transactionPDA,
+        creator: this.wallet.publicKey,
+      }).instruction();
+      await methods.postInstructions([setTagIx]).rpc();
+    } else {
+      await methods.rpc();
+    }
     return await this.getTransaction(transactionPDA);
   }
 
@@ -420,6 +458,36 @@ class Squads {
     return await methods.instruction();
   }
 
+  // creates the transaction, attaches the instructions and activates it in a single instruction
+  async createAndActivateTransaction(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      instructions: TransactionInstruction[]
+  ): Promise<TransactionAccount> {
+    const nextTransactionIndex = await this.getNextTransactionIndex(
+        multisigPDA
+    );
+    const [transactionPDA] = getTxPDA(
+        multisigPDA,
+        new BN(nextTransactionIndex, 10),
+        this.multisigProgramId
+    );
+    const instructionAccounts = instructions.map((_, i) => ({
+      pubkey: getIxPDA(transactionPDA, new BN(i + 1, 10), this.multisigProgramId)[0],
+      isSigner: false,
+      isWritable: true,
+    }));
+    await this.multisig.methods.createTransactionV2(authorityIndex, instructions).accounts({
+      multisig: multisigPDA,
+      transaction: transactionPDA,
+      creator: this.wallet.publicKey,
+    }).remainingAccounts([
+      ...instructionAccounts,
+      ...(await this._activityLogAccounts(multisigPDA)),
+    ]).rpc();
+    return await this.getTransaction(transactionPDA);
+  }
+
   private async _addInstruction(
       multisigPDA: PublicKey,
       transactionPDA: PublicKey,
@@ -480,7 +548,7 @@ class Squads {
       multisig: multisigPDA,
       transaction: transactionPDA,
       creator: this.wallet.publicKey,
-    });
+    }).remainingAccounts(await this._activityLogAccounts(multisigPDA));
   }
 
   async activateTransaction(
@@ -514,17 +582,23 @@ class Squads {
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(await this._activityLogAccounts(multisigPDA));
   }
 
   async approveTransaction(
-      transactionPDA: PublicKey
+      transactionPDA: PublicKey,
+      memo?: Buffer
   ): Promise<TransactionAccount> {
     const transaction = await this.getTransaction(transactionPDA);
     const methods = await this._approveTransaction(
         transaction.ms,
         transactionPDA
     );
+    if (memo) {
+      methods.postInstructions([
+        await this._buildAddVoteMemo(transaction.ms, transactionPDA, {approve: {}}, 0, memo),
+      ]);
+    }
     await methods.rpc();
     return await this.getTransaction(transactionPDA);
   }
@@ -537,6 +611,28 @@ class Squads {
     return await methods.instruction();
   }
 
+  // records the memo along with the vote, the vote index selects the memo account
+  private async _buildAddVoteMemo(
+      multisigPDA: PublicKey,
+      transactionPDA: PublicKey,
+      vote: any,
+      voteIndex: number,
+      memo: Buffer
+  ): Promise<TransactionInstruction> {
+    const [voteMemoPDA] = getVoteMemoPDA(
+        transactionPDA,
+        this.wallet.publicKey,
+        voteIndex,
+        this.multisigProgramId
+    );
+    return await this.multisig.methods.addVoteMemo(vote, memo).accounts({
+      multisig: multisigPDA,
+      transaction: transactionPDA,
+      voteMemo: voteMemoPDA,
+      member: this.wallet.publicKey,
+    }).instruction();
+  }
+
   private async _rejectTransaction(
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
@@ -545,17 +641,23 @@ class Squads {
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(await this._activityLogAccounts(multisigPDA));
   }
 
   async rejectTransaction(
-      transactionPDA: PublicKey
+      transactionPDA: PublicKey,
+      memo?: Buffer
   ): Promise<TransactionAccount> {
     const transaction = await this.getTransaction(transactionPDA);
     const methods = await this._rejectTransaction(
         transaction.ms,
         transactionPDA
     );
+    if (memo) {
+      methods.postInstructions([
+        await this._buildAddVoteMemo(transaction.ms, transactionPDA, {reject: {}}, 1, memo),
+      ]);
+    }
     await methods.rpc();
     return await this.getTransaction(transactionPDA);
   }
@@ -576,17 +678,23 @@ class Squads {
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(await this._activityLogAccounts(multisigPDA));
   }
 
   async cancelTransaction(
-      transactionPDA: PublicKey
+      transactionPDA: PublicKey,
+      memo?: Buffer
   ): Promise<TransactionAccount> {
     const transaction = await this.getTransaction(transactionPDA);
     const methods = await this._cancelTransaction(
         transaction.ms,
         transactionPDA
     );
+    if (memo) {
+      methods.postInstructions([
+        await this._buildAddVoteMemo(transaction.ms, transactionPDA, {cancel: {}}, 2, memo),
+      ]);
+    }
     await methods.rpc();
     return await this.getTransaction(transactionPDA);
   }
@@ -601,7 +709,8 @@ class Squads {
 
   private async _executeTransaction(
       transactionPDA: PublicKey,
-      feePayer: PublicKey
+      member: PublicKey,
+      approve = false
   ): Promise<TransactionInstruction> {
     const transaction = await this.getTransaction(transactionPDA);
     const ixList = await Promise.all(
@@ -667,25 +776,30 @@ class Squads {
       );
     });
 
-    const executeIx = await this.multisig.methods
-        .executeTransaction(Buffer.from(keyIndexMap))
+    const accountList = Buffer.from(keyIndexMap);
+    const methods = approve
+        ? this.multisig.methods.approveAndExecute(accountList)
+        : this.multisig.methods.executeTransaction(accountList);
+    const executeIx = await methods
         .accounts({
           multisig: transaction.ms,
           transaction: transactionPDA,
-          member: feePayer,
+          member,
         })
         .instruction();
-    executeIx.keys = executeIx.keys.concat(keysUnique);
+    executeIx.keys = executeIx.keys.concat(keysUnique, await this._activityLogAccounts(transaction.ms));
     return executeIx;
   }
 
+  // the member executing defaults to the fee payer, a separate member must sign as well
   async executeTransaction(
       transactionPDA: PublicKey,
       feePayer?: PublicKey,
-      signers?: Signer[]
+      signers?: Signer[],
+      member?: PublicKey
   ): Promise<TransactionAccount> {
     const payer = feePayer ?? this.wallet.publicKey;
-    const executeIx = await this._executeTransaction(transactionPDA, payer);
+    const executeIx = await this._executeTransaction(transactionPDA, member ?? payer);
 
     const {blockhash} = await this.connection.getLatestBlockhash();
     const lastValidBlockHeight = await this.connection.getBlockHeight();
@@ -701,10 +815,28 @@ class Squads {
 
   async buildExecuteTransaction(
       transactionPDA: PublicKey,
-      feePayer?: PublicKey
+      feePayer?: PublicKey,
+      member?: PublicKey
   ): Promise<TransactionInstruction> {
     const payer = feePayer ?? this.wallet.publicKey;
-    return await this._executeTransaction(transactionPDA, payer);
+    return await this._executeTransaction(transactionPDA, member ?? payer);
+  }
+
+  // casts the final approval and executes the transaction in the same instruction
+  async approveAndExecuteTransaction(
+      transactionPDA: PublicKey
+  ): Promise<TransactionAccount> {
+    const executeIx = await this._executeTransaction(transactionPDA, this.wallet.publicKey, true);
+    const {blockhash} = await this.connection.getLatestBlockhash();
+    const lastValidBlockHeight = await this.connection.getBlockHeight();
+    const executeTx = new anchor.web3.Transaction({
+      blockhash,
+      lastValidBlockHeight,
+      feePayer: this.wallet.publicKey,
+    });
+    executeTx.add(executeIx);
+    await this.provider.sendAndConfirm(executeTx);
+    return await this.getTransaction(transactionPDA);
   }
 
   private async _executeInstruction(
@@ -837,7 +969,7 @@ class Squads {
     const currNumKeys = ms.keys.length;
     const SIZE_WITHOUT_MEMBERS = 8 + // Anchor disriminator
         2 +         // threshold value
-        2 +         // authority index
+        4 +         // authority index
         4 +         // transaction index
         4 +         // processed internal transaction index
         1 +         // PDA bump
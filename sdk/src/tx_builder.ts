@@ -4,7 +4,7 @@ import {
   SquadsMethodsNamespace,
 } from "./types";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getAuthorityPDA, getIxPDA, getTxPDA } from "./address";
+import { getActivityLogPDA, getAuthorityPDA, getIxPDA, getTxPDA } from "./address";
 import BN from "bn.js";
 import { AnchorProvider } from "@coral-xyz/anchor";
 import * as anchor from "@coral-xyz/anchor";
@@ -187,6 +187,15 @@ export class TransactionBuilder {
         transaction: transactionPDA,
         creator: this.provider.wallet.publicKey,
       })
+      .remainingAccounts(
+        this.multisig.activityLogEnabled
+          ? [{
+            pubkey: getActivityLogPDA(this.multisig.publicKey, this.programId)[0],
+            isSigner: false,
+            isWritable: true,
+          }]
+          : []
+      )
       .instruction();
     const instructions = [createTxInstruction, ...wrappedAddInstructions];
     this.instructions = [];
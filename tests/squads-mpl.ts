@@ -11,19 +11,25 @@ import {
   createTestTransferTransaction,
 } from "../helpers/transactions";
 import { execSync } from "child_process";
-import { LAMPORTS_PER_SOL, ParsedAccountData, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { LAMPORTS_PER_SOL, ParsedAccountData, StakeProgram, SystemProgram, VoteInit, VoteProgram } from "@solana/web3.js";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, ExtensionType, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, createInitializeMintInstruction, createInitializeNonTransferableMintInstruction, createMint, getAssociatedTokenAddressSync, getAccount, getMintLen, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import Squads, {
   getMsPDA,
   getIxPDA,
   getProgramManagerPDA,
   getAuthorityPDA,
   getTxPDA,
+  getActivityLogPDA,
+  getVoteMemoPDA,
+  TRANSACTION_TAG_OFFSET,
 } from "../sdk/src/index";
 import BN from "bn.js";
 import { getExecuteProxyInstruction, getUserRolePDA, getUserDelegatePDA, getRolesManager } from "../helpers/roles";
 import { agnosticExecute } from "../helpers/sdkExecute";
 
 import {memberListApprove} from "../helpers/approve";
+import {bitmapMembers} from "../helpers/votes";
 
 const BPF_UPGRADE_ID = new anchor.web3.PublicKey(
   "BPFLoaderUpgradeab1e11111111111111111111111"
@@ -83,6 +89,85 @@ const getIxAuthority = async (txPda: anchor.web3.PublicKey, index: anchor.BN, pr
   );
 };
 
+// executes the instructions through an internal transaction of the multisig, approved by the
+// wallet of the client unless another approval is passed, ie. with a vote record
+const executeConfig = async (
+  squads: Squads,
+  multisig: anchor.web3.PublicKey,
+  instructions: anchor.web3.TransactionInstruction[],
+  approve = (txPDA: anchor.web3.PublicKey): Promise<unknown> => squads.approveTransaction(txPDA),
+) => {
+  const txBuilder = await squads.getTransactionBuilder(multisig, 0);
+  const [, txPDA] = await txBuilder
+    .withInstructions(instructions)
+    .executeInstructions();
+  await squads.activateTransaction(txPDA);
+  await approve(txPDA);
+  return squads.executeTransaction(txPDA);
+};
+
+// the message a member signs offline to vote on a transaction, at its current vote sequence
+const signedVoteMessage = (
+  multisig: anchor.web3.PublicKey,
+  transactionIndex: number,
+  voteSeq: number,
+  approve: boolean,
+) => {
+  const txIndex = Buffer.alloc(4);
+  txIndex.writeUInt32LE(transactionIndex);
+  const seq = Buffer.alloc(4);
+  seq.writeUInt32LE(voteSeq);
+  return Buffer.concat([
+    Buffer.from(anchor.utils.bytes.utf8.encode("squads-mpl signed vote v2")),
+    multisig.toBuffer(),
+    txIndex,
+    seq,
+    Buffer.from([approve ? 1 : 0]),
+  ]);
+};
+
+// the hash an approval request commits to for an attached instruction
+const instructionHash = (instruction: {
+  programId: anchor.web3.PublicKey,
+  keys: { pubkey: anchor.web3.PublicKey, isSigner: boolean, isWritable: boolean }[],
+  data: Buffer | Uint8Array,
+}) => {
+  const keysLen = Buffer.alloc(4);
+  keysLen.writeUInt32LE(instruction.keys.length);
+  const dataLen = Buffer.alloc(4);
+  dataLen.writeUInt32LE(instruction.data.length);
+  return createHash("sha256").update(Buffer.concat([
+    instruction.programId.toBuffer(),
+    keysLen,
+    ...instruction.keys.map((k) => Buffer.concat([k.pubkey.toBuffer(), Buffer.from([k.isSigner ? 1 : 0, k.isWritable ? 1 : 0])])),
+    dataLen,
+    Buffer.from(instruction.data),
+  ])).digest();
+};
+
+// the approval request an offline signer displays and signs, at the current vote sequence
+const approvalRequestMessage = (
+  multisig: anchor.web3.PublicKey,
+  transactionIndex: number,
+  authorityIndex: number,
+  threshold: number,
+  voteSeq: number,
+  instructionHashes: Buffer[],
+) => {
+  const fields = Buffer.alloc(4 + 4 + 2 + 4 + 4);
+  fields.writeUInt32LE(transactionIndex, 0);
+  fields.writeUInt32LE(authorityIndex, 4);
+  fields.writeUInt16LE(threshold, 8);
+  fields.writeUInt32LE(voteSeq, 10);
+  fields.writeUInt32LE(instructionHashes.length, 14);
+  return Buffer.concat([
+    Buffer.from(anchor.utils.bytes.utf8.encode("squads-mpl approval request v2")),
+    multisig.toBuffer(),
+    fields,
+    ...instructionHashes,
+  ]);
+};
+
 let provider;
 
 describe("Programs", function(){
@@ -239,7 +324,8 @@ describe("Programs", function(){
         await squads.approveTransaction(txState.publicKey);
 
         txState = await squads.getTransaction(txState.publicKey);
-        expect(txState.approved.length).to.equal(1);
+        const msState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[]).length).to.equal(1);
         expect(txState.status).to.have.property("executeReady");
       });
 
@@ -395,6 +481,17 @@ describe("Programs", function(){
         txState = await squads.getTransaction(txState.publicKey);
 
         expect(txState.executedIndex).to.equal(2);
+        // the sequence has to be finalized explicitly
+        expect(txState.status).to.have.property("executeReady");
+        await program.methods
+          .finalizeExecution()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: creator.publicKey,
+          })
+          .rpc();
+        txState = await squads.getTransaction(txState.publicKey);
         expect(txState.status).to.have.property("executed");
       });
 
@@ -410,13 +507,63 @@ describe("Programs", function(){
         // get the number of spots left
         const SIZE_WITHOUT_MEMBERS = 8 + // Anchor disriminator
         2 +         // threshold value
-        2 +         // authority index
+        4 +         // authority index
         4 +         // transaction index
         4 +         // processed internal transaction index
         1 +         // PDA bump
         32 +        // creator
         1 +         // allow external execute
-        4;          // for vec length
+        4 +         // for vec length
+        1 +         // version
+        2 +         // default vault index
+        4 +         // for member weights vec length
+        4 +         // for member permissions vec length
+        4 +         // time lock
+        2 +         // config threshold
+        4 +         // veto period
+        4 +         // for member programs vec length
+        4 +         // for member expirations vec length
+        (1 + 32) +  // guardian
+        1 +         // frozen
+        1 +         // paused
+        1 +         // vote records
+        4 +         // executing transaction
+        4 +         // for program allowlists vec length
+        4 +         // for denied instructions vec length
+        4 +         // removal delay
+        (1 + 40) +  // pending removal
+        8 +         // last execution epoch
+        2 +         // recovery threshold
+        4 +         // recovery delay
+        4 +         // for recovery keys vec length
+        4 +         // for retired authorities vec length
+        1 +         // any executor
+        4 +         // for executors vec length
+        (1 + 32) +  // execution hook
+        1 +         // policy enabled
+        1 +         // activity log enabled
+        8 +         // seq
+        2 +         // min quorum
+        1 +         // threshold percent
+        2 +         // max open transactions
+        4 +         // for open transactions vec length
+        (1 + 32) +  // parent
+        (1 + 1 + 32 + 2) +  // membership gate
+        (1 + 82) +  // stake weight source
+        4 +         // for value tiers vec length
+        (1 + 5) +   // execution window
+        4 +         // for blackout periods vec length
+        4 +         // config time lock
+        (1 + 32) +  // admin
+        8 +         // admin expiry slot
+        2 +         // max members
+        1 +         // member pages
+        4 +         // paged member count
+        (1 + 32) +  // member root
+        4 +         // root member count
+        (1 + 96) +  // ratification
+        2 +         // veto council threshold
+        4;          // for veto council vec length
 
         const spotsLeft = ((currDataSize - SIZE_WITHOUT_MEMBERS) / 32) - currNumKeys;
 
@@ -603,152 +750,5355 @@ describe("Programs", function(){
           await txBuilder.withChangeThreshold(2)
         ).executeInstructions();
 
-        // get the ix
-        let ixState = await squads.getInstruction(
-          getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId)[0]
-        );
-        expect(ixState.instructionIndex).to.equal(1);
+        // get the ix
+        let ixState = await squads.getInstruction(
+          getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId)[0]
+        );
+        expect(ixState.instructionIndex).to.equal(1);
+
+        // activate the tx
+        let txState = await squads.activateTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+
+        // approve the tx
+        await squads.approveTransaction(txPDA);
+
+        // execute the tx
+        try {
+          await squads.executeTransaction(txPDA);
+        } catch (e) {
+          expect(e.message).to.contain("Error processing Instruction");
+        }
+      });
+
+      it(`Change vote from approved to rejected`, async function(){
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await (
+          await txBuilder.withChangeThreshold(2)
+        ).executeInstructions();
+
+        // get the ix
+        let ixState = await squads.getInstruction(
+          getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId)[0]
+        );
+        expect(ixState.instructionIndex).to.equal(1);
+
+        // activate the tx
+        let txState = await squads.activateTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+
+        // approve the tx
+        txState = await squads.approveTransaction(txPDA);
+        const msState = await squads.getMultisig(msPDA);
+
+        // check that state is "approved"
+        expect(txState.status).to.have.property("active");
+        expect(
+          bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[])
+            .map((k) => k.toBase58())
+            .indexOf(creator.publicKey.toBase58())
+        ).is.greaterThanOrEqual(0);
+
+        // now reject
+        txState = await squads.rejectTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+        expect(
+          bitmapMembers(txState.rejected, msState.keys as anchor.web3.PublicKey[])
+            .map((k) => k.toBase58())
+            .indexOf(creator.publicKey.toBase58())
+        ).is.greaterThanOrEqual(0);
+        expect(
+          bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[])
+            .map((k) => k.toBase58())
+            .indexOf(creator.publicKey.toBase58())
+        ).is.lessThan(0);
+      });
+
+      it(`Add a new member & change threshold (conjoined)`, async function(){
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        let msState =  await squads.getMultisig(msPDA);
+        const startKeys = msState.keys.length;
+        const startTxIndex = msState.transactionIndex;
+        const [txInstructions, txPDA] = await (
+          await txBuilder.withAddMemberAndChangeThreshold(newMember, 1)
+        ).getInstructions();
+        const activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
+
+        let addMemberTx = await createBlankTransaction(
+          squads.connection,
+          creator.publicKey
+        );
+        addMemberTx.add(...txInstructions);
+        addMemberTx.add(activateIx);
+        try {
+          await provider.sendAndConfirm(addMemberTx), undefined, {commitment: "confirmed"};
+        } catch (e) {
+          console.log("Failed to send add member tx");
+          throw e;
+        }
+        msState = await squads.getMultisig(msPDA);
+        expect(startTxIndex + 1).to.equal(msState.transactionIndex);
+        // get necessary signers
+        // if the threshold has changed, use the other members to approve as well
+        for (let i = 0; i < memberList.length; i++) {
+          // check to see if we need more signers
+          const approvalState = await squads.getTransaction(txPDA);
+          if (Object.keys(approvalState.status).indexOf("active") < 0) {
+            break;
+          }
+
+          const inMultisig = (msState.keys as anchor.web3.PublicKey[]).findIndex(
+            (k) => {
+              return k.toBase58() == memberList[i].publicKey.toBase58();
+            }
+          );
+          if (inMultisig < 0) {
+            continue;
+          }
+          try {
+            await provider.connection.requestAirdrop(
+              memberList[i].publicKey,
+              anchor.web3.LAMPORTS_PER_SOL
+            );
+            const approveTx = await program.methods
+              .approveTransaction()
+              .accounts({
+                multisig: msPDA,
+                transaction: txPDA,
+                member: memberList[i].publicKey,
+              })
+              .signers([memberList[i]])
+              .transaction();
+            try {
+              await provider.sendAndConfirm(approveTx, [memberList[i]]);
+            } catch (e) {
+              console.log(memberList[i].publicKey.toBase58(), " signing error");
+            }
+          } catch (e) {
+            console.log(e);
+          }
+        }
+
+        let txState = await squads.getTransaction(txPDA);
+        expect(txState.status).has.property("executeReady");
+
+        const payer = memberList[4];
+        await provider.connection.requestAirdrop(
+          payer.publicKey,
+          anchor.web3.LAMPORTS_PER_SOL
+        );
+
+        await agnosticExecute(squads, txPDA, payer);
+
+        txState = await squads.getTransaction(txPDA);
+        expect(txState.status).has.property("executed");
+        msState = await squads.getMultisig(msPDA);
+        threshold = msState.threshold;
+        expect((msState.keys as any[]).length).to.equal(startKeys + 1);
+        expect(msState.threshold).to.equal(1);
+      });
+
+      it(`Change the default vault`, async function(){
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.defaultVaultIndex).to.equal(1);
+        const newVaultIndex = msState.authorityIndex + 1;
+
+        // track a new authority, then designate it as the default vault
+        const addAuthorityIx = await program.methods
+          .addAuthority()
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const changeDefaultVaultIx = await program.methods
+          .changeDefaultVault(newVaultIndex)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstructions([addAuthorityIx, changeDefaultVaultIx])
+          .executeInstructions();
+
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.authorityIndex).to.equal(newVaultIndex);
+        expect(msState.defaultVaultIndex).to.equal(newVaultIndex);
+      });
+
+      it(`Default vault outside the tracked authorities fails`, async function(){
+        let msState = await squads.getMultisig(msPDA);
+        const startDefaultVault = msState.defaultVaultIndex;
+        const changeDefaultVaultIx = await program.methods
+          .changeDefaultVault(msState.authorityIndex + 1)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(changeDefaultVaultIx)
+          .executeInstructions();
+
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        try {
+          await squads.executeTransaction(txPDA);
+        } catch (e) {
+          expect(e.message).to.contain("Error processing Instruction");
+        }
+        const txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.defaultVaultIndex).to.equal(startDefaultVault);
+      });
+
+      it(`Create a compensating tx linked to an executed tx`, async function(){
+        // execute a transfer that will be followed up on
+        const authorityPDA = squads.getAuthorityPDA(msPDA, 1);
+        const testPayee = anchor.web3.Keypair.generate();
+        const fundingTx = await createBlankTransaction(
+          squads.connection,
+          creator.publicKey
+        );
+        fundingTx.add(await createTestTransferTransaction(
+          creator.publicKey,
+          authorityPDA
+        ));
+        await provider.sendAndConfirm(fundingTx);
+        let originalTxState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(
+          originalTxState.publicKey,
+          await createTestTransferTransaction(authorityPDA, testPayee.publicKey)
+        );
+        await squads.activateTransaction(originalTxState.publicKey);
+        await squads.approveTransaction(originalTxState.publicKey);
+        originalTxState = await squads.executeTransaction(originalTxState.publicKey);
+        expect(originalTxState.status).to.have.property("executed");
+
+        // create the compensating tx pointing at the executed one
+        const nextTxIndex = await squads.getNextTransactionIndex(msPDA);
+        const [linkedTxPDA] = getTxPDA(
+          msPDA,
+          new BN(nextTxIndex, 10),
+          squads.multisigProgramId
+        );
+        await program.methods
+          .createLinkedTransaction(1)
+          .accounts({
+            multisig: msPDA,
+            transaction: linkedTxPDA,
+            linkedTransaction: originalTxState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+
+        const linkedTxState = await squads.getTransaction(linkedTxPDA);
+        expect(linkedTxState.status).to.have.property("draft");
+        expect(linkedTxState.linkedTx.toBase58()).to.equal(
+          originalTxState.publicKey.toBase58()
+        );
+      });
+
+      it(`Linking to a non-executed tx fails`, async function(){
+        const draftTxState = await squads.createTransaction(msPDA, 1);
+        const nextTxIndex = await squads.getNextTransactionIndex(msPDA);
+        const [linkedTxPDA] = getTxPDA(
+          msPDA,
+          new BN(nextTxIndex, 10),
+          squads.multisigProgramId
+        );
+        try {
+          await program.methods
+            .createLinkedTransaction(1)
+            .accounts({
+              multisig: msPDA,
+              transaction: linkedTxPDA,
+              linkedTransaction: draftTxState.publicKey,
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("linked a transaction that was not executed");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidTransactionState");
+        }
+      });
+
+      it(`Restrict a member to voting only`, async function(){
+        let msState = await squads.getMultisig(msPDA);
+        const member = (msState.keys as anchor.web3.PublicKey[]).find(
+          (k) => !k.equals(creator.publicKey)
+        );
+        const votePermission = 2;
+        const setPermissionsIx = await program.methods
+          .setMemberPermissions(member, votePermission)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(setPermissionsIx)
+          .executeInstructions();
+
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        msState = await squads.getMultisig(msPDA);
+        const override = (msState.memberPermissions as any[]).find((p) =>
+          p.member.equals(member)
+        );
+        expect(override.permissions).to.equal(votePermission);
+      });
+
+      it(`Unknown permission bits fail`, async function(){
+        const msState = await squads.getMultisig(msPDA);
+        const setPermissionsIx = await program.methods
+          .setMemberPermissions(creator.publicKey, 0x80)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(setPermissionsIx)
+          .executeInstructions();
+
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        try {
+          await squads.executeTransaction(txPDA);
+          expect.fail("set permission bits outside the known set");
+        } catch (e) {
+          const postMsState = await squads.getMultisig(msPDA);
+          expect(postMsState.memberPermissions).to.deep.equal(msState.memberPermissions);
+        }
+      });
+
+      it(`Enforce a time lock between approval and execution`, async function(){
+        const timeLock = 2;
+        const waitForTimeLock = () => new Promise((resolve) => setTimeout(resolve, (timeLock + 1) * 1000));
+        const setTimeLock = async (seconds: number) => {
+          const changeTimeLockIx = await program.methods
+            .changeTimeLock(seconds)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeTimeLockIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          return txPDA;
+        };
+
+        // no time lock is set yet, so the config change executes right away
+        let configTxPDA = await setTimeLock(timeLock);
+        await squads.executeTransaction(configTxPDA);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.timeLock).to.equal(timeLock);
+
+        let txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed before the time lock elapsed");
+        } catch (e) {
+          expect(e.message).to.contain("TimeLockNotElapsed");
+        }
+        await waitForTimeLock();
+        txState = await squads.executeTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+
+        // removing the time lock is itself subject to the time lock
+        configTxPDA = await setTimeLock(0);
+        await waitForTimeLock();
+        await squads.executeTransaction(configTxPDA);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.timeLock).to.equal(0);
+      });
+
+      it(`Expired transactions can't be approved and can be closed by anyone`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const now = await squads.connection.getBlockTime(await squads.connection.getSlot());
+        const expiresAt = now + 2;
+        await program.methods
+          .setTransactionExpiration(new BN(expiresAt))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await squads.activateTransaction(txState.publicKey);
+        let expiringTxState = await squads.getTransaction(txState.publicKey);
+        expect(expiringTxState.expiresAt.toNumber()).to.equal(expiresAt);
+
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        try {
+          await squads.approveTransaction(txState.publicKey);
+          expect.fail("approved an expired transaction");
+        } catch (e) {
+          expect(e.message).to.contain("TransactionExpired");
+        }
+
+        // a non-member can expire the transaction, the rent goes back to the creator
+        // (the rent outweighs the fee the creator pays as the fee payer here)
+        const cranker = anchor.web3.Keypair.generate();
+        const creatorBalance = await squads.connection.getBalance(creator.publicKey);
+        await program.methods
+          .expireTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+            payer: cranker.publicKey,
+          })
+          .signers([cranker])
+          .rpc();
+        const closedTx = await squads.connection.getAccountInfo(txState.publicKey);
+        expect(closedTx).to.be.null;
+        const postCreatorBalance = await squads.connection.getBalance(creator.publicKey);
+        expect(postCreatorBalance).to.be.greaterThan(creatorBalance);
+      });
+
+      it(`Spend from a vault under a spending limit`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const fundingTx = await createBlankTransaction(
+          squads.connection,
+          creator.publicKey
+        );
+        fundingTx.add(await createTestTransferTransaction(
+          creator.publicKey,
+          vaultPDA,
+          LAMPORTS_PER_SOL / 10
+        ));
+        await provider.sendAndConfirm(fundingTx);
+
+        const spender = anchor.web3.Keypair.generate();
+        const payee = anchor.web3.Keypair.generate();
+        const nativeMint = anchor.web3.PublicKey.default;
+        const limit = LAMPORTS_PER_SOL / 50;
+        const [spendingLimitPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(1).toArrayLike(Buffer, "le", 4),
+            nativeMint.toBuffer(),
+            anchor.utils.bytes.utf8.encode("spendinglimit"),
+          ],
+          squads.multisigProgramId
+        );
+
+        // the executing member pays the rent for the spending limit
+        const addSpendingLimitIx = await program.methods
+          .addSpendingLimit(1, nativeMint, spender.publicKey, new BN(limit), new BN(60 * 60 * 24))
+          .accounts({
+            multisig: msPDA,
+            spendingLimit: spendingLimitPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(addSpendingLimitIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        const spend = (amount: number) => program.methods
+          .spendFromVault(new BN(amount))
+          .accounts({
+            multisig: msPDA,
+            spendingLimit: spendingLimitPDA,
+            vault: vaultPDA,
+            destination: payee.publicKey,
+            member: spender.publicKey,
+          })
+          .signers([spender])
+          .rpc();
+
+        await spend(limit / 2);
+        expect(await squads.connection.getBalance(payee.publicKey)).to.equal(limit / 2);
+        const spendingLimitState = await program.account.spendingLimit.fetch(spendingLimitPDA);
+        expect(spendingLimitState.spent.toNumber()).to.equal(limit / 2);
+
+        try {
+          await spend(limit);
+          expect.fail("spent over the spending limit");
+        } catch (e) {
+          expect(e.message).to.contain("SpendingLimitExceeded");
+        }
+      });
+
+      it(`Any key can execute a tx with a bounty and collect it`, async function(){
+        const bounty = LAMPORTS_PER_SOL / 100;
+        const txState = await squads.createTransaction(msPDA, 1);
+        await program.methods
+          .fundExecutionBounty(new BN(bounty))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+
+        const bot = anchor.web3.Keypair.generate();
+        await program.methods
+          .executeTransaction(Buffer.from([]))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: bot.publicKey,
+          })
+          .signers([bot])
+          .rpc();
+
+        const executedTxState = await squads.getTransaction(txState.publicKey);
+        expect(executedTxState.status).to.have.property("executed");
+        expect(await squads.connection.getBalance(bot.publicKey)).to.equal(bounty);
+      });
+
+      it(`Attach multiple instructions in a single call`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const txState = await squads.createTransaction(msPDA, 1);
+        const numberOfInstructions = 3;
+        const incomingInstructions = [...new Array(numberOfInstructions)].map(() => {
+          const ix = SystemProgram.transfer({
+            fromPubkey: vaultPDA,
+            toPubkey: anchor.web3.Keypair.generate().publicKey,
+            lamports: LAMPORTS_PER_SOL / 100,
+          });
+          return {
+            programId: ix.programId,
+            keys: ix.keys,
+            data: ix.data,
+          };
+        });
+        const instructionPDAs = incomingInstructions.map((_, i) =>
+          getIxPDA(txState.publicKey, new BN(i + 1, 10), squads.multisigProgramId)[0]
+        );
+
+        await program.methods
+          .addInstructions(incomingInstructions)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(instructionPDAs.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          })))
+          .rpc();
+
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        expect(postTxState.instructionIndex).to.equal(numberOfInstructions);
+        for (let i = 0; i < numberOfInstructions; i++) {
+          const ixState = await squads.getInstruction(instructionPDAs[i]);
+          expect(ixState.instructionIndex).to.equal(i + 1);
+          expect(ixState.programId.toBase58()).to.equal(SystemProgram.programId.toBase58());
+        }
+      });
+
+      it(`Executing without the tx lookup tables fails`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const lookupTable = anchor.web3.Keypair.generate().publicKey;
+        await program.methods
+          .setLookupTables([lookupTable])
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        expect(postTxState.lookupTables.map((t) => t.toBase58())).to.deep.equal([lookupTable.toBase58()]);
+
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed without the lookup tables");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidLookupTable");
+        }
+      });
+
+      it(`Emit events for the transaction lifecycle`, async function(){
+        const events = [];
+        const listeners = [
+          program.addEventListener("TransactionCreated", (e) => events.push(["created", e])),
+          program.addEventListener("VoteCast", (e) => events.push(["vote", e])),
+          program.addEventListener("TransactionExecuted", (e) => events.push(["executed", e])),
+        ];
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        await squads.executeTransaction(txState.publicKey);
+        // give the log subscription time to catch up
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        await Promise.all(listeners.map((l) => program.removeEventListener(l)));
+
+        expect(events.map(([name]) => name)).to.deep.equal(["created", "vote", "executed"]);
+        events.forEach(([_, e]) => {
+          expect(e.transaction.toBase58()).to.equal(txState.publicKey.toBase58());
+        });
+        expect(events[1][1].vote).to.have.property("approve");
+      });
+
+      it(`Close the instruction accounts of an executed tx`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const txState = await squads.createTransaction(msPDA, 1);
+        const ixState = await squads.addInstruction(
+          txState.publicKey,
+          await createTestTransferTransaction(vaultPDA, anchor.web3.Keypair.generate().publicKey, LAMPORTS_PER_SOL / 100)
+        );
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        await squads.executeTransaction(txState.publicKey);
+
+        const creatorBalance = await squads.connection.getBalance(creator.publicKey);
+        await program.methods
+          .closeExecutedInstructions()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts([{
+            pubkey: ixState.publicKey,
+            isSigner: false,
+            isWritable: true,
+          }])
+          .rpc();
+        const closedIx = await squads.connection.getAccountInfo(ixState.publicKey);
+        expect(closedIx).to.be.null;
+        // the rent outweighs the fee the creator pays as the fee payer here
+        const postCreatorBalance = await squads.connection.getBalance(creator.publicKey);
+        expect(postCreatorBalance).to.be.greaterThan(creatorBalance);
+      });
+
+      it(`The default vault tops up the rent when adding members reallocates`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex);
+        const startDataSize = (await squads.connection.getAccountInfo(msPDA)).data.length;
+        const startVaultLamports = await squads.connection.getBalance(vaultPDA);
+
+        // add members until the multisig has to grow (at most 10 keys worth of spare space)
+        let dataSize = startDataSize;
+        for (let i = 0; i <= 10 && dataSize == startDataSize; i++) {
+          const addMemberIx = await program.methods
+            .addMember(anchor.web3.Keypair.generate().publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([{
+              pubkey: vaultPDA,
+              isSigner: false,
+              isWritable: true,
+            }])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(addMemberIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          const txState = await squads.executeTransaction(txPDA);
+          expect(txState.status).to.have.property("executed");
+          dataSize = (await squads.connection.getAccountInfo(msPDA)).data.length;
+        }
+
+        expect(dataSize).to.be.greaterThan(startDataSize);
+        expect(await squads.connection.getBalance(vaultPDA)).to.be.lessThan(startVaultLamports);
+      });
+
+      it(`Abstain from a tx, then change the vote to approve`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await program.methods
+          .abstainTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: creator.publicKey,
+          })
+          .rpc();
+        let abstainedTxState = await squads.getTransaction(txState.publicKey);
+        expect(abstainedTxState.status).to.have.property("active");
+        const abstainMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(abstainedTxState.abstained, abstainMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.deep.equal([creator.publicKey.toBase58()]);
+
+        await squads.approveTransaction(txState.publicKey);
+        abstainedTxState = await squads.getTransaction(txState.publicKey);
+        expect(bitmapMembers(abstainedTxState.abstained, abstainMsState.keys as anchor.web3.PublicKey[])).to.be.empty;
+        expect(abstainedTxState.status).to.have.property("executeReady");
+      });
+
+      it(`Votes of a tx never exceed the member count`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [capMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        const members = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+        await squads.createMultisig(2, createKey, [creator.publicKey, ...members.map((m) => m.publicKey)]);
+        const txState = await squads.createTransaction(capMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const txSize = (await squads.connection.getAccountInfo(txState.publicKey)).data.length;
+        const vote = (method: string, member: anchor.web3.Keypair) => program.methods[method]()
+          .accounts({
+            multisig: capMsPDA,
+            transaction: txState.publicKey,
+            member: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+        const voteCount = async () => {
+          const counts = await program.methods
+            .getVoteCounts()
+            .accounts({multisig: capMsPDA, transaction: txState.publicKey})
+            .view();
+          return counts.approved + counts.rejected + counts.cancelled + counts.abstained;
+        };
+
+        // every member votes, up to the member count
+        await squads.approveTransaction(txState.publicKey);
+        await vote("rejectTransaction", members[0]);
+        await vote("abstainTransaction", members[1]);
+        expect(await voteCount()).to.equal(3);
+
+        // changing votes replaces them, so the votes stay at the member count
+        await vote("abstainTransaction", members[0]);
+        await vote("rejectTransaction", members[1]);
+        await vote("rejectTransaction", members[1]);
+        expect(await voteCount()).to.equal(3);
+        await vote("approveTransaction", members[0]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executeReady");
+        expect(await voteCount()).to.equal(3);
+
+        // and so do the cancellations, which replace the approvals
+        await squads.cancelTransaction(txState.publicKey);
+        let counts = await program.methods
+          .getVoteCounts()
+          .accounts({multisig: capMsPDA, transaction: txState.publicKey})
+          .view();
+        expect(counts.approved).to.equal(1);
+        expect(counts.cancelled).to.equal(1);
+        expect(await voteCount()).to.equal(3);
+
+        // a further vote from a key that isn't a member is rejected
+        const outsider = anchor.web3.Keypair.generate();
+        try {
+          await vote("cancelTransaction", outsider);
+          expect.fail("recorded the vote of a non member");
+        } catch (e) {
+          expect(e.message).to.contain("KeyNotInMultisig");
+        }
+        await vote("cancelTransaction", members[0]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("cancelled");
+        expect(await voteCount()).to.equal(3);
+        expect((await squads.connection.getAccountInfo(txState.publicKey)).data.length).to.equal(txSize);
+      });
+
+      it(`Config transactions require the config threshold`, async function(){
+        const buildConfigThresholdTx = async (configThreshold: number) => {
+          const changeConfigThresholdIx = await program.methods
+            .changeConfigThreshold(configThreshold)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeConfigThresholdIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          return txPDA;
+        };
+
+        // the config threshold isn't set yet, so the regular threshold of 1 applies
+        let txPDA = await buildConfigThresholdTx(2);
+        await squads.approveTransaction(txPDA);
+        await squads.executeTransaction(txPDA);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.configThreshold).to.equal(2);
+
+        // now a second member needs to approve config changes
+        txPDA = await buildConfigThresholdTx(0);
+        await squads.approveTransaction(txPDA);
+        let txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+
+        const keys = (msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+        const secondMember = memberList.find((m) => keys.includes(m.publicKey.toBase58()));
+        await program.methods
+          .approveTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            member: secondMember.publicKey,
+          })
+          .signers([secondMember])
+          .rpc();
+        txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.configThreshold).to.equal(0);
+      });
+
+      it(`Veto an approved tx within the veto period`, async function(){
+        const vetoPeriod = 2;
+        const waitForVetoPeriod = () => new Promise((resolve) => setTimeout(resolve, (vetoPeriod + 1) * 1000));
+        const setVetoPeriod = async (seconds: number) => {
+          const changeVetoPeriodIx = await program.methods
+            .changeVetoPeriod(seconds)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeVetoPeriodIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          return txPDA;
+        };
+        const veto = (transaction: anchor.web3.PublicKey) => program.methods
+          .vetoTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction,
+            member: creator.publicKey,
+          })
+          .rpc();
+
+        let configTxPDA = await setVetoPeriod(vetoPeriod);
+        await squads.executeTransaction(configTxPDA);
+        expect((await squads.getMultisig(msPDA)).vetoPeriod).to.equal(vetoPeriod);
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        await veto(txState.publicKey);
+        const vetoedTxState = await squads.getTransaction(txState.publicKey);
+        expect(vetoedTxState.status).to.have.property("cancelled");
+
+        // once the veto period is over, the tx can no longer be vetoed and can be executed
+        configTxPDA = await setVetoPeriod(0);
+        await waitForVetoPeriod();
+        try {
+          await veto(configTxPDA);
+          expect.fail("vetoed after the veto period");
+        } catch (e) {
+          expect(e.message).to.contain("VetoPeriodElapsed");
+        }
+        await squads.executeTransaction(configTxPDA);
+        expect((await squads.getMultisig(msPDA)).vetoPeriod).to.equal(0);
+      });
+
+      it(`Add a PDA of an allowed program as a member`, async function(){
+        // ie. a governance program or risk engine voting through CPI
+        const memberProgram = anchor.web3.Keypair.generate().publicKey;
+        const [programMember, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+          [anchor.utils.bytes.utf8.encode("risk-engine")],
+          memberProgram
+        );
+        const setMemberProgramIx = await program.methods
+          .setMemberProgram(memberProgram, true)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const addProgramMemberIx = await program.methods
+          .addProgramMember(memberProgram, [
+            Buffer.from(anchor.utils.bytes.utf8.encode("risk-engine")),
+            Buffer.from([bump]),
+          ])
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstructions([setMemberProgramIx, addProgramMemberIx])
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.memberPrograms.map((p) => p.toBase58())).to.include(memberProgram.toBase58());
+        expect((msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.include(programMember.toBase58());
+      });
+
+      it(`Prune a member after their membership expired`, async function(){
+        // ie. an auditor with a temporary seat
+        const tempMember = anchor.web3.Keypair.generate();
+        const expiresAt = Math.floor(Date.now() / 1000) + 3;
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const addMemberIx = await program.methods
+          .addMember(tempMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([vaultAccount])
+          .instruction();
+        const setMemberExpirationIx = await program.methods
+          .setMemberExpiration(tempMember.publicKey, new anchor.BN(expiresAt))
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([vaultAccount])
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstructions([addMemberIx, setMemberExpirationIx])
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        const prune = () => program.methods
+          .pruneExpiredMember(tempMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .rpc();
+        try {
+          await prune();
+          expect.fail("pruned a member before their membership expired");
+        } catch (e) {
+          expect(e.message).to.contain("MemberNotExpired");
+        }
+
+        // an expired member can no longer vote on config transactions either
+        const configTxPDA = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN((await squads.getMultisig(msPDA)).transactionIndex + 1).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("configtransaction"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        await program.methods
+          .createConfigTransaction([{ removeMember: { member: tempMember.publicKey } }])
+          .accounts({
+            multisig: msPDA,
+            transaction: configTxPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+
+        // once expired, anyone can prune the member
+        await new Promise((resolve) => setTimeout(resolve, 5000));
+        try {
+          await program.methods
+            .rejectConfigTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: configTxPDA,
+              member: tempMember.publicKey,
+            })
+            .signers([tempMember])
+            .rpc();
+          expect.fail("an expired member voted on a config transaction");
+        } catch (e) {
+          expect(e.message).to.contain("MemberExpired");
+        }
+        await prune();
+        const msState = await squads.getMultisig(msPDA);
+        expect((msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.not.include(tempMember.publicKey.toBase58());
+        expect(msState.memberExpirations.length).to.equal(0);
+      });
+
+      it(`Guardian freezes the multisig until it unfreezes itself`, async function(){
+        const guardian = anchor.web3.Keypair.generate();
+        const setGuardianIx = await program.methods
+          .setGuardian(guardian.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        await executeConfig(squads, msPDA, [setGuardianIx]);
+        expect((await squads.getMultisig(msPDA)).guardian.toBase58()).to.equal(guardian.publicKey.toBase58());
+
+        await program.methods
+          .freeze()
+          .accounts({
+            multisig: msPDA,
+            guardian: guardian.publicKey,
+          })
+          .signers([guardian])
+          .rpc();
+        expect((await squads.getMultisig(msPDA)).frozen).to.be.true;
+
+        // vault transactions can't be activated while frozen
+        const txState = await squads.createTransaction(msPDA, 1);
+        try {
+          await squads.activateTransaction(txState.publicKey);
+          expect.fail("activated a tx while frozen");
+        } catch (e) {
+          expect(e.message).to.contain("MultisigFrozen");
+        }
+
+        // but the multisig can still unfreeze itself
+        const unfreezeIx = await program.methods
+          .unfreeze()
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        await executeConfig(squads, msPDA, [unfreezeIx]);
+        expect((await squads.getMultisig(msPDA)).frozen).to.be.false;
+        const activatedTxState = await squads.activateTransaction(txState.publicKey);
+        expect(activatedTxState.status).to.have.property("active");
+      });
+
+      it(`Pause executions while voting continues`, async function(){
+        const pauseIx = await program.methods
+          .pause()
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        await executeConfig(squads, msPDA, [pauseIx]);
+        expect((await squads.getMultisig(msPDA)).paused).to.be.true;
+
+        // vault transactions can still be voted on, but not executed
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const approvedTxState = await squads.approveTransaction(txState.publicKey);
+        expect(approvedTxState.status).to.have.property("executeReady");
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed a tx while paused");
+        } catch (e) {
+          expect(e.message).to.contain("MultisigPaused");
+        }
+
+        const unpauseIx = await program.methods
+          .unpause()
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        await executeConfig(squads, msPDA, [unpauseIx]);
+        expect((await squads.getMultisig(msPDA)).paused).to.be.false;
+        const executedTxState = await squads.executeTransaction(txState.publicKey);
+        expect(executedTxState.status).to.have.property("executed");
+      });
+
+      it(`Label the multisig with a metadata account`, async function(){
+        const [metadataPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            anchor.utils.bytes.utf8.encode("metadata"),
+          ],
+          squads.multisigProgramId
+        );
+        const uriHash = Array(32).fill(7);
+        // the executing member pays the rent for the metadata
+        const createMetadataIx = await program.methods
+          .createMetadata("Treasury", "Main treasury of the squad", uriHash)
+          .accounts({
+            multisig: msPDA,
+            metadata: metadataPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(createMetadataIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        const metadataState = await program.account.msMetadata.fetch(metadataPDA);
+        expect(metadataState.multisig.toBase58()).to.equal(msPDA.toBase58());
+        expect(metadataState.name).to.equal("Treasury");
+        expect(metadataState.description).to.equal("Main treasury of the squad");
+        expect(metadataState.uriHash).to.deep.equal(uriHash);
+
+        // only the multisig itself can update the metadata
+        try {
+          await program.methods
+            .updateMetadata("Hacked", "", uriHash)
+            .accounts({
+              multisig: msPDA,
+              metadata: metadataPDA,
+            })
+            .rpc();
+          expect.fail("updated the metadata without the multisig signing");
+        } catch (e) {
+          expect(e.message).to.contain("Signature verification failed");
+        }
+      });
+
+      it(`Set a title and description on a draft tx`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const setDescription = (title: string, description: string) => program.methods
+          .setTransactionDescription(title, description)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await setDescription("Monthly payroll", "Pays the contributors for the month");
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        expect(postTxState.title).to.equal("Monthly payroll");
+        expect(postTxState.description).to.equal("Pays the contributors for the month");
+
+        // once active, the description is bound to the proposal
+        await squads.activateTransaction(txState.publicKey);
+        try {
+          await setDescription("Something else", "");
+          expect.fail("changed the description of an active tx");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidTransactionState");
+        }
+      });
+
+      it(`Relay an approval a member signed offline`, async function(){
+        // ie. a signer on an air-gapped device
+        const offlineMember = anchor.web3.Keypair.generate();
+        const addMemberIx = await program.methods
+          .addMember(offlineMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(addMemberIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        await squads.executeTransaction(txPDA);
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const signVote = (voteSeq: number, approve: boolean) => anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: offlineMember.secretKey,
+          message: signedVoteMessage(msPDA, txState.transactionIndex, voteSeq, approve),
+        });
+        const relayVote = (approve: boolean, ed25519Ix: anchor.web3.TransactionInstruction) => program.methods
+          .submitSignedVote(offlineMember.publicKey, approve)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            relayer: creator.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .preInstructions([ed25519Ix])
+          .rpc();
+
+        // the creator relays the vote, the offline member never sends a transaction
+        const rejectIx = signVote(0, false);
+        await relayVote(false, rejectIx);
+        expect((await squads.getTransaction(txState.publicKey)).voteSeq).to.equal(1);
+
+        // once the member changes their vote, the old signature can't be replayed to undo it
+        await program.methods
+          .abstainTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: offlineMember.publicKey,
+          })
+          .signers([offlineMember])
+          .rpc();
+        try {
+          await relayVote(false, rejectIx);
+          expect.fail("replayed a signed vote");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidSignedVote");
+        }
+        const replayedTxState = await squads.getTransaction(txState.publicKey);
+        const replayedMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(replayedTxState.rejected, replayedMsState.keys as anchor.web3.PublicKey[])).to.be.empty;
+        expect(replayedTxState.voteSeq).to.equal(2);
+
+        await relayVote(true, signVote(replayedTxState.voteSeq, true));
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        const postMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(postTxState.approved, postMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.include(offlineMember.publicKey.toBase58());
+        expect(postTxState.status).to.have.property("executeReady");
+      });
+
+      it(`Relay an approval request signed on a hardware wallet`, async function(){
+        const offlineMember = anchor.web3.Keypair.generate();
+        const addMemberIx = await program.methods
+          .addMember(offlineMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        await executeConfig(squads, msPDA, [addMemberIx]);
+
+        const msState = await squads.getMultisig(msPDA);
+        const vaultPDA = squads.getAuthorityPDA(msPDA, msState.defaultVaultIndex);
+        const txState = await squads.createTransaction(msPDA, msState.defaultVaultIndex);
+        const transferIx = await createTestTransferTransaction(vaultPDA, creator.publicKey);
+        const ixState = await squads.addInstruction(txState.publicKey, transferIx);
+        const activeTxState = await squads.activateTransaction(txState.publicKey);
+        const threshold = activeTxState.threshold || msState.threshold;
+
+        // the member signs the request built off-chain from the instructions they were shown
+        const signRequest = (instructions: anchor.web3.TransactionInstruction[]) => anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: offlineMember.secretKey,
+          message: approvalRequestMessage(
+            msPDA,
+            activeTxState.transactionIndex,
+            activeTxState.authorityIndex,
+            threshold,
+            activeTxState.voteSeq,
+            instructions.map(instructionHash),
+          ),
+        });
+        const relayRequest = (ed25519Ix: anchor.web3.TransactionInstruction, instructionPDA: anchor.web3.PublicKey) => program.methods
+          .submitApprovalRequest(offlineMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            relayer: creator.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .remainingAccounts([{ pubkey: instructionPDA, isSigner: false, isWritable: false }])
+          .preInstructions([ed25519Ix])
+          .rpc();
+
+        // a request signed over a tampered instruction doesn't match the attached one
+        const tamperedIx = await createTestTransferTransaction(vaultPDA, creator.publicKey, 2000000);
+        try {
+          await relayRequest(signRequest([tamperedIx]), ixState.publicKey);
+          expect.fail("relayed an approval request of a tampered instruction");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidApprovalRequest");
+        }
+
+        // nor can the instruction of another transaction stand in for the attached one
+        const otherTxState = await squads.createTransaction(msPDA, msState.defaultVaultIndex);
+        const otherIxState = await squads.addInstruction(otherTxState.publicKey, transferIx);
+        try {
+          await relayRequest(signRequest([transferIx]), otherIxState.publicKey);
+          expect.fail("relayed an approval request with the instruction of another tx");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidInstructionAccount");
+        }
+
+        // the request built off-chain round-trips to the one the program builds on-chain
+        await relayRequest(signRequest([transferIx]), ixState.publicKey);
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        const postMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(postTxState.approved, postMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.include(offlineMember.publicKey.toBase58());
+        expect(postTxState.voteSeq).to.equal(activeTxState.voteSeq + 1);
+      });
+
+      it(`Delegate votes to a hot key and revoke it`, async function(){
+        const hotKey = anchor.web3.Keypair.generate();
+        const [delegationPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            creator.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("delegate"),
+          ],
+          squads.multisigProgramId
+        );
+        await program.methods
+          .addDelegate(hotKey.publicKey)
+          .accounts({
+            multisig: msPDA,
+            delegation: delegationPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+
+        const voteAsDelegate = (transaction: anchor.web3.PublicKey) => program.methods
+          .submitDelegatedVote(true)
+          .accounts({
+            multisig: msPDA,
+            transaction,
+            delegation: delegationPDA,
+            delegate: hotKey.publicKey,
+          })
+          .signers([hotKey])
+          .rpc();
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await voteAsDelegate(txState.publicKey);
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        // the vote counts as the member's own
+        const postMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(postTxState.approved, postMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.include(creator.publicKey.toBase58());
+        expect(postTxState.status).to.have.property("executeReady");
+
+        await program.methods
+          .removeDelegate()
+          .accounts({
+            multisig: msPDA,
+            delegation: delegationPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+        const nextTxState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(nextTxState.publicKey);
+        try {
+          await voteAsDelegate(nextTxState.publicKey);
+          expect.fail("voted with a revoked delegate");
+        } catch (e) {
+          expect(e.message).to.contain("AccountNotInitialized");
+        }
+      });
+
+      it(`Crank a recurring tx on its schedule`, async function(){
+        // ie. a monthly payroll, here every 2 seconds
+        const interval = 2;
+        const amount = LAMPORTS_PER_SOL / 100;
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const payee = anchor.web3.Keypair.generate().publicKey;
+        const fundingTx = await createBlankTransaction(squads.connection, creator.publicKey);
+        fundingTx.add(await createTestTransferTransaction(creator.publicKey, vaultPDA, 3 * amount));
+        await provider.sendAndConfirm(fundingTx);
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(
+          txState.publicKey,
+          SystemProgram.transfer({ fromPubkey: vaultPDA, toPubkey: payee, lamports: amount })
+        );
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+
+        const [recurringPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            txState.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("recurring"),
+          ],
+          squads.multisigProgramId
+        );
+        const createRecurringIx = await program.methods
+          .createRecurringTransaction(new BN(interval), new BN(Math.floor(Date.now() / 1000)))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            recurringTransaction: recurringPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, configTxPDA] = await txBuilder
+          .withInstruction(createRecurringIx)
+          .executeInstructions();
+        await squads.activateTransaction(configTxPDA);
+        await squads.approveTransaction(configTxPDA);
+        await squads.executeTransaction(configTxPDA);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("recurring");
+
+        // the accounts are passed the same way as for execute_transaction
+        const executeIx = await squads.buildExecuteTransaction(txState.publicKey, creator.publicKey);
+        const { accountList } = program.coder.instruction.decode(executeIx.data).data as { accountList: Buffer };
+        const crank = () => program.methods
+          .executeRecurringTransaction(accountList)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            recurringTransaction: recurringPDA,
+            cranker: creator.publicKey,
+          })
+          .remainingAccounts(executeIx.keys.slice(3))
+          .rpc();
+
+        await crank();
+        expect(await squads.connection.getBalance(payee)).to.equal(amount);
+        try {
+          await crank();
+          expect.fail("executed a recurring tx before it was due");
+        } catch (e) {
+          expect(e.message).to.contain("RecurringTransactionNotDue");
+        }
+
+        await new Promise((resolve) => setTimeout(resolve, (interval + 1) * 1000));
+        await crank();
+        expect(await squads.connection.getBalance(payee)).to.equal(2 * amount);
+        const recurringState = await program.account.recurringTransaction.fetch(recurringPDA);
+        expect(recurringState.executions).to.equal(2);
+      });
+
+      it(`A non-member pulls funds from a vault under an allowance`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const fundingTx = await createBlankTransaction(
+          squads.connection,
+          creator.publicKey
+        );
+        fundingTx.add(await createTestTransferTransaction(
+          creator.publicKey,
+          vaultPDA,
+          LAMPORTS_PER_SOL / 10
+        ));
+        await provider.sendAndConfirm(fundingTx);
+
+        // ie. an expense account, the spender is not a member
+        const spender = anchor.web3.Keypair.generate();
+        const payee = anchor.web3.Keypair.generate();
+        const nativeMint = anchor.web3.PublicKey.default;
+        const cap = LAMPORTS_PER_SOL / 50;
+        const [allowancePDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(1).toArrayLike(Buffer, "le", 4),
+            nativeMint.toBuffer(),
+            spender.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("allowance"),
+          ],
+          squads.multisigProgramId
+        );
+        const addAllowanceIx = await program.methods
+          .addAllowance(1, nativeMint, spender.publicKey, new BN(cap), new BN(Math.floor(Date.now() / 1000) + 60 * 60))
+          .accounts({
+            multisig: msPDA,
+            allowance: allowancePDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(addAllowanceIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        const spend = (amount: number) => program.methods
+          .spendAllowance(new BN(amount))
+          .accounts({
+            multisig: msPDA,
+            allowance: allowancePDA,
+            vault: vaultPDA,
+            destination: payee.publicKey,
+            spender: spender.publicKey,
+          })
+          .signers([spender])
+          .rpc();
+
+        await spend(cap / 2);
+        expect(await squads.connection.getBalance(payee.publicKey)).to.equal(cap / 2);
+        const allowanceState = await program.account.allowance.fetch(allowancePDA);
+        expect(allowanceState.spent.toNumber()).to.equal(cap / 2);
+
+        try {
+          await spend(cap);
+          expect.fail("spent over the allowance cap");
+        } catch (e) {
+          expect(e.message).to.contain("AllowanceExceeded");
+        }
+      });
+
+      it(`Vote with vote records`, async function(){
+        const voteRecordPDA = (txPDA: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            creator.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("vote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const voteWithRecord = (txPDA: anchor.web3.PublicKey, approve: boolean) => program.methods
+          .createVoteRecord(approve)
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            voteRecord: voteRecordPDA(txPDA),
+            member: creator.publicKey,
+            payer: creator.publicKey,
+          })
+          .rpc();
+        const setVoteRecordsIx = (enabled: boolean) => program.methods
+          .setVoteRecords(enabled)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        await executeConfig(squads, msPDA, [await setVoteRecordsIx(true)]);
+        expect((await squads.getMultisig(msPDA)).voteRecords).to.be.true;
+
+        // votes are tallied on the transaction, and held in the vote record
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        try {
+          await squads.approveTransaction(txState.publicKey);
+          expect.fail("approved without a vote record");
+        } catch (e) {
+          expect(e.message).to.contain("VoteRecordsEnabled");
+        }
+        try {
+          await program.methods
+            .abstainTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("abstained without a vote record");
+        } catch (e) {
+          expect(e.message).to.contain("VoteRecordsEnabled");
+        }
+        // config transactions only hold votes in bitmaps
+        try {
+          await program.methods
+            .createConfigTransaction([{ changeThreshold: { threshold: 1 } }])
+            .accounts({
+              multisig: msPDA,
+              transaction: anchor.web3.PublicKey.findProgramAddressSync(
+                [
+                  anchor.utils.bytes.utf8.encode("squad"),
+                  msPDA.toBuffer(),
+                  new BN((await squads.getMultisig(msPDA)).transactionIndex + 1).toArrayLike(Buffer, "le", 4),
+                  anchor.utils.bytes.utf8.encode("configtransaction"),
+                ],
+                squads.multisigProgramId
+              )[0],
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("created a config transaction with vote records");
+        } catch (e) {
+          expect(e.message).to.contain("VoteRecordsEnabled");
+        }
+        await voteWithRecord(txState.publicKey, true);
+        const approvedTxState = await squads.getTransaction(txState.publicKey);
+        expect(approvedTxState.status).to.have.property("executeReady");
+        expect(approvedTxState.approvedWeight).to.equal(1);
+        const voteRecord = await program.account.voteRecord.fetch(voteRecordPDA(txState.publicKey));
+        expect(voteRecord.approve).to.be.true;
+        expect(voteRecord.member.toBase58()).to.equal(creator.publicKey.toBase58());
+
+        const disableTxPDA = (await executeConfig(squads, msPDA, [await setVoteRecordsIx(false)], (txPDA) => voteWithRecord(txPDA, true))).publicKey;
+        expect((await squads.getMultisig(msPDA)).voteRecords).to.be.false;
+
+        // the vote records can be closed once voting has ended
+        for (const txPDA of [txState.publicKey, disableTxPDA]) {
+          await program.methods
+            .closeVoteRecord()
+            .accounts({
+              multisig: msPDA,
+              transaction: txPDA,
+              voteRecord: voteRecordPDA(txPDA),
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect(await squads.connection.getAccountInfo(voteRecordPDA(txPDA))).to.be.null;
+        }
+      });
+
+      it(`Accounts are created at the current layout version`, async function(){
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.version).to.equal(1);
+        const txState = await squads.createTransaction(msPDA, 1);
+        expect(txState.version).to.equal(1);
+        try {
+          await program.methods
+            .migrateTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("migrated an up to date transaction");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyMigrated");
+        }
+        try {
+          await program.methods
+            .migrateBaselineTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("migrated an up to date transaction as one of the original layout");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyMigrated");
+        }
+        // current multisigs aren't of the original layout
+        try {
+          await program.methods
+            .migrateBaselineMultisig()
+            .accounts({
+              multisig: msPDA,
+              payer: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("migrated an up to date multisig");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyMigrated");
+        }
+      });
+
+      it(`Migrate a multisig of the original layout`, async function(){
+        // loaded by the validator from tests/fixtures/baseline-multisig.json: a multisig of the
+        // original layout with 2 members (the keypairs of the seeds [1; 32] and [2; 32]), after
+        // a third member was removed
+        const baselinePDA = new anchor.web3.PublicKey("2zTEGCTpLsQs2u6gqAmUf3YtxcXevGaXR2udpPyLuFvR");
+        const members = [
+          new anchor.web3.PublicKey("9hSR6S7WPtxmTojgo6GG3k4yDPecgJY292j7xrsUGWBu"),
+          new anchor.web3.PublicKey("AKnL4NNf3DGWZJS6cPknBuEGnVsV4A4m5tgebLHaRSZ9"),
+        ];
+        await program.methods
+          .migrateBaselineMultisig()
+          .accounts({
+            multisig: baselinePDA,
+            payer: creator.publicKey,
+          })
+          .rpc();
+        const msState = await squads.getMultisig(baselinePDA);
+        expect(msState.version).to.equal(1);
+        expect(msState.threshold).to.equal(2);
+        expect(msState.authorityIndex).to.equal(3);
+        expect(msState.transactionIndex).to.equal(5);
+        expect(msState.msChangeIndex).to.equal(4);
+        expect(msState.createKey.toBase58()).to.equal("US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx");
+        expect(msState.keys.map((k) => k.toBase58())).to.deep.equal(members.map((k) => k.toBase58()));
+        // the stale bytes of the removed member aren't read as the fields introduced since
+        expect(msState.defaultVaultIndex).to.equal(1);
+        expect(msState.memberWeights).to.be.empty;
+        expect(msState.timeLock).to.equal(0);
+        expect(msState.guardian).to.be.null;
+
+        try {
+          await program.methods
+            .migrateBaselineMultisig()
+            .accounts({
+              multisig: baselinePDA,
+              payer: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("migrated a multisig twice");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyMigrated");
+        }
+      });
+
+      it(`Migrate a transaction of the original layout`, async function(){
+        // loaded by the validator from tests/fixtures/baseline-transaction.json: transaction 5 of
+        // the multisig migrated above, active and approved by the member of the seed [1; 32]
+        const baselinePDA = new anchor.web3.PublicKey("2zTEGCTpLsQs2u6gqAmUf3YtxcXevGaXR2udpPyLuFvR");
+        const baselineTxPDA = new anchor.web3.PublicKey("EN51Wy61j1UcijQfubjStkSPTwHT9szUC3jGhQopuNwk");
+        const member = anchor.web3.Keypair.fromSeed(Uint8Array.from(Array(32).fill(1)));
+        // the member covers the rent of the fields introduced since
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: member.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const migrate = (method: "migrateTransaction" | "migrateBaselineTransaction") => program.methods[method]()
+          .accounts({
+            multisig: baselinePDA,
+            transaction: baselineTxPDA,
+            member: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+
+        // the original layout isn't read as the current one
+        try {
+          await migrate("migrateTransaction");
+          expect.fail("migrated a transaction of the original layout as a versioned one");
+        } catch (e) {
+          expect(e.message).to.contain("AccountDidNotDeserialize");
+        }
+
+        await migrate("migrateBaselineTransaction");
+        const txState = await squads.getTransaction(baselineTxPDA);
+        const msState = await squads.getMultisig(baselinePDA);
+        expect(txState.version).to.equal(1);
+        expect(txState.ms.toBase58()).to.equal(baselinePDA.toBase58());
+        expect(txState.creator.toBase58()).to.equal(member.publicKey.toBase58());
+        expect(txState.transactionIndex).to.equal(5);
+        expect(txState.authorityIndex).to.equal(1);
+        expect(txState.status).to.have.property("active");
+        // the approval key list is now a bitmap over the members
+        expect(bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.deep.equal([member.publicKey.toBase58()]);
+        expect(bitmapMembers(txState.rejected, msState.keys as anchor.web3.PublicKey[])).to.be.empty;
+        expect(bitmapMembers(txState.abstained, msState.keys as anchor.web3.PublicKey[])).to.be.empty;
+        expect(txState.title).to.equal("");
+        expect(txState.voteSeq).to.equal(0);
+
+        try {
+          await migrate("migrateBaselineTransaction");
+          expect.fail("migrated a transaction twice");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyMigrated");
+        }
+      });
+
+      it(`Migrate an unvoted transaction of the original layout`, async function(){
+        // loaded by the validator from tests/fixtures/baseline-transaction-3.json: transaction 3 of
+        // the multisig migrated above, active and without votes. Its empty approvals length reads
+        // as a zero layout version, and the zero padding as empty fields of the current layout
+        const baselinePDA = new anchor.web3.PublicKey("2zTEGCTpLsQs2u6gqAmUf3YtxcXevGaXR2udpPyLuFvR");
+        const baselineTxPDA = new anchor.web3.PublicKey("8xQ1F1DqKfeDaYVZXqySHC3zh6V7WV2caWVNWTUw6v3H");
+        const member = anchor.web3.Keypair.fromSeed(Uint8Array.from(Array(32).fill(1)));
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: member.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const migrate = (method: "migrateTransaction" | "migrateBaselineTransaction") => program.methods[method]()
+          .accounts({
+            multisig: baselinePDA,
+            transaction: baselineTxPDA,
+            member: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+
+        // it's neither migrated nor voted on as a transaction of the current layout
+        try {
+          await migrate("migrateTransaction");
+          expect.fail("migrated an unvoted transaction of the original layout as a versioned one");
+        } catch (e) {
+          expect(e.message).to.contain("NotMigrated");
+        }
+        try {
+          await program.methods
+            .approveTransaction()
+            .accounts({
+              multisig: baselinePDA,
+              transaction: baselineTxPDA,
+              member: member.publicKey,
+            })
+            .signers([member])
+            .rpc();
+          expect.fail("voted on a transaction of the original layout");
+        } catch (e) {
+          expect(e.message).to.contain("NotMigrated");
+        }
+
+        await migrate("migrateBaselineTransaction");
+        const txState = await squads.getTransaction(baselineTxPDA);
+        const msState = await squads.getMultisig(baselinePDA);
+        expect(txState.version).to.equal(1);
+        expect(txState.transactionIndex).to.equal(3);
+        expect(txState.status).to.have.property("active");
+        // the votes are bitmaps over the members, rather than the empty lists they were read as
+        expect(txState.approved.length).to.equal(Math.ceil(msState.keys.length / 8));
+        expect(txState.rejected.length).to.equal(Math.ceil(msState.keys.length / 8));
+        expect(bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[])).to.be.empty;
+      });
+
+      it(`Filter transactions created before the tag by it once migrated`, async function(){
+        // the transactions of the original layout migrated above, and transaction 4 of the same
+        // multisig loaded by the validator from tests/fixtures/baseline-transaction-4.json, of the
+        // original layout as well, with its approvals where the tag is inserted
+        const baselinePDA = new anchor.web3.PublicKey("2zTEGCTpLsQs2u6gqAmUf3YtxcXevGaXR2udpPyLuFvR");
+        const baselineTxPDA = new anchor.web3.PublicKey("EN51Wy61j1UcijQfubjStkSPTwHT9szUC3jGhQopuNwk");
+        const unvotedTxPDA = new anchor.web3.PublicKey("8xQ1F1DqKfeDaYVZXqySHC3zh6V7WV2caWVNWTUw6v3H");
+        const untaggedTxPDA = new anchor.web3.PublicKey("GUDXk4HbKW5HQ51dMSvwtcAyNBzJfVhM7Wj5ZD9yM1nm");
+        const member = anchor.web3.Keypair.fromSeed(Uint8Array.from(Array(32).fill(1)));
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: member.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const untagged = async () => (await squads.getTransactionsByTag(baselinePDA, Buffer.alloc(4)))
+          .map((t) => t.publicKey.toBase58());
+
+        // the bytes at the tag offset are the rest of the approvals length and the first approving key
+        const baselineAccount = await squads.connection.getAccountInfo(untaggedTxPDA);
+        expect([...baselineAccount.data.subarray(TRANSACTION_TAG_OFFSET, TRANSACTION_TAG_OFFSET + 4)]).to.deep.equal([0, 0, 0, member.publicKey.toBytes()[0]]);
+        expect((await untagged()).sort()).to.deep.equal([baselineTxPDA.toBase58(), unvotedTxPDA.toBase58()].sort());
+
+        await program.methods
+          .migrateBaselineTransaction()
+          .accounts({
+            multisig: baselinePDA,
+            transaction: untaggedTxPDA,
+            member: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+        expect((await untagged()).sort()).to.deep.equal([baselineTxPDA.toBase58(), unvotedTxPDA.toBase58(), untaggedTxPDA.toBase58()].sort());
+        expect(await squads.getTransactionsByTag(baselinePDA, Buffer.from("PAYR"))).to.be.empty;
+
+        // the votes follow the tag once migrated, and read the same
+        const txState = await squads.getTransaction(untaggedTxPDA);
+        const msState = await squads.getMultisig(baselinePDA);
+        expect(txState.version).to.equal(1);
+        expect(txState.tag.category).to.deep.equal([0, 0, 0, 0]);
+        expect(txState.tag.reference).to.equal(0);
+        expect(txState.transactionIndex).to.equal(4);
+        expect(bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.deep.equal([member.publicKey.toBase58()]);
+      });
+
+      it(`Upload instruction data in chunks`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const memoIx = new anchor.web3.TransactionInstruction({
+          programId: new anchor.web3.PublicKey("MemoSq4gqABAXKb96qnH8TyNYS8gZxtsjKX3JGskhe9"),
+          keys: [{pubkey: vault, isSigner: true, isWritable: false}],
+          data: Buffer.from("chunk 0;"),
+        });
+        const ixState = await squads.addInstruction(txState.publicKey, memoIx);
+        const chunks = [1, 2, 3].map((i) => Buffer.from(`chunk ${i};`.repeat(40)));
+        const fullData = Buffer.concat([memoIx.data, ...chunks]);
+        const fullHash = [...createHash("sha256").update(fullData).digest()];
+        const append = (data: Buffer, finalHash: number[] | null) => program.methods
+          .appendInstructionData(data, finalHash)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: ixState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await append(chunks[0], null);
+        await append(chunks[1], null);
+        try {
+          await append(chunks[2], Array(32).fill(0));
+          expect.fail("appended data with the wrong hash");
+        } catch (e) {
+          expect(e.message).to.contain("InstructionDataHashMismatch");
+        }
+        await append(chunks[2], fullHash);
+        const uploadedIx = await squads.getInstruction(ixState.publicKey);
+        expect(Buffer.from(uploadedIx.data).equals(fullData)).to.be.true;
+      });
+
+      it(`Replace and remove attached instructions in draft`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const memoIx = (memo: string) => new anchor.web3.TransactionInstruction({
+          programId: new anchor.web3.PublicKey("MemoSq4gqABAXKb96qnH8TyNYS8gZxtsjKX3JGskhe9"),
+          keys: [{pubkey: vault, isSigner: true, isWritable: false}],
+          data: Buffer.from(memo),
+        });
+        const ix1State = await squads.addInstruction(txState.publicKey, memoIx("mistake"));
+        const ix2State = await squads.addInstruction(txState.publicKey, memoIx("second"));
+
+        const replacement = memoIx("a much longer memo than the one that was attached by mistake");
+        await program.methods
+          .replaceInstruction({
+            programId: replacement.programId,
+            keys: replacement.keys,
+            data: replacement.data,
+          })
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: ix1State.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        const replacedIx = await squads.getInstruction(ix1State.publicKey);
+        expect(Buffer.from(replacedIx.data).equals(replacement.data)).to.be.true;
+        expect(replacedIx.instructionIndex).to.equal(1);
+
+        // only the last instruction can be removed
+        const removeIx = (instruction: anchor.web3.PublicKey) => program.methods
+          .removeInstruction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        try {
+          await removeIx(ix1State.publicKey);
+          expect.fail("removed an instruction that isn't the last");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidInstructionAccount");
+        }
+        await removeIx(ix2State.publicKey);
+        expect(await squads.connection.getAccountInfo(ix2State.publicKey)).to.be.null;
+        expect((await squads.getTransaction(txState.publicKey)).instructionIndex).to.equal(1);
+      });
+
+      it(`Clone a rejected transaction into a new draft`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const memoIx = new anchor.web3.TransactionInstruction({
+          programId: new anchor.web3.PublicKey("MemoSq4gqABAXKb96qnH8TyNYS8gZxtsjKX3JGskhe9"),
+          keys: [{pubkey: vault, isSigner: true, isWritable: false}],
+          data: Buffer.from("re-propose me"),
+        });
+        const ixState = await squads.addInstruction(txState.publicKey, memoIx);
+        await squads.activateTransaction(txState.publicKey);
+        const rejectedTxState = await squads.rejectTransaction(txState.publicKey);
+        expect(rejectedTxState.status).to.have.property("rejected");
+
+        const msState = await squads.getMultisig(msPDA);
+        const [clonePDA] = getTxPDA(msPDA, new BN(msState.transactionIndex + 1), squads.multisigProgramId);
+        const [cloneIxPDA] = getIxPDA(clonePDA, new BN(1), squads.multisigProgramId);
+        await program.methods
+          .cloneTransaction()
+          .accounts({
+            multisig: msPDA,
+            sourceTransaction: txState.publicKey,
+            transaction: clonePDA,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts([
+            {pubkey: ixState.publicKey, isSigner: false, isWritable: false},
+            {pubkey: cloneIxPDA, isSigner: false, isWritable: true},
+          ])
+          .rpc();
+        const cloneState = await squads.getTransaction(clonePDA);
+        expect(cloneState.status).to.have.property("draft");
+        expect(cloneState.authorityIndex).to.equal(1);
+        expect(cloneState.instructionIndex).to.equal(1);
+        const cloneIxState = await squads.getInstruction(cloneIxPDA);
+        expect(cloneIxState.programId.toBase58()).to.equal(memoIx.programId.toBase58());
+        expect(Buffer.from(cloneIxState.data).equals(memoIx.data)).to.be.true;
+      });
+
+      it(`Read transaction status, vote counts and membership through views`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+
+        const status = await program.methods
+          .getTransactionStatus()
+          .accounts({multisig: msPDA, transaction: txState.publicKey})
+          .view();
+        expect(status).to.have.property("executeReady");
+
+        const counts = await program.methods
+          .getVoteCounts()
+          .accounts({multisig: msPDA, transaction: txState.publicKey})
+          .view();
+        expect(counts.approved).to.equal(1);
+        expect(counts.rejected).to.equal(0);
+        expect(counts.approvedWeight).to.equal(1);
+        expect(counts.threshold).to.equal(1);
+
+        const isMember = (member: anchor.web3.PublicKey) => program.methods
+          .isMember(member)
+          .accounts({multisig: msPDA})
+          .view();
+        expect(await isMember(creator.publicKey)).to.be.true;
+        expect(await isMember(anchor.web3.Keypair.generate().publicKey)).to.be.false;
+      });
+
+      it(`Restrict the programs a vault can target with an allowlist`, async function(){
+        const tokenProgramId = new anchor.web3.PublicKey("TokenkegQfeZyiNwAJbNbGqPENrm7YXXSPRqg2mqwHd");
+        const memoProgramId = new anchor.web3.PublicKey("MemoSq4gqABAXKb96qnH8TyNYS8gZxtsjKX3JGskhe9");
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const setAllowlist = async (authorityIndex: number, programs: anchor.web3.PublicKey[]) => {
+          const setAllowlistIx = await program.methods
+            .setProgramAllowlist(authorityIndex, programs)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(setAllowlistIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          await squads.executeTransaction(txPDA);
+        };
+
+        // vault 1 is restricted to the token and system programs, vault 2 stays unrestricted
+        await setAllowlist(1, [tokenProgramId, SystemProgram.programId]);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.programAllowlists.length).to.equal(1);
+        expect(msState.programAllowlists[0].authorityIndex).to.equal(1);
+
+        const memoIx = (vault: anchor.web3.PublicKey) => new anchor.web3.TransactionInstruction({
+          programId: memoProgramId,
+          keys: [{pubkey: vault, isSigner: true, isWritable: false}],
+          data: Buffer.from("not allowed"),
+        });
+        const [vault1] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const [vault2] = await getAuthorityPDA(msPDA, new BN(2), squads.multisigProgramId);
+        const tx1State = await squads.createTransaction(msPDA, 1);
+        try {
+          await squads.addInstruction(tx1State.publicKey, memoIx(vault1));
+          expect.fail("attached an instruction for a program outside the allowlist");
+        } catch (e) {
+          expect(e.message).to.contain("ProgramNotAllowed");
+        }
+        await squads.addInstruction(tx1State.publicKey, SystemProgram.transfer({
+          fromPubkey: vault1,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        const tx2State = await squads.createTransaction(msPDA, 2);
+        await squads.addInstruction(tx2State.publicKey, memoIx(vault2));
+
+        // an empty allowlist lifts the restriction
+        await setAllowlist(1, []);
+        expect((await squads.getMultisig(msPDA)).programAllowlists.length).to.equal(0);
+        await squads.addInstruction(tx1State.publicKey, memoIx(vault1));
+      });
+
+      it(`Deny attaching instructions by program and discriminator`, async function(){
+        const tokenProgramId = new anchor.web3.PublicKey("TokenkegQfeZyiNwAJbNbGqPENrm7YXXSPRqg2mqwHd");
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const setDenied = async (prefix: Buffer, denied: boolean) => {
+          const setDeniedIx = await program.methods
+            .setDeniedInstruction(tokenProgramId, prefix, denied)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(setDeniedIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          await squads.executeTransaction(txPDA);
+        };
+
+        // SetAuthority is instruction 6 of the token program
+        const setAuthorityPrefix = Buffer.from([6]);
+        await setDenied(setAuthorityPrefix, true);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.deniedInstructions.length).to.equal(1);
+        expect(msState.deniedInstructions[0].programId.toBase58()).to.equal(tokenProgramId.toBase58());
+
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const tokenIx = (data: Buffer) => new anchor.web3.TransactionInstruction({
+          programId: tokenProgramId,
+          keys: [{pubkey: vault, isSigner: true, isWritable: false}],
+          data,
+        });
+        const txState = await squads.createTransaction(msPDA, 1);
+        try {
+          await squads.addInstruction(txState.publicKey, tokenIx(Buffer.from([6, 2, 0])));
+          expect.fail("attached a denied instruction");
+        } catch (e) {
+          expect(e.message).to.contain("InstructionDenied");
+        }
+        // other instructions of the program can still be attached
+        await squads.addInstruction(txState.publicKey, tokenIx(Buffer.from([3, 0, 0, 0, 0, 0, 0, 0, 0])));
+
+        await setDenied(setAuthorityPrefix, false);
+        expect((await squads.getMultisig(msPDA)).deniedInstructions.length).to.equal(0);
+        await squads.addInstruction(txState.publicKey, tokenIx(Buffer.from([6, 2, 0])));
+      });
+
+      it(`Remove a member in two steps with a removal delay`, async function(){
+        const removalDelay = 3;
+        const tempMember = anchor.web3.Keypair.generate();
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const removeMemberIx = await program.methods
+          .removeMember(tempMember.publicKey)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .addMember(tempMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction(),
+          await program.methods
+            .changeRemovalDelay(removalDelay)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+        ]);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.removalDelay).to.equal(removalDelay);
+
+        // the removal is only scheduled, and can be aborted by a member with the veto permission
+        await executeConfig(squads, msPDA, [removeMemberIx]);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.pendingRemoval.member.toBase58()).to.equal(tempMember.publicKey.toBase58());
+        expect(msState.keys.map((k) => k.toBase58())).to.include(tempMember.publicKey.toBase58());
+        await program.methods
+          .abortMemberRemoval()
+          .accounts({
+            multisig: msPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+        expect((await squads.getMultisig(msPDA)).pendingRemoval).to.be.null;
+
+        // otherwise anyone can finalize it once the delay has passed
+        await executeConfig(squads, msPDA, [removeMemberIx]);
+        const finalize = () => program.methods
+          .finalizeMemberRemoval()
+          .accounts({
+            multisig: msPDA,
+          })
+          .rpc();
+        try {
+          await finalize();
+          expect.fail("finalized a removal before the removal delay passed");
+        } catch (e) {
+          expect(e.message).to.contain("RemovalDelayNotElapsed");
+        }
+        await new Promise((resolve) => setTimeout(resolve, (removalDelay + 1) * 1000));
+        await finalize();
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.pendingRemoval).to.be.null;
+        expect(msState.keys.map((k) => k.toBase58())).to.not.include(tempMember.publicKey.toBase58());
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .changeRemovalDelay(0)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+        ]);
+      });
+
+      it(`Recover an inactive multisig with the recovery key`, async function(){
+        const recoveryKey = anchor.web3.Keypair.generate();
+        const [recoveryPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [anchor.utils.bytes.utf8.encode("squad"), msPDA.toBuffer(), anchor.utils.bytes.utf8.encode("recovery")],
+          squads.multisigProgramId
+        );
+
+        // the executing member pays the rent for the recovery
+        await executeConfig(squads, msPDA, [await program.methods
+          .addRecovery(recoveryKey.publicKey, new BN(1000))
+          .accounts({
+            multisig: msPDA,
+            recovery: recoveryPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction()]);
+        const recoveryState = await program.account.recovery.fetch(recoveryPDA);
+        expect(recoveryState.recoveryKey.toBase58()).to.equal(recoveryKey.publicKey.toBase58());
+
+        // the multisig just executed a transaction, so it isn't inactive
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.lastExecutionEpoch.toNumber()).to.equal((await squads.connection.getEpochInfo()).epoch);
+        await squads.connection.confirmTransaction(
+          await squads.connection.requestAirdrop(recoveryKey.publicKey, LAMPORTS_PER_SOL),
+          "confirmed"
+        );
+        try {
+          await program.methods
+            .recover([recoveryKey.publicKey], 1)
+            .accounts({
+              multisig: msPDA,
+              recovery: recoveryPDA,
+              recoveryKey: recoveryKey.publicKey,
+            })
+            .signers([recoveryKey])
+            .rpc();
+          expect.fail("recovered a multisig that is still active");
+        } catch (e) {
+          expect(e.message).to.contain("RecoveryNotDue");
+        }
+
+        await executeConfig(squads, msPDA, [await program.methods
+          .removeRecovery()
+          .accounts({
+            multisig: msPDA,
+            recovery: recoveryPDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction()]);
+        expect(await squads.connection.getAccountInfo(recoveryPDA)).to.be.null;
+      });
+
+      it(`Replace a lost member key with the recovery set`, async function(){
+        const recoveryDelay = 2;
+        const lostMember = anchor.web3.Keypair.generate();
+        const newMember = anchor.web3.Keypair.generate();
+        const recoveryKeys = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+        for (const recoveryKey of recoveryKeys) {
+          await squads.connection.confirmTransaction(
+            await squads.connection.requestAirdrop(recoveryKey.publicKey, LAMPORTS_PER_SOL),
+            "confirmed"
+          );
+        }
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const setRecoverySetIx = (keys: anchor.web3.PublicKey[], threshold: number, delay: number) => program.methods
+          .setRecoverySet(keys, threshold, delay)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([vaultAccount])
+          .instruction();
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .addMember(lostMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction(),
+          await setRecoverySetIx(recoveryKeys.map((k) => k.publicKey), 2, recoveryDelay),
+        ]);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.recoveryKeys.length).to.equal(2);
+        expect(msState.recoveryThreshold).to.equal(2);
+
+        const memberRecoveryPDA = (oldMember: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [anchor.utils.bytes.utf8.encode("squad"), msPDA.toBuffer(), oldMember.toBuffer(), anchor.utils.bytes.utf8.encode("memberrecovery")],
+          squads.multisigProgramId
+        )[0];
+        const propose = (oldMember: anchor.web3.PublicKey, member: anchor.web3.PublicKey) => program.methods
+          .proposeMemberRecovery(oldMember, member)
+          .accounts({
+            multisig: msPDA,
+            memberRecovery: memberRecoveryPDA(oldMember),
+            recoveryKey: recoveryKeys[0].publicKey,
+          })
+          .signers([recoveryKeys[0]])
+          .rpc();
+        const execute = () => program.methods
+          .executeMemberRecovery()
+          .accounts({
+            multisig: msPDA,
+            memberRecovery: memberRecoveryPDA(lostMember.publicKey),
+            proposer: recoveryKeys[0].publicKey,
+          })
+          .rpc();
+
+        await propose(lostMember.publicKey, newMember.publicKey);
+        try {
+          await execute();
+          expect.fail("executed a recovery without the recovery threshold");
+        } catch (e) {
+          expect(e.message).to.contain("RecoveryNotApproved");
+        }
+        await program.methods
+          .approveMemberRecovery()
+          .accounts({
+            multisig: msPDA,
+            memberRecovery: memberRecoveryPDA(lostMember.publicKey),
+            recoveryKey: recoveryKeys[1].publicKey,
+          })
+          .signers([recoveryKeys[1]])
+          .rpc();
+        try {
+          await execute();
+          expect.fail("executed a recovery before the recovery delay passed");
+        } catch (e) {
+          expect(e.message).to.contain("RecoveryDelayNotElapsed");
+        }
+        await new Promise((resolve) => setTimeout(resolve, (recoveryDelay + 1) * 1000));
+        await execute();
+        msState = await squads.getMultisig(msPDA);
+        const keys = msState.keys.map((k) => k.toBase58());
+        expect(keys).to.include(newMember.publicKey.toBase58());
+        expect(keys).to.not.include(lostMember.publicKey.toBase58());
+
+        // a member whose key isn't lost can cancel the recovery
+        await propose(newMember.publicKey, anchor.web3.Keypair.generate().publicKey);
+        await program.methods
+          .cancelMemberRecovery()
+          .accounts({
+            multisig: msPDA,
+            memberRecovery: memberRecoveryPDA(newMember.publicKey),
+            proposer: recoveryKeys[0].publicKey,
+            oldMember: newMember.publicKey,
+          })
+          .signers([newMember])
+          .rpc();
+        expect(await squads.connection.getAccountInfo(memberRecoveryPDA(newMember.publicKey))).to.be.null;
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .removeMember(newMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+          await setRecoverySetIx([], 0, 0),
+        ]);
+      });
+
+      it(`Rotate a member key in one step`, async function(){
+        const oldMember = anchor.web3.Keypair.generate();
+        const newMember = anchor.web3.Keypair.generate();
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .addMember(oldMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction(),
+          await program.methods
+            .setMemberWeight(oldMember.publicKey, 2)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction(),
+        ]);
+        const msBefore = await squads.getMultisig(msPDA);
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .replaceMember(oldMember.publicKey, newMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+        ]);
+        const msAfter = await squads.getMultisig(msPDA);
+        const keys = msAfter.keys.map((k) => k.toBase58());
+        expect(keys).to.include(newMember.publicKey.toBase58());
+        expect(keys).to.not.include(oldMember.publicKey.toBase58());
+        expect(msAfter.keys.length).to.equal(msBefore.keys.length);
+        expect(msAfter.threshold).to.equal(msBefore.threshold);
+        // the weight override carries over to the new key
+        const weight = msAfter.memberWeights.find((w) => w.member.equals(newMember.publicKey));
+        expect(weight.weight).to.equal(2);
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .removeMember(newMember.publicKey)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+        ]);
+      });
+
+      it(`Apply a batch of config changes in one proposal`, async function(){
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const applyActions = async (actions: any[]) => {
+          const applyIx = await program.methods
+            .applyConfigActions(actions)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([vaultAccount])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(applyIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          return squads.executeTransaction(txPDA);
+        };
+        const msBefore = await squads.getMultisig(msPDA);
+
+        // the member is added along with its weight and permissions
+        const votePermission = 1 << 1;
+        const txState = await applyActions([
+          {addMember: {member: newMember}},
+          {setMemberWeight: {member: newMember, weight: 2}},
+          {setMemberPermissions: {member: newMember, permissions: votePermission}},
+        ]);
+        expect(txState.status).to.have.property("executed");
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.keys.length).to.equal(msBefore.keys.length + 1);
+        expect(msState.memberWeights.find((w) => w.member.equals(newMember)).weight).to.equal(2);
+        expect(msState.memberPermissions.find((p) => p.member.equals(newMember)).permissions).to.equal(votePermission);
+        // active transactions are deprecated once for the whole batch
+        expect(msState.msChangeIndex).to.equal(txState.transactionIndex);
+
+        // a batch leaving the multisig inconsistent fails as a whole
+        try {
+          await applyActions([
+            {removeMember: {member: newMember}},
+            {changeThreshold: {threshold: msState.keys.length + 1}},
+          ]);
+          expect.fail("applied a threshold above the total weight");
+        } catch (e) {
+          expect(e.message).to.contain("ThresholdTooHigh");
+        }
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.keys.map((k) => k.toBase58())).to.include(newMember.toBase58());
+
+        await applyActions([
+          {removeMember: {member: newMember}},
+        ]);
+        expect((await squads.getMultisig(msPDA)).keys.length).to.equal(msBefore.keys.length);
+      });
+
+      it(`Change the config through a typed config transaction`, async function(){
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        const configTxPDA = (transactionIndex: number) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(transactionIndex).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("configtransaction"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const proposeAndExecute = async (actions: any[]) => {
+          const msState = await squads.getMultisig(msPDA);
+          const txPDA = configTxPDA(msState.transactionIndex + 1);
+          await program.methods
+            .createConfigTransaction(actions)
+            .accounts({
+              multisig: msPDA,
+              transaction: txPDA,
+              creator: creator.publicKey,
+            })
+            .rpc();
+          let txState = await program.account.msConfigTransaction.fetch(txPDA);
+          expect(txState.status).to.have.property("active");
+          await program.methods
+            .approveConfigTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: txPDA,
+              member: creator.publicKey,
+            })
+            .rpc();
+          await program.methods
+            .executeConfigTransaction()
+            .accounts({
+              multisig: msPDA,
+              transaction: txPDA,
+              member: creator.publicKey,
+              payer: creator.publicKey,
+            })
+            .rpc();
+          txState = await program.account.msConfigTransaction.fetch(txPDA);
+          expect(txState.status).to.have.property("executed");
+        };
+        const membersBefore = (await squads.getMultisig(msPDA)).keys.length;
+
+        await proposeAndExecute([{addMember: {member: newMember}}]);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.keys.length).to.equal(membersBefore + 1);
+
+        // actions that can't be applied are rejected when proposed
+        try {
+          await program.methods
+            .createConfigTransaction([{changeThreshold: {threshold: msState.keys.length + 1}}])
+            .accounts({
+              multisig: msPDA,
+              transaction: configTxPDA(msState.transactionIndex + 1),
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("proposed a threshold above the total weight");
+        } catch (e) {
+          expect(e.message).to.contain("ThresholdTooHigh");
+        }
+
+        await proposeAndExecute([{removeMember: {member: newMember}}]);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.keys.length).to.equal(membersBefore);
+      });
+
+      it(`Sweep and retire a vault`, async function(){
+        const retiredIndex = 42;
+        const [retiredVault] = await getAuthorityPDA(msPDA, new BN(retiredIndex), squads.multisigProgramId);
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        const fundTx = new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: retiredVault,
+          lamports: LAMPORTS_PER_SOL / 10,
+        }));
+        await provider.sendAndConfirm(fundTx);
+
+        const retireIx = await program.methods
+          .retireAuthority(retiredIndex)
+          .accounts({
+            multisig: msPDA,
+            vault: retiredVault,
+            destination,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(retireIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        await squads.executeTransaction(txPDA);
+
+        // whatever the multisig didn't need for its own rent ends up at the destination
+        expect(await provider.connection.getBalance(retiredVault)).to.equal(0);
+        expect(await provider.connection.getBalance(destination)).to.be.greaterThan(0);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.retiredAuthorities).to.deep.equal([retiredIndex]);
+        try {
+          await squads.createTransaction(msPDA, retiredIndex);
+          expect.fail("created a transaction for a retired vault");
+        } catch (e) {
+          expect(e.message).to.contain("AuthorityRetired");
+        }
+      });
+
+      it(`Add an authority labeled by an authority record`, async function(){
+        const authorityIndex = (await squads.getMultisig(msPDA)).authorityIndex + 1;
+        const [authorityRecordPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(authorityIndex).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("authorityrecord"),
+          ],
+          squads.multisigProgramId
+        );
+        // the executing member pays the rent for the record
+        const addNamedAuthorityIx = await program.methods
+          .addNamedAuthority("ops vault", "pays for infrastructure")
+          .accounts({
+            multisig: msPDA,
+            authorityRecord: authorityRecordPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(addNamedAuthorityIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        const txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+
+        expect((await squads.getMultisig(msPDA)).authorityIndex).to.equal(authorityIndex);
+        const recordState = await program.account.authorityRecord.fetch(authorityRecordPDA);
+        expect(recordState.multisig.toBase58()).to.equal(msPDA.toBase58());
+        expect(recordState.authorityIndex).to.equal(authorityIndex);
+        expect(recordState.label).to.equal("ops vault");
+        expect(recordState.purpose).to.equal("pays for infrastructure");
+
+        // only the multisig itself can update the record
+        try {
+          await program.methods
+            .updateAuthorityRecord("hacked", "")
+            .accounts({
+              multisig: msPDA,
+              authorityRecord: authorityRecordPDA,
+            })
+            .rpc();
+          expect.fail("updated the record without the multisig signing");
+        } catch (e) {
+          expect(e.message).to.contain("Signature verification failed");
+        }
+      });
+
+      it(`Create the associated token account of a vault without a proposal`, async function(){
+        const payer = (creator as anchor.Wallet).payer;
+        const mint = await createMint(provider.connection, payer, creator.publicKey, null, 6);
+        const authorityIndex = (await squads.getMultisig(msPDA)).defaultVaultIndex;
+        const [vault] = await getAuthorityPDA(msPDA, new BN(authorityIndex), squads.multisigProgramId);
+        const vaultTokenAccount = getAssociatedTokenAddressSync(mint, vault, true);
+        const createVaultAta = () => program.methods
+          .createVaultAta(authorityIndex)
+          .accounts({
+            multisig: msPDA,
+            vault,
+            vaultTokenAccount,
+            mint,
+            payer: creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        await createVaultAta();
+        const tokenAccount = await getAccount(provider.connection, vaultTokenAccount);
+        expect(tokenAccount.owner.toBase58()).to.equal(vault.toBase58());
+        expect(tokenAccount.mint.toBase58()).to.equal(mint.toBase58());
+        // creating it again is a no-op
+        await createVaultAta();
+      });
+
+      it(`Withdraw from a vault stake account through a typed stake action`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const stakeAccount = anchor.web3.Keypair.generate();
+        const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(StakeProgram.space);
+        const createStakeTx = StakeProgram.createAccount({
+          fromPubkey: creator.publicKey,
+          stakePubkey: stakeAccount.publicKey,
+          authorized: new anchor.web3.Authorized(vault, vault),
+          lamports: rentExempt + LAMPORTS_PER_SOL / 10,
+        });
+        await provider.sendAndConfirm(createStakeTx, [stakeAccount]);
+
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        const txState = await squads.createTransaction(msPDA, 1);
+        await program.methods
+          .addTypedInstruction({stake: {action: {withdraw: {
+            stake: stakeAccount.publicKey,
+            destination,
+            lamports: new BN(LAMPORTS_PER_SOL / 10),
+          }}}})
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId)[0],
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        const executedTx = await squads.executeTransaction(txState.publicKey);
+        expect(executedTx.status).to.have.property("executed");
+        expect(await provider.connection.getBalance(destination)).to.equal(LAMPORTS_PER_SOL / 10);
+        expect(await provider.connection.getBalance(stakeAccount.publicKey)).to.equal(rentExempt);
+      });
+
+      it(`Withdraw from a vault-controlled vote account through a typed vote action`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const node = anchor.web3.Keypair.generate();
+        const voteAccount = anchor.web3.Keypair.generate();
+        const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(VoteProgram.space);
+        const createVoteTx = VoteProgram.createAccount({
+          fromPubkey: creator.publicKey,
+          votePubkey: voteAccount.publicKey,
+          voteInit: new VoteInit(node.publicKey, node.publicKey, vault, 0),
+          lamports: rentExempt + LAMPORTS_PER_SOL / 10,
+        });
+        await provider.sendAndConfirm(createVoteTx, [voteAccount, node]);
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        const addVoteInstruction = (action: any) => program.methods
+          .addTypedInstruction({vote: {action}})
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId)[0],
+            creator: creator.publicKey,
+          })
+          .rpc();
+        try {
+          await addVoteInstruction({withdraw: {
+            voteAccount: voteAccount.publicKey,
+            destination: voteAccount.publicKey,
+            lamports: new BN(1),
+          }});
+          expect.fail("attached a withdrawal back into the vote account");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidVoteAction");
+        }
+
+        // only the balance above the rent exempt minimum can be withdrawn
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await addVoteInstruction({withdraw: {
+          voteAccount: voteAccount.publicKey,
+          destination,
+          lamports: new BN(LAMPORTS_PER_SOL / 10),
+        }});
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        const executedTx = await squads.executeTransaction(txState.publicKey);
+        expect(executedTx.status).to.have.property("executed");
+        expect(await provider.connection.getBalance(destination)).to.equal(LAMPORTS_PER_SOL / 10);
+        expect(await provider.connection.getBalance(voteAccount.publicKey)).to.equal(rentExempt);
+      });
+
+      it(`Approve and execute in durable nonce transactions`, async function(){
+        const payer = (creator as anchor.Wallet).payer;
+        const nonceAccount = anchor.web3.Keypair.generate();
+        const createNonceTx = new anchor.web3.Transaction().add(
+          ...SystemProgram.createNonceAccount({
+            fromPubkey: creator.publicKey,
+            noncePubkey: nonceAccount.publicKey,
+            authorizedPubkey: creator.publicKey,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(anchor.web3.NONCE_ACCOUNT_LENGTH),
+          }).instructions
+        );
+        await provider.sendAndConfirm(createNonceTx, [nonceAccount]);
+        // signs and sends the instructions with the current nonce instead of a recent blockhash,
+        // as a cold signer would have prepared them ahead of time
+        const sendWithNonce = async (instructions: anchor.web3.TransactionInstruction[]) => {
+          const nonceInfo = await provider.connection.getNonce(nonceAccount.publicKey);
+          const nonceTx = new anchor.web3.Transaction({
+            feePayer: creator.publicKey,
+            nonceInfo: {
+              nonce: nonceInfo.nonce,
+              nonceInstruction: SystemProgram.nonceAdvance({
+                noncePubkey: nonceAccount.publicKey,
+                authorizedPubkey: creator.publicKey,
+              }),
+            },
+          }).add(...instructions);
+          nonceTx.sign(payer);
+          const signature = await provider.connection.sendRawTransaction(nonceTx.serialize());
+          await provider.connection.confirmTransaction(signature, "confirmed");
+        };
+
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        await squads.activateTransaction(txState.publicKey);
+        await sendWithNonce([await squads.buildApproveTransaction(msPDA, txState.publicKey)]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executeReady");
+        await sendWithNonce([await squads.buildExecuteTransaction(txState.publicKey)]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executed");
+
+        // a signed vote only needs the ed25519 instruction directly before it
+        const signedTxState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(signedTxState.publicKey);
+        const ed25519Ix = anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: payer.secretKey,
+          message: signedVoteMessage(msPDA, signedTxState.transactionIndex, signedTxState.voteSeq, true),
+        });
+        const submitIx = await program.methods
+          .submitSignedVote(creator.publicKey, true)
+          .accounts({
+            multisig: msPDA,
+            transaction: signedTxState.publicKey,
+            relayer: creator.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .instruction();
+        await sendWithNonce([ed25519Ix, submitIx]);
+        expect((await squads.getTransaction(signedTxState.publicKey)).status).to.have.property("executeReady");
+      });
+
+      it(`Have a separate fee payer pay for approving and executing`, async function(){
+        // ie. an ops bot paying the fees, while the member only signs
+        const feePayer = anchor.web3.Keypair.generate();
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: feePayer.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        await squads.activateTransaction(txState.publicKey);
+
+        const memberBalance = await provider.connection.getBalance(creator.publicKey);
+        const feePayerBalance = await provider.connection.getBalance(feePayer.publicKey);
+        const paidTx = new anchor.web3.Transaction({feePayer: feePayer.publicKey}).add(
+          await squads.buildApproveTransaction(msPDA, txState.publicKey),
+          await squads.buildExecuteTransaction(txState.publicKey, feePayer.publicKey, creator.publicKey),
+        );
+        await provider.sendAndConfirm(paidTx, [feePayer]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executed");
+        expect(await provider.connection.getBalance(creator.publicKey)).to.equal(memberBalance);
+        expect(await provider.connection.getBalance(feePayer.publicKey)).to.be.lessThan(feePayerBalance);
+      });
+
+      it(`Let a keeper that isn't a member execute approved transactions`, async function(){
+        const keeper = anchor.web3.Keypair.generate();
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: keeper.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const setExecutors = async (anyExecutor: boolean, executors: anchor.web3.PublicKey[]) => {
+          const setExecutorsIx = await program.methods
+            .setExecutors(anyExecutor, executors)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([{
+              pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+              isSigner: false,
+              isWritable: true,
+            }])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(setExecutorsIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          await squads.executeTransaction(txPDA);
+        };
+        const createApprovedTx = async () => {
+          const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+          const txState = await squads.createTransaction(msPDA, 1);
+          await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+            fromPubkey: vault,
+            toPubkey: creator.publicKey,
+            lamports: 0,
+          }));
+          await squads.activateTransaction(txState.publicKey);
+          await squads.approveTransaction(txState.publicKey);
+          return txState.publicKey;
+        };
+
+        const txPDA = await createApprovedTx();
+        try {
+          await squads.executeTransaction(txPDA, keeper.publicKey, [keeper]);
+          expect.fail("executed with a key that isn't a member or executor");
+        } catch (e) {
+          expect(e.message).to.contain("KeyNotInMultisig");
+        }
+
+        await setExecutors(false, [keeper.publicKey]);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.executors.map((k) => k.toBase58())).to.deep.equal([keeper.publicKey.toBase58()]);
+        const executedTx = await squads.executeTransaction(txPDA, keeper.publicKey, [keeper]);
+        expect(executedTx.status).to.have.property("executed");
+
+        await setExecutors(false, []);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.executors.length).to.equal(0);
+        expect(msState.anyExecutor).to.be.false;
+      });
+
+      it(`Execution hook aborts executions unless the hook program is passed`, async function(){
+        const hook = anchor.web3.Keypair.generate().publicKey;
+        const setExecutionHook = async (executionHook: anchor.web3.PublicKey | null) => {
+          const setExecutionHookIx = await program.methods
+            .setExecutionHook(executionHook)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([{
+              pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+              isSigner: false,
+              isWritable: true,
+            }])
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(setExecutionHookIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          await squads.executeTransaction(txPDA);
+        };
+
+        await setExecutionHook(hook);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.executionHook.toBase58()).to.equal(hook.toBase58());
+
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed without invoking the execution hook");
+        } catch (e) {
+          expect(e.message).to.contain("MissingExecutionHook");
+        }
+
+        // internal transactions skip the hook, so it can always be removed
+        await setExecutionHook(null);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.executionHook).to.be.null;
+        const executedTx = await squads.executeTransaction(txState.publicKey);
+        expect(executedTx.status).to.have.property("executed");
+      });
+
+      it(`Policy program must be consulted on vault transactions`, async function(){
+        const policyProgram = anchor.web3.Keypair.generate().publicKey;
+        const [policyConfigPDA] = await anchor.web3.PublicKey.findProgramAddress([
+          anchor.utils.bytes.utf8.encode("squad"),
+          msPDA.toBuffer(),
+          anchor.utils.bytes.utf8.encode("policyconfig"),
+        ], squads.multisigProgramId);
+        const executeInternal = async (ix: anchor.web3.TransactionInstruction) => {
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(ix)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          const txState = await squads.executeTransaction(txPDA);
+          expect(txState.status).to.have.property("executed");
+        };
+
+        // consulted on activate, approve and execute
+        await executeInternal(await program.methods
+          .addPolicy(policyProgram, 0b111)
+          .accounts({
+            multisig: msPDA,
+            policyConfig: policyConfigPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction());
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.policyEnabled).to.be.true;
+        const policyConfig = await program.account.policyConfig.fetch(policyConfigPDA);
+        expect(policyConfig.policyProgram.toBase58()).to.equal(policyProgram.toBase58());
+        expect(policyConfig.stages).to.equal(0b111);
+
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        try {
+          await squads.activateTransaction(txState.publicKey);
+          expect.fail("activated without consulting the policy program");
+        } catch (e) {
+          expect(e.message).to.contain("MissingPolicy");
+        }
+
+        // internal transactions aren't checked, so the policy can always be removed
+        await executeInternal(await program.methods
+          .removePolicy()
+          .accounts({
+            multisig: msPDA,
+            policyConfig: policyConfigPDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction());
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.policyEnabled).to.be.false;
+        const activatedTx = await squads.activateTransaction(txState.publicKey);
+        expect(activatedTx.status).to.have.property("active");
+      });
+
+      it(`Activity log records the recent transaction lifecycle`, async function(){
+        const [activityLogPDA] = getActivityLogPDA(msPDA, squads.multisigProgramId);
+        const executeInternal = async (ix: anchor.web3.TransactionInstruction) => {
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(ix)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          const txState = await squads.executeTransaction(txPDA);
+          expect(txState.status).to.have.property("executed");
+        };
+
+        await executeInternal(await program.methods
+          .createActivityLog()
+          .accounts({
+            multisig: msPDA,
+            activityLog: activityLogPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction());
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.activityLogEnabled).to.be.true;
+        // the execution creating the log is the first entry
+        let activityLog = await program.account.msActivityLog.fetch(activityLogPDA);
+        expect(activityLog.entries.length).to.equal(1);
+        expect(activityLog.entries[0].action).to.have.property("execute");
+        expect(activityLog.entries[0].transactionIndex).to.equal(msState.transactionIndex);
+
+        // the sdk passes the log along once it's enabled
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        await squads.executeTransaction(txState.publicKey);
+
+        activityLog = await program.account.msActivityLog.fetch(activityLogPDA);
+        const entries = activityLog.entries.slice(1);
+        expect(entries.map((e) => Object.keys(e.action)[0])).to.deep.equal(["create", "activate", "approve", "execute"]);
+        entries.forEach((e) => {
+          expect(e.actor.toBase58()).to.equal(creator.publicKey.toBase58());
+          expect(e.transactionIndex).to.equal(txState.transactionIndex);
+        });
+
+        try {
+          await program.methods
+            .createTransaction(1)
+            .accounts({
+              multisig: msPDA,
+              transaction: getTxPDA(msPDA, new BN(txState.transactionIndex + 1), squads.multisigProgramId)[0],
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("created a transaction without recording it");
+        } catch (e) {
+          expect(e.message).to.contain("MissingActivityLog");
+        }
+
+        await executeInternal(await program.methods
+          .closeActivityLog()
+          .accounts({
+            multisig: msPDA,
+            activityLog: activityLogPDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction());
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.activityLogEnabled).to.be.false;
+        expect(await squads.connection.getAccountInfo(activityLogPDA)).to.be.null;
+      });
+
+      it(`Sequence number advances on every change of the multisig or its transactions`, async function(){
+        const seqs: number[] = [];
+        const listener = program.addEventListener("SeqAdvanced", (e) => {
+          if (e.multisig.toBase58() === msPDA.toBase58()) {
+            seqs.push(e.seq.toNumber());
+          }
+        });
+        const startSeq = (await squads.getMultisig(msPDA)).seq.toNumber();
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        await squads.executeTransaction(txState.publicKey);
+        // give the log subscription time to catch up
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        await program.removeEventListener(listener);
+
+        expect(seqs).to.deep.equal([startSeq + 1, startSeq + 2, startSeq + 3, startSeq + 4]);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.seq.toNumber()).to.equal(startSeq + 4);
+      });
+
+      it(`Sequence number advances once for a combined member and threshold change`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [seqMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        await squads.createMultisig(1, createKey, [creator.publicKey]);
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(seqMsPDA, 1),
+          isSigner: false,
+          isWritable: true,
+        };
+        const countSeqs = async (ix: anchor.web3.TransactionInstruction) => {
+          const seqs: number[] = [];
+          const listener = program.addEventListener("SeqAdvanced", (e) => {
+            if (e.multisig.toBase58() === seqMsPDA.toBase58()) {
+              seqs.push(e.seq.toNumber());
+            }
+          });
+          const startSeq = (await squads.getMultisig(seqMsPDA)).seq.toNumber();
+          await executeConfig(squads, seqMsPDA, [ix]);
+          // give the log subscription time to catch up
+          await new Promise((resolve) => setTimeout(resolve, 2000));
+          await program.removeEventListener(listener);
+          // every event carries the next seq, none is emitted twice
+          expect(seqs).to.deep.equal(seqs.map((_, i) => startSeq + i + 1));
+          expect((await squads.getMultisig(seqMsPDA)).seq.toNumber()).to.equal(startSeq + seqs.length);
+          return seqs.length;
+        };
+
+        const single = await countSeqs(await program.methods
+          .changeThreshold(1)
+          .accounts({
+            multisig: seqMsPDA,
+          })
+          .instruction());
+        expect(await countSeqs(await program.methods
+          .addMemberAndChangeThreshold(newMember, 1)
+          .accounts({
+            multisig: seqMsPDA,
+          })
+          .remainingAccounts([vaultAccount])
+          .instruction())).to.equal(single);
+        expect(await countSeqs(await program.methods
+          .removeMemberAndChangeThreshold(newMember, 1)
+          .accounts({
+            multisig: seqMsPDA,
+          })
+          .instruction())).to.equal(single);
+        const msState = await squads.getMultisig(seqMsPDA);
+        expect(msState.keys.length).to.equal(1);
+        expect(msState.threshold).to.equal(1);
+      });
+
+      it(`Tag transactions and filter them by category`, async function(){
+        const payroll = [...Buffer.from("PAYR")];
+        const txState = await squads.createTransaction(msPDA, 1, {category: payroll, reference: 202610});
+        expect(txState.tag.category).to.deep.equal(payroll);
+        expect(txState.tag.reference).to.equal(202610);
+        const untaggedTx = await squads.createTransaction(msPDA, 1);
+        expect(untaggedTx.tag.category).to.deep.equal([0, 0, 0, 0]);
+
+        const tagged = await squads.getTransactionsByTag(msPDA, Buffer.from(payroll));
+        expect(tagged.map((t) => t.publicKey.toBase58())).to.deep.equal([txState.publicKey.toBase58()]);
+
+        // the tag is fixed once the transaction is activated
+        await squads.activateTransaction(txState.publicKey);
+        try {
+          await program.methods
+            .setTransactionTag({category: [...Buffer.from("GRNT")], reference: 1})
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("changed the tag of an active transaction");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidTransactionState");
+        }
+      });
+
+      it(`Record the reason of a rejection with the vote`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const reason = Buffer.from("recipient not on the approved vendor list");
+        const rejectedTx = await squads.rejectTransaction(txState.publicKey, reason);
+        expect(rejectedTx.rejected.some((b) => b !== 0)).to.be.true;
+
+        const [rejectMemoPDA] = getVoteMemoPDA(txState.publicKey, creator.publicKey, 1, squads.multisigProgramId);
+        const voteMemo = await program.account.voteMemo.fetch(rejectMemoPDA);
+        expect(voteMemo.member.toBase58()).to.equal(creator.publicKey.toBase58());
+        expect(voteMemo.vote).to.have.property("reject");
+        expect(voteMemo.transactionIndex).to.equal(txState.transactionIndex);
+        expect(Buffer.from(voteMemo.memo).toString()).to.equal(reason.toString());
+
+        // the memo must be about a vote the member cast
+        const [approveMemoPDA] = getVoteMemoPDA(txState.publicKey, creator.publicKey, 0, squads.multisigProgramId);
+        try {
+          await program.methods
+            .addVoteMemo({approve: {}}, Buffer.from("lgtm"))
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              voteMemo: approveMemoPDA,
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("recorded a memo for a vote that wasn't cast");
+        } catch (e) {
+          expect(e.message).to.contain("VoteNotCast");
+        }
+      });
+
+      it(`Transactions are only decided once the min quorum has voted`, async function(){
+        const keys = ((await squads.getMultisig(msPDA)).keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+        const secondMember = memberList.find((m) => keys.includes(m.publicKey.toBase58()));
+        const approveBySecondMember = (txPDA: anchor.web3.PublicKey) => program.methods
+          .approveTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            member: secondMember.publicKey,
+          })
+          .signers([secondMember])
+          .rpc();
+        const changeMinQuorum = async (minQuorum: number) => {
+          const changeMinQuorumIx = await program.methods
+            .changeMinQuorum(minQuorum)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeMinQuorumIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          if ((await squads.getTransaction(txPDA)).status.active) {
+            await approveBySecondMember(txPDA);
+          }
+          await squads.executeTransaction(txPDA);
+        };
+
+        await changeMinQuorum(2);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.minQuorum).to.equal(2);
+
+        // the approval reaches the threshold, but a second member has to vote
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        let votedTx = await squads.approveTransaction(txState.publicKey);
+        expect(votedTx.status).to.have.property("active");
+        // abstentions count towards the quorum
+        await program.methods
+          .abstainTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: secondMember.publicKey,
+          })
+          .signers([secondMember])
+          .rpc();
+        votedTx = await squads.getTransaction(txState.publicKey);
+        expect(votedTx.status).to.have.property("executeReady");
+
+        // config transactions wait for the quorum as well
+        msState = await squads.getMultisig(msPDA);
+        const configTxPDA = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(msState.transactionIndex + 1).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("configtransaction"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        await program.methods
+          .createConfigTransaction([{ changeConfigThreshold: { configThreshold: msState.configThreshold } }])
+          .accounts({
+            multisig: msPDA,
+            transaction: configTxPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await program.methods
+          .approveConfigTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: configTxPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+        let configTxState = await program.account.msConfigTransaction.fetch(configTxPDA);
+        expect(configTxState.status).to.have.property("active");
+        await program.methods
+          .approveConfigTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: configTxPDA,
+            member: secondMember.publicKey,
+          })
+          .signers([secondMember])
+          .rpc();
+        configTxState = await program.account.msConfigTransaction.fetch(configTxPDA);
+        expect(configTxState.status).to.have.property("executeReady");
+        await program.methods
+          .executeConfigTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: configTxPDA,
+            member: creator.publicKey,
+            payer: creator.publicKey,
+          })
+          .rpc();
+
+        try {
+          await changeMinQuorum(keys.length + 1);
+          expect.fail("set a quorum the members can't reach");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidQuorum");
+        }
+
+        // a removal doesn't lower the quorum to the voters left, it fails instead
+        try {
+          await executeConfig(squads, msPDA, [
+            await program.methods
+              .changeMinQuorum(keys.length)
+              .accounts({
+                multisig: msPDA,
+              })
+              .instruction(),
+            await program.methods
+              .removeMember(secondMember.publicKey)
+              .accounts({
+                multisig: msPDA,
+              })
+              .instruction(),
+          ], async (txPDA) => {
+            await squads.approveTransaction(txPDA);
+            await approveBySecondMember(txPDA);
+          });
+          expect.fail("removed a member the quorum needs");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidQuorum");
+        }
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.minQuorum).to.equal(2);
+
+        await changeMinQuorum(0);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.minQuorum).to.equal(0);
+      });
+
+      it(`Percentage threshold is recomputed when members change`, async function(){
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        const [vaultPDA] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const totalWeight = (ms) => ms.keys.length + ms.memberWeights.reduce((sum, w) => sum + w.weight - 1, 0);
+        // a percentage threshold can take more than one approval
+        const approveByMembers = async (txPDA: anchor.web3.PublicKey) => {
+          await squads.approveTransaction(txPDA);
+          const keys = ((await squads.getMultisig(msPDA)).keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+          for (const member of memberList.filter((m) => keys.includes(m.publicKey.toBase58()))) {
+            if (!(await squads.getTransaction(txPDA)).status.active) {
+              break;
+            }
+            await program.methods
+              .approveTransaction()
+              .accounts({
+                multisig: msPDA,
+                transaction: txPDA,
+                member: member.publicKey,
+              })
+              .signers([member])
+              .rpc();
+          }
+        };
+
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .changeThresholdPercent(60)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+          await program.methods
+            .addMember(newMember)
+            .accounts({
+              multisig: msPDA,
+            })
+            .remainingAccounts([{
+              pubkey: vaultPDA,
+              isSigner: false,
+              isWritable: true,
+            }])
+            .instruction(),
+        ], approveByMembers);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.thresholdPercent).to.equal(60);
+        expect(msState.threshold).to.equal(Math.ceil(totalWeight(msState) * 60 / 100));
+
+        // an absolute threshold replaces the percentage
+        await executeConfig(squads, msPDA, [
+          await program.methods
+            .removeMember(newMember)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+          await program.methods
+            .changeThreshold(1)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction(),
+        ], approveByMembers);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.thresholdPercent).to.equal(0);
+        expect(msState.threshold).to.equal(1);
+      });
+
+      it(`Cap the open transactions of a creator`, async function(){
+        const changeMaxOpenTransactions = async (maxOpenTransactions: number) => {
+          const changeMaxOpenTransactionsIx = await program.methods
+            .changeMaxOpenTransactions(maxOpenTransactions)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeMaxOpenTransactionsIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          await squads.executeTransaction(txPDA);
+        };
+
+        await changeMaxOpenTransactions(2);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.maxOpenTransactions).to.equal(2);
+
+        const firstTx = await squads.createTransaction(msPDA, 1);
+        await squads.createTransaction(msPDA, 1);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.openTransactions.length).to.equal(1);
+        expect(msState.openTransactions[0].creator.toBase58()).to.equal(creator.publicKey.toBase58());
+        expect(msState.openTransactions[0].count).to.equal(2);
+        try {
+          await squads.createTransaction(msPDA, 1);
+          expect.fail("created more than the max open transactions");
+        } catch (e) {
+          expect(e.message).to.contain("MaxOpenTransactionsReached");
+        }
+
+        // config transactions count against the cap as well
+        const configTxPDA = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            new BN(msState.transactionIndex + 1).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("configtransaction"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        try {
+          await program.methods
+            .createConfigTransaction([{ changeThreshold: { threshold: 1 } }])
+            .accounts({
+              multisig: msPDA,
+              transaction: configTxPDA,
+              creator: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("created a config transaction over the max open transactions");
+        } catch (e) {
+          expect(e.message).to.contain("MaxOpenTransactionsReached");
+        }
+
+        // an approved transaction is still open until it's executed, which frees up a slot
+        await squads.activateTransaction(firstTx.publicKey);
+        await squads.approveTransaction(firstTx.publicKey);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.openTransactions[0].count).to.equal(2);
+        await squads.executeTransaction(firstTx.publicKey);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.openTransactions[0].count).to.equal(1);
+        await squads.createTransaction(msPDA, 1);
+
+        // removing the cap deprecates the open transactions, which are no longer counted
+        await changeMaxOpenTransactions(0);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.maxOpenTransactions).to.equal(0);
+        expect(msState.openTransactions.length).to.equal(0);
+      });
+
+      it(`Creator closes their draft tx and its instructions`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const txState = await squads.createTransaction(msPDA, 1);
+        const ixState = await squads.addInstruction(
+          txState.publicKey,
+          await createTestTransferTransaction(vaultPDA, anchor.web3.Keypair.generate().publicKey, LAMPORTS_PER_SOL / 100)
+        );
+        const closeDraft = (remainingAccounts: anchor.web3.AccountMeta[]) => program.methods
+          .closeDraftTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(remainingAccounts)
+          .rpc();
+
+        // every instruction account has to be closed along with the transaction
+        try {
+          await closeDraft([]);
+          expect.fail("closed the draft without its instructions");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidNumberOfAccounts");
+        }
+
+        const creatorBalance = await squads.connection.getBalance(creator.publicKey);
+        await closeDraft([{
+          pubkey: ixState.publicKey,
+          isSigner: false,
+          isWritable: true,
+        }]);
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.be.null;
+        expect(await squads.connection.getAccountInfo(ixState.publicKey)).to.be.null;
+        const postCreatorBalance = await squads.connection.getBalance(creator.publicKey);
+        expect(postCreatorBalance).to.be.greaterThan(creatorBalance);
+      });
+
+      it(`Anyone closes a tx deprecated by a change of the multisig`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const closeStale = () => program.methods
+          .closeStaleTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+
+        try {
+          await closeStale();
+          expect.fail("closed a transaction that can still be voted on");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidTransactionState");
+        }
+
+        // any config change deprecates the active transactions
+        const msState = await squads.getMultisig(msPDA);
+        const changeThresholdIx = await program.methods
+          .changeThreshold(msState.threshold)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, configTxPDA] = await txBuilder
+          .withInstruction(changeThresholdIx)
+          .executeInstructions();
+        await squads.activateTransaction(configTxPDA);
+        await squads.approveTransaction(configTxPDA);
+        await squads.executeTransaction(configTxPDA);
+
+        await closeStale();
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.be.null;
+      });
+
+      it(`Invalidate a single pending tx without deprecating the others`, async function(){
+        const badTx = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(badTx.publicKey);
+        const otherTx = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(otherTx.publicKey);
+
+        const invalidateTransactionIx = await program.methods
+          .invalidateTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: badTx.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [txInstructions, txPDA] = await txBuilder
+          .withInstruction(invalidateTransactionIx)
+          .executeInstructions();
+        await squads.activateTransaction(txPDA);
+        await squads.approveTransaction(txPDA);
+        await squads.executeTransaction(txPDA);
+
+        const badTxState = await squads.getTransaction(badTx.publicKey);
+        expect(badTxState.status).to.have.property("cancelled");
+        // the other proposal can still be voted on
+        const otherTxState = await squads.approveTransaction(otherTx.publicKey);
+        expect(otherTxState.status).to.have.property("executeReady");
+      });
+
+      it(`Co-creators attach instructions to a draft`, async function(){
+        const keys = ((await squads.getMultisig(msPDA)).keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+        const coCreator = memberList.find((m) => keys.includes(m.publicKey.toBase58()));
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const txState = await squads.createTransaction(msPDA, 1);
+        const addInstructionByCoCreator = async () => {
+          const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+          await program.methods
+            .addInstruction(await createTestTransferTransaction(vaultPDA, coCreator.publicKey))
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              instruction: ixPDA,
+              creator: coCreator.publicKey,
+            })
+            .signers([coCreator])
+            .rpc();
+        };
+
+        try {
+          await addInstructionByCoCreator();
+          expect.fail("attached an instruction to someone else's draft");
+        } catch (e) {
+          expect(e.message).to.contain("ConstraintRaw");
+        }
+
+        await program.methods
+          .setCoCreators([coCreator.publicKey])
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        let updatedTx = await squads.getTransaction(txState.publicKey);
+        expect(updatedTx.coCreators.map((k) => k.toBase58())).to.deep.equal([coCreator.publicKey.toBase58()]);
+
+        await addInstructionByCoCreator();
+        updatedTx = await squads.getTransaction(txState.publicKey);
+        expect(updatedTx.instructionIndex).to.equal(1);
+      });
+
+      it(`Approve and execute a tx in the same instruction`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.addInstruction(txState.publicKey, SystemProgram.transfer({
+          fromPubkey: vault,
+          toPubkey: creator.publicKey,
+          lamports: 0,
+        }));
+        await squads.activateTransaction(txState.publicKey);
+
+        const executedTx = await squads.approveAndExecuteTransaction(txState.publicKey);
+        expect(executedTx.status).to.have.property("executed");
+        const msState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(executedTx.approved, msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58()))
+          .to.deep.equal([creator.publicKey.toBase58()]);
+      });
+
+      it(`Create, fill and activate a tx in the same instruction`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const txState = await squads.createAndActivateTransaction(msPDA, 1, [
+          SystemProgram.transfer({fromPubkey: vault, toPubkey: creator.publicKey, lamports: 0}),
+          SystemProgram.transfer({fromPubkey: vault, toPubkey: creator.publicKey, lamports: 0}),
+        ]);
+        expect(txState.status).to.have.property("active");
+        expect(txState.instructionIndex).to.equal(2);
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(2), squads.multisigProgramId);
+        const ixState = await squads.getInstruction(ixPDA);
+        expect(ixState.programId.toBase58()).to.equal(SystemProgram.programId.toBase58());
+      });
+
+      it(`Relay the signed votes of several members at once`, async function(){
+        const keys = ((await squads.getMultisig(msPDA)).keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+        const secondMember = memberList.find((m) => keys.includes(m.publicKey.toBase58()));
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const signVote = (signer: anchor.web3.Keypair, voteSeq: number) => anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signer.secretKey,
+          message: signedVoteMessage(msPDA, txState.transactionIndex, voteSeq, true),
+        });
+        const submitVotes = (members: anchor.web3.PublicKey[], ed25519Ixs: anchor.web3.TransactionInstruction[]) => program.methods
+          .submitSignedVotes(members, true)
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            relayer: creator.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .preInstructions(ed25519Ixs)
+          .rpc();
+        const signers = [secondMember, (creator as anchor.Wallet).payer];
+
+        // the second member signs before the creator votes, so that signature goes stale
+        const staleIx = signVote(secondMember, txState.voteSeq);
+        await program.methods
+          .abstainTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: creator.publicKey,
+          })
+          .rpc();
+        const voteSeq = (await squads.getTransaction(txState.publicKey)).voteSeq;
+        expect(voteSeq).to.equal(txState.voteSeq + 1);
+
+        // the members must be in the order of the signatures
+        try {
+          await submitVotes([creator.publicKey, secondMember.publicKey], signers.map((signer) => signVote(signer, voteSeq)));
+          expect.fail("votes in the wrong order should be rejected");
+        } catch (e) {
+          expect(e.message).to.include("InvalidSignedVote");
+        }
+        // and a single stale signature fails the whole batch
+        try {
+          await submitVotes(signers.map((signer) => signer.publicKey), [staleIx, signVote(signers[1], voteSeq)]);
+          expect.fail("relayed a stale signed vote");
+        } catch (e) {
+          expect(e.message).to.include("InvalidSignedVote");
+        }
+        expect((await squads.getTransaction(txState.publicKey)).voteSeq).to.equal(voteSeq);
+        await submitVotes(signers.map((signer) => signer.publicKey), signers.map((signer) => signVote(signer, voteSeq)));
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        const postMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(postTxState.approved, postMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58()))
+          .to.include(secondMember.publicKey.toBase58());
+        expect(postTxState.status).to.have.property("executeReady");
+      });
+
+      it(`A parent multisig proposes into its child through CPI`, async function(){
+        const parentCreateKey = anchor.web3.Keypair.generate().publicKey;
+        const [parentPDA] = getMsPDA(parentCreateKey, squads.multisigProgramId);
+        await squads.createMultisig(1, parentCreateKey, [creator.publicKey]);
+        const parentVault = squads.getAuthorityPDA(parentPDA, 1);
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: parentVault,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+
+        const setParentIx = await program.methods
+          .setParent(parentVault)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [, setParentTxPDA] = await txBuilder
+          .withInstruction(setParentIx)
+          .executeInstructions();
+        await squads.activateTransaction(setParentTxPDA);
+        await squads.approveTransaction(setParentTxPDA);
+        await squads.executeTransaction(setParentTxPDA);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.parent.toBase58()).to.equal(parentVault.toBase58());
+
+        // the parent vault signs for the creation of the child transaction when the parent executes
+        const [childTxPDA] = getTxPDA(msPDA, new BN(msState.transactionIndex + 1), squads.multisigProgramId);
+        const proposeIx = await program.methods
+          .createTransaction(1)
+          .accounts({
+            multisig: msPDA,
+            transaction: childTxPDA,
+            creator: parentVault,
+          })
+          .remainingAccounts(msState.activityLogEnabled ? [{
+            pubkey: getActivityLogPDA(msPDA, squads.multisigProgramId)[0],
+            isSigner: false,
+            isWritable: true,
+          }] : [])
+          .instruction();
+        const parentTx = await squads.createTransaction(parentPDA, 1);
+        await squads.addInstruction(parentTx.publicKey, proposeIx);
+        await squads.activateTransaction(parentTx.publicKey);
+        await squads.approveTransaction(parentTx.publicKey);
+        await squads.executeTransaction(parentTx.publicKey);
+
+        const childTx = await squads.getTransaction(childTxPDA);
+        expect(childTx.creator.toBase58()).to.equal(parentVault.toBase58());
+        expect(childTx.status).to.have.property("draft");
+      });
+
+      it(`Attach SPL Governance instructions built from typed actions`, async function(){
+        // realms run their own deployment of the governance program
+        const governanceProgram = anchor.web3.Keypair.generate().publicKey;
+        const realm = anchor.web3.Keypair.generate().publicKey;
+        const councilMint = anchor.web3.Keypair.generate().publicKey;
+        const txState = await squads.createTransaction(msPDA, 1);
+        const addGovernanceInstruction = (action: any) => program.methods
+          .addTypedInstruction({governance: {governanceProgram, action}})
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId)[0],
+            creator: creator.publicKey,
+          })
+          .rpc();
+        try {
+          await addGovernanceInstruction({depositGoverningTokens: {
+            realm,
+            governingTokenMint: councilMint,
+            governingTokenSource: anchor.web3.Keypair.generate().publicKey,
+            amount: new BN(0),
+          }});
+          expect.fail("attached a deposit of zero tokens");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidGovernanceAction");
+        }
+
+        await addGovernanceInstruction({castVote: {
+          realm,
+          governance: anchor.web3.Keypair.generate().publicKey,
+          proposal: anchor.web3.Keypair.generate().publicKey,
+          proposalOwnerRecord: anchor.web3.Keypair.generate().publicKey,
+          governingTokenMint: councilMint,
+          approve: true,
+        }});
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const ixState = await squads.getInstruction(ixPDA);
+        expect(ixState.programId.toBase58()).to.equal(governanceProgram.toBase58());
+        // CastVote with an approval of the single choice (rank 0, 100%)
+        expect(Buffer.from(ixState.data).toString("hex")).to.equal("0d" + "00" + "01000000" + "0064");
+        const vault = squads.getAuthorityPDA(msPDA, 1);
+        expect(ixState.keys[5].pubkey.toBase58()).to.equal(vault.toBase58());
+        expect(ixState.keys[5].isSigner).to.be.true;
+      });
+
+      it(`Vote as the holder of a membership token or NFT`, async function(){
+        const payer = (creator as anchor.Wallet).payer;
+        const defaultVault = squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex);
+        const memberVoteRecordPDA = (txPDA: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            creator.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("vote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const approveWithRecord = (txPDA: anchor.web3.PublicKey) => program.methods
+          .createVoteRecord(true)
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            voteRecord: memberVoteRecordPDA(txPDA),
+            member: creator.publicKey,
+            payer: creator.publicKey,
+          })
+          .rpc();
+        const setMembershipGateIx = (membershipGate: any) => program.methods
+          .setMembershipGate(membershipGate)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: defaultVault,
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        await executeConfig(squads, msPDA, [await program.methods.setVoteRecords(true).accounts({ multisig: msPDA }).instruction()]);
+
+        const collection = anchor.web3.Keypair.generate().publicKey;
+        // the gate needs a token threshold
+        try {
+          await executeConfig(squads, msPDA, [await setMembershipGateIx({ collection: { collection, threshold: 0 } })], approveWithRecord);
+          expect.fail("set a membership gate without a token threshold");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidMembershipGate");
+        }
+        await executeConfig(squads, msPDA, [await setMembershipGateIx({ collection: { collection, threshold: 2 } })], approveWithRecord);
+        const gate = (await squads.getMultisig(msPDA)).membershipGate.collection;
+        expect(gate.collection.toBase58()).to.equal(collection.toBase58());
+        expect(gate.threshold).to.equal(2);
+
+        const holder = anchor.web3.Keypair.generate();
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: holder.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const mint = await createMint(provider.connection, payer, creator.publicKey, null, 0);
+        const holderTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, holder.publicKey);
+        await mintTo(provider.connection, payer, mint, holderTokenAccount.address, payer, 5);
+        const tokenVoteRecordPDA = (txPDA: anchor.web3.PublicKey, votingKey: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            votingKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("tokenvote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        // a fungible token without a verified collection doesn't give a vote
+        try {
+          await program.methods
+            .createTokenVoteRecord(mint, true)
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              voteRecord: tokenVoteRecordPDA(txState.publicKey, mint),
+              voter: holder.publicKey,
+              tokenAccount: holderTokenAccount.address,
+            })
+            .signers([holder])
+            .rpc();
+          expect.fail("voted without holding an NFT of the collection");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidMembershipToken");
+        }
+        const txAfter = await squads.getTransaction(txState.publicKey);
+        expect(txAfter.status).to.have.property("active");
+        expect(txAfter.tokenApproved).to.equal(0);
+
+        // holders of a non-transferable token of the mint vote once each
+        const membershipMint = anchor.web3.Keypair.generate();
+        const membershipMintLen = getMintLen([ExtensionType.NonTransferable]);
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: creator.publicKey,
+            newAccountPubkey: membershipMint.publicKey,
+            space: membershipMintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(membershipMintLen),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeNonTransferableMintInstruction(membershipMint.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeMintInstruction(membershipMint.publicKey, 0, creator.publicKey, null, TOKEN_2022_PROGRAM_ID),
+        ), [membershipMint]);
+        const holderMembershipAccount = await getOrCreateAssociatedTokenAccount(provider.connection, payer, membershipMint.publicKey, holder.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+        await mintTo(provider.connection, payer, membershipMint.publicKey, holderMembershipAccount.address, payer, 1, [], undefined, TOKEN_2022_PROGRAM_ID);
+        await executeConfig(squads, msPDA, [await setMembershipGateIx({ mint: { mint: membershipMint.publicKey, threshold: 1 } })], approveWithRecord);
+        expect((await squads.getMultisig(msPDA)).membershipGate.mint.mint.toBase58()).to.equal(membershipMint.publicKey.toBase58());
+        const voteAsHolder = (txPDA: anchor.web3.PublicKey) => program.methods
+          .createTokenVoteRecord(holder.publicKey, true)
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            voteRecord: tokenVoteRecordPDA(txPDA, holder.publicKey),
+            voter: holder.publicKey,
+            tokenAccount: holderMembershipAccount.address,
+          })
+          .signers([holder])
+          .rpc();
+
+        // token votes don't decide internal transactions
+        const internalTxBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [, internalTxPDA] = await internalTxBuilder
+          .withInstruction(await program.methods.setVoteRecords(true).accounts({ multisig: msPDA }).instruction())
+          .executeInstructions();
+        await squads.activateTransaction(internalTxPDA);
+        await voteAsHolder(internalTxPDA);
+        const internalTx = await squads.getTransaction(internalTxPDA);
+        expect(internalTx.tokenApproved).to.equal(1);
+        expect(internalTx.status).to.have.property("active");
+
+        // vault transactions need the token votes besides the members
+        const vaultTx = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(vaultTx.publicKey);
+        await approveWithRecord(vaultTx.publicKey);
+        expect((await squads.getTransaction(vaultTx.publicKey)).status).to.have.property("active");
+        await voteAsHolder(vaultTx.publicKey);
+        expect((await squads.getTransaction(vaultTx.publicKey)).status).to.have.property("executeReady");
+
+        await executeConfig(squads, msPDA, [await setMembershipGateIx(null)], approveWithRecord);
+        expect((await squads.getMultisig(msPDA)).membershipGate).to.be.null;
+        await executeConfig(squads, msPDA, [await program.methods.setVoteRecords(false).accounts({ multisig: msPDA }).instruction()], approveWithRecord);
+      });
+
+      it(`Vote with stake weights snapshotted at activation`, async function(){
+        const defaultVault = squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex);
+        const memberVoteRecordPDA = (txPDA: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            creator.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("vote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const stakeSnapshotPDA = (txPDA: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            anchor.utils.bytes.utf8.encode("stakesnapshot"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const voteWithRecord = (txPDA: anchor.web3.PublicKey, remainingAccounts: anchor.web3.AccountMeta[] = []) => program.methods
+          .createVoteRecord(true)
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            voteRecord: memberVoteRecordPDA(txPDA),
+            member: creator.publicKey,
+            payer: creator.publicKey,
+          })
+          .remainingAccounts(remainingAccounts)
+          .rpc();
+        const setStakeWeightSourceIx = (stakeWeightSource: any) => program.methods
+          .setStakeWeightSource(stakeWeightSource)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: defaultVault,
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        // internal transactions keep the member weights
+        await executeConfig(squads, msPDA, [await program.methods.setVoteRecords(true).accounts({ multisig: msPDA }).instruction()]);
+
+        // a staking program whose lockers are derived from ["Escrow", registrar, member]
+        const stakeWeightSource = {
+          program: TOKEN_PROGRAM_ID,
+          registrar: anchor.web3.Keypair.generate().publicKey,
+          seedPrefix: Array.from(Buffer.from("Escrow\0\0")),
+          amountOffset: 8,
+          amountPerVote: new BN(1_000_000),
+        };
+        await executeConfig(squads, msPDA, [await setStakeWeightSourceIx(stakeWeightSource)], (txPDA) => voteWithRecord(txPDA));
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.stakeWeightSource.registrar.toBase58()).to.equal(stakeWeightSource.registrar.toBase58());
+        const lockers = (msState.keys as anchor.web3.PublicKey[]).map((member) => ({
+          pubkey: anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("Escrow"), stakeWeightSource.registrar.toBuffer(), member.toBuffer()],
+            stakeWeightSource.program
+          )[0],
+          isSigner: false,
+          isWritable: false,
+        }));
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        try {
+          await squads.activateTransaction(txState.publicKey);
+          expect.fail("activated without a stake snapshot");
+        } catch (e) {
+          expect(e.message).to.contain("StakeSnapshotRequired");
+        }
+        const activateWithStakeSnapshot = (remainingAccounts: anchor.web3.AccountMeta[]) => program.methods
+          .activateWithStakeSnapshot()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            stakeSnapshot: stakeSnapshotPDA(txState.publicKey),
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(remainingAccounts)
+          .rpc();
+        // the locker of every member has to be passed
+        try {
+          await activateWithStakeSnapshot(lockers.slice(1));
+          expect.fail("activated without the locker of every member");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidStakeLocker");
+        }
+        await activateWithStakeSnapshot(lockers);
+        const stakeSnapshot = await program.account.stakeSnapshot.fetch(stakeSnapshotPDA(txState.publicKey));
+        expect(stakeSnapshot.weights.length).to.equal(msState.keys.length);
+        // members without a locker have no weight
+        expect(stakeSnapshot.weights.every((w) => w.weight === 0)).to.be.true;
+
+        // votes are weighted with the snapshot, which has to be passed
+        try {
+          await voteWithRecord(txState.publicKey);
+          expect.fail("voted without the stake snapshot");
+        } catch (e) {
+          expect(e.message).to.contain("StakeSnapshotRequired");
+        }
+        await voteWithRecord(txState.publicKey, [{
+          pubkey: stakeSnapshotPDA(txState.publicKey),
+          isSigner: false,
+          isWritable: false,
+        }]);
+        const votedTxState = await squads.getTransaction(txState.publicKey);
+        expect(votedTxState.status).to.have.property("active");
+        expect(votedTxState.approvedWeight).to.equal(0);
+
+        await executeConfig(squads, msPDA, [await setStakeWeightSourceIx(null)], (txPDA) => voteWithRecord(txPDA));
+        expect((await squads.getMultisig(msPDA)).stakeWeightSource).to.be.null;
+        // the snapshot can be closed once the transaction is deprecated
+        await program.methods
+          .closeStakeSnapshot()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            stakeSnapshot: stakeSnapshotPDA(txState.publicKey),
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await executeConfig(squads, msPDA, [await program.methods.setVoteRecords(false).accounts({ multisig: msPDA }).instruction()], (txPDA) => voteWithRecord(txPDA));
+      });
+
+      it(`Vote with an approve-only session key`, async function(){
+        const sessionKey = anchor.web3.Keypair.generate();
+        const [sessionPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            sessionKey.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("sessionkey"),
+          ],
+          squads.multisigProgramId
+        );
+        const approveAction = 1;
+        const expiresAtSlot = (await provider.connection.getSlot()) + 1000;
+        await program.methods
+          .addSessionKey(sessionKey.publicKey, approveAction, new BN(expiresAtSlot))
+          .accounts({
+            multisig: msPDA,
+            session: sessionPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+        const session = await program.account.sessionKey.fetch(sessionPDA);
+        expect(session.member.toBase58()).to.equal(creator.publicKey.toBase58());
+        expect(session.actions).to.equal(approveAction);
+
+        const voteWithSession = (transaction: anchor.web3.PublicKey, approve: boolean) => program.methods
+          .submitSessionVote(approve)
+          .accounts({
+            multisig: msPDA,
+            transaction,
+            session: sessionPDA,
+            sessionKey: sessionKey.publicKey,
+          })
+          .signers([sessionKey])
+          .rpc();
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        // the session can only approve
+        try {
+          await voteWithSession(txState.publicKey, false);
+          expect.fail("rejected with an approve-only session key");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidSessionKey");
+        }
+        await voteWithSession(txState.publicKey, true);
+        const postTxState = await squads.getTransaction(txState.publicKey);
+        // the vote counts as the member's own
+        const postMsState = await squads.getMultisig(msPDA);
+        expect(bitmapMembers(postTxState.approved, postMsState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58())).to.include(creator.publicKey.toBase58());
+        expect(postTxState.status).to.have.property("executeReady");
+
+        await program.methods
+          .removeSessionKey()
+          .accounts({
+            multisig: msPDA,
+            session: sessionPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+        expect(await provider.connection.getAccountInfo(sessionPDA)).to.be.null;
+      });
+
+      it(`Tier the threshold by the transferred value`, async function(){
+        const msState = await squads.getMultisig(msPDA);
+        const vaultPDA = squads.getAuthorityPDA(msPDA, msState.defaultVaultIndex);
+        const setValueTiersIx = (valueTiers: any[]) => program.methods
+          .setValueTiers(valueTiers)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: vaultPDA,
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        // 1 approval below 100 SOL, the full threshold from 100 SOL
+        // internal transactions aren't tiered
+        await executeConfig(squads, msPDA, [await setValueTiersIx([
+          { mint: anchor.web3.PublicKey.default, minAmount: new BN(0), threshold: 1 },
+          { mint: anchor.web3.PublicKey.default, minAmount: new BN(100 * LAMPORTS_PER_SOL), threshold: msState.threshold },
+        ])]);
+        expect((await squads.getMultisig(msPDA)).valueTiers.length).to.equal(2);
+
+        const createTransfer = async (lamports: number) => {
+          const txState = await squads.createTransaction(msPDA, msState.defaultVaultIndex);
+          const ixState = await squads.addInstruction(
+            txState.publicKey,
+            SystemProgram.transfer({ fromPubkey: vaultPDA, toPubkey: creator.publicKey, lamports })
+          );
+          return [txState.publicKey, ixState.publicKey];
+        };
+        const activateWithInstructions = (txPDA: anchor.web3.PublicKey, instructions: anchor.web3.PublicKey[]) => program.methods
+          .activateTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txPDA,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(instructions.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+          .rpc();
+
+        const [smallTxPDA, smallIxPDA] = await createTransfer(LAMPORTS_PER_SOL / 10);
+        // the attached instructions have to be passed to activate it
+        try {
+          await activateWithInstructions(smallTxPDA, []);
+          expect.fail("activated without the instruction accounts");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidInstructionAccount");
+        }
+        await activateWithInstructions(smallTxPDA, [smallIxPDA]);
+        expect((await squads.getTransaction(smallTxPDA)).threshold).to.equal(1);
+
+        const [largeTxPDA, largeIxPDA] = await createTransfer(200 * LAMPORTS_PER_SOL);
+        await activateWithInstructions(largeTxPDA, [largeIxPDA]);
+        expect((await squads.getTransaction(largeTxPDA)).threshold).to.equal(msState.threshold);
+
+        await executeConfig(squads, msPDA, [await setValueTiersIx([])]);
+        expect((await squads.getMultisig(msPDA)).valueTiers).to.be.empty;
+      });
+
+      it(`Hold the execution on an oracle price condition`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        const priceAccount = anchor.web3.Keypair.generate().publicKey;
+        await program.methods
+          .setExecutionCondition({
+            oracle: { pyth: {} },
+            priceAccount,
+            comparison: { above: {} },
+            price: new BN(100),
+            expo: 0,
+            maxAge: 60,
+          })
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        const conditionedTxState = await squads.getTransaction(txState.publicKey);
+        expect(conditionedTxState.executionCondition.priceAccount.toBase58()).to.equal(priceAccount.toBase58());
+
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        // the price account has to be passed, and be an actual oracle price
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed without checking the execution condition");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidPriceAccount");
+        }
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executeReady");
+      });
+
+      it(`Block executions during a blackout period`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex);
+        const setBlackoutPeriodsIx = (blackoutPeriods: any[]) => program.methods
+          .setBlackoutPeriods(blackoutPeriods)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([{
+            pubkey: vaultPDA,
+            isSigner: false,
+            isWritable: true,
+          }])
+          .instruction();
+        const now = Math.floor(Date.now() / 1000);
+        // internal transactions aren't blocked, so the blackout can be lifted
+        await executeConfig(squads, msPDA, [await setBlackoutPeriodsIx([{ start: new BN(now - 3600), end: new BN(now + 3600) }])]);
+        expect((await squads.getMultisig(msPDA)).blackoutPeriods.length).to.equal(1);
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed during a blackout period");
+        } catch (e) {
+          expect(e.message).to.contain("OutsideExecutionWindow");
+        }
+
+        await executeConfig(squads, msPDA, [await setBlackoutPeriodsIx([])]);
+        await squads.executeTransaction(txState.publicKey);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executed");
+      });
+      it(`Enforce a config time lock on internal transactions only`, async function(){
+        const configTimeLock = 2;
+        const waitForTimeLock = () => new Promise((resolve) => setTimeout(resolve, (configTimeLock + 1) * 1000));
+        const setConfigTimeLock = async (seconds: number) => {
+          const changeConfigTimeLockIx = await program.methods
+            .changeConfigTimeLock(seconds)
+            .accounts({
+              multisig: msPDA,
+            })
+            .instruction();
+          const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+          const [txInstructions, txPDA] = await txBuilder
+            .withInstruction(changeConfigTimeLockIx)
+            .executeInstructions();
+          await squads.activateTransaction(txPDA);
+          await squads.approveTransaction(txPDA);
+          return txPDA;
+        };
+
+        let configTxPDA = await setConfigTimeLock(configTimeLock);
+        await squads.executeTransaction(configTxPDA);
+        let msState = await squads.getMultisig(msPDA);
+        expect(msState.configTimeLock).to.equal(configTimeLock);
+
+        // vault transactions are not delayed by the config time lock
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        const executedTxState = await squads.executeTransaction(txState.publicKey);
+        expect(executedTxState.status).to.have.property("executed");
+
+        // removing the config time lock is itself delayed
+        configTxPDA = await setConfigTimeLock(0);
+        try {
+          await squads.executeTransaction(configTxPDA);
+          expect.fail("executed a config change before the config time lock elapsed");
+        } catch (e) {
+          expect(e.message).to.contain("TimeLockNotElapsed");
+        }
+        await waitForTimeLock();
+        await squads.executeTransaction(configTxPDA);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.configTimeLock).to.equal(0);
+      });
+      it(`A temporary admin applies config changes until it renounces`, async function(){
+        const adminCreateKey = anchor.web3.Keypair.generate().publicKey;
+        const [adminMsPDA] = getMsPDA(adminCreateKey, squads.multisigProgramId);
+        const secondMember = anchor.web3.Keypair.generate().publicKey;
+        const slot = await squads.connection.getSlot();
+        await program.methods
+          .createWithAdmin(1, adminCreateKey, [creator.publicKey], "", creator.publicKey, new BN(slot + 1000))
+          .accounts({
+            multisig: adminMsPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        let msState = await squads.getMultisig(adminMsPDA);
+        expect(msState.admin.toBase58()).to.equal(creator.publicKey.toBase58());
+        expect(msState.adminExpiresAtSlot.toNumber()).to.equal(slot + 1000);
+
+        // the admin changes the config without a vote
+        const adminConfig = (actions: any[]) => program.methods
+          .adminConfigActions(actions)
+          .accounts({
+            multisig: adminMsPDA,
+            admin: creator.publicKey,
+          })
+          .rpc();
+        await adminConfig([
+          { addMember: { member: secondMember } },
+          { changeThreshold: { threshold: 2 } },
+        ]);
+        msState = await squads.getMultisig(adminMsPDA);
+        expect(msState.keys.map((k) => k.toBase58())).to.include(secondMember.toBase58());
+        expect(msState.threshold).to.equal(2);
+
+        await program.methods
+          .renounceAdmin()
+          .accounts({
+            multisig: adminMsPDA,
+            admin: creator.publicKey,
+          })
+          .rpc();
+        msState = await squads.getMultisig(adminMsPDA);
+        expect(msState.admin).to.be.null;
+        try {
+          await adminConfig([{ changeThreshold: { threshold: 1 } }]);
+          expect.fail("applied a config change after renouncing");
+        } catch (e) {
+          expect(e.message).to.contain("NotAdmin");
+        }
+      });
+
+      it(`The admin expiry can't be in the past or too far away`, async function(){
+        const slot = await squads.connection.getSlot();
+        for (const expiresAt of [slot, slot + 6_480_000 + 1000]) {
+          const adminCreateKey = anchor.web3.Keypair.generate().publicKey;
+          const [adminMsPDA] = getMsPDA(adminCreateKey, squads.multisigProgramId);
+          try {
+            await program.methods
+              .createWithAdmin(1, adminCreateKey, [creator.publicKey], "", creator.publicKey, new BN(expiresAt))
+              .accounts({
+                multisig: adminMsPDA,
+                creator: creator.publicKey,
+              })
+              .rpc();
+            expect.fail("created a multisig with an invalid admin expiry");
+          } catch (e) {
+            expect(e.message).to.contain("InvalidAdmin");
+          }
+        }
+      });
+      it(`Track a managed program and its proposed upgrades`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(msPDA, 1);
+        const programAddress = programManagerProgram.programId;
+        const [programData] = anchor.web3.PublicKey.findProgramAddressSync([programAddress.toBuffer()], BPF_UPGRADE_ID);
+        const [managedProgramPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            programAddress.toBuffer(),
+            anchor.utils.bytes.utf8.encode("managedprogram"),
+          ],
+          squads.multisigProgramId
+        );
+        setProgramAuthority(programAddress, vaultPDA);
+
+        // the upgrade authority must be the vault at the given index
+        try {
+          await executeConfig(squads, msPDA, [await program.methods
+            .createManagedProgram(2, "program manager")
+            .accounts({
+              multisig: msPDA,
+              program: programAddress,
+              programData,
+              managedProgram: managedProgramPDA,
+              rentPayer: creator.publicKey,
+            })
+            .instruction()]);
+          expect.fail("registered a program upgraded by another authority");
+        } catch (e) {
+          expect(await squads.connection.getAccountInfo(managedProgramPDA)).to.be.null;
+        }
+        await executeConfig(squads, msPDA, [await program.methods
+          .createManagedProgram(1, "program manager")
+          .accounts({
+            multisig: msPDA,
+            program: programAddress,
+            programData,
+            managedProgram: managedProgramPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction()]);
+        let managedProgram = await program.account.managedProgram.fetch(managedProgramPDA);
+        expect(managedProgram.programAddress.toBase58()).to.equal(programAddress.toBase58());
+        expect(managedProgram.authorityIndex).to.equal(1);
+        expect(managedProgram.upgradeIndex).to.equal(0);
+
+        // propose an upgrade, carried by a vault transaction of the upgrade authority
+        const txState = await squads.createTransaction(msPDA, 1);
+        const buffer = anchor.web3.Keypair.generate().publicKey;
+        const expectedHash = [...createHash("sha256").update("program").digest()];
+        const [programUpgradePDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            managedProgramPDA.toBuffer(),
+            new BN(1).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("programupgrade"),
+          ],
+          squads.multisigProgramId
+        );
+        await program.methods
+          .createProgramUpgrade(buffer, expectedHash)
+          .accounts({
+            multisig: msPDA,
+            managedProgram: managedProgramPDA,
+            transaction: txState.publicKey,
+            programUpgrade: programUpgradePDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        managedProgram = await program.account.managedProgram.fetch(managedProgramPDA);
+        expect(managedProgram.upgradeIndex).to.equal(1);
+        const programUpgrade = await program.account.programUpgrade.fetch(programUpgradePDA);
+        expect(programUpgrade.transaction.toBase58()).to.equal(txState.publicKey.toBase58());
+        expect(programUpgrade.buffer.toBase58()).to.equal(buffer.toBase58());
+        expect(programUpgrade.upgradedAt.toNumber()).to.equal(0);
+
+        await executeConfig(squads, msPDA, [await program.methods
+          .closeProgramUpgrade()
+          .accounts({
+            multisig: msPDA,
+            managedProgram: managedProgramPDA,
+            programUpgrade: programUpgradePDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction()]);
+        await executeConfig(squads, msPDA, [await program.methods
+          .removeManagedProgram()
+          .accounts({
+            multisig: msPDA,
+            managedProgram: managedProgramPDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction()]);
+        expect(await squads.connection.getAccountInfo(managedProgramPDA)).to.be.null;
+        expect(await squads.connection.getAccountInfo(programUpgradePDA)).to.be.null;
+      });
+      it(`Rollbacks need a buffer retained by the last upgrade`, async function(){
+        const programAddress = programManagerProgram.programId;
+        const [programData] = anchor.web3.PublicKey.findProgramAddressSync([programAddress.toBuffer()], BPF_UPGRADE_ID);
+        const [managedProgramPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            programAddress.toBuffer(),
+            anchor.utils.bytes.utf8.encode("managedprogram"),
+          ],
+          squads.multisigProgramId
+        );
+        const [programRollbackPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            managedProgramPDA.toBuffer(),
+            anchor.utils.bytes.utf8.encode("programrollback"),
+          ],
+          squads.multisigProgramId
+        );
+        // the upgrade authority of the program was set to the vault by the previous test
+        await executeConfig(squads, msPDA, [await program.methods
+          .createManagedProgram(1, "program manager")
+          .accounts({
+            multisig: msPDA,
+            program: programAddress,
+            programData,
+            managedProgram: managedProgramPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction()]);
+        await executeConfig(squads, msPDA, [await program.methods
+          .setRollbackThreshold(1)
+          .accounts({
+            multisig: msPDA,
+            managedProgram: managedProgramPDA,
+          })
+          .instruction()]);
+        const managedProgram = await program.account.managedProgram.fetch(managedProgramPDA);
+        expect(managedProgram.rollbackThreshold).to.equal(1);
+        expect(managedProgram.rollbackBuffer).to.be.null;
+
+        // no upgrade retained a rollback buffer yet
+        try {
+          await program.methods
+            .proposeRollback()
+            .accounts({
+              multisig: msPDA,
+              managedProgram: managedProgramPDA,
+              programRollback: programRollbackPDA,
+              member: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("proposed a rollback without a rollback buffer");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidRollbackBuffer");
+        }
+
+        await executeConfig(squads, msPDA, [await program.methods
+          .removeManagedProgram()
+          .accounts({
+            multisig: msPDA,
+            managedProgram: managedProgramPDA,
+            rentCollector: creator.publicKey,
+          })
+          .instruction()]);
+      });
+      it(`Attach an IDL authority change through a typed IDL action`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        const programId = programManagerProgram.programId;
+        const [idlBase] = anchor.web3.PublicKey.findProgramAddressSync([], programId);
+        const idlAddress = await anchor.web3.PublicKey.createWithSeed(idlBase, "anchor:idl", programId);
+        const newAuthority = anchor.web3.Keypair.generate().publicKey;
+
+        const txState = await squads.createTransaction(msPDA, 1);
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const addIdlInstruction = (action: any) => program.methods
+          .addTypedInstruction({idl: {action}})
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            instruction: ixPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        try {
+          await addIdlInstruction({setBuffer: {programId, buffer: idlAddress}});
+          expect.fail("attached an IDL buffer that is the IDL account itself");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidIdlAction");
+        }
+
+        await addIdlInstruction({setAuthority: {programId, newAuthority}});
+        const ixState = await squads.getInstruction(ixPDA);
+        expect(ixState.programId.toBase58()).to.equal(programId.toBase58());
+        // the anchor IDL instruction tag, then the SetAuthority variant and its argument
+        const idlTag = Buffer.from("40f4bc78a7e9690a", "hex");
+        expect(Buffer.from(ixState.data as Buffer).toString("hex")).to.equal(
+          Buffer.concat([idlTag, Buffer.from([4]), newAuthority.toBuffer()]).toString("hex")
+        );
+        expect(ixState.keys[0].pubkey.toBase58()).to.equal(idlAddress.toBase58());
+        expect(ixState.keys[0].isWritable).to.be.true;
+        expect(ixState.keys[1].pubkey.toBase58()).to.equal(vault.toBase58());
+        expect(ixState.keys[1].isSigner).to.be.true;
+      });
+      it(`Execute a transaction from deduplicated remaining accounts`, async function(){
+        const [vault] = await getAuthorityPDA(msPDA, new BN(1), squads.multisigProgramId);
+        await squads.connection.confirmTransaction(
+          await squads.connection.requestAirdrop(vault, LAMPORTS_PER_SOL),
+          "confirmed"
+        );
+        const payee = anchor.web3.Keypair.generate().publicKey;
+        let txState = await squads.createTransaction(msPDA, 1);
+        // both instructions reference the same program, vault and payee
+        for (let i = 0; i < 2; i++) {
+          await squads.addInstruction(
+            txState.publicKey,
+            await createTestTransferTransaction(vault, payee, LAMPORTS_PER_SOL / 10)
+          );
+        }
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+
+        // the instruction accounts in order, then each account only once
+        const [ix1PDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const [ix2PDA] = getIxPDA(txState.publicKey, new BN(2, 10), squads.multisigProgramId);
+        await program.methods
+          .executeTransaction(Buffer.alloc(0))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            member: creator.publicKey,
+          })
+          .remainingAccounts([
+            {pubkey: ix1PDA, isSigner: false, isWritable: false},
+            {pubkey: ix2PDA, isSigner: false, isWritable: false},
+            {pubkey: payee, isSigner: false, isWritable: true},
+            {pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false},
+            {pubkey: vault, isSigner: false, isWritable: true},
+          ])
+          .rpc();
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+        expect(await squads.connection.getBalance(payee)).to.equal(LAMPORTS_PER_SOL / 5);
+      });
+      it(`Close a dissolved multisig and reclaim its rent`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [closingMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        await squads.createMultisig(1, createKey, [creator.publicKey]);
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        const msRent = await squads.connection.getBalance(closingMsPDA);
+
+        // the closure is approved as an internal transaction with the close instruction attached
+        const txState = await squads.createTransaction(closingMsPDA, 0);
+        const [ixPDA] = getIxPDA(txState.publicKey, new BN(1, 10), squads.multisigProgramId);
+        const closeAccounts = {
+          multisig: closingMsPDA,
+          transaction: txState.publicKey,
+          instruction: ixPDA,
+          destinationAccount: destination,
+          creator: creator.publicKey,
+          member: creator.publicKey,
+        };
+        await squads.addInstruction(
+          txState.publicKey,
+          await program.methods.closeMultisig(destination).accounts(closeAccounts).instruction()
+        );
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+
+        // the vaults must be empty
+        const vault = squads.getAuthorityPDA(closingMsPDA, 1);
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(SystemProgram.transfer({
+          fromPubkey: creator.publicKey,
+          toPubkey: vault,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })));
+        const vaultAccount = {pubkey: vault, isSigner: false, isWritable: false};
+        try {
+          await program.methods
+            .closeMultisig(destination)
+            .accounts(closeAccounts)
+            .remainingAccounts([vaultAccount])
+            .rpc();
+          expect.fail("closed a multisig with a funded vault");
+        } catch (e) {
+          expect(e.message).to.contain("MultisigNotEmpty");
+        }
+
+        // empty the vault through a vault transaction, which is done once executed
+        const withdrawTx = await squads.createTransaction(closingMsPDA, 1);
+        await squads.addInstruction(
+          withdrawTx.publicKey,
+          await createTestTransferTransaction(vault, creator.publicKey, LAMPORTS_PER_SOL / 10)
+        );
+        await squads.activateTransaction(withdrawTx.publicKey);
+        await squads.approveTransaction(withdrawTx.publicKey);
+        await squads.executeTransaction(withdrawTx.publicKey);
+
+        // a pending config transaction holds off the closure as well, it takes the index of a transaction
+        const configTxIndex = (await squads.getMultisig(closingMsPDA)).transactionIndex + 1;
+        const [configTxPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            closingMsPDA.toBuffer(),
+            new BN(configTxIndex).toArrayLike(Buffer, "le", 4),
+            anchor.utils.bytes.utf8.encode("configtransaction"),
+          ],
+          squads.multisigProgramId
+        );
+        await program.methods
+          .createConfigTransaction([{ changeThreshold: { threshold: 1 } }])
+          .accounts({
+            multisig: closingMsPDA,
+            transaction: configTxPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        const [unusedTxPDA] = getTxPDA(closingMsPDA, new BN(configTxIndex), squads.multisigProgramId);
+        const transactionAccounts = [
+          {pubkey: withdrawTx.publicKey, isSigner: false, isWritable: false},
+          {pubkey: unusedTxPDA, isSigner: false, isWritable: false},
+          {pubkey: configTxPDA, isSigner: false, isWritable: false},
+        ];
+        try {
+          await program.methods
+            .closeMultisig(destination)
+            .accounts(closeAccounts)
+            .remainingAccounts([vaultAccount, ...transactionAccounts])
+            .rpc();
+          expect.fail("closed a multisig with an active config transaction");
+        } catch (e) {
+          expect(e.message).to.contain("MultisigNotEmpty");
+        }
+        await program.methods
+          .rejectConfigTransaction()
+          .accounts({
+            multisig: closingMsPDA,
+            transaction: configTxPDA,
+            member: creator.publicKey,
+          })
+          .rpc();
+
+        await program.methods
+          .closeMultisig(destination)
+          .accounts(closeAccounts)
+          .remainingAccounts([vaultAccount, ...transactionAccounts])
+          .rpc();
+        expect(await squads.connection.getAccountInfo(closingMsPDA)).to.be.null;
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.be.null;
+        expect(await squads.connection.getBalance(destination)).to.equal(msRent);
+      });
+      it(`Reject a member removal the remaining members can't reach the threshold of`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [removalMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        const otherMember = anchor.web3.Keypair.generate();
+        await squads.createMultisig(2, createKey, [creator.publicKey, otherMember.publicKey]);
+        const approveByBoth = async (txPDA: anchor.web3.PublicKey) => {
+          await squads.approveTransaction(txPDA);
+          await program.methods
+            .approveTransaction()
+            .accounts({
+              multisig: removalMsPDA,
+              transaction: txPDA,
+              member: otherMember.publicKey,
+            })
+            .signers([otherMember])
+            .rpc();
+        };
+
+        // the threshold is no longer lowered to what the remaining members can reach
+        try {
+          await executeConfig(squads, removalMsPDA, [await program.methods
+              .removeMember(otherMember.publicKey)
+              .accounts({
+                multisig: removalMsPDA,
+              })
+              .instruction()], approveByBoth);
+          expect.fail("removed a member below the threshold");
+        } catch (e) {
+          expect(e.message).to.contain("RemovalBelowThreshold");
+        }
+        let msState = await squads.getMultisig(removalMsPDA);
+        expect(msState.keys.length).to.equal(2);
+        expect(msState.threshold).to.equal(2);
+
+        // it has to be lowered explicitly along with the removal
+        await executeConfig(squads, removalMsPDA, [await program.methods
+            .removeMemberAndChangeThreshold(otherMember.publicKey, 1)
+            .accounts({
+              multisig: removalMsPDA,
+            })
+            .instruction()], approveByBoth);
+        msState = await squads.getMultisig(removalMsPDA);
+        expect(msState.keys.length).to.equal(1);
+        expect(msState.threshold).to.equal(1);
+      });
+      it(`Cap the number of members`, async function(){
+        const changeMaxMembersIx = (maxMembers: number) => program.methods
+          .changeMaxMembers(maxMembers)
+          .accounts({
+            multisig: msPDA,
+          })
+          .instruction();
+        let msState = await squads.getMultisig(msPDA);
+        const membersCount = msState.keys.length;
+
+        // the cap can't be below the current members
+        try {
+          await executeConfig(squads, msPDA, [await changeMaxMembersIx(membersCount - 1)]);
+          expect.fail("capped the members below the member count");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidMaxMembers");
+        }
+
+        await executeConfig(squads, msPDA, [await changeMaxMembersIx(membersCount)]);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.maxMembers).to.equal(membersCount);
 
-        // activate the tx
-        let txState = await squads.activateTransaction(txPDA);
-        expect(txState.status).to.have.property("active");
+        // adding a member over the cap fails before the account grows
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, msState.defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        try {
+          await executeConfig(squads, msPDA, [await program.methods
+              .addMember(anchor.web3.Keypair.generate().publicKey)
+              .accounts({
+                multisig: msPDA,
+              })
+              .remainingAccounts([vaultAccount])
+              .instruction()]);
+          expect.fail("added a member over the cap");
+        } catch (e) {
+          expect(e.message).to.contain("MaxMembersReached");
+        }
+        expect((await squads.getMultisig(msPDA)).keys.length).to.equal(membersCount);
 
-        // approve the tx
-        await squads.approveTransaction(txPDA);
+        await executeConfig(squads, msPDA, [await changeMaxMembersIx(0)]);
+        msState = await squads.getMultisig(msPDA);
+        expect(msState.maxMembers).to.equal(0);
+      });
+      it(`Reject duplicate members`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const otherMember = anchor.web3.Keypair.generate().publicKey;
+        // a duplicate key would count twice toward the threshold
+        try {
+          await squads.createMultisig(2, createKey, [creator.publicKey, otherMember, creator.publicKey]);
+          expect.fail("created a multisig with a duplicate member");
+        } catch (e) {
+          expect(e.message).to.contain("DuplicateMember");
+        }
 
-        // execute the tx
+        // the members can be passed in any order, they're stored sorted
+        await squads.createMultisig(1, createKey, [otherMember, creator.publicKey]);
+        const [dupMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        const msState = await squads.getMultisig(dupMsPDA);
+        const keys = (msState.keys as anchor.web3.PublicKey[]).map((k) => k.toBase58());
+        expect(keys).to.deep.equal([...keys].sort((a, b) =>
+          Buffer.compare(new anchor.web3.PublicKey(a).toBuffer(), new anchor.web3.PublicKey(b).toBuffer())));
+
+        // adding an existing member fails instead of being a no-op
+        const txState = await squads.createTransaction(dupMsPDA, 0);
+        await squads.addInstruction(
+          txState.publicKey,
+          await program.methods
+            .addMember(otherMember)
+            .accounts({
+              multisig: dupMsPDA,
+            })
+            .instruction()
+        );
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
         try {
-          await squads.executeTransaction(txPDA);
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("added an existing member");
         } catch (e) {
-          expect(e.message).to.contain("Error processing Instruction");
+          expect(e.message).to.contain("DuplicateMember");
         }
       });
+      it(`Vote as a member of a page of additional members`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [pagedMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        await squads.createMultisig(1, createKey, [creator.publicKey]);
+        const pagedMember = anchor.web3.Keypair.generate();
+        const membersPagePDA = (pageIndex: number) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            pagedMsPDA.toBuffer(),
+            Buffer.from([pageIndex]),
+            anchor.utils.bytes.utf8.encode("members"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const voteRecordPDA = (txPDA: anchor.web3.PublicKey, member: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            member.toBuffer(),
+            anchor.utils.bytes.utf8.encode("vote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const approveAsMembers = async (txPDA: anchor.web3.PublicKey) => {
+          const msState = await squads.getMultisig(pagedMsPDA);
+          if (msState.voteRecords) {
+            await program.methods
+              .createVoteRecord(true)
+              .accounts({
+                multisig: pagedMsPDA,
+                transaction: txPDA,
+                voteRecord: voteRecordPDA(txPDA, creator.publicKey),
+                member: creator.publicKey,
+                payer: creator.publicKey,
+              })
+              .rpc();
+          } else {
+            await squads.approveTransaction(txPDA);
+          }
+          if (msState.threshold > 1) {
+            await program.methods
+              .createPagedVoteRecord(true)
+              .accounts({
+                multisig: pagedMsPDA,
+                transaction: txPDA,
+                membersPage: membersPagePDA(0),
+                voteRecord: voteRecordPDA(txPDA, pagedMember.publicKey),
+                member: pagedMember.publicKey,
+                payer: creator.publicKey,
+              })
+              .signers([pagedMember])
+              .rpc();
+          }
+        };
 
-      it(`Change vote from approved to rejected`, async function(){
-        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
-        const [txInstructions, txPDA] = await (
-          await txBuilder.withChangeThreshold(2)
-        ).executeInstructions();
+        // member pages can only be voted from with vote records
+        const createPageIx = await program.methods
+          .createMemberPage()
+          .accounts({
+            multisig: pagedMsPDA,
+            membersPage: membersPagePDA(0),
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        try {
+          await executeConfig(squads, pagedMsPDA, [createPageIx], approveAsMembers);
+          expect.fail("created a member page without vote records");
+        } catch (e) {
+          expect(e.message).to.contain("VoteRecordsRequired");
+        }
+        await executeConfig(squads, pagedMsPDA, [await program.methods.setVoteRecords(true).accounts({ multisig: pagedMsPDA }).instruction()], approveAsMembers);
+        await executeConfig(squads, pagedMsPDA, [createPageIx], approveAsMembers);
+        let msState = await squads.getMultisig(pagedMsPDA);
+        expect(msState.memberPages).to.equal(1);
 
-        // get the ix
-        let ixState = await squads.getInstruction(
-          getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId)[0]
-        );
-        expect(ixState.instructionIndex).to.equal(1);
+        await executeConfig(squads, pagedMsPDA, [await program.methods
+          .addPagedMember(pagedMember.publicKey)
+          .accounts({
+            multisig: pagedMsPDA,
+            membersPage: membersPagePDA(0),
+          })
+          .instruction()], approveAsMembers);
+        await executeConfig(squads, pagedMsPDA, [await program.methods
+          .changeThreshold(2)
+          .accounts({
+            multisig: pagedMsPDA,
+          })
+          .instruction()], approveAsMembers);
+        msState = await squads.getMultisig(pagedMsPDA);
+        expect(msState.pagedMemberCount).to.equal(1);
+        expect(msState.threshold).to.equal(2);
+        const page = await program.account.msMembers.fetch(membersPagePDA(0));
+        expect(page.keys.map((k) => k.toBase58())).to.deep.equal([pagedMember.publicKey.toBase58()]);
 
-        // activate the tx
-        let txState = await squads.activateTransaction(txPDA);
-        expect(txState.status).to.have.property("active");
+        // the paged member's vote counts toward the threshold
+        const txState = await squads.createTransaction(pagedMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await program.methods
+          .createVoteRecord(true)
+          .accounts({
+            multisig: pagedMsPDA,
+            transaction: txState.publicKey,
+            voteRecord: voteRecordPDA(txState.publicKey, creator.publicKey),
+            member: creator.publicKey,
+            payer: creator.publicKey,
+          })
+          .rpc();
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("active");
+        await program.methods
+          .createPagedVoteRecord(true)
+          .accounts({
+            multisig: pagedMsPDA,
+            transaction: txState.publicKey,
+            membersPage: membersPagePDA(0),
+            voteRecord: voteRecordPDA(txState.publicKey, pagedMember.publicKey),
+            member: pagedMember.publicKey,
+            payer: creator.publicKey,
+          })
+          .signers([pagedMember])
+          .rpc();
+        const votedTx = await squads.getTransaction(txState.publicKey);
+        expect(votedTx.status).to.have.property("executeReady");
+        expect(votedTx.approvedWeight).to.equal(2);
+
+        // the paged member can't be removed while the threshold needs it
+        const removePagedMemberIx = await program.methods
+          .removePagedMember(pagedMember.publicKey)
+          .accounts({
+            multisig: pagedMsPDA,
+            membersPage: membersPagePDA(0),
+          })
+          .instruction();
+        try {
+          await executeConfig(squads, pagedMsPDA, [removePagedMemberIx], approveAsMembers);
+          expect.fail("removed a paged member below the threshold");
+        } catch (e) {
+          expect(e.message).to.contain("RemovalBelowThreshold");
+        }
+      });
+      it(`Vote as a member of the member root with an inclusion proof`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [rootMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        await squads.createMultisig(1, createKey, [creator.publicKey]);
+        const voteRecordPDA = (txPDA: anchor.web3.PublicKey, member: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            txPDA.toBuffer(),
+            member.toBuffer(),
+            anchor.utils.bytes.utf8.encode("vote"),
+          ],
+          squads.multisigProgramId
+        )[0];
+        const approveAsCreator = async (txPDA: anchor.web3.PublicKey) => {
+          if ((await squads.getMultisig(rootMsPDA)).voteRecords) {
+            await program.methods
+              .createVoteRecord(true)
+              .accounts({
+                multisig: rootMsPDA,
+                transaction: txPDA,
+                voteRecord: voteRecordPDA(txPDA, creator.publicKey),
+                member: creator.publicKey,
+                payer: creator.publicKey,
+              })
+              .rpc();
+          } else {
+            await squads.approveTransaction(txPDA);
+          }
+        };
 
-        // approve the tx
-        txState = await squads.approveTransaction(txPDA);
+        // a tree of four members, leaves and nodes are hashed with domain prefixes
+        const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+        const node = (a: Buffer, b: Buffer) => Buffer.compare(a, b) <= 0
+          ? sha256(Buffer.from([1]), a, b)
+          : sha256(Buffer.from([1]), b, a);
+        const rootMembers = [...Array(4)].map(() => anchor.web3.Keypair.generate());
+        const leaves = rootMembers.map((m) => sha256(Buffer.from([0]), m.publicKey.toBuffer()));
+        const left = node(leaves[0], leaves[1]);
+        const right = node(leaves[2], leaves[3]);
+        const root = node(left, right);
+
+        await executeConfig(squads, rootMsPDA, [await program.methods.setVoteRecords(true).accounts({ multisig: rootMsPDA }).instruction()], approveAsCreator);
+        await executeConfig(squads, rootMsPDA, [await program.methods
+          .setMemberRoot([...root], rootMembers.length)
+          .accounts({
+            multisig: rootMsPDA,
+          })
+          .instruction()], approveAsCreator);
+        let msState = await squads.getMultisig(rootMsPDA);
+        expect(Buffer.from(msState.memberRoot).equals(root)).to.be.true;
+        expect(msState.rootMemberCount).to.equal(rootMembers.length);
 
-        // check that state is "approved"
-        expect(txState.status).to.have.property("active");
-        expect(
-          txState.approved
-            .map((k) => k.toBase58())
-            .indexOf(creator.publicKey.toBase58())
-        ).is.greaterThanOrEqual(0);
+        const txState = await squads.createTransaction(rootMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        const voteWithProof = (member: anchor.web3.Keypair, proof: Buffer[]) => program.methods
+          .createProofVoteRecord(true, proof.map((p) => [...p]))
+          .accounts({
+            multisig: rootMsPDA,
+            transaction: txState.publicKey,
+            voteRecord: voteRecordPDA(txState.publicKey, member.publicKey),
+            member: member.publicKey,
+            payer: creator.publicKey,
+          })
+          .signers([member])
+          .rpc();
 
-        // now reject
-        txState = await squads.rejectTransaction(txPDA);
-        expect(txState.status).to.have.property("active");
-        expect(
-          txState.rejected
-            .map((k) => k.toBase58())
-            .indexOf(creator.publicKey.toBase58())
-        ).is.greaterThanOrEqual(0);
-        expect(
-          txState.approved
-            .map((k) => k.toBase58())
-            .indexOf(creator.publicKey.toBase58())
-        ).is.lessThan(0);
+        // a proof for another member doesn't prove membership
+        try {
+          await voteWithProof(rootMembers[0], [leaves[0], right]);
+          expect.fail("voted with an invalid proof");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidMemberProof");
+        }
+        await voteWithProof(rootMembers[0], [leaves[1], right]);
+        const votedTx = await squads.getTransaction(txState.publicKey);
+        expect(votedTx.status).to.have.property("executeReady");
+        expect(votedTx.approvedWeight).to.equal(1);
       });
+      it(`Hold the execution until the token holders ratify the transaction`, async function(){
+        const createKey = anchor.web3.Keypair.generate().publicKey;
+        const [ratifiedMsPDA] = getMsPDA(createKey, squads.multisigProgramId);
+        await squads.createMultisig(1, createKey, [creator.publicKey]);
+        const ratification = {
+          governanceProgram: anchor.web3.Keypair.generate().publicKey,
+          governance: anchor.web3.Keypair.generate().publicKey,
+          governingTokenMint: anchor.web3.Keypair.generate().publicKey,
+        };
+        const configTxState = await squads.createTransaction(ratifiedMsPDA, 0);
+        await squads.addInstruction(configTxState.publicKey, await program.methods
+          .setRatification(ratification)
+          .accounts({
+            multisig: ratifiedMsPDA,
+          })
+          .instruction());
+        await squads.activateTransaction(configTxState.publicKey);
+        await squads.approveTransaction(configTxState.publicKey);
+        await squads.executeTransaction(configTxState.publicKey);
+        const msState = await squads.getMultisig(ratifiedMsPDA);
+        expect(msState.ratification.governance.toBase58()).to.equal(ratification.governance.toBase58());
+
+        // a transaction without a proposal can't be executed
+        const txState = await squads.createTransaction(ratifiedMsPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        try {
+          await squads.executeTransaction(txState.publicKey);
+          expect.fail("executed without a ratifying proposal");
+        } catch (e) {
+          expect(e.message).to.contain("RatificationRequired");
+        }
 
-      it(`Add a new member & change threshold (conjoined)`, async function(){
-        const newMember = anchor.web3.Keypair.generate().publicKey;
-        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
-        let msState =  await squads.getMultisig(msPDA);
-        const startKeys = msState.keys.length;
-        const startTxIndex = msState.transactionIndex;
-        const [txInstructions, txPDA] = await (
-          await txBuilder.withAddMemberAndChangeThreshold(newMember, 1)
-        ).getInstructions();
-        const activateIx = await squads.buildActivateTransaction(msPDA, txPDA);
-
-        let addMemberTx = await createBlankTransaction(
-          squads.connection,
-          creator.publicKey
+        // nor cranked as a recurring transaction
+        const [recurringPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            ratifiedMsPDA.toBuffer(),
+            txState.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("recurring"),
+          ],
+          squads.multisigProgramId
         );
-        addMemberTx.add(...txInstructions);
-        addMemberTx.add(activateIx);
+        const recurringTxState = await squads.createTransaction(ratifiedMsPDA, 0);
+        await squads.addInstruction(recurringTxState.publicKey, await program.methods
+          .createRecurringTransaction(new BN(60), new BN(Math.floor(Date.now() / 1000)))
+          .accounts({
+            multisig: ratifiedMsPDA,
+            transaction: txState.publicKey,
+            recurringTransaction: recurringPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction());
+        await squads.activateTransaction(recurringTxState.publicKey);
+        await squads.approveTransaction(recurringTxState.publicKey);
+        await squads.executeTransaction(recurringTxState.publicKey);
         try {
-          await provider.sendAndConfirm(addMemberTx), undefined, {commitment: "confirmed"};
+          await program.methods
+            .executeRecurringTransaction(Buffer.from([]))
+            .accounts({
+              multisig: ratifiedMsPDA,
+              transaction: txState.publicKey,
+              recurringTransaction: recurringPDA,
+              cranker: creator.publicKey,
+            })
+            .rpc();
+          expect.fail("cranked a recurring tx without a ratifying proposal");
         } catch (e) {
-          console.log("Failed to send add member tx");
-          throw e;
+          expect(e.message).to.contain("RatificationRequired");
         }
-        msState = await squads.getMultisig(msPDA);
-        expect(startTxIndex + 1).to.equal(msState.transactionIndex);
-        // get necessary signers
-        // if the threshold has changed, use the other members to approve as well
-        for (let i = 0; i < memberList.length; i++) {
-          // check to see if we need more signers
-          const approvalState = await squads.getTransaction(txPDA);
-          if (Object.keys(approvalState.status).indexOf("active") < 0) {
-            break;
-          }
 
-          const inMultisig = (msState.keys as anchor.web3.PublicKey[]).findIndex(
-            (k) => {
-              return k.toBase58() == memberList[i].publicKey.toBase58();
-            }
+        // and the proposal has to be one of the governance
+        const proposal = creator.publicKey;
+        const ratifiedTxState = await squads.createTransaction(ratifiedMsPDA, 1);
+        await program.methods
+          .setRatifyingProposal(proposal)
+          .accounts({
+            multisig: ratifiedMsPDA,
+            transaction: ratifiedTxState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        expect((await squads.getTransaction(ratifiedTxState.publicKey)).ratifyingProposal.toBase58()).to.equal(proposal.toBase58());
+        await squads.activateTransaction(ratifiedTxState.publicKey);
+        await squads.approveTransaction(ratifiedTxState.publicKey);
+        try {
+          await program.methods
+            .executeTransaction(Buffer.from([]))
+            .accounts({
+              multisig: ratifiedMsPDA,
+              transaction: ratifiedTxState.publicKey,
+              member: creator.publicKey,
+            })
+            .remainingAccounts([{ pubkey: proposal, isSigner: false, isWritable: false }])
+            .rpc();
+          expect.fail("executed with a proposal of another program");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidRatifyingProposal");
+        }
+        expect((await squads.getTransaction(ratifiedTxState.publicKey)).status).to.have.property("executeReady");
+      });
+      it(`Reject an approved transaction with the vetoes of the veto council`, async function(){
+        const vetoCouncil = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+        for (const councilMember of vetoCouncil) {
+          await squads.connection.confirmTransaction(
+            await squads.connection.requestAirdrop(councilMember.publicKey, LAMPORTS_PER_SOL),
+            "confirmed"
           );
-          if (inMultisig < 0) {
-            continue;
-          }
-          try {
-            await provider.connection.requestAirdrop(
-              memberList[i].publicKey,
-              anchor.web3.LAMPORTS_PER_SOL
-            );
-            const approveTx = await program.methods
-              .approveTransaction()
-              .accounts({
-                multisig: msPDA,
-                transaction: txPDA,
-                member: memberList[i].publicKey,
-              })
-              .signers([memberList[i]])
-              .transaction();
-            try {
-              await provider.sendAndConfirm(approveTx, [memberList[i]]);
-            } catch (e) {
-              console.log(memberList[i].publicKey.toBase58(), " signing error");
-            }
-          } catch (e) {
-            console.log(e);
-          }
         }
+        // the default vault tops up the rent if the multisig has to grow
+        const vaultAccount = {
+          pubkey: squads.getAuthorityPDA(msPDA, (await squads.getMultisig(msPDA)).defaultVaultIndex),
+          isSigner: false,
+          isWritable: true,
+        };
+        const setVetoCouncilIx = (keys: anchor.web3.PublicKey[], threshold: number) => program.methods
+          .setVetoCouncil(keys, threshold)
+          .accounts({
+            multisig: msPDA,
+          })
+          .remainingAccounts([vaultAccount])
+          .instruction();
 
-        let txState = await squads.getTransaction(txPDA);
-        expect(txState.status).has.property("executeReady");
+        // members can't sit on the veto council
+        try {
+          await executeConfig(squads, msPDA, [await setVetoCouncilIx([creator.publicKey], 1)]);
+          expect.fail("added a member to the veto council");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidVetoCouncil");
+        }
+        await executeConfig(squads, msPDA, [await setVetoCouncilIx(vetoCouncil.map((k) => k.publicKey), 2)]);
+        const msState = await squads.getMultisig(msPDA);
+        expect(msState.vetoCouncil.length).to.equal(2);
+        expect(msState.vetoCouncilThreshold).to.equal(2);
 
-        const payer = memberList[4];
-        await provider.connection.requestAirdrop(
-          payer.publicKey,
-          anchor.web3.LAMPORTS_PER_SOL
-        );
+        const txState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executeReady");
+        const councilVeto = (councilMember: anchor.web3.Keypair) => program.methods
+          .councilVetoTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            councilMember: councilMember.publicKey,
+          })
+          .signers([councilMember])
+          .rpc();
 
-        await agnosticExecute(squads, txPDA, payer);
+        await councilVeto(vetoCouncil[0]);
+        expect((await squads.getTransaction(txState.publicKey)).status).to.have.property("executeReady");
+        try {
+          await councilVeto(vetoCouncil[0]);
+          expect.fail("vetoed twice");
+        } catch (e) {
+          expect(e.message).to.contain("AlreadyVetoed");
+        }
+        // the second veto reaches the threshold, and rejects the approved transaction
+        await councilVeto(vetoCouncil[1]);
+        const vetoedTxState = await squads.getTransaction(txState.publicKey);
+        expect(vetoedTxState.status).to.have.property("rejected");
+        expect(vetoedTxState.councilVetoes.length).to.equal(2);
+
+        // the council can veto a recurring transaction too, which ends its schedule
+        const recurringTxState = await squads.createTransaction(msPDA, 1);
+        await squads.activateTransaction(recurringTxState.publicKey);
+        await squads.approveTransaction(recurringTxState.publicKey);
+        const [recurringPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            recurringTxState.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("recurring"),
+          ],
+          squads.multisigProgramId
+        );
+        await executeConfig(squads, msPDA, [await program.methods
+          .createRecurringTransaction(new BN(60), new BN(Math.floor(Date.now() / 1000)))
+          .accounts({
+            multisig: msPDA,
+            transaction: recurringTxState.publicKey,
+            recurringTransaction: recurringPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction()]);
+        expect((await squads.getTransaction(recurringTxState.publicKey)).status).to.have.property("recurring");
+        const councilVetoRecurring = (councilMember: anchor.web3.Keypair) => program.methods
+          .councilVetoRecurringTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: recurringTxState.publicKey,
+            recurringTransaction: recurringPDA,
+            creator: creator.publicKey,
+            councilMember: councilMember.publicKey,
+          })
+          .signers([councilMember])
+          .rpc();
+        await councilVetoRecurring(vetoCouncil[0]);
+        expect(await provider.connection.getAccountInfo(recurringPDA)).to.not.be.null;
+        await councilVetoRecurring(vetoCouncil[1]);
+        expect((await squads.getTransaction(recurringTxState.publicKey)).status).to.have.property("rejected");
+        expect(await provider.connection.getAccountInfo(recurringPDA)).to.be.null;
+
+        await executeConfig(squads, msPDA, [await setVetoCouncilIx([], 0)]);
+        expect((await squads.getMultisig(msPDA)).vetoCouncil).to.be.empty;
+      });
+      it(`Check the oracle price condition on every run of a recurring tx`, async function(){
+        const txState = await squads.createTransaction(msPDA, 1);
+        await program.methods
+          .setExecutionCondition({
+            oracle: { pyth: {} },
+            priceAccount: anchor.web3.Keypair.generate().publicKey,
+            comparison: { above: {} },
+            price: new BN(100),
+            expo: 0,
+            maxAge: 60,
+          })
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        await squads.addInstruction(
+          txState.publicKey,
+          await createTestTransferTransaction(squads.getAuthorityPDA(msPDA, 1), creator.publicKey, 1)
+        );
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
 
-        txState = await squads.getTransaction(txPDA);
-        expect(txState.status).has.property("executed");
-        msState = await squads.getMultisig(msPDA);
-        threshold = msState.threshold;
-        expect((msState.keys as any[]).length).to.equal(startKeys + 1);
-        expect(msState.threshold).to.equal(1);
+        const [recurringPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            msPDA.toBuffer(),
+            txState.publicKey.toBuffer(),
+            anchor.utils.bytes.utf8.encode("recurring"),
+          ],
+          squads.multisigProgramId
+        );
+        const createRecurringIx = await program.methods
+          .createRecurringTransaction(new BN(60), new BN(Math.floor(Date.now() / 1000)))
+          .accounts({
+            multisig: msPDA,
+            transaction: txState.publicKey,
+            recurringTransaction: recurringPDA,
+            rentPayer: creator.publicKey,
+          })
+          .instruction();
+        const txBuilder = await squads.getTransactionBuilder(msPDA, 0);
+        const [, configTxPDA] = await txBuilder
+          .withInstruction(createRecurringIx)
+          .executeInstructions();
+        await squads.activateTransaction(configTxPDA);
+        await squads.approveTransaction(configTxPDA);
+        await squads.executeTransaction(configTxPDA);
+
+        const executeIx = await squads.buildExecuteTransaction(txState.publicKey, creator.publicKey);
+        const { accountList } = program.coder.instruction.decode(executeIx.data).data as { accountList: Buffer };
+        try {
+          await program.methods
+            .executeRecurringTransaction(accountList)
+            .accounts({
+              multisig: msPDA,
+              transaction: txState.publicKey,
+              recurringTransaction: recurringPDA,
+              cranker: creator.publicKey,
+            })
+            .remainingAccounts(executeIx.keys.slice(3))
+            .rpc();
+          expect.fail("cranked a recurring tx without checking the execution condition");
+        } catch (e) {
+          expect(e.message).to.contain("InvalidPriceAccount");
+        }
+        const recurringState = await program.account.recurringTransaction.fetch(recurringPDA);
+        expect(recurringState.executions).to.equal(0);
       });
     });
 
@@ -1423,8 +6773,10 @@ describe("Programs", function(){
           expect(true).to.equal(false);
         }
         txState = await program.account.msTransaction.fetch(txPDA);
-        expect(txState.approved.length).to.equal(1);
-        expect(txState.approved[0].toBase58()).to.equal(userWithVoteRoleDelegatePDA.toBase58());
+        msState = await program.account.ms.fetch(msPDA);
+        const approvedMembers = bitmapMembers(txState.approved, msState.keys as anchor.web3.PublicKey[]);
+        expect(approvedMembers.length).to.equal(1);
+        expect(approvedMembers[0].toBase58()).to.equal(userWithVoteRoleDelegatePDA.toBase58());
 
         // sign to approve with the other members
         await memberListApprove(memberList, msPDA, txPDA, squads, provider, program);